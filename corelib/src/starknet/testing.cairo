@@ -11,7 +11,9 @@
 #[allow(unused_imports)]
 use core::array::{ArrayTrait, SpanTrait};
 use core::traits::Into;
-use starknet::ContractAddress;
+use starknet::storage::StorageAsPointer;
+use starknet::storage_access::storage_address_from_base;
+use starknet::{ClassHash, ContractAddress};
 
 /// A general cheatcode function used to simplify implementation of Starknet testing functions.
 ///
@@ -189,6 +191,25 @@ pub fn set_block_hash(block_number: u64, value: felt252) {
     cheatcode::<'set_block_hash'>([block_number.into(), value].span());
 }
 
+/// Advances the simulated chain by the given number of blocks.
+///
+/// # Arguments
+///
+/// `n_blocks` - The number of blocks to advance.
+/// `block_time` - The number of seconds between consecutive blocks.
+///
+/// Each advanced block is closed and recorded in the block history, and the block number and
+/// timestamp of the following block are updated accordingly.
+/// Once a block is at least 10 blocks behind the current block,
+/// `starknet::syscalls::get_block_hash_syscall` returns its hash, and fails with
+/// `'Block number out of range'` for more recent blocks, as in Starknet.
+///
+/// The run fails if more than 10000 blocks are advanced at once, or if the block number or
+/// timestamp would overflow.
+pub fn advance_blocks(n_blocks: u64, block_time: u64) {
+    cheatcode::<'advance_blocks'>([n_blocks.into(), block_time.into()].span());
+}
+
 /// Pop the earliest unpopped logged event for the contract.
 ///
 /// # Arguments
@@ -251,6 +272,229 @@ pub fn pop_log<T, +starknet::Event<T>>(address: ContractAddress) -> Option<T> {
     starknet::Event::deserialize(ref keys, ref data)
 }
 
+/// An event emitted by a contract, as collected by an [`EventSpy`].
+#[derive(Drop, Clone, Debug, PartialEq, Serde)]
+pub struct SpiedEvent {
+    /// The address of the contract that emitted the event.
+    pub from: ContractAddress,
+    /// The keys of the event.
+    pub keys: Span<felt252>,
+    /// The data of the event.
+    pub data: Span<felt252>,
+}
+
+/// A spy on the events emitted by all contracts, started by [`spy_events`].
+#[derive(Copy, Drop)]
+pub struct EventSpy {
+    /// The number of events emitted before the spy was started.
+    start: u32,
+}
+
+/// Starts a spy on the events emitted by all contracts from now on.
+///
+/// Unlike [`pop_log`], the spy collects the events of all contracts in order of emission, and
+/// fetching them does not consume them.
+///
+/// # Examples
+///
+/// ```
+/// use starknet::testing::{EventSpyTrait, SpiedEventsTrait, spy_events};
+///
+/// #[test]
+/// fn test_transfer_events() {
+///     let spy = spy_events();
+///     let token = deploy_token();
+///     token.transfer(recipient, 100);
+///     let events = spy.get_events();
+///     events.assert_emitted(token.contract_address, @token::Event::Transfer(
+///         token::Transfer { from: owner, to: recipient, amount: 100 },
+///     ));
+///     assert_eq!(events.emitted_by(token.contract_address).events.len(), 2);
+/// }
+/// ```
+pub fn spy_events() -> EventSpy {
+    let mut output = cheatcode::<'spy_events'>([].span());
+    EventSpy { start: Serde::deserialize(ref output).unwrap() }
+}
+
+/// Functions of an [`EventSpy`].
+#[generate_trait]
+pub impl EventSpyImpl of EventSpyTrait {
+    /// Returns the events emitted by all contracts since the spy was started, in order of emission.
+    fn get_events(self: @EventSpy) -> SpiedEvents {
+        let mut output = cheatcode::<'spied_events'>([(*self.start).into()].span());
+        SpiedEvents { events: Serde::deserialize(ref output).unwrap() }
+    }
+}
+
+/// Events collected by an [`EventSpy`], in order of emission.
+#[derive(Drop, Clone, Debug, PartialEq)]
+pub struct SpiedEvents {
+    /// The collected events.
+    pub events: Array<SpiedEvent>,
+}
+
+/// Filters, decoding and assertions on [`SpiedEvents`].
+///
+/// Events are decoded using their `starknet::Event` implementation, usually derived on the `Event`
+/// enum of the emitting contract. Events that do not decode as the requested type are skipped by
+/// decoding, and are shown in their raw form in failure messages.
+#[generate_trait]
+pub impl SpiedEventsImpl of SpiedEventsTrait {
+    /// Returns the events emitted by the contract at the given address.
+    fn emitted_by(self: @SpiedEvents, from: ContractAddress) -> SpiedEvents {
+        let mut events = array![];
+        for event in self.events.span() {
+            if *event.from == from {
+                events.append(event.clone());
+            }
+        }
+        SpiedEvents { events }
+    }
+
+    /// Returns the events whose first key is the given selector - for derived events, the
+    /// selector of the name of the event variant.
+    fn with_selector(self: @SpiedEvents, selector: felt252) -> SpiedEvents {
+        let mut events = array![];
+        for event in self.events.span() {
+            let keys = *event.keys;
+            if !keys.is_empty() && *keys[0] == selector {
+                events.append(event.clone());
+            }
+        }
+        SpiedEvents { events }
+    }
+
+    /// Returns the events that decode as `T`, decoded.
+    fn decode<T, +starknet::Event<T>, +Drop<T>>(self: @SpiedEvents) -> Array<T> {
+        let mut decoded = array![];
+        for event in self.events.span() {
+            if let Some(value) = decode_event(event) {
+                decoded.append(value);
+            }
+        }
+        decoded
+    }
+
+    /// Asserts that the given event was emitted by the contract at the given address.
+    fn assert_emitted<T, +starknet::Event<T>, +PartialEq<T>, +core::fmt::Debug<T>, +Drop<T>>(
+        self: @SpiedEvents, from: ContractAddress, event: @T,
+    ) {
+        let emitted = self.emitted_by(from);
+        if !contains_event(@emitted, event) {
+            panic!(
+                "Event `{}` was not emitted by `{:?}`.\nEmitted events: {}",
+                format_event(event),
+                from,
+                format_events::<T>(@emitted),
+            );
+        }
+    }
+
+    /// Asserts that the given event was not emitted by the contract at the given address.
+    fn assert_not_emitted<T, +starknet::Event<T>, +PartialEq<T>, +core::fmt::Debug<T>, +Drop<T>>(
+        self: @SpiedEvents, from: ContractAddress, event: @T,
+    ) {
+        let emitted = self.emitted_by(from);
+        if contains_event(@emitted, event) {
+            panic!(
+                "Event `{}` was emitted by `{:?}`.\nEmitted events: {}",
+                format_event(event),
+                from,
+                format_events::<T>(@emitted),
+            );
+        }
+    }
+
+    /// Asserts that the events emitted by the contract at the given address are exactly the given
+    /// events, in order.
+    fn assert_events<T, +starknet::Event<T>, +PartialEq<T>, +core::fmt::Debug<T>, +Drop<T>>(
+        self: @SpiedEvents, from: ContractAddress, expected: Span<T>,
+    ) {
+        let emitted = self.emitted_by(from);
+        let mut matching = emitted.events.len() == expected.len();
+        if matching {
+            for (event, expected_event) in emitted.events.span().into_iter().zip(expected) {
+                match decode_event::<T>(event) {
+                    Some(value) => if @value != expected_event {
+                        matching = false;
+                    },
+                    None => { matching = false; },
+                }
+            }
+        }
+        if !matching {
+            let mut expected_events: ByteArray = "[";
+            for (i, event) in expected.into_iter().enumerate() {
+                if i != 0 {
+                    expected_events.append(@", ");
+                }
+                expected_events.append(@format_event(event));
+            }
+            expected_events.append(@"]");
+            panic!(
+                "Unexpected events emitted by `{:?}`.\nExpected: {}\nActual: {}",
+                from,
+                expected_events,
+                format_events::<T>(@emitted),
+            );
+        }
+    }
+}
+
+/// Decodes a spied event as `T`, if it fully decodes as one.
+fn decode_event<T, +starknet::Event<T>, +Drop<T>>(event: @SpiedEvent) -> Option<T> {
+    let mut keys = *event.keys;
+    let mut data = *event.data;
+    let value = starknet::Event::deserialize(ref keys, ref data)?;
+    if keys.is_empty() && data.is_empty() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Returns whether any of the given events decodes as `T` to the given event.
+fn contains_event<T, +starknet::Event<T>, +PartialEq<T>, +Drop<T>>(
+    events: @SpiedEvents, event: @T,
+) -> bool {
+    let mut found = false;
+    for spied in events.events.span() {
+        if let Some(value) = decode_event::<T>(spied) {
+            if @value == event {
+                found = true;
+                break;
+            }
+        }
+    }
+    found
+}
+
+/// Formats an event using its `Debug` implementation.
+fn format_event<T, +core::fmt::Debug<T>>(event: @T) -> ByteArray {
+    let mut formatter: core::fmt::Formatter = Default::default();
+    core::fmt::Debug::fmt(event, ref formatter).unwrap();
+    formatter.buffer
+}
+
+/// Formats the given events, decoded as `T` where possible, and raw otherwise.
+fn format_events<T, +starknet::Event<T>, +core::fmt::Debug<T>, +Drop<T>>(
+    events: @SpiedEvents,
+) -> ByteArray {
+    let mut formatted: ByteArray = "[";
+    for (i, event) in events.events.span().into_iter().enumerate() {
+        if i != 0 {
+            formatted.append(@", ");
+        }
+        match decode_event::<T>(event) {
+            Some(value) => formatted.append(@format_event(@value)),
+            None => formatted.append(@format!("{:?}", event)),
+        }
+    }
+    formatted.append(@"]");
+    formatted
+}
+
 // TODO(Ilya): Decide if we limit the type of `to_address`.
 /// Pop the earliest unpopped l2 to l1 message for the contract.
 ///
@@ -266,3 +510,212 @@ pub fn pop_l2_to_l1_message(address: ContractAddress) -> Option<(felt252, Span<f
     let mut l2_to_l1_message = cheatcode::<'pop_l2_to_l1_message'>([address.into()].span());
     Some((Serde::deserialize(ref l2_to_l1_message)?, Serde::deserialize(ref l2_to_l1_message)?))
 }
+
+/// Delivers a message sent from L1 to the `#[l1_handler]` function of a deployed contract, as done
+/// by the sequencer for an L1 to L2 message.
+///
+/// # Arguments
+///
+/// `to_address` - The address of the contract handling the message.
+/// `selector` - The selector of the name of the L1 handler function.
+/// `from_address` - The address of the L1 contract sending the message, passed as the
+/// `from_address` argument of the handler.
+/// `payload` - The payload of the message, passed as the rest of the arguments of the handler.
+///
+/// The handler is called with a zero caller address. Returns the panic data of the handler if it
+/// failed, in which case its changes are reverted.
+///
+/// # Examples
+///
+/// ```
+/// #[starknet::contract]
+/// mod bridge {
+///     #[l1_handler]
+///     fn deposit(ref self: ContractState, from_address: felt252, account: felt252, amount: u128) {
+///         ...
+///     }
+/// }
+///
+/// #[test]
+/// fn test_deposit() {
+///     let bridge_address = deploy_bridge();
+///     starknet::testing::call_l1_handler(
+///         bridge_address, selector!("deposit"), L1_BRIDGE_ADDRESS, [ACCOUNT, 100].span(),
+///     )
+///         .unwrap();
+/// }
+/// ```
+pub fn call_l1_handler(
+    to_address: ContractAddress, selector: felt252, from_address: felt252, payload: Span<felt252>,
+) -> Result<(), Array<felt252>> {
+    let mut input = array![to_address.into(), selector, from_address];
+    input.append_span(payload);
+    let mut output = cheatcode::<'call_l1_handler'>(input.span());
+    Serde::deserialize(ref output).unwrap()
+}
+
+/// Returns the address `starknet::syscalls::deploy_syscall` would deploy a contract at, if called
+/// with the same arguments from the current contract.
+///
+/// # Arguments
+///
+/// `class_hash` - The class hash of the contract.
+/// `contract_address_salt` - The salt of the deployment.
+/// `calldata` - The calldata of the constructor.
+/// `deploy_from_zero` - Whether the contract is deployed from the zero address, and not from the
+/// current contract.
+///
+/// Useful for passing the address of a contract to another contract before deploying it, e.g. for
+/// contracts that refer to each other.
+///
+/// # Examples
+///
+/// ```
+/// let token_address = starknet::testing::precalculate_address(
+///     token::TEST_CLASS_HASH, 0, [].span(), false,
+/// );
+/// // The minter refers to the token, which is only deployed afterwards.
+/// deploy_syscall(minter::TEST_CLASS_HASH, 0, [token_address.into()].span(), false).unwrap();
+/// let (deployed_address, _) = deploy_syscall(token::TEST_CLASS_HASH, 0, [].span(), false)
+///     .unwrap();
+/// assert_eq!(deployed_address, token_address);
+/// ```
+pub fn precalculate_address(
+    class_hash: ClassHash,
+    contract_address_salt: felt252,
+    calldata: Span<felt252>,
+    deploy_from_zero: bool,
+) -> ContractAddress {
+    let mut input = array![class_hash.into(), contract_address_salt, deploy_from_zero.into()];
+    input.append_span(calldata);
+    let output = cheatcode::<'precalculate_address'>(input.span());
+    (*output[0]).try_into().unwrap()
+}
+
+/// Stores values directly in the storage of a contract, without running any of its code.
+///
+/// # Arguments
+///
+/// `address` - The address of the contract.
+/// `key` - The storage address of the first value.
+/// `values` - The values to store, at consecutive storage addresses starting from `key`.
+///
+/// Useful for setting up the storage of a contract directly, with `storage_address` computing the
+/// storage address of a storage member.
+pub fn store(address: ContractAddress, key: felt252, values: Span<felt252>) {
+    let mut input = array![address.into(), key];
+    input.append_span(values);
+    cheatcode::<'store'>(input.span());
+}
+
+/// Loads values directly from the storage of a contract, without running any of its code.
+///
+/// # Arguments
+///
+/// `address` - The address of the contract.
+/// `key` - The storage address of the first value.
+/// `size` - The number of values to load, from consecutive storage addresses starting from `key`.
+///
+/// Values that were never written are loaded as 0.
+pub fn load(address: ContractAddress, key: felt252, size: u32) -> Span<felt252> {
+    cheatcode::<'load'>([address.into(), key, size.into()].span())
+}
+
+/// Returns the storage address of a storage member, given its storage path.
+///
+/// # Arguments
+///
+/// `path` - The path of the storage member, e.g. a storage variable or a map entry accessed
+/// through the state of a contract.
+///
+/// # Examples
+///
+/// ```
+/// let state = contract::contract_state_for_testing();
+/// let balance_address = starknet::testing::storage_address(@state.balances.entry(account));
+/// starknet::testing::store(contract_address, balance_address, [100, 0].span());
+/// ```
+pub fn storage_address<T, +StorageAsPointer<T>>(path: @T) -> felt252 {
+    storage_address_from_base(path.as_ptr().__storage_pointer_address__).into()
+}
+
+/// Mocks calls to an entry point of a contract, without requiring a deployed contract.
+///
+/// # Arguments
+///
+/// `address` - The address of the called contract.
+/// `selector` - The selector of the called entry point.
+/// `result` - The mocked result - `Ok` with the returned data, or `Err` with the revert reason.
+/// `n_calls` - The number of calls to mock, or `None` to mock all the following calls.
+///
+/// The run fails if `n_calls` is `Some(0)`, as no calls would be mocked.
+///
+/// After a call to `mock_call`, `starknet::syscalls::call_contract_syscall` to the address and
+/// selector will return the mocked data without running any code, or fail with the mocked revert
+/// reason followed by `'ENTRYPOINT_FAILED'`.
+/// Once `n_calls` calls were made, the following calls are executed as usual.
+/// A new mock of the same address and selector replaces the previous one.
+pub fn mock_call(
+    address: ContractAddress,
+    selector: felt252,
+    result: Result<Span<felt252>, Span<felt252>>,
+    n_calls: Option<u32>,
+) {
+    mock_call_raw(0, address.into(), selector, result, n_calls);
+}
+
+/// Mocks library calls to an entry point of a class, without requiring a declared class.
+///
+/// # Arguments
+///
+/// `class_hash` - The hash of the called class.
+/// `selector` - The selector of the called entry point.
+/// `result` - The mocked result - `Ok` with the returned data, or `Err` with the revert reason.
+/// `n_calls` - The number of calls to mock, or `None` to mock all the following calls.
+///
+/// The run fails if `n_calls` is `Some(0)`, as no calls would be mocked.
+///
+/// Same as `mock_call`, for `starknet::syscalls::library_call_syscall` to the class hash and
+/// selector.
+pub fn mock_library_call(
+    class_hash: ClassHash,
+    selector: felt252,
+    result: Result<Span<felt252>, Span<felt252>>,
+    n_calls: Option<u32>,
+) {
+    mock_call_raw(1, class_hash.into(), selector, result, n_calls);
+}
+
+/// Removes the mock of calls to an entry point of a contract, set by `mock_call`.
+///
+/// # Arguments
+///
+/// `address` - The address of the called contract.
+/// `selector` - The selector of the called entry point.
+pub fn clear_mock_call(address: ContractAddress, selector: felt252) {
+    cheatcode::<'clear_mock_call'>([0, address.into(), selector].span());
+}
+
+/// Removes the mock of library calls to an entry point of a class, set by `mock_library_call`.
+///
+/// # Arguments
+///
+/// `class_hash` - The hash of the called class.
+/// `selector` - The selector of the called entry point.
+pub fn clear_mock_library_call(class_hash: ClassHash, selector: felt252) {
+    cheatcode::<'clear_mock_call'>([1, class_hash.into(), selector].span());
+}
+
+/// Mocks calls of the given kind - `0` for contract calls and `1` for library calls.
+fn mock_call_raw(
+    kind: felt252,
+    target: felt252,
+    selector: felt252,
+    result: Result<Span<felt252>, Span<felt252>>,
+    n_calls: Option<u32>,
+) {
+    let mut input = array![kind, target, selector];
+    n_calls.serialize(ref input);
+    result.serialize(ref input);
+    cheatcode::<'mock_call'>(input.span());
+}
@@ -2,8 +2,11 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_compiler::{CompilerConfig, compile_cairo_project_at_path};
+use cairo_lang_diagnostics::ErrorCode;
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
 
@@ -32,7 +35,8 @@ impl From<crate::InliningStrategy> for cairo_lang_lowering::utils::InliningStrat
 #[command(version, verbatim_doc_comment)]
 struct Args {
     /// The Cairo project path.
-    path: PathBuf,
+    #[arg(required_unless_present = "explain")]
+    path: Option<PathBuf>,
     /// Whether path is a single file.
     #[arg(short, long)]
     single_file: bool,
@@ -44,6 +48,14 @@ struct Args {
     /// Overrides inlining behavior.
     #[arg(short, long, default_value = "default")]
     inlining_strategy: InliningStrategy,
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
+    /// Prints the explanation of the given error code, e.g. `E0001`, instead of compiling.
+    /// Only some error codes have a long-form explanation so far, the others print their short
+    /// description.
+    #[arg(long, value_name = "CODE")]
+    explain: Option<String>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -52,14 +64,20 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
+    if let Some(code) = args.explain {
+        return explain(&code);
+    }
+    let path = args.path.expect("The path is required when not explaining an error code.");
+
     // Check if args.path is a file or a directory.
-    check_compiler_path(args.single_file, &args.path)?;
+    check_compiler_path(args.single_file, &path)?;
 
     let sierra_program = compile_cairo_project_at_path(
-        &args.path,
+        &path,
         CompilerConfig {
             replace_ids: args.replace_ids,
             inlining_strategy: args.inlining_strategy.into(),
+            diagnostics_reporter: DiagnosticsReporter::stderr_with_format(args.message_format),
             ..CompilerConfig::default()
         },
     )?;
@@ -73,3 +91,19 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Prints the long-form explanation of the given error code, or its short description if it has no
+/// explanation.
+fn explain(code: &str) -> anyhow::Result<()> {
+    let error_code = ErrorCode::assigned(&code.to_uppercase())
+        .with_context(|| format!("Unknown error code `{code}`."))?;
+    match error_code.explanation() {
+        Some(explanation) => print!("{explanation}"),
+        None => println!(
+            "{error_code}: {}\n\nThere is no long-form explanation of this error code yet, only \
+             some error codes have one so far.",
+            error_code.description().expect("Assigned error codes have a description.")
+        ),
+    }
+    Ok(())
+}
@@ -5,6 +5,7 @@ use anyhow::Context;
 use bincode::enc::write::Writer;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::{check_compiler_path, setup_project};
 use cairo_lang_debug::debug::DebugWithDb;
 use cairo_lang_executable::compile::{
@@ -16,11 +17,16 @@ use cairo_lang_filesystem::ids::CrateInput;
 use cairo_lang_runnable_utils::builder::RunnableBuilder;
 use cairo_lang_runner::casm_run::format_for_panic;
 use cairo_lang_runner::clap::RunProfilerConfigArg;
+use cairo_lang_runner::debugger::dap::run_dap_server;
+use cairo_lang_runner::debugger::repl::run_repl;
+use cairo_lang_runner::debugger::{DebugSession, DebugSources, RecordedRun};
 use cairo_lang_runner::profiling::{
     ProfilingInfo, ProfilingInfoProcessor, ProfilingInfoProcessorParams,
 };
 use cairo_lang_runner::{Arg, CairoHintProcessor, ProfilingInfoCollectionConfig};
-use cairo_lang_sierra_generator::program_generator::SierraProgramDebugInfo;
+use cairo_lang_sierra_generator::program_generator::{
+    SierraProgramDebugInfo, get_program_variable_names,
+};
 use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
 use cairo_vm::cairo_run;
 use cairo_vm::cairo_run::{CairoRunConfig, cairo_run_program};
@@ -51,7 +57,7 @@ struct Args {
     /// In `--build-only` this would be the executable artifact.
     /// In bootloader mode it will be the resulting cairo PIE file.
     /// In standalone mode this parameter is disallowed.
-    #[arg(long, required_unless_present_any(["standalone", "run_profiler"]))]
+    #[arg(long, required_unless_present_any(["standalone", "run_profiler", "debug", "dap"]))]
     output_path: Option<PathBuf>,
 
     /// Whether to only run a prebuilt executable.
@@ -64,6 +70,16 @@ struct Args {
     #[arg(short, long, default_value_t, value_enum, conflicts_with = "prebuilt")]
     run_profiler: RunProfilerConfigArg,
 
+    /// Whether to debug the run interactively, with commands read from stdin.
+    /// Does not work with prebuilt executables as it requires additional debug info.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["prebuilt", "build_only", "dap"])]
+    debug: bool,
+
+    /// Whether to debug the run by serving the Debug Adapter Protocol over stdio.
+    /// Does not work with prebuilt executables as it requires additional debug info.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["prebuilt", "build_only"])]
+    dap: bool,
+
     #[command(flatten)]
     build: BuildArgs,
 
@@ -86,6 +102,9 @@ struct BuildArgs {
     /// Allow warnings and don't print them (implies allow_warnings).
     #[arg(long, conflicts_with = "prebuilt")]
     ignore_warnings: bool,
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum, conflicts_with = "prebuilt")]
+    message_format: MessageFormat,
     /// Allow syscalls in the program.
     #[arg(long, conflicts_with = "prebuilt")]
     allow_syscalls: bool,
@@ -183,7 +202,8 @@ struct ProofOutputArgs {
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
-    let mut diagnostics_reporter = DiagnosticsReporter::stderr();
+    let mut diagnostics_reporter =
+        DiagnosticsReporter::stderr_with_format(args.build.message_format);
 
     let (db, opt_debug_data, executable) = {
         if args.prebuilt {
@@ -271,12 +291,14 @@ fn main() -> anyhow::Result<()> {
         None => None,
     };
 
+    let is_debugging = args.debug || args.dap;
     let trace_enabled = args.run_profiler != RunProfilerConfigArg::None
-        || args.run.proof_outputs.trace_file.is_some();
+        || args.run.proof_outputs.trace_file.is_some()
+        || is_debugging;
 
     let cairo_run_config = CairoRunConfig {
         trace_enabled,
-        relocate_mem: args.run.proof_outputs.memory_file.is_some(),
+        relocate_mem: args.run.proof_outputs.memory_file.is_some() || is_debugging,
         layout: args.run.layout,
         dynamic_layout_params,
         proof_mode: args.run.standalone,
@@ -298,6 +320,31 @@ fn main() -> anyhow::Result<()> {
             return Err(err).context("Failed running program.");
         }
     };
+    if is_debugging {
+        let DebugData { db, builder, debug_info, header_len } =
+            opt_debug_data.expect("debug data should be available when debugging");
+        let run = RecordedRun {
+            trace: runner.relocated_trace.with_context(|| "Trace not relocated.")?,
+            memory: runner.relocated_memory,
+            load_offset: 1 + header_len,
+        };
+        let sources = DebugSources {
+            code_locations: debug_info
+                .statements_locations
+                .extract_statements_source_code_locations(db),
+            functions: debug_info.statements_locations.extract_statements_functions(db),
+            program: Some(replace_sierra_ids_in_program(db, builder.sierra_program())),
+            variable_names: get_program_variable_names(db, builder.sierra_program()),
+        };
+        let mut session = DebugSession::new(&builder, &run, &sources);
+        if args.dap {
+            run_dap_server(&mut session, io::stdin().lock(), io::stdout().lock())?;
+        } else {
+            run_repl(&mut session, io::stdin().lock(), &mut io::stdout().lock())?;
+        }
+        return Ok(());
+    }
+
     if args.run.print_outputs {
         let mut output_buffer = "Program Output:\n".to_string();
         runner.vm.write_output(&mut output_buffer)?;
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
+use cairo_lang_compiler::project::{check_compiler_path, setup_project};
+use cairo_lang_diagnostics::{Applicability, ResolvedSuggestion, apply_suggestions};
+use cairo_lang_utils::logging::init_logging;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use clap::Parser;
+
+/// The maximal number of times the project is compiled and fixed. Applying fixes may enable
+/// fixes that conflicted with them, or reveal new diagnostics.
+const MAX_PASSES: usize = 4;
+
+/// Applies the machine-applicable fixes suggested by the diagnostics of a Cairo project to its
+/// source files, in place, and prints the diagnostics remaining after the fixes.
+/// Exits with 0/1 if the fixing succeeds/fails.
+#[derive(Parser, Debug)]
+#[command(version, verbatim_doc_comment)]
+struct Args {
+    /// The Cairo project path.
+    path: PathBuf,
+    /// Whether path is a single file.
+    #[arg(short, long)]
+    single_file: bool,
+    /// The format of the diagnostics remaining after the fixes, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
+}
+
+fn main() -> anyhow::Result<()> {
+    init_logging(log::LevelFilter::Off);
+    log::info!("Starting Cairo fixing.");
+
+    let args = Args::parse();
+
+    // Check if args.path is a file or a directory.
+    check_compiler_path(args.single_file, &args.path)?;
+
+    let mut n_fixes = 0;
+    for _ in 0..MAX_PASSES {
+        let n_pass_fixes = fix_project(&args.path)?;
+        if n_pass_fixes == 0 {
+            break;
+        }
+        n_fixes += n_pass_fixes;
+    }
+    eprintln!("Applied {n_fixes} fixes.");
+    report_diagnostics(&args.path, args.message_format)?;
+
+    Ok(())
+}
+
+/// Compiles the project at the given path, and prints its diagnostics to stderr in the given
+/// format.
+fn report_diagnostics(path: &Path, message_format: MessageFormat) -> anyhow::Result<()> {
+    let mut db = RootDatabase::builder().detect_corelib().build()?;
+    let main_crate_inputs = setup_project(&mut db, path)?;
+    DiagnosticsReporter::stderr_with_format(message_format)
+        .with_crates(&main_crate_inputs)
+        .check(&db);
+    Ok(())
+}
+
+/// Compiles the project at the given path, and applies the machine-applicable suggestions of its
+/// diagnostics to its files. Returns the number of applied suggestions.
+fn fix_project(path: &Path) -> anyhow::Result<usize> {
+    let mut db = RootDatabase::builder().detect_corelib().build()?;
+    let main_crate_inputs = setup_project(&mut db, path)?;
+
+    let mut suggestions_by_file = OrderedHashMap::<String, Vec<ResolvedSuggestion>>::default();
+    DiagnosticsReporter::callback(|diagnostic| {
+        let Some(details) = diagnostic.details() else {
+            return;
+        };
+        for suggestion in &details.suggestions {
+            if suggestion.applicability != Applicability::MachineApplicable {
+                continue;
+            }
+            if let Some(file_path) = suggestion.file_path() {
+                suggestions_by_file
+                    .entry(file_path.to_string())
+                    .or_default()
+                    .push(suggestion.clone());
+            }
+        }
+    })
+    .with_crates(&main_crate_inputs)
+    .check(&db);
+
+    let mut n_fixes = 0;
+    for (file_path, suggestions) in suggestions_by_file {
+        let content = fs::read_to_string(&file_path)
+            .with_context(|| format!("Failed to read `{file_path}`."))?;
+        let (fixed_content, n_file_fixes) = apply_suggestions(&content, &suggestions);
+        fs::write(&file_path, fixed_content)
+            .with_context(|| format!("Failed to write `{file_path}`."))?;
+        eprintln!("Fixed {file_path} ({n_file_fixes} fixes).");
+        n_fixes += n_file_fixes;
+    }
+    Ok(n_fixes)
+}
@@ -1,20 +1,27 @@
 //! Compiles and runs a Cairo program.
 
+use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Ok};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::{check_compiler_path, setup_project};
 use cairo_lang_diagnostics::ToOption;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::ids::CrateInput;
 use cairo_lang_runner::casm_run::format_next_item;
+use cairo_lang_runner::debugger::dap::run_dap_server;
+use cairo_lang_runner::debugger::repl::run_repl;
+use cairo_lang_runner::debugger::{DebugSession, DebugSources, RecordedRun};
 use cairo_lang_runner::profiling::ProfilingInfoProcessor;
 use cairo_lang_runner::{ProfilingInfoCollectionConfig, SierraCasmRunner, StarknetState};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
-use cairo_lang_sierra_generator::program_generator::SierraProgramWithDebug;
+use cairo_lang_sierra_generator::program_generator::{
+    SierraProgramWithDebug, get_program_variable_names,
+};
 use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
 use cairo_lang_starknet::contract::{find_contracts, get_contracts_info};
 use clap::Parser;
@@ -32,6 +39,9 @@ struct Args {
     /// Allows the compilation to succeed with warnings.
     #[arg(long)]
     allow_warnings: bool,
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
     /// In cases where gas is available, the amount of provided gas.
     #[arg(long)]
     available_gas: Option<usize>,
@@ -41,6 +51,20 @@ struct Args {
     /// Whether to run the profiler.
     #[arg(long, default_value_t = false)]
     run_profiler: bool,
+    /// Whether to debug the run interactively, with commands read from stdin.
+    #[arg(long, default_value_t = false, conflicts_with_all = ["run_profiler", "dap"])]
+    debug: bool,
+    /// Whether to debug the run by serving the Debug Adapter Protocol over stdio.
+    #[arg(long, default_value_t = false, conflicts_with = "run_profiler")]
+    dap: bool,
+    /// A dumped Starknet state to start the run from. The format is JSON for a `.json` file, and
+    /// binary otherwise.
+    #[arg(long, value_name = "PATH")]
+    state_in: Option<PathBuf>,
+    /// A file to dump the Starknet state the run ends in into. The format is JSON for a `.json`
+    /// file, and binary otherwise.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["debug", "dap"])]
+    state_out: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -60,7 +84,8 @@ fn main() -> anyhow::Result<()> {
 
     let main_crate_inputs = setup_project(db, Path::new(&args.path))?;
 
-    let mut reporter = DiagnosticsReporter::stderr().with_crates(&main_crate_inputs);
+    let mut reporter = DiagnosticsReporter::stderr_with_format(args.message_format)
+        .with_crates(&main_crate_inputs);
     if args.allow_warnings {
         reporter = reporter.allow_warnings();
     }
@@ -91,14 +116,50 @@ fn main() -> anyhow::Result<()> {
         if args.run_profiler { Some(ProfilingInfoCollectionConfig::default()) } else { None },
     )
     .with_context(|| "Failed setting up runner.")?;
+    let starknet_state = match &args.state_in {
+        Some(path) => StarknetState::load(path)
+            .with_context(|| format!("Failed loading the state from `{}`.", path.display()))?,
+        None => StarknetState::default(),
+    };
+    if args.debug || args.dap {
+        let run = RecordedRun::record(
+            &runner,
+            runner.find_function("::main")?,
+            vec![],
+            args.available_gas,
+            starknet_state,
+        )
+        .with_context(|| "Failed to run the function.")?;
+        let sources = DebugSources {
+            code_locations: debug_info
+                .statements_locations
+                .extract_statements_source_code_locations(db),
+            functions: debug_info.statements_locations.extract_statements_functions(db),
+            program: None,
+            variable_names: get_program_variable_names(db, &sierra_program),
+        };
+        let mut session = DebugSession::new(runner.builder(), &run, &sources);
+        if args.dap {
+            run_dap_server(&mut session, io::stdin().lock(), io::stdout().lock())?;
+        } else {
+            run_repl(&mut session, io::stdin().lock(), &mut io::stdout().lock())?;
+        }
+        return Ok(());
+    }
     let result = runner
         .run_function_with_starknet_context(
             runner.find_function("::main")?,
             vec![],
             args.available_gas,
-            StarknetState::default(),
+            starknet_state,
         )
         .with_context(|| "Failed to run the function.")?;
+    if let Some(path) = &args.state_out {
+        result
+            .starknet_state
+            .dump(path)
+            .with_context(|| format!("Failed dumping the state into `{}`.", path.display()))?;
+    }
 
     if args.run_profiler {
         match result.profiling_info {
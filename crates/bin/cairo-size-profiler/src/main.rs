@@ -15,6 +15,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Ok};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::{check_compiler_path, setup_project};
 use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_diagnostics::ToOption;
@@ -50,6 +51,9 @@ struct Args {
     /// Allows the compilation to succeed with warnings.
     #[arg(long)]
     allow_warnings: bool,
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
     /// A path to a contract to analyze size for.
     ///
     /// Implies usage of the starknet plugin.
@@ -90,7 +94,8 @@ fn main() -> anyhow::Result<()> {
 
     let main_crate_inputs = setup_project(db, Path::new(&args.path))?;
 
-    let mut reporter = DiagnosticsReporter::stderr().with_crates(&main_crate_inputs);
+    let mut reporter = DiagnosticsReporter::stderr_with_format(args.message_format)
+        .with_crates(&main_crate_inputs);
     if args.allow_warnings {
         reporter = reporter.allow_warnings();
     }
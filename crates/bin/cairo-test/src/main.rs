@@ -2,11 +2,21 @@
 
 use std::path::PathBuf;
 
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_runner::clap::RunProfilerConfigArg;
+use cairo_lang_test_runner::contract_classes::ContractClassSource;
+use cairo_lang_test_runner::report::OutputFormat;
+use cairo_lang_test_runner::resource_snapshot::ResourceSnapshotConfig;
+use cairo_lang_test_runner::snapshot::SnapshotConfig;
 use cairo_lang_test_runner::{TestRunConfig, TestRunner};
 use clap::Parser;
 
+/// The directory of the snapshot files, relative to the project.
+const SNAPSHOTS_DIR: &str = "snapshots";
+/// The directory of the resource snapshot files, relative to the snapshots directory.
+const RESOURCE_SNAPSHOTS_DIR: &str = "resources";
+
 /// Compiles a Cairo project and runs all the functions marked as `#[test]`.
 /// Exits with 1 if the compilation or run fails, otherwise 0.
 #[derive(Parser, Debug)]
@@ -20,6 +30,9 @@ struct Args {
     /// Allows the compilation to succeed with warnings.
     #[arg(long)]
     allow_warnings: bool,
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
     /// The filter for the tests, running only tests containing the filter string.
     #[arg(short, long, default_value_t = String::default())]
     filter: String,
@@ -42,6 +55,52 @@ struct Args {
     /// Whether to print resource usage after each test.
     #[arg(long, default_value_t = false)]
     print_resource_usage: bool,
+    /// Whether to collect the code coverage of the tests, and the directory to write the LCOV
+    /// (`lcov.info`) and JSON (`coverage.json`) reports to.
+    #[arg(long, num_args = 0..=1, default_missing_value = "coverage", value_name = "DIR")]
+    coverage: Option<PathBuf>,
+    /// The format of the test results. Resource usage and profiling info are only printed in the
+    /// `pretty` format.
+    #[arg(long, default_value_t, value_enum)]
+    format: OutputFormat,
+    /// The number of tests to run in parallel (default: the number of CPUs).
+    #[arg(short, long)]
+    jobs: Option<usize>,
+    /// Whether to write the values asserted by `assert_snapshot!` into the snapshot files, instead
+    /// of comparing them. The snapshot files are in the `snapshots` directory of the project.
+    /// With `--resource-snapshots`, also writes the resource usage of the tests into their
+    /// resource snapshots.
+    #[arg(long, default_value_t = false)]
+    update_snapshots: bool,
+    /// Whether to check the resource usage (gas, steps, memory holes, builtins and syscalls) of
+    /// the passing tests against their resource snapshots, in the `snapshots/resources`
+    /// directory of the project.
+    #[arg(long, default_value_t = false)]
+    resource_snapshots: bool,
+    /// The percentage a resource may go over its resource snapshot without counting as a
+    /// regression.
+    #[arg(long, default_value_t = 0.0, value_name = "PERCENT", requires = "resource_snapshots")]
+    resource_threshold: f64,
+    /// Whether to only warn on resource regressions, instead of failing the regressed tests.
+    #[arg(long, default_value_t = false, requires = "resource_snapshots")]
+    warn_on_resource_regression: bool,
+    /// A `ContractClass` JSON file, whose class the tests can deploy by its class hash. Requires
+    /// `--starknet`. May be given multiple times.
+    #[arg(long, value_name = "PATH", requires = "starknet")]
+    contract_class: Vec<PathBuf>,
+    /// A crate, or a project, whose contracts the tests can deploy by their class hashes. The
+    /// contracts are compiled separately from the tested crates. Requires `--starknet`. May be
+    /// given multiple times.
+    #[arg(long, value_name = "PATH", requires = "starknet")]
+    contract_crate: Vec<PathBuf>,
+    /// A dumped Starknet state to start the tests from. The format is JSON for a `.json` file,
+    /// and binary otherwise. Requires `--starknet`.
+    #[arg(long, value_name = "PATH", requires = "starknet")]
+    state_in: Option<PathBuf>,
+    /// A directory to dump the Starknet states of the passing tests into, one JSON file per test.
+    /// Requires `--starknet`.
+    #[arg(long, value_name = "DIR", requires = "starknet")]
+    state_out: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -50,6 +109,11 @@ fn main() -> anyhow::Result<()> {
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
 
+    let project_dir = if args.single_file {
+        args.path.parent().map(PathBuf::from).unwrap_or_default()
+    } else {
+        args.path.clone()
+    };
     let config = TestRunConfig {
         filter: args.filter,
         ignored: args.ignored,
@@ -57,6 +121,28 @@ fn main() -> anyhow::Result<()> {
         profiler_config: args.run_profiler.try_into().ok(),
         gas_enabled: !args.gas_disabled,
         print_resource_usage: args.print_resource_usage,
+        coverage_dir: args.coverage,
+        output_format: args.format,
+        jobs: args.jobs,
+        snapshots: Some(SnapshotConfig {
+            dir: project_dir.join(SNAPSHOTS_DIR),
+            update: args.update_snapshots,
+        }),
+        resource_snapshots: args.resource_snapshots.then(|| ResourceSnapshotConfig {
+            dir: project_dir.join(SNAPSHOTS_DIR).join(RESOURCE_SNAPSHOTS_DIR),
+            update: args.update_snapshots,
+            threshold_percent: args.resource_threshold,
+            fail_on_regression: !args.warn_on_resource_regression,
+        }),
+        contract_classes: args
+            .contract_class
+            .into_iter()
+            .map(ContractClassSource::File)
+            .chain(args.contract_crate.into_iter().map(ContractClassSource::Crate))
+            .collect(),
+        state_in: args.state_in,
+        state_out_dir: args.state_out,
+        message_format: args.message_format,
     };
 
     let runner = TestRunner::new(&args.path, args.starknet, args.allow_warnings, config)?;
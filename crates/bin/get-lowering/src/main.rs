@@ -6,6 +6,7 @@ use std::{fmt, fs};
 use anyhow::Context;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::{check_compiler_path, setup_project};
 use cairo_lang_debug::debug::DebugWithDb;
 use cairo_lang_defs::db::DefsGroup;
@@ -90,6 +91,10 @@ struct Args {
     #[arg(long)]
     generated_function_index: Option<usize>,
 
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
+
     /// The output file name (default: stdout).
     output: Option<String>,
 }
@@ -294,7 +299,7 @@ fn main() -> anyhow::Result<()> {
 
         let Ok(lowered) = db.lowered_body(function_id, LoweringStage::Final) else {
             // Run DiagnosticsReporter only in case of failure.
-            DiagnosticsReporter::default()
+            DiagnosticsReporter::stderr_with_format(args.message_format)
                 .with_crates(&main_crate_inputs)
                 .ensure(db)
                 .with_context(|| "Failed to compile")?;
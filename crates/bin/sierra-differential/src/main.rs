@@ -0,0 +1,99 @@
+//! Runs Sierra functions by both the Sierra simulator and the CASM runner, and reports differences.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use cairo_lang_runner::differential::DifferentialRunner;
+use cairo_lang_sierra::ProgramParser;
+use cairo_lang_sierra::program::Function;
+use clap::Parser;
+use indoc::formatdoc;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use starknet_types_core::felt::Felt as Felt252;
+
+/// Runs functions of a Sierra program by both the Sierra simulator and the CASM runner, and
+/// reports any difference in their returned values, panics or remaining gas.
+/// Exits with 1 if any difference was found, otherwise 0.
+#[derive(Parser, Debug)]
+#[command(version, verbatim_doc_comment)]
+struct Args {
+    /// The path of the Sierra file.
+    file: PathBuf,
+    /// The suffix of the name of the function to run. If not provided, all the functions whose
+    /// arguments can be generated are run.
+    #[arg(long)]
+    function: Option<String>,
+    /// The serialized arguments of the function, as hex or decimal felts. If not provided,
+    /// random arguments are generated.
+    #[arg(long, num_args = 0.., requires = "function")]
+    args: Option<Vec<Felt252>>,
+    /// The number of runs with random arguments per function.
+    #[arg(long, default_value_t = 100)]
+    runs: usize,
+    /// The seed of the random arguments generation.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// The amount of gas available to each run, if the program requires gas.
+    #[arg(long, default_value_t = 1_000_000)]
+    available_gas: usize,
+    /// Whether to print the reports of the matching runs as well.
+    #[arg(long, default_value_t = false)]
+    verbose: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let sierra_code = fs::read_to_string(&args.file).with_context(|| "Could not read file!")?;
+    let program = match ProgramParser::new().parse(&sierra_code) {
+        Ok(program) => program,
+        Err(err) => {
+            anyhow::bail!(formatdoc! {"
+            Failed to parse sierra program with: `{err:?}`.
+            Note: Starknet contracts are not supported."
+            })
+        }
+    };
+    let runner = DifferentialRunner::new(program).with_context(|| "Failed setting up runner.")?;
+    let functions: Vec<&Function> = match &args.function {
+        Some(name) => vec![runner.find_function(name)?],
+        None => runner.program().funcs.iter().filter(|f| runner.supports_args_of(f)).collect(),
+    };
+
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let (mut n_runs, mut n_mismatches) = (0, 0);
+    for func in functions {
+        let args_list = match &args.args {
+            Some(felts) => vec![runner.args_from_serialized(func, felts)?],
+            None => (0..args.runs)
+                .map(|_| runner.random_args(func, &mut rng))
+                .collect::<Result<_, _>>()?,
+        };
+        for func_args in args_list {
+            let report = match runner.run(func, func_args, Some(args.available_gas)) {
+                Ok(report) => report,
+                Err(err) if args.function.is_none() => {
+                    eprintln!("Skipping `{}`: {err}", func.id);
+                    break;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            n_runs += 1;
+            let is_match = report.is_match();
+            if !is_match {
+                n_mismatches += 1;
+            }
+            if !is_match || args.verbose {
+                print!("{report}");
+            }
+        }
+    }
+
+    println!("Completed {n_runs} runs, with {n_mismatches} mismatches.");
+    if n_mismatches > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
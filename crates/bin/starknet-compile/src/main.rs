@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use anyhow::Context;
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::check_compiler_path;
 use cairo_lang_starknet::compile::starknet_compile;
 use cairo_lang_starknet_classes::allowed_libfuncs::ListSelector;
@@ -22,6 +23,9 @@ struct Args {
     /// Allows the compilation to succeed with warnings.
     #[arg(long)]
     allow_warnings: bool,
+    /// The format of the compilation diagnostics, printed to stderr.
+    #[arg(long, default_value_t, value_enum)]
+    message_format: MessageFormat,
     // The contract fully qualified path.
     #[arg(short, long)]
     contract_path: Option<String>,
@@ -48,7 +52,7 @@ fn main() -> anyhow::Result<()> {
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file).expect(
             "Cannot supply both --allowed-libfuncs-list-name and --allowed-libfuncs-list-file",
         );
-    let mut diagnostics_reporter = DiagnosticsReporter::stderr();
+    let mut diagnostics_reporter = DiagnosticsReporter::stderr_with_format(args.message_format);
     if args.allow_warnings {
         diagnostics_reporter = diagnostics_reporter.allow_warnings();
     }
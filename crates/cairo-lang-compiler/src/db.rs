@@ -10,6 +10,7 @@ use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{CrateId, FlagLongId};
 use cairo_lang_lowering::db::{ExternalCodeSizeEstimator, LoweringGroup, init_lowering_group};
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
+use cairo_lang_lowering::lint::{LintPass, builtin_lint_passes, set_lint_passes};
 use cairo_lang_project::ProjectConfig;
 use cairo_lang_runnable_utils::builder::RunnableBuilder;
 use cairo_lang_semantic::db::{Elongate, PluginSuiteInput, SemanticGroup, init_semantic_group};
@@ -117,6 +118,7 @@ pub struct RootDatabaseBuilder {
     project_config: Option<Box<ProjectConfig>>,
     cfg_set: Option<CfgSet>,
     inlining_strategy: InliningStrategy,
+    lint_passes: Vec<Arc<dyn LintPass>>,
 }
 
 impl RootDatabaseBuilder {
@@ -130,6 +132,7 @@ impl RootDatabaseBuilder {
             project_config: None,
             cfg_set: None,
             inlining_strategy: InliningStrategy::Default,
+            lint_passes: builtin_lint_passes(),
         }
     }
 
@@ -143,6 +146,12 @@ impl RootDatabaseBuilder {
         self
     }
 
+    /// Adds a lint pass to run on the functions of the crates, along with the built-in ones.
+    pub fn with_lint_pass(&mut self, lint_pass: Arc<dyn LintPass>) -> &mut Self {
+        self.lint_passes.push(lint_pass);
+        self
+    }
+
     pub fn with_inlining_strategy(&mut self, inlining_strategy: InliningStrategy) -> &mut Self {
         self.inlining_strategy = inlining_strategy;
         self
@@ -209,6 +218,8 @@ impl RootDatabaseBuilder {
         let unsafe_panic_flag_id = FlagLongId("unsafe_panic".into());
         db.set_flag(unsafe_panic_flag_id, Some(Arc::new(Flag::UnsafePanic(self.unsafe_panic))));
 
+        set_lint_passes(&mut db, self.lint_passes.clone());
+
         if let Some(config) = &self.project_config {
             update_crate_roots_from_project_config(&mut db, config.as_ref());
         }
@@ -5,15 +5,17 @@ use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_diagnostics::{
     DiagnosticEntry, Diagnostics, FormattedDiagnosticEntry, PluginFileDiagnosticNotes, Severity,
 };
-use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::db::{FilesGroup, LintLevel};
 use cairo_lang_filesystem::ids::{CrateId, CrateInput, FileLongId};
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::lint::LintLevelResolver;
 use cairo_lang_utils::Intern;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use thiserror::Error;
 
 use crate::db::RootDatabase;
+use crate::message_format::{MessageFormat, diagnostic_to_json, diagnostics_to_sarif};
 
 #[cfg(test)]
 #[path = "diagnostics_test.rs"]
@@ -35,6 +37,24 @@ impl DiagnosticCallback for Option<Box<dyn DiagnosticCallback + '_>> {
     }
 }
 
+/// Collects the diagnostics to report them all in a single SARIF log, written when dropped.
+struct SarifCollector<'a> {
+    diagnostics: Vec<FormattedDiagnosticEntry>,
+    write: Box<dyn FnMut(&str) + Send + Sync + 'a>,
+}
+
+impl DiagnosticCallback for SarifCollector<'_> {
+    fn on_diagnostic(&mut self, diagnostic: FormattedDiagnosticEntry) {
+        self.diagnostics.push(diagnostic);
+    }
+}
+
+impl Drop for SarifCollector<'_> {
+    fn drop(&mut self) {
+        (self.write)(&format!("{:#}\n", diagnostics_to_sarif(&self.diagnostics)));
+    }
+}
+
 /// Collects compilation diagnostics and presents them in a preconfigured way.
 pub struct DiagnosticsReporter<'a> {
     callback: Option<Box<dyn DiagnosticCallback + 'a>>,
@@ -70,6 +90,12 @@ impl DiagnosticsReporter<'_> {
     pub fn stderr() -> Self {
         Self::callback(|diagnostic| eprint!("{diagnostic}"))
     }
+
+    /// Create a reporter which prints all diagnostics to [`std::io::Stderr`] in the given format.
+    /// A SARIF log is printed once the reporter is dropped.
+    pub fn stderr_with_format(format: MessageFormat) -> Self {
+        Self::with_format(format, |output| eprint!("{output}"))
+    }
 }
 
 impl<'a> DiagnosticsReporter<'a> {
@@ -99,6 +125,28 @@ impl<'a> DiagnosticsReporter<'a> {
         })
     }
 
+    /// Create a reporter which appends all diagnostics to provided string in the given format.
+    /// A SARIF log is appended once the reporter is dropped.
+    pub fn write_to_string_with_format(string: &'a mut String, format: MessageFormat) -> Self {
+        Self::with_format(format, move |output| string.push_str(output))
+    }
+
+    /// Create a reporter which formats the diagnostics in the given format, and passes the output
+    /// to `write`.
+    fn with_format(format: MessageFormat, mut write: impl FnMut(&str) + Send + Sync + 'a) -> Self {
+        match format {
+            MessageFormat::Human => {
+                Self::callback(move |diagnostic| write(&diagnostic.to_string()))
+            }
+            MessageFormat::Json => Self::callback(move |diagnostic| {
+                write(&format!("{}\n", diagnostic_to_json(&diagnostic)))
+            }),
+            MessageFormat::Sarif => {
+                Self::new(SarifCollector { diagnostics: vec![], write: Box::new(write) })
+            }
+        }
+    }
+
     /// Create a reporter which calls [`DiagnosticCallback::on_diagnostic`].
     fn new(callback: impl DiagnosticCallback + 'a) -> Self {
         Self {
@@ -183,6 +231,7 @@ impl<'a> DiagnosticsReporter<'a> {
             let ignore_warnings_in_crate =
                 self.ignore_all_warnings || self.ignore_warnings_crate_ids.contains(crate_input);
             let modules = db.crate_modules(crate_id);
+            let mut lint_levels = LintLevelResolver::new(crate_id);
             let mut processed_file_ids = UnorderedHashSet::<_>::default();
             for module_id in modules.iter() {
                 let default = Default::default();
@@ -195,10 +244,12 @@ impl<'a> DiagnosticsReporter<'a> {
                     for file_id in module_files.iter().copied() {
                         if processed_file_ids.insert(file_id) {
                             found_diagnostics |= self.check_diag_group(
+                                db,
                                 db.as_dyn_database(),
                                 db.file_syntax_diagnostics(file_id).clone(),
                                 ignore_warnings_in_crate,
                                 diagnostic_notes,
+                                &mut lint_levels,
                             );
                         }
                     }
@@ -206,10 +257,12 @@ impl<'a> DiagnosticsReporter<'a> {
 
                 if let Ok(group) = db.module_semantic_diagnostics(*module_id) {
                     found_diagnostics |= self.check_diag_group(
+                        db,
                         db.upcast(),
                         group,
                         ignore_warnings_in_crate,
                         diagnostic_notes,
+                        &mut lint_levels,
                     );
                 }
 
@@ -219,34 +272,80 @@ impl<'a> DiagnosticsReporter<'a> {
 
                 if let Ok(group) = db.module_lowering_diagnostics(*module_id) {
                     found_diagnostics |= self.check_diag_group(
+                        db,
                         db.upcast(),
                         group,
                         ignore_warnings_in_crate,
                         diagnostic_notes,
+                        &mut lint_levels,
+                    );
+                }
+
+                // Lint passes are run even if warnings are ignored, as their denied lints are still
+                // reported as errors.
+                if let Ok(group) = db.module_lint_diagnostics(*module_id) {
+                    found_diagnostics |= self.check_diag_group(
+                        db,
+                        db.upcast(),
+                        group,
+                        ignore_warnings_in_crate,
+                        diagnostic_notes,
+                        &mut lint_levels,
                     );
                 }
             }
+
+            // Expectations of lowering lints cannot be fulfilled without lowering diagnostics.
+            if !self.skip_lowering_diagnostics {
+                let expectations = lint_levels.unfulfilled_expectations(db.upcast());
+                found_diagnostics |= self.check_diag_group(
+                    db,
+                    db.upcast(),
+                    expectations,
+                    ignore_warnings_in_crate,
+                    &Default::default(),
+                    &mut lint_levels,
+                );
+            }
         }
         found_diagnostics
     }
 
     /// Checks if a diagnostics group contains any diagnostics and reports them to the provided
     /// callback as strings. Returns `true` if diagnostics were found.
+    ///
+    /// Warnings of lints are reported according to the lint levels, and denied ones are reported
+    /// as errors.
     fn check_diag_group<'db, TEntry: DiagnosticEntry<'db> + salsa::Update>(
         &mut self,
-        db: &'db TEntry::DbType,
+        db: &'db dyn LoweringGroup,
+        entry_db: &'db TEntry::DbType,
         group: Diagnostics<'db, TEntry>,
         skip_warnings: bool,
         file_notes: &PluginFileDiagnosticNotes<'db>,
+        lint_levels: &mut LintLevelResolver<'db>,
     ) -> bool {
         let mut found: bool = false;
-        for entry in group.format_with_severity(db, file_notes) {
-            if skip_warnings && entry.severity() == Severity::Warning {
+        for entry in group.get_diagnostics_without_duplicates(entry_db) {
+            let mut severity = entry.severity();
+            if severity == Severity::Warning {
+                let location = entry.location(entry_db).user_location(db.as_dyn_database());
+                match lint_levels.level(db.upcast(), entry.lint(), entry.error_code(), &location) {
+                    Some(LintLevel::Allow) => continue,
+                    Some(LintLevel::Deny) => severity = Severity::Error,
+                    Some(LintLevel::Warn) | None => {}
+                }
+            }
+            if skip_warnings && severity == Severity::Warning {
                 continue;
             }
+            let entry =
+                Diagnostics::format_entry(entry_db, &entry, file_notes).with_severity(severity);
             if !entry.is_empty() {
                 self.callback.on_diagnostic(entry);
-                found |= !self.allow_warnings || group.check_error_free().is_err();
+                found |= !self.allow_warnings
+                    || severity == Severity::Error
+                    || group.check_error_free().is_err();
             }
         }
         found
@@ -1,9 +1,18 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::{FunctionWithBodyId, NamedLanguageElementId};
+use cairo_lang_diagnostics::error_code_explanations;
 use cairo_lang_filesystem::db::CrateConfiguration;
 use cairo_lang_filesystem::ids::{CrateId, Directory};
 use cairo_lang_filesystem::set_crate_config;
+use cairo_lang_lowering::lint::{LintContext, LintPass};
+use cairo_lang_semantic::test_utils::{setup_test_crate, setup_test_crate_ex};
+use cairo_lang_syntax::node::TypedStablePtr;
+use indoc::indoc;
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{DiagnosticsReporter, get_diagnostics_as_string};
+use crate::message_format::MessageFormat;
 
 #[test]
 fn test_diagnostics() {
@@ -18,3 +27,441 @@ fn test_diagnostics() {
 
     assert_eq!(get_diagnostics_as_string(&db, None), "error: no/such/path/lib.cairo not found\n");
 }
+
+/// Returns the diagnostics of a crate with the given content, reported in the given format.
+fn format_crate_diagnostics(content: &str, format: MessageFormat) -> String {
+    let db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let crate_id = setup_test_crate(&db, content);
+    let crate_input = crate_id.long(&db).clone().into_crate_input(&db);
+    let mut diagnostics = String::new();
+    DiagnosticsReporter::write_to_string_with_format(&mut diagnostics, format)
+        .with_crates(&[crate_input])
+        .check(&db);
+    diagnostics
+}
+
+#[test]
+fn test_json_diagnostics() {
+    let json = format_crate_diagnostics("fn foo() {\n    bar();\n}\n", MessageFormat::Json);
+    let diagnostic: serde_json::Value = serde_json::from_str(json.trim_end()).unwrap();
+    assert_eq!(
+        diagnostic,
+        serde_json::json!({
+            "severity": "error",
+            "code": "E0006",
+            "message": "Function not found.",
+            "span": {
+                "file": "lib.cairo",
+                "byte_start": 15,
+                "byte_end": 18,
+                "line_start": 2,
+                "column_start": 5,
+                "line_end": 2,
+                "column_end": 8,
+            },
+            "notes": [],
+            "generated_file": null,
+            "plugin": null,
+            "suggestions": [],
+            "rendered": "error[E0006]: Function not found.\n --> lib.cairo:2:5\n    bar();\n    ^^^\n\n",
+        })
+    );
+}
+
+#[test]
+fn test_sarif_diagnostics() {
+    let sarif = format_crate_diagnostics(
+        "fn foo(a: Array<felt252>) -> Array<felt252> {\n    let b = a;\n    drop(b);\n    a\n}\n",
+        MessageFormat::Sarif,
+    );
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "cairo");
+    // The note with a location is a related location, and the other note is part of the message.
+    assert_eq!(
+        sarif["runs"][0]["results"],
+        serde_json::json!([{
+            "level": "error",
+            "ruleId": "E2002",
+            "message": {
+                "text": "Variable was previously moved.\nnote: Trait has no implementation in context: \
+                         core::traits::Copy::<core::array::Array::<core::felt252>>.",
+            },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "lib.cairo" },
+                    "region": {
+                        "byteOffset": 78,
+                        "byteLength": 1,
+                        "startLine": 4,
+                        "startColumn": 5,
+                        "endLine": 4,
+                        "endColumn": 6,
+                    },
+                },
+            }],
+            "relatedLocations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "lib.cairo" },
+                    "region": {
+                        "byteOffset": 70,
+                        "byteLength": 1,
+                        "startLine": 3,
+                        "startColumn": 10,
+                        "endLine": 3,
+                        "endColumn": 11,
+                    },
+                },
+                "message": { "text": "variable was previously used here" },
+            }],
+        }])
+    );
+}
+
+#[test]
+fn test_generated_file_diagnostics() {
+    let json = format_crate_diagnostics(
+        "#[derive(Drop)]\nstruct A {\n    b: B,\n}\nstruct B {}\n",
+        MessageFormat::Json,
+    );
+    let diagnostic: serde_json::Value = serde_json::from_str(json.trim_end()).unwrap();
+    assert_eq!(diagnostic["span"]["line_start"], 1);
+    assert_eq!(diagnostic["generated_file"], "impls");
+    assert_eq!(diagnostic["plugin"], "DerivePlugin");
+
+    let sarif = format_crate_diagnostics(
+        "#[derive(Drop)]\nstruct A {\n    b: B,\n}\nstruct B {}\n",
+        MessageFormat::Sarif,
+    );
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    assert_eq!(
+        sarif["runs"][0]["results"][0]["properties"],
+        serde_json::json!({ "plugin": "DerivePlugin", "generatedFile": "impls" })
+    );
+}
+
+#[test]
+fn test_plugin_diagnostics() {
+    let json = format_crate_diagnostics(
+        "#[derive(Default)]\nenum A {}\nfn foo() -> felt252 {\n    consteval_int!(1 +)\n}\n",
+        MessageFormat::Json,
+    );
+    let diagnostics: Vec<serde_json::Value> =
+        json.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    // The diagnostics are reported by the plugins directly on user code.
+    let plugins: Vec<_> = diagnostics.iter().map(|diagnostic| &diagnostic["plugin"]).collect();
+    assert_eq!(plugins, ["DerivePlugin", "consteval_int"]);
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic["generated_file"].is_null()));
+}
+
+#[test]
+fn test_suggestion_diagnostics() {
+    let content = "fn foo() {\n    let a = 5;\n}\n";
+    let json = format_crate_diagnostics(content, MessageFormat::Json);
+    let diagnostic: serde_json::Value = serde_json::from_str(json.trim_end()).unwrap();
+    assert_eq!(
+        diagnostic["suggestions"],
+        serde_json::json!([{
+            "message": "Prefix the variable with `_`",
+            "applicability": "machine-applicable",
+            "edits": [{
+                "span": {
+                    "file": "lib.cairo",
+                    "byte_start": 19,
+                    "byte_end": 19,
+                    "line_start": 2,
+                    "column_start": 9,
+                    "line_end": 2,
+                    "column_end": 9,
+                },
+                "replacement": "_",
+            }],
+        }])
+    );
+
+    let sarif = format_crate_diagnostics(content, MessageFormat::Sarif);
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    assert_eq!(
+        sarif["runs"][0]["results"][0]["fixes"],
+        serde_json::json!([{
+            "description": { "text": "Prefix the variable with `_`" },
+            "artifactChanges": [{
+                "artifactLocation": { "uri": "lib.cairo" },
+                "replacements": [{
+                    "deletedRegion": {
+                        "byteOffset": 19,
+                        "byteLength": 0,
+                        "startLine": 2,
+                        "startColumn": 9,
+                        "endLine": 2,
+                        "endColumn": 9,
+                    },
+                    "insertedContent": { "text": "_" },
+                }],
+            }],
+            "properties": { "applicability": "machine-applicable" },
+        }])
+    );
+}
+
+/// Returns the diagnostics of a crate with the given content and settings, in the JSON format,
+/// along with whether the reporter found diagnostics when allowing warnings.
+fn crate_json_diagnostics(
+    db: &RootDatabase,
+    content: &str,
+    settings: &str,
+) -> (Vec<serde_json::Value>, bool) {
+    let crate_id = setup_test_crate_ex(db, content, Some(settings), None);
+    let crate_input = crate_id.long(db).clone().into_crate_input(db);
+    let mut json = String::new();
+    let found = DiagnosticsReporter::write_to_string_with_format(&mut json, MessageFormat::Json)
+        .with_crates(&[crate_input])
+        .allow_warnings()
+        .check(db);
+    (json.lines().map(|line| serde_json::from_str(line).unwrap()).collect(), found)
+}
+
+/// Returns the codes of the diagnostics of a crate of the latest edition with the given content.
+fn crate_diagnostic_codes(db: &RootDatabase, content: &str) -> Vec<String> {
+    let (diagnostics, _) = crate_json_diagnostics(db, content, "edition = \"2024_07\"");
+    diagnostics.iter().map(|diagnostic| diagnostic["code"].as_str().unwrap().to_string()).collect()
+}
+
+/// Returns the severities, codes and lines of the diagnostics of a crate with the given content
+/// and settings, along with whether the reporter found diagnostics when allowing warnings.
+fn crate_lint_diagnostics(content: &str, settings: &str) -> (Vec<String>, bool) {
+    let db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let (diagnostics, found) = crate_json_diagnostics(&db, content, settings);
+    let diagnostics = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            format!(
+                "{} {} at line {}",
+                diagnostic["severity"].as_str().unwrap(),
+                diagnostic["code"].as_str().unwrap(),
+                diagnostic["span"]["line_start"],
+            )
+        })
+        .collect();
+    (diagnostics, found)
+}
+
+#[test]
+fn test_lint_level_attributes() {
+    let (diagnostics, found) = crate_lint_diagnostics(
+        indoc! {"
+            fn warned() {
+                let a = 1;
+            }
+
+            #[allow(unused_variables)]
+            fn allowed() {
+                let a = 1;
+            }
+
+            #[deny(unused)]
+            fn denied() {
+                let a = 1;
+                #[warn(E0001)]
+                let b = 1;
+            }
+
+            #[allow(unused)]
+            mod inner {
+                #[warn(unused_variables)]
+                fn warned_again() {
+                    let a = 1;
+                }
+            }
+
+            #[deny(unreachable_code)]
+            fn unreachable() -> felt252 {
+                return 1;
+                2
+            }
+
+            #[expect(unused_variables)]
+            fn expected() {
+                let a = 1;
+            }
+
+            #[expect(unused_variables)]
+            fn unfulfilled() {}
+
+            #[deny(no_such_lint)]
+            fn unknown() {}
+        "},
+        "edition = \"2024_07\"",
+    );
+    assert_eq!(
+        diagnostics,
+        [
+            "warning E0001 at line 2",
+            "error E0001 at line 12",
+            "warning E0001 at line 14",
+            "warning E0199 at line 39",
+            "error E2001 at line 28",
+            "warning E0001 at line 21",
+            "warning E0200 at line 36",
+        ]
+    );
+    assert!(found);
+}
+
+#[test]
+fn test_lint_level_crate_settings() {
+    let content = indoc! {"
+        fn foo() -> felt252 {
+            let a = 1;
+            return 1;
+            2
+        }
+
+        #[allow(E0001)]
+        fn bar() {
+            let a = 1;
+        }
+    "};
+    let (diagnostics, found) = crate_lint_diagnostics(
+        content,
+        indoc! {r#"
+            edition = "2024_07"
+
+            [lints]
+            unused_variables = "deny"
+            unreachable_code = "allow"
+        "#},
+    );
+    assert_eq!(diagnostics, ["error E0001 at line 2"]);
+    assert!(found);
+
+    // An error code takes precedence over its lint.
+    let (diagnostics, found) = crate_lint_diagnostics(
+        content,
+        indoc! {r#"
+            edition = "2024_07"
+
+            [lints]
+            unused = "deny"
+            E0001 = "warn"
+        "#},
+    );
+    assert_eq!(diagnostics, ["warning E0001 at line 2", "warning E2001 at line 4"]);
+    assert!(!found);
+}
+
+/// A lint pass reporting functions named `todo`.
+#[derive(Debug)]
+struct TodoFunctions;
+
+impl LintPass for TodoFunctions {
+    fn lints(&self) -> Vec<&'static str> {
+        vec!["todo_functions"]
+    }
+
+    fn check_semantic<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: FunctionWithBodyId<'db>,
+    ) {
+        if let FunctionWithBodyId::Free(free_function_id) = function_id
+            && free_function_id.name(ctx.db) == "todo"
+        {
+            ctx.report(
+                "todo_functions",
+                free_function_id.stable_ptr(ctx.db).untyped(),
+                "Function is not implemented.",
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lint_passes() {
+    let db = RootDatabase::builder()
+        .detect_corelib()
+        .with_lint_pass(Arc::new(TodoFunctions))
+        .build()
+        .unwrap();
+    let content = indoc! {"
+        fn todo() {}
+
+        #[deny(todo_functions)]
+        mod denied {
+            fn todo() {}
+        }
+
+        #[allow(todo_functions)]
+        mod allowed {
+            fn todo() {}
+        }
+
+        fn compare(x: u8) -> bool {
+            x >= 0
+        }
+
+        #[warn(absurd_extreme_comparisons)]
+        fn warned_compare(x: u8) -> bool {
+            x >= 0
+        }
+    "};
+    let (diagnostics, found) = crate_json_diagnostics(&db, content, "edition = \"2024_07\"");
+    let diagnostics = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            format!(
+                "{} {} at line {}",
+                diagnostic["severity"].as_str().unwrap(),
+                diagnostic["code"].as_str().unwrap_or("without code"),
+                diagnostic["span"]["line_start"],
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        diagnostics,
+        [
+            "warning without code at line 1",
+            "warning E2015 at line 19",
+            "error without code at line 5",
+        ]
+    );
+    assert!(found);
+
+    // Denied lints of lint passes are reported even if warnings are ignored.
+    let crate_id = setup_test_crate_ex(&db, content, Some("edition = \"2024_07\""), None);
+    let crate_input = crate_id.long(&db).clone().into_crate_input(&db);
+    let mut diagnostics = String::new();
+    let found = DiagnosticsReporter::write_to_string(&mut diagnostics)
+        .with_crates(&[crate_input])
+        .ignore_all_warnings()
+        .check(&db);
+    assert_eq!(diagnostics.matches("error: Function is not implemented.").count(), 1);
+    assert!(!diagnostics.contains("warning"));
+    assert!(found);
+}
+
+#[test]
+fn test_error_code_explanation_examples() {
+    let db = RootDatabase::builder().detect_corelib().build().unwrap();
+    for (code, explanation) in error_code_explanations() {
+        let mut lines = explanation.lines();
+        while let Some(line) = lines.next() {
+            let Some(info) = line.strip_prefix("```") else {
+                continue;
+            };
+            let example =
+                lines.by_ref().take_while(|line| *line != "```").collect::<Vec<_>>().join("\n");
+            let codes = crate_diagnostic_codes(&db, &example);
+            match info {
+                "cairo,erroneous" => assert!(
+                    codes.iter().any(|c| c == code.as_str()),
+                    "Example of {code} reports {codes:?}:\n{example}"
+                ),
+                "cairo" => {
+                    assert!(codes.is_empty(), "Example of {code} reports {codes:?}:\n{example}")
+                }
+                _ => panic!("Unexpected code block `{info}` in the explanation of {code}."),
+            }
+        }
+    }
+}
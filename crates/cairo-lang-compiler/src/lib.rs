@@ -32,6 +32,7 @@ use crate::project::{ProjectConfig, get_main_crate_ids_from_project, setup_proje
 
 pub mod db;
 pub mod diagnostics;
+pub mod message_format;
 pub mod project;
 
 #[cfg(test)]
@@ -0,0 +1,211 @@
+//! Structured formats of the diagnostics reported by the compiler, for tools consuming them
+//! without parsing the human-readable text.
+
+use cairo_lang_diagnostics::{
+    FormattedDiagnosticEntry, ResolvedLocation, ResolvedSuggestion, Severity,
+};
+use serde_json::{Value, json};
+
+/// The format of the diagnostics reported by the compiler binaries. With the `clap` feature, it may
+/// be parsed as a command line argument value.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum MessageFormat {
+    /// Human-readable text, with the location marked in the source.
+    #[default]
+    Human,
+    /// A JSON object per diagnostic, one per line.
+    Json,
+    /// A single SARIF 2.1.0 log of all the diagnostics, for code scanning tools.
+    Sarif,
+}
+
+/// The URI of the SARIF 2.1.0 schema.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Returns the JSON object representing a diagnostic.
+///
+/// Positions are 1-based lines and columns (in characters), along with the byte range of the span.
+/// Diagnostics not attached to a location, such as a missing crate root, have a `null` span.
+///
+/// Diagnostics originating from a plugin report its name in `plugin`, whether the plugin reported
+/// them or generated the code they are reported on. Diagnostics originating from plugin generated
+/// code also report the name of the generated file in `generated_file`. Both are `null` for
+/// diagnostics reported by the compiler directly on user code.
+pub fn diagnostic_to_json(diagnostic: &FormattedDiagnosticEntry) -> Value {
+    let details = diagnostic.details();
+    json!({
+        "severity": diagnostic.severity().to_string(),
+        "code": diagnostic.error_code().map(|code| code.to_string()),
+        "message": details
+            .map_or_else(|| diagnostic.message().trim_end(), |details| details.message.as_str()),
+        "span": details.map(|details| span_to_json(&details.location)),
+        "notes": details.map_or_else(Vec::new, |details| {
+            details
+                .notes
+                .iter()
+                .map(|note| {
+                    json!({
+                        "message": note.text,
+                        "span": note.location.as_ref().map(span_to_json),
+                    })
+                })
+                .collect()
+        }),
+        "generated_file": details.and_then(|details| details.generated_file.clone()),
+        "plugin": details.and_then(|details| details.plugin.clone()),
+        "suggestions": details.map_or_else(Vec::new, |details| {
+            details.suggestions.iter().map(suggestion_to_json).collect()
+        }),
+        "rendered": diagnostic.to_string(),
+    })
+}
+
+/// Returns the JSON object representing a suggested fix of a diagnostic.
+fn suggestion_to_json(suggestion: &ResolvedSuggestion) -> Value {
+    json!({
+        "message": suggestion.message,
+        "applicability": suggestion.applicability.to_string(),
+        "edits": suggestion
+            .edits
+            .iter()
+            .map(|edit| json!({ "span": span_to_json(&edit.location), "replacement": edit.replacement }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Returns the JSON object representing the span of a location.
+fn span_to_json(location: &ResolvedLocation) -> Value {
+    json!({
+        "file": location.file_path,
+        "byte_start": location.byte_range.start,
+        "byte_end": location.byte_range.end,
+        "line_start": location.start.map(|position| position.line + 1),
+        "column_start": location.start.map(|position| position.col + 1),
+        "line_end": location.end.map(|position| position.line + 1),
+        "column_end": location.end.map(|position| position.col + 1),
+    })
+}
+
+/// Returns a SARIF log of a single run of the compiler, reporting the given diagnostics.
+pub fn diagnostics_to_sarif(diagnostics: &[FormattedDiagnosticEntry]) -> Value {
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "cairo",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": diagnostics.iter().map(sarif_result).collect::<Vec<_>>(),
+        }],
+    })
+}
+
+/// Returns the SARIF result of a diagnostic. Notes with a location are reported as related
+/// locations, and notes without one are appended to the message.
+/// As in [diagnostic_to_json], the plugin and the plugin generated file the diagnostic originates
+/// from are reported in the `plugin` and `generatedFile` properties.
+fn sarif_result(diagnostic: &FormattedDiagnosticEntry) -> Value {
+    let level = match diagnostic.severity() {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    let mut result = json!({ "level": level });
+    if let Some(code) = diagnostic.error_code() {
+        result["ruleId"] = code.to_string().into();
+    }
+    let Some(details) = diagnostic.details() else {
+        result["message"] = json!({ "text": diagnostic.message().trim_end() });
+        return result;
+    };
+    let mut text = details.message.clone();
+    for note in details.notes.iter().filter(|note| note.location.is_none()) {
+        text += &format!("\nnote: {}", note.text);
+    }
+    result["message"] = json!({ "text": text });
+    result["locations"] = json!([sarif_location(&details.location)]);
+    let related_locations = details
+        .notes
+        .iter()
+        .filter_map(|note| {
+            let mut location = sarif_location(note.location.as_ref()?);
+            location["message"] = json!({ "text": note.text });
+            Some(location)
+        })
+        .collect::<Vec<_>>();
+    if !related_locations.is_empty() {
+        result["relatedLocations"] = related_locations.into();
+    }
+    let mut properties = serde_json::Map::new();
+    if let Some(plugin) = &details.plugin {
+        properties.insert("plugin".into(), plugin.as_str().into());
+    }
+    if let Some(generated_file) = &details.generated_file {
+        properties.insert("generatedFile".into(), generated_file.as_str().into());
+    }
+    if !properties.is_empty() {
+        result["properties"] = properties.into();
+    }
+    if !details.suggestions.is_empty() {
+        result["fixes"] = details.suggestions.iter().map(sarif_fix).collect::<Vec<_>>().into();
+    }
+    result
+}
+
+/// Returns the SARIF fix of a suggestion, with the replacements grouped by the file they are in.
+fn sarif_fix(suggestion: &ResolvedSuggestion) -> Value {
+    let mut artifact_changes: Vec<(&str, Vec<Value>)> = vec![];
+    for edit in &suggestion.edits {
+        let replacement = json!({
+            "deletedRegion": sarif_region(&edit.location),
+            "insertedContent": { "text": edit.replacement },
+        });
+        let file_path = edit.location.file_path.as_str();
+        match artifact_changes.iter_mut().find(|(path, _)| *path == file_path) {
+            Some((_, replacements)) => replacements.push(replacement),
+            None => artifact_changes.push((file_path, vec![replacement])),
+        }
+    }
+    json!({
+        "description": { "text": suggestion.message },
+        "artifactChanges": artifact_changes
+            .into_iter()
+            .map(|(file_path, replacements)| {
+                json!({ "artifactLocation": { "uri": file_path }, "replacements": replacements })
+            })
+            .collect::<Vec<_>>(),
+        "properties": { "applicability": suggestion.applicability.to_string() },
+    })
+}
+
+/// Returns the SARIF physical location of a location.
+fn sarif_location(location: &ResolvedLocation) -> Value {
+    json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": location.file_path },
+            "region": sarif_region(location),
+        },
+    })
+}
+
+/// Returns the SARIF region of the span of a location.
+fn sarif_region(location: &ResolvedLocation) -> Value {
+    let mut region = json!({
+        "byteOffset": location.byte_range.start,
+        "byteLength": location.byte_range.len(),
+    });
+    if let Some(start) = location.start {
+        region["startLine"] = (start.line + 1).into();
+        region["startColumn"] = (start.col + 1).into();
+    }
+    if let Some(end) = location.end {
+        region["endLine"] = (end.line + 1).into();
+        region["endColumn"] = (end.col + 1).into();
+    }
+    region
+}
@@ -3,7 +3,7 @@ use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::{DiagnosticLocation, DiagnosticNote, Maybe, Severity};
+use cairo_lang_diagnostics::{DiagnosticLocation, DiagnosticNote, ErrorCode, Maybe, Severity};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::{
@@ -1787,6 +1787,7 @@ struct VirtualFileCached {
     code_mappings: Vec<CodeMapping>,
     kind: FileKind,
     original_item_removed: bool,
+    plugin: Option<String>,
 }
 
 impl VirtualFileCached {
@@ -1798,6 +1799,7 @@ impl VirtualFileCached {
             code_mappings: virtual_file.code_mappings.to_vec(),
             kind: virtual_file.kind,
             original_item_removed: virtual_file.original_item_removed,
+            plugin: virtual_file.plugin.clone(),
         }
     }
     fn embed<'db>(self, ctx: &mut DefCacheLoadingContext<'db>) -> VirtualFile<'db> {
@@ -1808,6 +1810,7 @@ impl VirtualFileCached {
             code_mappings: self.code_mappings.into(),
             kind: self.kind,
             original_item_removed: self.original_item_removed,
+            plugin: self.plugin,
         }
     }
 }
@@ -1851,6 +1854,8 @@ struct PluginDiagnosticCached {
     message: String,
     severity: SeverityCached,
     inner_span: Option<(TextWidth, TextWidth)>,
+    error_code: Option<String>,
+    plugin: Option<String>,
 }
 
 impl PluginDiagnosticCached {
@@ -1866,6 +1871,8 @@ impl PluginDiagnosticCached {
                 Severity::Warning => SeverityCached::Warning,
             },
             inner_span: diagnostic.inner_span,
+            error_code: diagnostic.error_code.map(|code| code.as_str().to_string()),
+            plugin: diagnostic.plugin.clone(),
         }
     }
     fn embed<'db>(self, ctx: &mut DefCacheLoadingContext<'db>) -> PluginDiagnostic<'db> {
@@ -1877,6 +1884,8 @@ impl PluginDiagnosticCached {
                 SeverityCached::Warning => Severity::Warning,
             },
             inner_span: self.inner_span,
+            error_code: self.error_code.and_then(|code| ErrorCode::assigned(&code)),
+            plugin: self.plugin,
         }
     }
 }
@@ -2,16 +2,18 @@ use std::any::Any;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::{DiagnosticNote, Maybe, PluginFileDiagnosticNotes, ToMaybe};
+use cairo_lang_diagnostics::{
+    DiagnosticNote, Maybe, PluginFileDiagnosticNotes, ToMaybe, error_code,
+};
 use cairo_lang_filesystem::db::{ExternalFiles, FilesGroup, TryExtAsVirtual};
 use cairo_lang_filesystem::ids::{
     CrateId, CrateInput, Directory, FileId, FileKind, FileLongId, Tracked, VirtualFile,
 };
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::attribute::consts::{
-    ALLOW_ATTR, ALLOW_ATTR_ATTR, DEPRECATED_ATTR, FEATURE_ATTR, FMT_SKIP_ATTR,
-    IMPLICIT_PRECEDENCE_ATTR, INLINE_ATTR, INTERNAL_ATTR, MUST_USE_ATTR, PHANTOM_ATTR,
-    STARKNET_INTERFACE_ATTR, UNSTABLE_ATTR,
+    ALLOW_ATTR, ALLOW_ATTR_ATTR, DENY_ATTR, DEPRECATED_ATTR, EXPECT_ATTR, FEATURE_ATTR,
+    FMT_SKIP_ATTR, IMPLICIT_PRECEDENCE_ATTR, INLINE_ATTR, INTERNAL_ATTR, MUST_USE_ATTR,
+    PHANTOM_ATTR, STARKNET_INTERFACE_ATTR, UNSTABLE_ATTR, WARN_ATTR,
 };
 use cairo_lang_syntax::attribute::structured::AttributeStructurize;
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
@@ -475,6 +477,9 @@ fn allowed_attributes<'db>(
         DEPRECATED_ATTR,
         INTERNAL_ATTR,
         ALLOW_ATTR,
+        WARN_ATTR,
+        DENY_ATTR,
+        EXPECT_ATTR,
         ALLOW_ATTR_ATTR,
         FEATURE_ATTR,
         PHANTOM_ATTR,
@@ -495,7 +500,8 @@ fn allowed_attributes<'db>(
 // TODO(eytan-starkware): Untrack this
 #[salsa::tracked(returns(ref))]
 fn allowed_statement_attributes<'db>(_db: &'db dyn Database) -> OrderedHashSet<String> {
-    let all_attributes = [FMT_SKIP_ATTR, ALLOW_ATTR, FEATURE_ATTR];
+    let all_attributes =
+        [FMT_SKIP_ATTR, ALLOW_ATTR, WARN_ATTR, DENY_ATTR, EXPECT_ATTR, FEATURE_ATTR];
     OrderedHashSet::from_iter(all_attributes.map(|attr| attr.into()))
 }
 
@@ -1104,7 +1110,8 @@ fn priv_module_sub_files<'db>(
             let plugin = plugin_id.long(db);
 
             let result = plugin.generate_code(db, item_ast.clone(), &metadata);
-            plugin_diagnostics.extend(result.diagnostics);
+            plugin_diagnostics
+                .extend(result.diagnostics.into_iter().map(|diag| diag.with_plugin(plugin.name())));
             if result.remove_original_item {
                 remove_original_item = true;
             }
@@ -1133,6 +1140,7 @@ fn priv_module_sub_files<'db>(
                         code_mappings: generated.code_mappings.into(),
                         kind: FileKind::Module,
                         original_item_removed: remove_original_item,
+                        plugin: Some(plugin.name()),
                     },
                 );
                 aux_data.push(generated.aux_data);
@@ -1162,10 +1170,10 @@ fn collect_extra_allowed_attributes<'db>(
     for attr in item.query_attr(db, ALLOW_ATTR_ATTR) {
         let args = attr.clone().structurize(db).args;
         if args.is_empty() {
-            plugin_diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                "Expected arguments.".to_string(),
-            ));
+            plugin_diagnostics.push(
+                PluginDiagnostic::error(attr.stable_ptr(db), "Expected arguments.".to_string())
+                    .with_error_code(error_code!(E3001)),
+            );
             continue;
         }
         for arg in args {
@@ -1176,10 +1184,13 @@ fn collect_extra_allowed_attributes<'db>(
                 extra_allowed_attributes.insert(segment.ident(db).text(db).into());
                 continue;
             }
-            plugin_diagnostics.push(PluginDiagnostic::error(
-                arg.arg.stable_ptr(db),
-                "Expected simple identifier.".to_string(),
-            ));
+            plugin_diagnostics.push(
+                PluginDiagnostic::error(
+                    arg.arg.stable_ptr(db),
+                    "Expected simple identifier.".to_string(),
+                )
+                .with_error_code(error_code!(E3002)),
+            );
         }
     }
     extra_allowed_attributes
@@ -1200,10 +1211,10 @@ pub fn validate_attributes_flat<'db>(
             || extra_allowed_attributes.contains(attr_text)
             || local_extra_attributes.contains(attr_text))
         {
-            plugin_diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                "Unsupported attribute.".to_string(),
-            ));
+            plugin_diagnostics.push(
+                PluginDiagnostic::error(attr.stable_ptr(db), "Unsupported attribute.".to_string())
+                    .with_error_code(error_code!(E3003)),
+            );
         }
     }
 }
@@ -405,6 +405,10 @@ impl MacroPlugin for MacroPluginLongId {
         // as the underlying plugin object.
         self.0.plugin_type_id()
     }
+
+    fn name(&self) -> String {
+        self.0.name()
+    }
 }
 
 // `PartialEq` and `Hash` cannot be derived on `Arc<dyn ...>`,
@@ -3,7 +3,7 @@ use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::db::Edition;
 use cairo_lang_filesystem::ids::CodeMapping;
@@ -94,6 +94,12 @@ pub struct PluginDiagnostic<'db> {
     /// is not segmented into each argument.
     /// The tuple is (offset, width).
     pub inner_span: Option<(TextWidth, TextWidth)>,
+    /// The code identifying the kind of the diagnostic. Diagnostics without one are given the
+    /// code of plugin diagnostics.
+    pub error_code: Option<ErrorCode>,
+    /// The name of the plugin that reported the diagnostic. Set by the compiler when running the
+    /// plugin, so plugins do not need to set it themselves.
+    pub plugin: Option<String>,
 }
 impl<'db> PluginDiagnostic<'db> {
     pub fn error(
@@ -105,6 +111,8 @@ impl<'db> PluginDiagnostic<'db> {
             message,
             severity: Severity::Error,
             inner_span: None,
+            error_code: None,
+            plugin: None,
         }
     }
 
@@ -123,6 +131,8 @@ impl<'db> PluginDiagnostic<'db> {
             message,
             severity: Severity::Error,
             inner_span: Some((offset, width)),
+            error_code: None,
+            plugin: None,
         }
     }
 
@@ -135,8 +145,28 @@ impl<'db> PluginDiagnostic<'db> {
             message,
             severity: Severity::Warning,
             inner_span: None,
+            error_code: None,
+            plugin: None,
         }
     }
+
+    /// Sets the code identifying the kind of the diagnostic.
+    pub fn with_error_code(self, error_code: ErrorCode) -> Self {
+        Self { error_code: Some(error_code), ..self }
+    }
+
+    /// Sets the name of the plugin that reported the diagnostic.
+    pub fn with_plugin(self, plugin: String) -> Self {
+        Self { plugin: Some(plugin), ..self }
+    }
+}
+
+/// Returns the name of the type of a plugin, without its path and generic arguments, used as the
+/// default name of the plugin.
+pub fn plugin_type_name<T: ?Sized>() -> String {
+    let name = any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name).to_string()
 }
 
 /// A structure containing additional info about the current module item on which macro plugin
@@ -201,6 +231,12 @@ pub trait MacroPlugin: std::fmt::Debug + Sync + Send + Any {
     fn plugin_type_id(&self) -> any::TypeId {
         self.type_id()
     }
+
+    /// The name of the plugin, reported with the diagnostics it originates.
+    /// Defaults to the name of the plugin type.
+    fn name(&self) -> String {
+        plugin_type_name::<Self>()
+    }
 }
 
 /// Result of plugin code generation.
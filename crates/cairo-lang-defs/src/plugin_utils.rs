@@ -1,3 +1,4 @@
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
@@ -64,15 +65,18 @@ pub fn unsupported_bracket_diagnostic<'db, CallAst: InlineMacroCall<'db>>(
     legacy_macro_ast: &CallAst,
     macro_ast: impl Into<SyntaxStablePtrId<'db>>,
 ) -> CallAst::Result {
-    CallAst::Result::diagnostic_only(PluginDiagnostic::error_with_inner_span(
-        db,
-        macro_ast,
-        legacy_macro_ast.arguments(db).left_bracket_syntax_node(db),
-        format!(
-            "Macro `{}` does not support this bracket type.",
-            legacy_macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
-        ),
-    ))
+    CallAst::Result::diagnostic_only(
+        PluginDiagnostic::error_with_inner_span(
+            db,
+            macro_ast,
+            legacy_macro_ast.arguments(db).left_bracket_syntax_node(db),
+            format!(
+                "Macro `{}` does not support this bracket type.",
+                legacy_macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
+            ),
+        )
+        .with_error_code(error_code!(E3004)),
+    )
 }
 
 pub fn not_legacy_macro_diagnostic(stable_ptr: SyntaxStablePtrId<'_>) -> PluginDiagnostic<'_> {
@@ -82,6 +86,7 @@ pub fn not_legacy_macro_diagnostic(stable_ptr: SyntaxStablePtrId<'_>) -> PluginD
          parentheses, brackets, or braces."
             .to_string(),
     )
+    .with_error_code(error_code!(E3005))
 }
 
 /// Extracts a single unnamed argument.
@@ -163,7 +168,8 @@ macro_rules! extract_macro_unnamed_args {
                             .get_text_without_trivia($db),
                         $n
                     ),
-                ),
+                )
+                .with_error_code(cairo_lang_diagnostics::error_code!(E3046)),
             );
         };
         let args: [ast::Expr; $n] = args.try_into().unwrap();
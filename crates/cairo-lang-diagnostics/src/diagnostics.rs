@@ -1,17 +1,19 @@
 use std::fmt;
 use std::hash::Hash;
+use std::ops::Range;
 use std::sync::Arc;
 
 use cairo_lang_debug::debug::DebugWithDb;
 use cairo_lang_filesystem::db::get_originating_location;
 use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_filesystem::span::{TextPosition, TextSpan};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::Itertools;
 use salsa::{AsDynDatabase, Database};
 
 use crate::error_code::{ErrorCode, OptionErrorCodeExt};
 use crate::location_marks::get_location_marks;
+use crate::suggestion::{ResolvedSuggestion, Suggestion};
 
 #[cfg(test)]
 #[path = "diagnostics_test.rs"]
@@ -47,6 +49,19 @@ pub trait DiagnosticEntry<'db>: Clone + fmt::Debug + Eq + Hash {
     fn error_code(&self) -> Option<ErrorCode> {
         None
     }
+    /// Returns the name of the lint the diagnostic is a warning of. Needed only for lints whose
+    /// warnings have no error code.
+    fn lint(&self) -> Option<&str> {
+        None
+    }
+    /// Returns the name of the plugin that reported the diagnostic, if reported by a plugin.
+    fn plugin(&self) -> Option<&str> {
+        None
+    }
+    /// Returns the suggested fixes of the diagnostic.
+    fn suggestions(&self, _db: &'db Self::DbType) -> Vec<Suggestion<'db>> {
+        vec![]
+    }
     /// Returns true if the two should be regarded as the same kind when filtering duplicate
     /// diagnostics.
     fn is_same_kind(&self, other: &Self) -> bool;
@@ -260,16 +275,82 @@ pub fn format_diagnostics(
     format!("{message}\n --> {:?}\n", location.debug(db))
 }
 
+/// A location of a diagnostic resolved to a position in its file, for structured output.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedLocation {
+    /// The full path of the file.
+    pub file_path: String,
+    /// The byte range of the span in the file.
+    pub byte_range: Range<usize>,
+    /// The position of the start of the span, if found in the file.
+    pub start: Option<TextPosition>,
+    /// The position of the end of the span, if found in the file.
+    pub end: Option<TextPosition>,
+}
+impl ResolvedLocation {
+    /// Resolves the given location.
+    pub fn new(db: &dyn Database, location: &DiagnosticLocation<'_>) -> Self {
+        Self {
+            file_path: location.file_id.full_path(db),
+            byte_range: location.span.to_str_range(),
+            start: location.span.start.position_in_file(db, location.file_id),
+            end: location.span.end.position_in_file(db, location.file_id),
+        }
+    }
+}
+
+/// A note of a diagnostic with its location resolved, for structured output.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedNote {
+    pub text: String,
+    /// The location of the note in the originating user code, if any.
+    pub location: Option<ResolvedLocation>,
+}
+
+/// The details of a diagnostic attached to a location, for consumers that do not parse the
+/// formatted message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosticDetails {
+    /// The message of the diagnostic, without its location and notes.
+    pub message: String,
+    /// The location of the diagnostic in the originating user code.
+    pub location: ResolvedLocation,
+    /// The notes of the diagnostic, including the notes of the plugin generated files it
+    /// originates from.
+    pub notes: Vec<ResolvedNote>,
+    /// The name of the plugin generated file the diagnostic originates from, if it is not
+    /// reported directly on user code.
+    pub generated_file: Option<String>,
+    /// The name of the plugin the diagnostic originates from: the plugin that reported it, or
+    /// else the plugin that generated the code it is reported on.
+    pub plugin: Option<String>,
+    /// The suggested fixes of the diagnostic, excluding the ones editing plugin generated code.
+    pub suggestions: Vec<ResolvedSuggestion>,
+}
+
 #[derive(Debug)]
 pub struct FormattedDiagnosticEntry {
     severity: Severity,
     error_code: Option<ErrorCode>,
     message: String,
+    details: Option<DiagnosticDetails>,
 }
 
 impl FormattedDiagnosticEntry {
     pub fn new(severity: Severity, error_code: Option<ErrorCode>, message: String) -> Self {
-        Self { severity, error_code, message }
+        Self { severity, error_code, message, details: None }
+    }
+
+    /// Overrides the severity of the diagnostic, e.g. by the level of its lint.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Attaches the structured details of the diagnostic.
+    pub fn with_details(mut self, details: DiagnosticDetails) -> Self {
+        self.details = Some(details);
+        self
     }
 
     pub fn is_empty(&self) -> bool {
@@ -287,6 +368,11 @@ impl FormattedDiagnosticEntry {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Returns the structured details of the diagnostic, if it is attached to a location.
+    pub fn details(&self) -> Option<&DiagnosticDetails> {
+        self.details.as_ref()
+    }
 }
 
 impl fmt::Display for FormattedDiagnosticEntry {
@@ -327,39 +413,66 @@ impl<'db, TEntry: DiagnosticEntry<'db> + salsa::Update> Diagnostics<'db, TEntry>
         db: &'db TEntry::DbType,
         file_notes: &OrderedHashMap<FileId<'db>, DiagnosticNote<'db>>,
     ) -> Vec<FormattedDiagnosticEntry> {
-        let mut res: Vec<FormattedDiagnosticEntry> = Vec::new();
+        self.get_diagnostics_without_duplicates(db)
+            .iter()
+            .map(|entry| Self::format_entry(db, entry, file_notes))
+            .collect()
+    }
 
+    /// Formats a single entry to a pair of severity and message.
+    pub fn format_entry(
+        db: &'db TEntry::DbType,
+        entry: &TEntry,
+        file_notes: &OrderedHashMap<FileId<'db>, DiagnosticNote<'db>>,
+    ) -> FormattedDiagnosticEntry {
+        let mut msg = String::new();
         let files_db = db.as_dyn_database();
-        for entry in &self.get_diagnostics_without_duplicates(db) {
-            let mut msg = String::new();
-            let diag_location = entry.location(db);
-            let (user_location, parent_file_notes) =
-                diag_location.user_location_with_plugin_notes(files_db, file_notes);
-
-            let include_generated_location = diag_location != user_location
-                && std::env::var("CAIRO_DEBUG_GENERATED_CODE").is_ok();
-            msg += &format_diagnostics(files_db, &entry.format(db), user_location);
-
-            if include_generated_location {
-                msg += &format!(
-                    "note: The error originates from the generated code in {:?}\n",
-                    diag_location.debug(files_db)
-                );
-            }
-
-            for note in entry.notes(db) {
-                msg += &format!("note: {:?}\n", note.debug(files_db))
-            }
-            for note in parent_file_notes {
-                msg += &format!("note: {:?}\n", note.debug(files_db))
-            }
-            msg += "\n";
+        let diag_location = entry.location(db);
+        let (user_location, parent_file_notes) =
+            diag_location.user_location_with_plugin_notes(files_db, file_notes);
+
+        let include_generated_location =
+            diag_location != user_location && std::env::var("CAIRO_DEBUG_GENERATED_CODE").is_ok();
+        let message = entry.format(db);
+        msg += &format_diagnostics(files_db, &message, user_location.clone());
+
+        if include_generated_location {
+            msg += &format!(
+                "note: The error originates from the generated code in {:?}\n",
+                diag_location.debug(files_db)
+            );
+        }
 
-            let formatted =
-                FormattedDiagnosticEntry::new(entry.severity(), entry.error_code(), msg);
-            res.push(formatted);
+        let mut notes = vec![];
+        for note in entry.notes(db).iter().chain(&parent_file_notes) {
+            msg += &format!("note: {:?}\n", note.debug(files_db));
+            notes.push(ResolvedNote {
+                text: note.text.clone(),
+                location: note.location.as_ref().map(|location| {
+                    ResolvedLocation::new(files_db, &location.user_location(files_db))
+                }),
+            });
         }
-        res
+        msg += "\n";
+
+        let details = DiagnosticDetails {
+            message,
+            location: ResolvedLocation::new(files_db, &user_location),
+            notes,
+            generated_file: (diag_location.file_id != user_location.file_id)
+                .then(|| diag_location.file_id.file_name(files_db)),
+            plugin: entry
+                .plugin()
+                .map(ToString::to_string)
+                .or_else(|| diag_location.file_id.plugin(files_db)),
+            suggestions: entry
+                .suggestions(db)
+                .iter()
+                .filter_map(|suggestion| ResolvedSuggestion::new(files_db, suggestion))
+                .collect(),
+        };
+        FormattedDiagnosticEntry::new(entry.severity(), entry.error_code(), msg)
+            .with_details(details)
     }
 
     /// Format entries to a [`String`] with messages prefixed by severity.
@@ -40,6 +40,7 @@ fn setup<'db>(db: &'db FilesDatabaseForTesting) -> FileId<'db> {
         code_mappings: [].into(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     })
     .intern(db)
 }
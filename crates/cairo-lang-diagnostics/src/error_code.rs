@@ -1,5 +1,9 @@
 use std::fmt;
 
+#[cfg(test)]
+#[path = "error_code_test.rs"]
+mod test;
+
 /// The unique and never-changing identifier of an error or warning.
 ///
 /// Valid error codes must start with capital `E` followed by 4 decimal digits, e.g.: `E0001`.
@@ -29,9 +33,24 @@ impl ErrorCode {
         format!("[{self}]")
     }
 
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'static str {
         self.0
     }
+
+    /// Returns the short description of this error code, if it is assigned.
+    pub fn description(self) -> Option<&'static str> {
+        ERROR_CODES.iter().find(|(code, _)| *code == self).map(|(_, description)| *description)
+    }
+
+    /// Returns the long-form explanation of this error code, in markdown, if there is one.
+    pub fn explanation(self) -> Option<&'static str> {
+        EXPLANATIONS.iter().find(|(code, _)| *code == self).map(|(_, explanation)| *explanation)
+    }
+
+    /// Returns the error code with the given text, e.g. `E0001`, if it is assigned.
+    pub fn assigned(code: &str) -> Option<Self> {
+        ERROR_CODES.iter().map(|(assigned_code, _)| *assigned_code).find(|c| c.0 == code)
+    }
 }
 
 impl fmt::Display for ErrorCode {
@@ -74,3 +93,470 @@ impl OptionErrorCodeExt for Option<ErrorCode> {
         self.map(ErrorCode::display_bracketed).unwrap_or_default()
     }
 }
+
+/// Constructs the registry of error codes, along with their short descriptions.
+macro_rules! error_codes {
+    ($($code:ident: $description:literal),* $(,)?) => {
+        &[$((ErrorCode::new(stringify!($code)), $description)),*]
+    };
+}
+
+/// The registry of all the assigned error codes, along with their short descriptions, sorted by
+/// code.
+///
+/// Codes are grouped by the compilation stage reporting them: `E0xxx` for semantic diagnostics,
+/// `E1xxx` for parser diagnostics, `E2xxx` for lowering diagnostics and `E3xxx` for diagnostics of
+/// the compiler plugins: `E30xx` for the core plugins, `E31xx` for the test plugin, `E32xx` for the
+/// Starknet plugin and `E33xx` for the executable plugin.
+/// Every code given to a diagnostic must be registered here.
+const ERROR_CODES: &[(ErrorCode, &str)] = error_codes![
+    E0001: "Unused variable.",
+    E0002: "Method cannot be called on the value.",
+    E0003: "Missing member in a struct constructor.",
+    E0004: "Missing items in an impl.",
+    E0005: "Module file not found.",
+    E0006: "Path not found.",
+    E0007: "No such member of a type.",
+    E0008: "Unsupported feature.",
+    E0009: "Unknown literal.",
+    E0010: "Unknown binary operator.",
+    E0011: "Unknown trait.",
+    E0012: "Unknown impl.",
+    E0013: "Unexpected kind of element.",
+    E0014: "Unknown type.",
+    E0015: "Unknown enum.",
+    E0016: "Invalid literal.",
+    E0017: "Not a variant.",
+    E0018: "Not a struct.",
+    E0019: "Not a type.",
+    E0020: "Not a trait.",
+    E0021: "Not an impl.",
+    E0022: "Impl item is not a member of the trait.",
+    E0023: "Implicit impl cannot be inferred.",
+    E0024: "Generic parameters are not supported in the item.",
+    E0025: "Unexpected generic arguments.",
+    E0026: "Unknown member.",
+    E0027: "Instances of phantom types cannot be created.",
+    E0028: "Non-phantom type containing a phantom type.",
+    E0029: "Member specified more than once.",
+    E0030: "Base struct is not the last argument.",
+    E0031: "Base struct has no effect.",
+    E0032: "Cycle of `const` items.",
+    E0033: "Cycle of `use` items.",
+    E0034: "Cycle of type aliases or impl types.",
+    E0035: "Cycle of impl aliases.",
+    E0036: "Cycle of generic impl requirements.",
+    E0037: "Wrong number of parameters in an impl function.",
+    E0038: "Wrong number of arguments.",
+    E0039: "Wrong parameter type in an impl function.",
+    E0040: "Variant constructor argument is not immutable.",
+    E0041: "Mutable parameter in a trait function.",
+    E0042: "Parameter of an impl function should be a reference.",
+    E0043: "Parameter of an impl function should not be a reference.",
+    E0044: "Wrong parameter name in an impl function.",
+    E0045: "Wrong trait of a generic parameter in an impl function.",
+    E0046: "Wrong kind of a generic parameter in an impl function.",
+    E0047: "Wrong type.",
+    E0048: "Variable bound inconsistently across pattern alternatives.",
+    E0049: "Wrong argument type.",
+    E0050: "Wrong return type.",
+    E0051: "Wrong expression type.",
+    E0052: "Wrong number of generic parameters in an impl function.",
+    E0053: "Wrong return type in an impl function.",
+    E0054: "Ambiguous trait.",
+    E0055: "Variable not found.",
+    E0056: "Variable missing in a pattern alternative.",
+    E0057: "Struct member redefinition.",
+    E0058: "Enum variant redefinition.",
+    E0059: "Type of infinite size.",
+    E0060: "Array of zero sized elements.",
+    E0061: "Parameter name redefinition.",
+    E0062: "Condition is not a `bool`.",
+    E0063: "Incompatible types of arms.",
+    E0064: "Type has no members.",
+    E0065: "No such struct member.",
+    E0066: "Member is not visible.",
+    E0067: "No such variant.",
+    E0068: "`?` in a function not returning `Option` or `Result`.",
+    E0069: "Incompatible error propagation type.",
+    E0070: "Error propagation on a non-error type.",
+    E0071: "Unhandled `#[must_use]` type.",
+    E0072: "Use of an unstable feature.",
+    E0073: "Use of a deprecated feature.",
+    E0074: "Use of an internal feature.",
+    E0075: "Invalid feature marker attribute.",
+    E0076: "Unhandled `#[must_use]` function.",
+    E0077: "Unused constant.",
+    E0078: "Unused `use` item.",
+    E0079: "Constant defined multiple times.",
+    E0080: "Binding defined multiple times.",
+    E0081: "Generic item defined multiple times.",
+    E0082: "Unsupported `use` item in a statement.",
+    E0083: "Const generic arguments are not allowed in the context.",
+    E0084: "Negative impls are not enabled.",
+    E0085: "Negative impls in a non-impl definition.",
+    E0086: "`ref` argument is not a variable.",
+    E0087: "`ref` argument is not a mutable variable.",
+    E0088: "`ref` argument without `ref`.",
+    E0089: "Argument with modifiers to an immutable parameter.",
+    E0090: "Assignment to an immutable variable.",
+    E0091: "Invalid left-hand side of an assignment.",
+    E0092: "Invalid member expression.",
+    E0093: "Invalid path.",
+    E0094: "Ambiguous path.",
+    E0095: "`self` in a `use` item not in a multi-use.",
+    E0096: "`self` in a `use` item with an empty path.",
+    E0097: "`*` in a `use` item with an empty path.",
+    E0098: "Global uses are not supported in the edition.",
+    E0099: "Trait in a trait must be explicit.",
+    E0100: "Impl in an impl must be explicit.",
+    E0101: "Trait item forbidden in its trait.",
+    E0102: "Trait item forbidden in its impl.",
+    E0103: "Impl item forbidden in its impl.",
+    E0104: "`super` used in the crate's root module.",
+    E0105: "`super` used at the top level of a macro call.",
+    E0106: "Item is not visible.",
+    E0107: "Unused import.",
+    E0108: "Redundant modifier.",
+    E0109: "Reference to a local variable.",
+    E0110: "Unexpected enum pattern.",
+    E0111: "Unexpected struct pattern.",
+    E0112: "Unexpected tuple pattern.",
+    E0113: "Unexpected fixed size array pattern.",
+    E0114: "Wrong number of tuple elements in a pattern.",
+    E0115: "Wrong number of fixed size array elements in a pattern.",
+    E0116: "Wrong enum in a pattern.",
+    E0117: "Invalid `Copy` impl.",
+    E0118: "Invalid `Drop` impl.",
+    E0119: "Invalid impl item.",
+    E0120: "Panicking function passed as `nopanic`.",
+    E0121: "Non-const function passed as `const`.",
+    E0122: "`nopanic` function calls a function that may panic.",
+    E0123: "Extern function not marked as `nopanic`.",
+    E0124: "Diagnostic of a plugin without a code.",
+    E0125: "Name defined multiple times.",
+    E0126: "`pub` global `use`.",
+    E0127: "Named arguments are not supported in the context.",
+    E0128: "Argument passed to a negative impl.",
+    E0129: "Unnamed argument after a named argument.",
+    E0130: "Named argument does not match the parameter name.",
+    E0131: "Unsupported outside of a function.",
+    E0132: "Unsupported constant expression.",
+    E0133: "Failed constant calculation.",
+    E0134: "Constant calculation depth exceeded.",
+    E0135: "Division by zero.",
+    E0136: "Extern type with impl generics.",
+    E0137: "Missing semicolon.",
+    E0138: "Trait mismatch.",
+    E0139: "Dereference of a non-reference.",
+    E0140: "Inference error.",
+    E0141: "No implementation of the index operator.",
+    E0142: "No implementation of a trait.",
+    E0143: "Call of a non-function.",
+    E0144: "Multiple implementations of the index operator.",
+    E0145: "Unsupported `inline` arguments.",
+    E0146: "Redundant `inline` attribute.",
+    E0147: "`inline` attribute on an extern function.",
+    E0148: "`#[inline(always)]` on a function with impl generic parameters.",
+    E0149: "Tail expression in a `loop` block.",
+    E0150: "`continue` outside of a loop.",
+    E0151: "`break` outside of a loop.",
+    E0152: "`break` with a value outside of a `loop`.",
+    E0153: "`?` inside a loop.",
+    E0154: "`implicit_precedence` attribute on an extern function.",
+    E0155: "Redundant `implicit_precedence` attribute.",
+    E0156: "Unsupported `implicit_precedence` arguments.",
+    E0157: "Unsupported `feature` attribute arguments.",
+    E0158: "Unsupported `allow` attribute arguments.",
+    E0159: "Unsupported `pub` argument.",
+    E0160: "Unknown statement attribute.",
+    E0161: "Inline macro not found.",
+    E0162: "Inline macro failed.",
+    E0163: "No rule of a macro matches the call.",
+    E0164: "Macro call to a non-macro.",
+    E0165: "Unknown generic parameter.",
+    E0166: "Positional generic argument after a named one.",
+    E0167: "Duplicate generic argument.",
+    E0168: "Too many generic arguments.",
+    E0169: "Generic arguments out of order.",
+    E0170: "Coupon for an extern function.",
+    E0171: "`__coupon__` argument with modifiers.",
+    E0172: "Coupons are disabled.",
+    E0173: "Fixed size array type with not exactly one type.",
+    E0174: "Fixed size array type without a size.",
+    E0175: "Fixed size array type with a non-numeric size.",
+    E0176: "Fixed size array with a size and not exactly one value.",
+    E0177: "Fixed size array size is too big.",
+    E0178: "`Self` is not supported in the context.",
+    E0179: "`Self` is not the first segment of a path.",
+    E0180: "`$` is not supported in the context.",
+    E0181: "Unknown resolver modifier.",
+    E0182: "Empty path after a resolver modifier.",
+    E0183: "Dereference cycle.",
+    E0184: "Reimplementation of a compiler trait.",
+    E0185: "Closure in the global scope.",
+    E0186: "Possibly missing `::`.",
+    E0187: "Call of a shadowed function.",
+    E0188: "Reference argument to a closure.",
+    E0189: "Reference parameter of a closure.",
+    E0190: "Attribute must be next to a type or trait.",
+    E0191: "Closure captures a mutable variable.",
+    E0192: "Constraint on a non-trait type.",
+    E0193: "Duplicate type constraint.",
+    E0194: "Type constraints syntax is not enabled.",
+    E0195: "Pattern missing arguments.",
+    E0196: "Undefined macro placeholder.",
+    E0197: "User-defined inline macros are disabled.",
+    E0198: "`let else` block that does not diverge.",
+    E0199: "Unsupported lint level attribute arguments.",
+    E0200: "Unfulfilled lint expectation.",
+    E1001: "Skipped element.",
+    E1002: "Missing token.",
+    E1003: "Missing expression.",
+    E1004: "Missing path segment.",
+    E1005: "Missing type clause.",
+    E1006: "Missing type expression.",
+    E1007: "Missing wrapped argument list.",
+    E1008: "Missing pattern.",
+    E1009: "Missing kind of a macro rule parameter.",
+    E1010: "Invalid parameter kind in a macro expansion.",
+    E1011: "Invalid parameter kind in a macro rule.",
+    E1012: "Expected `in`.",
+    E1013: "Item inline macro without `!`.",
+    E1014: "Reserved identifier.",
+    E1015: "`_` used as an identifier.",
+    E1016: "Missing literal suffix.",
+    E1017: "Invalid numeric literal value.",
+    E1018: "Illegal string escaping.",
+    E1019: "Non-ASCII character in a short string literal.",
+    E1020: "Non-ASCII character in a string literal.",
+    E1021: "Unterminated short string literal.",
+    E1022: "Unterminated string literal.",
+    E1023: "Visibility without an item.",
+    E1024: "Attributes without an item.",
+    E1025: "Attributes without a trait item.",
+    E1026: "Attributes without an impl item.",
+    E1027: "Attributes without a statement.",
+    E1028: "Trailing `|` in a pattern.",
+    E1029: "Consecutive comparison operators.",
+    E1030: "Expected a semicolon or a body.",
+    E1031: "Low precedence operator in an `if let` condition.",
+    E2001: "Unreachable code.",
+    E2002: "Use of a moved variable.",
+    E2003: "Variable not dropped.",
+    E2004: "Desnap of a non-copyable type.",
+    E2005: "Unexpected internal error.",
+    E2006: "Inlining of a function that might call itself.",
+    E2007: "Loop changing part of a variable.",
+    E2008: "Call cycle of `nopanic` functions.",
+    E2009: "Invalid literal.",
+    E2010: "Repeated non-copyable fixed size array element.",
+    E2011: "Zero-sized repeated fixed size array element.",
+    E2012: "Unsupported inner pattern.",
+    E2013: "Unsupported feature.",
+    E2014: "Redundant clone.",
+    E2015: "Comparison that is always true or false.",
+    E2016: "Division of `felt252` literals that is not an integer division.",
+    E2017: "Discarded syscall result.",
+    E2018: "Needless `ref` parameter.",
+    E2019: "Unbounded loop.",
+    E2101: "Unsupported matched type.",
+    E2102: "Unsupported matched tuple.",
+    E2103: "Match arm is not a variant.",
+    E2104: "Match arm is not a tuple.",
+    E2105: "Unreachable match arm.",
+    E2106: "Missing match arm.",
+    E2107: "Match arm is not a literal.",
+    E2108: "Non-sequential numeric match arms.",
+    E2109: "Non-exhaustive match on a value.",
+    E2110: "Numeric pattern in a `let` condition.",
+    E3001: "`allow_attr` attribute without arguments.",
+    E3002: "`allow_attr` argument is not a simple identifier.",
+    E3003: "Unsupported attribute.",
+    E3004: "Unsupported bracket type of a macro call.",
+    E3005: "Macro call that is not a legacy macro call.",
+    E3006: "`doc` attribute without arguments.",
+    E3007: "Invalid `doc(hidden)` argument.",
+    E3008: "Invalid argument of a `doc` attribute.",
+    E3009: "Invalid arguments of a `doc` attribute.",
+    E3010: "Unsupported argument of a `doc` attribute.",
+    E3011: "Invalid `doc(group)` argument.",
+    E3012: "`generate_trait` applied to a non-impl.",
+    E3013: "Generated trait path with multiple segments.",
+    E3014: "Invalid `generate_trait` argument.",
+    E3015: "Generated trait path with a missing segment.",
+    E3016: "Generated trait generic arguments not matching the impl.",
+    E3017: "Unsupported item in a `#[generate_trait]` impl.",
+    E3018: "Repeated `#[panic_with]` attribute.",
+    E3019: "`#[panic_with]` on a function not returning `Option` or `Result`.",
+    E3020: "Invalid `#[panic_with]` arguments.",
+    E3021: "Invalid `compile_error!` argument.",
+    E3022: "Error reported by `compile_error!`.",
+    E3023: "`cfg` `not` operator without exactly one argument.",
+    E3024: "`cfg` `and` operator with less than two arguments.",
+    E3025: "`cfg` `or` operator with less than two arguments.",
+    E3026: "Unsupported `cfg` operator.",
+    E3027: "Invalid `cfg` argument.",
+    E3028: "`derive` applied to a non-struct non-enum.",
+    E3029: "`derive` attribute without arguments.",
+    E3030: "`derive` argument is not a path.",
+    E3031: "Unknown derive.",
+    E3032: "Derive of `Default` for an enum without a default variant.",
+    E3033: "Multiple `#[default]` variants in a `Default` derive.",
+    E3034: "Assert macro without arguments.",
+    E3035: "Named first argument of an assert macro.",
+    E3036: "Invalid arguments of a formatting macro.",
+    E3037: "Invalid format string.",
+    E3038: "Invalid positional argument reference in a format string.",
+    E3039: "Unmatched `}` in a format string.",
+    E3040: "Missing arguments of a format string.",
+    E3041: "Unused argument of a formatting macro.",
+    E3042: "Use of the deprecated `consteval_int!` macro.",
+    E3043: "Unsupported binary operator in `consteval_int!`.",
+    E3044: "Unsupported unary operator in `consteval_int!`.",
+    E3045: "Unsupported expression in `consteval_int!`.",
+    E3046: "Wrong number of macro arguments.",
+    E3101: "Fuzz test without parameters.",
+    E3102: "Test attribute used together with `#[test_case]`.",
+    E3103: "Test attribute with arguments.",
+    E3104: "Test attribute on a non-test.",
+    E3105: "Invalid `should_panic` arguments.",
+    E3106: "Invalid `available_gas` arguments.",
+    E3107: "Invalid `fuzzer` arguments.",
+    E3108: "Duplicate test case name.",
+    E3109: "Unexpected `test_case` argument.",
+    E3110: "Wrong number of `test_case` arguments.",
+    E3111: "Invalid test case name.",
+    E3113: "Invalid `fixture` arguments.",
+    E3114: "Attribute used together with `#[fixture]`.",
+    E3115: "Fixture with generic parameters.",
+    E3116: "Fixture with parameters.",
+    E3117: "Fixture without a return value.",
+    E3118: "`ref` parameter of a test taking fixtures.",
+    E3119: "Unknown fixture.",
+    E3120: "Test taking multiple module-scoped fixtures.",
+    E3121: "Assert macro with less than two arguments.",
+    E3122: "Named first argument of an assert macro.",
+    E3123: "Named second argument of an assert macro.",
+    E3124: "Test macro used outside of test mode.",
+    E3125: "Wrong number of `assert_snapshot!` arguments.",
+    E3126: "Invalid snapshot name.",
+    E3127: "Hand-written `fixture_params` attribute.",
+    E3201: "Use of the deprecated `starknet::contract` attribute.",
+    E3202: "Starknet module without a body.",
+    E3203: "Starknet module without a `Storage` struct.",
+    E3204: "`Storage` struct without the `#[storage]` attribute.",
+    E3205: "Empty component path.",
+    E3206: "Component storage is not a substorage member of the contract storage.",
+    E3207: "Component event is not a nested event of the contract events.",
+    E3208: "Invalid `abi` argument on an impl alias.",
+    E3209: "Invalid `abi` argument on an impl.",
+    E3210: "Generic parameters of an embedded impl alias.",
+    E3211: "Embedded impl alias not on the contract state.",
+    E3212: "Invalid `component!` macro call.",
+    E3213: "`component!` argument is not a simple identifier.",
+    E3214: "`component!` argument is not a path.",
+    E3215: "Invalid `component!` argument.",
+    E3216: "Embeddable component impl not generic over the contract state.",
+    E3217: "Embeddable component impl without a `HasComponent` impl parameter.",
+    E3218: "Invalid `embeddable_as` arguments.",
+    E3219: "`embeddable_as` on an empty impl.",
+    E3220: "Embeddable component impl with `Destruct` impl parameters.",
+    E3221: "Function of an embeddable component impl without `self`.",
+    E3222: "Invalid `self` parameter of an embeddable component impl function.",
+    E3223: "Constructor with a wrong name.",
+    E3224: "Entry point with generic parameters.",
+    E3225: "Entry point without `self`.",
+    E3226: "`raw_output` function with `ref` parameters.",
+    E3227: "`raw_output` function not returning `Span<felt252>`.",
+    E3228: "L1 handler `from_address` parameter not of type `felt252`.",
+    E3229: "L1 handler second parameter not named `from_address`.",
+    E3230: "L1 handler without a `from_address` parameter.",
+    E3231: "`substorage` attribute on a non-storage member.",
+    E3232: "`storage_node` attribute on a non-struct.",
+    E3233: "`sub_pointers` attribute on a non-enum.",
+    E3234: "Multiple `storage_node` attributes.",
+    E3235: "`storage_node` attribute with a `Store` derive.",
+    E3236: "Multiple `sub_pointers` attributes.",
+    E3237: "Invalid `sub_pointers` arguments.",
+    E3238: "Multiple storage kind attributes of a member.",
+    E3239: "`flat` attribute with arguments.",
+    E3240: "`rename` attribute with other storage attributes.",
+    E3241: "Multiple `rename` attributes.",
+    E3242: "Invalid `rename` arguments.",
+    E3243: "`Event` type without the `#[event]` attribute.",
+    E3244: "`#[event]` type not named `Event`.",
+    E3245: "Generic event struct.",
+    E3246: "Nested event field in an event struct.",
+    E3247: "Serde event field.",
+    E3248: "Generic event enum.",
+    E3249: "Multiple `#[default]` variants in a `Store` derive.",
+    E3250: "Use of the deprecated `abi` attribute on a trait.",
+    E3251: "Starknet interface without a body.",
+    E3252: "Starknet interface without exactly one generic type parameter.",
+    E3253: "Starknet interface function with a body.",
+    E3254: "Starknet interface function without `self`.",
+    E3255: "Starknet interface function first parameter not named `self`.",
+    E3256: "Invalid `self` parameter of a Starknet interface function.",
+    E3257: "`ref` parameter of a Starknet interface function.",
+    E3258: "Starknet interface function parameter depending on the generic type.",
+    E3259: "Parameter named `__calldata__`.",
+    E3260: "Type item in a Starknet interface.",
+    E3261: "Constant item in a Starknet interface.",
+    E3262: "Impl item in a Starknet interface.",
+    E3263: "Empty embeddable impl.",
+    E3264: "Embeddable impl with `Destruct` impl parameters.",
+    E3265: "Embeddable impl not generic over the contract state.",
+    E3266: "Use of a deprecated entry point attribute.",
+    E3267: "Entry point attribute without `v0`.",
+    E3268: "Entry point attribute inside an embedded impl.",
+    E3269: "`selector!` argument is not a string.",
+    E3270: "Invalid modifiers of a `get_dep_component!` argument.",
+    E3271: "Embeddable impl not implementing a Starknet interface.",
+    E3272: "Failed ABI generation.",
+    E3273: "Storage member type that cannot be stored.",
+    E3274: "Colliding storage paths.",
+    E3275: "`Store` derive on an enum without a default variant.",
+    E3301: "`#[executable_raw]` function with a return value.",
+    E3302: "`#[executable_raw]` function without exactly two parameters.",
+    E3303: "`#[executable_raw]` first parameter not of type `Span<felt252>`.",
+    E3304: "`#[executable_raw]` first parameter passed by `ref`.",
+    E3305: "`#[executable_raw]` second parameter not of type `Array<felt252>`.",
+    E3306: "`#[executable_raw]` second parameter not passed by `ref`.",
+    E3307: "Executable function with generic parameters.",
+    E3308: "`ref` parameter of an executable function.",
+];
+
+/// Returns all the assigned error codes, along with their short descriptions.
+pub fn error_codes() -> &'static [(ErrorCode, &'static str)] {
+    ERROR_CODES
+}
+
+/// Constructs the registry of explanations, read from the `error_codes` directory.
+macro_rules! explanations {
+    ($($code:ident),* $(,)?) => {
+        &[$(
+            (
+                ErrorCode::new(stringify!($code)),
+                include_str!(concat!("error_codes/", stringify!($code), ".md")),
+            )
+        ),*]
+    };
+}
+
+/// The long-form explanations of error codes, sorted by code.
+/// Only some of the assigned error codes have an explanation so far. The others are described only
+/// by their short description in [ERROR_CODES].
+///
+/// Examples of erroneous code are in code blocks marked as `cairo,erroneous`, and are expected to
+/// report the explained code. Other `cairo` code blocks are expected to compile without
+/// diagnostics.
+const EXPLANATIONS: &[(ErrorCode, &str)] = explanations![
+    E0001, E0002, E0003, E0004, E0005, E0006, E0007, E0049, E0071, E0087, E0090, E0107, E1002,
+    E2001, E2002, E2003, E2106,
+];
+
+/// Returns the error codes that have a long-form explanation, along with their explanations.
+pub fn error_code_explanations() -> &'static [(ErrorCode, &'static str)] {
+    EXPLANATIONS
+}
@@ -0,0 +1,85 @@
+use std::fs;
+use std::path::Path;
+
+use itertools::Itertools;
+use test_log::test;
+
+use super::{ERROR_CODES, EXPLANATIONS, ErrorCode};
+use crate::error_code;
+
+#[test]
+fn test_error_codes_sorted() {
+    assert!(ERROR_CODES.iter().map(|(code, _)| code).tuple_windows().all(|(a, b)| a < b));
+}
+
+#[test]
+fn test_explanations_sorted() {
+    assert!(EXPLANATIONS.iter().map(|(code, _)| code).tuple_windows().all(|(a, b)| a < b));
+}
+
+#[test]
+fn test_explained_codes_assigned() {
+    for (code, _) in EXPLANATIONS {
+        assert!(code.description().is_some(), "Explained code `{code}` is not assigned.");
+    }
+}
+
+#[test]
+fn test_error_code_lookup() {
+    assert_eq!(error_code!(E0001).description(), Some("Unused variable."));
+    assert!(error_code!(E0001).explanation().unwrap().starts_with("A variable is defined"));
+    assert_eq!(error_code!(E1003).explanation(), None);
+    assert_eq!(ErrorCode::assigned("E0001"), Some(error_code!(E0001)));
+    assert_eq!(ErrorCode::assigned("E9999"), None);
+    assert_eq!(error_code!(E9999).description(), None);
+}
+
+/// Returns the codes given to diagnostics by the sources in the given directory, skipping tests
+/// and comments.
+fn codes_in_sources(dir: &Path, codes: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            codes_in_sources(&path, codes);
+            continue;
+        }
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !file_name.ends_with(".rs") || file_name == "test.rs" || file_name.ends_with("_test.rs")
+        {
+            continue;
+        }
+        for line in fs::read_to_string(&path).unwrap().lines() {
+            if line.trim_start().starts_with("//") {
+                continue;
+            }
+            for (_, rest) in
+                line.match_indices("error_code!(").map(|(i, m)| line.split_at(i + m.len()))
+            {
+                if let Some(code) = rest.get(..5) {
+                    codes.push(code.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_all_codes_assigned() {
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut codes = vec![];
+    codes_in_sources(crates_dir, &mut codes);
+    let unregistered = codes
+        .iter()
+        .filter(|code| code.starts_with('E') && ErrorCode::assigned(code).is_none())
+        .unique()
+        .collect_vec();
+    assert!(unregistered.is_empty(), "Codes missing from the registry: {unregistered:?}.");
+    let unused = ERROR_CODES
+        .iter()
+        .filter(|(code, _)| !codes.iter().any(|used| used == code.as_str()))
+        .map(|(code, _)| code)
+        .collect_vec();
+    assert!(unused.is_empty(), "Registered codes given to no diagnostic: {unused:?}.");
+}
@@ -2,13 +2,18 @@
 //! source files.
 
 pub use diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, Diagnostics,
-    DiagnosticsBuilder, FormattedDiagnosticEntry, Maybe, PluginFileDiagnosticNotes, Severity,
-    ToMaybe, ToOption, format_diagnostics, skip_diagnostic,
+    DiagnosticAdded, DiagnosticDetails, DiagnosticEntry, DiagnosticLocation, DiagnosticNote,
+    Diagnostics, DiagnosticsBuilder, FormattedDiagnosticEntry, Maybe, PluginFileDiagnosticNotes,
+    ResolvedLocation, ResolvedNote, Severity, ToMaybe, ToOption, format_diagnostics,
+    skip_diagnostic,
 };
-pub use error_code::{ErrorCode, OptionErrorCodeExt};
+pub use error_code::{ErrorCode, OptionErrorCodeExt, error_code_explanations, error_codes};
 pub use location_marks::get_location_marks;
+pub use suggestion::{
+    Applicability, ResolvedSuggestion, ResolvedTextEdit, Suggestion, TextEdit, apply_suggestions,
+};
 
 mod diagnostics;
 mod error_code;
 mod location_marks;
+mod suggestion;
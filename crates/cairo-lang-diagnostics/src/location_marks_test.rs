@@ -26,6 +26,7 @@ fn test_location_marks() {
         code_mappings: [].into(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     })
     .intern(&db);
     let summary = db.file_summary(file).unwrap();
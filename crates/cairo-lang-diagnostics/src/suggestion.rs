@@ -0,0 +1,168 @@
+//! Suggested fixes of diagnostics, given as text replacements in the source files.
+
+use std::fmt;
+
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use itertools::Itertools;
+use salsa::Database;
+
+use crate::diagnostics::{DiagnosticLocation, ResolvedLocation};
+
+#[cfg(test)]
+#[path = "suggestion_test.rs"]
+mod test;
+
+/// How confident a suggestion is to be what the user intended, which determines whether tools may
+/// apply it without a review.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Applicability {
+    /// The suggestion is what the user intended, and may be applied automatically.
+    MachineApplicable,
+    /// The suggestion results in valid code, but may not be what the user intended.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders that must be filled in by the user.
+    HasPlaceholders,
+}
+impl fmt::Display for Applicability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Applicability::MachineApplicable => write!(f, "machine-applicable"),
+            Applicability::MaybeIncorrect => write!(f, "maybe-incorrect"),
+            Applicability::HasPlaceholders => write!(f, "has-placeholders"),
+        }
+    }
+}
+
+/// A replacement of the text in a location.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TextEdit<'a> {
+    pub location: DiagnosticLocation<'a>,
+    pub replacement: String,
+}
+impl<'a> TextEdit<'a> {
+    /// An edit replacing the text in the given location.
+    pub fn replace(location: DiagnosticLocation<'a>, replacement: impl Into<String>) -> Self {
+        Self { location, replacement: replacement.into() }
+    }
+
+    /// An edit inserting the given text at an offset of a file.
+    pub fn insert(file_id: FileId<'a>, offset: TextOffset, text: impl Into<String>) -> Self {
+        Self::replace(DiagnosticLocation { file_id, span: TextSpan::new(offset, offset) }, text)
+    }
+
+    /// An edit removing the text in the given location.
+    pub fn remove(location: DiagnosticLocation<'a>) -> Self {
+        Self::replace(location, "")
+    }
+}
+
+/// A suggested fix of a diagnostic, made of edits that should be applied together.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Suggestion<'a> {
+    /// A short description of the fix, e.g. "Remove the unused import".
+    pub message: String,
+    pub applicability: Applicability,
+    pub edits: Vec<TextEdit<'a>>,
+}
+impl<'a> Suggestion<'a> {
+    pub fn new(message: impl Into<String>, applicability: Applicability) -> Self {
+        Self { message: message.into(), applicability, edits: vec![] }
+    }
+
+    /// Adds an edit to the suggestion.
+    pub fn with_edit(mut self, edit: TextEdit<'a>) -> Self {
+        self.edits.push(edit);
+        self
+    }
+}
+
+/// A text edit with its location resolved, for structured output.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedTextEdit {
+    pub location: ResolvedLocation,
+    pub replacement: String,
+}
+
+/// A suggestion with the locations of its edits resolved, for structured output.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedSuggestion {
+    pub message: String,
+    pub applicability: Applicability,
+    pub edits: Vec<ResolvedTextEdit>,
+}
+impl ResolvedSuggestion {
+    /// Resolves the given suggestion.
+    ///
+    /// Returns `None` if any of its edits is in plugin generated code, as such edits cannot be
+    /// applied to the source files.
+    pub fn new(db: &dyn Database, suggestion: &Suggestion<'_>) -> Option<Self> {
+        let edits = suggestion
+            .edits
+            .iter()
+            .map(|edit| {
+                (edit.location.user_location(db) == edit.location).then(|| ResolvedTextEdit {
+                    location: ResolvedLocation::new(db, &edit.location),
+                    replacement: edit.replacement.clone(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            message: suggestion.message.clone(),
+            applicability: suggestion.applicability,
+            edits,
+        })
+    }
+
+    /// Returns the path of the file the edits of the suggestion are in, or `None` if the
+    /// suggestion has no edits or edits several files.
+    pub fn file_path(&self) -> Option<&str> {
+        self.edits.iter().map(|edit| edit.location.file_path.as_str()).all_equal_value().ok()
+    }
+}
+
+/// Applies the given suggestions to the text of a file, which all their edits must be in.
+///
+/// The suggestions are applied in order, skipping any suggestion with an edit overlapping an edit
+/// of a previously applied one, or identical to a previously applied one. Returns the new text
+/// and the number of applied suggestions.
+pub fn apply_suggestions<'a>(
+    text: &str,
+    suggestions: impl IntoIterator<Item = &'a ResolvedSuggestion>,
+) -> (String, usize) {
+    let mut applied: Vec<&ResolvedSuggestion> = vec![];
+    for suggestion in suggestions {
+        let conflicts = applied.iter().any(|applied_suggestion| {
+            applied_suggestion.edits == suggestion.edits
+                || applied_suggestion.edits.iter().any(|applied_edit| {
+                    suggestion
+                        .edits
+                        .iter()
+                        .any(|edit| overlaps(&applied_edit.location, &edit.location))
+                })
+        });
+        if !conflicts {
+            applied.push(suggestion);
+        }
+    }
+
+    let edits = applied
+        .iter()
+        .flat_map(|suggestion| &suggestion.edits)
+        .sorted_by_key(|edit| (edit.location.byte_range.start, edit.location.byte_range.end));
+    let mut new_text = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for edit in edits {
+        new_text += &text[cursor..edit.location.byte_range.start];
+        new_text += &edit.replacement;
+        cursor = edit.location.byte_range.end;
+    }
+    new_text += &text[cursor..];
+    (new_text, applied.len())
+}
+
+/// Returns whether the two locations of edits overlap. An insertion overlaps a location only if it
+/// is strictly inside it.
+fn overlaps(a: &ResolvedLocation, b: &ResolvedLocation) -> bool {
+    a.byte_range.start < b.byte_range.end && b.byte_range.start < a.byte_range.end
+}
@@ -0,0 +1,48 @@
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{Applicability, ResolvedSuggestion, ResolvedTextEdit, apply_suggestions};
+use crate::ResolvedLocation;
+
+/// Returns a machine-applicable suggestion replacing the given byte ranges of a file.
+fn suggestion(edits: &[(usize, usize, &str)]) -> ResolvedSuggestion {
+    ResolvedSuggestion {
+        message: "Fix.".into(),
+        applicability: Applicability::MachineApplicable,
+        edits: edits
+            .iter()
+            .map(|(start, end, replacement)| ResolvedTextEdit {
+                location: ResolvedLocation {
+                    file_path: "lib.cairo".into(),
+                    byte_range: *start..*end,
+                    start: None,
+                    end: None,
+                },
+                replacement: replacement.to_string(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn test_apply_suggestions() {
+    let text = "let x = a;\nlet y = b;\n";
+    let suggestions = [
+        suggestion(&[(4, 4, "mut ")]),
+        // Prefix both variables, in a single suggestion. Inserting at the same offset as an
+        // applied insertion is allowed, and goes after it.
+        suggestion(&[(15, 15, "_"), (4, 4, "_")]),
+        // Overlaps the previous suggestion's insertion, so skipped.
+        suggestion(&[(13, 16, "z")]),
+        // Identical to an applied suggestion, so skipped.
+        suggestion(&[(15, 15, "_"), (4, 4, "_")]),
+        suggestion(&[(8, 9, "c")]),
+    ];
+    assert_eq!(apply_suggestions(text, &suggestions), ("let mut _x = c;\nlet _y = b;\n".into(), 3));
+}
+
+#[test]
+fn test_suggestion_file_path() {
+    assert_eq!(suggestion(&[(0, 1, ""), (2, 3, "")]).file_path(), Some("lib.cairo"));
+    assert_eq!(suggestion(&[]).file_path(), None);
+}
@@ -56,6 +56,7 @@ fn get_virtual_syntax_file_signature<'db>(
         code_mappings: [].into(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     })
     .intern(sig_db);
 
@@ -362,6 +362,7 @@ impl<'db> DocumentationCommentParser<'db> {
             code_mappings: Default::default(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         })
         .intern(self.db);
 
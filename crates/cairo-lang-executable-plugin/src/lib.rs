@@ -6,6 +6,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::plugin::{AnalyzerPlugin, PluginSuite};
 use cairo_lang_semantic::{GenericArgumentId, Mutability, corelib};
@@ -63,18 +64,24 @@ impl AnalyzerPlugin for RawExecutableAnalyzer {
                 continue;
             };
             if signature.return_type != corelib::unit_ty(db) {
-                diagnostics.push(PluginDiagnostic::error(
-                    signature.stable_ptr.lookup(db).ret_ty(db).stable_ptr(db),
-                    "Invalid return type for `#[executable_raw]` function, expected `()`."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        signature.stable_ptr.lookup(db).ret_ty(db).stable_ptr(db),
+                        "Invalid return type for `#[executable_raw]` function, expected `()`."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3301)),
+                );
             }
             let [input, output] = &signature.params[..] else {
-                diagnostics.push(PluginDiagnostic::error(
-                    signature.stable_ptr.lookup(db).parameters(db).stable_ptr(db),
-                    "Invalid number of params for `#[executable_raw]` function, expected 2."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        signature.stable_ptr.lookup(db).parameters(db).stable_ptr(db),
+                        "Invalid number of params for `#[executable_raw]` function, expected 2."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3302)),
+                );
                 continue;
             };
             if input.ty
@@ -84,36 +91,48 @@ impl AnalyzerPlugin for RawExecutableAnalyzer {
                     vec![GenericArgumentId::Type(db.core_info().felt252)],
                 )
             {
-                diagnostics.push(PluginDiagnostic::error(
-                    input.stable_ptr.untyped(),
-                    "Invalid first param type for `#[executable_raw]` function, expected \
-                     `Span<felt252>`."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        input.stable_ptr.untyped(),
+                        "Invalid first param type for `#[executable_raw]` function, expected \
+                         `Span<felt252>`."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3303)),
+                );
             }
             if input.mutability == Mutability::Reference {
-                diagnostics.push(PluginDiagnostic::error(
-                    input.stable_ptr.untyped(),
-                    "Invalid first param mutability for `#[executable_raw]` function, got \
-                     unexpected `ref`."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        input.stable_ptr.untyped(),
+                        "Invalid first param mutability for `#[executable_raw]` function, got \
+                         unexpected `ref`."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3304)),
+                );
             }
             if output.ty != corelib::core_array_felt252_ty(db) {
-                diagnostics.push(PluginDiagnostic::error(
-                    output.stable_ptr.untyped(),
-                    "Invalid second param type for `#[executable_raw]` function, expected \
-                     `Array<felt252>`."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        output.stable_ptr.untyped(),
+                        "Invalid second param type for `#[executable_raw]` function, expected \
+                         `Array<felt252>`."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3305)),
+                );
             }
             if output.mutability != Mutability::Reference {
-                diagnostics.push(PluginDiagnostic::error(
-                    output.stable_ptr.untyped(),
-                    "Invalid second param mutability for `#[executable_raw]` function, expected \
-                     `ref`."
-                        .to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        output.stable_ptr.untyped(),
+                        "Invalid second param mutability for `#[executable_raw]` function, \
+                         expected `ref`."
+                            .to_string(),
+                    )
+                    .with_error_code(error_code!(E3306)),
+                );
             }
         }
         diagnostics
@@ -142,10 +161,13 @@ impl MacroPlugin for ExecutablePlugin {
         let declaration = item.declaration(db);
         let generics = declaration.generic_params(db);
         if !generics.is_empty(db) {
-            diagnostics.push(PluginDiagnostic::error(
-                generics.stable_ptr(db),
-                "Executable functions cannot have generic params.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    generics.stable_ptr(db),
+                    "Executable functions cannot have generic params.".to_string(),
+                )
+                .with_error_code(error_code!(E3307)),
+            );
         }
         let name = declaration.name(db);
         let implicits_precedence =
@@ -167,10 +189,13 @@ impl MacroPlugin for ExecutablePlugin {
         for (param_idx, param) in params.iter().enumerate() {
             for modifier in param.modifiers(db).elements(db) {
                 if let ast::Modifier::Ref(terminal_ref) = modifier {
-                    diagnostics.push(PluginDiagnostic::error(
-                        terminal_ref.stable_ptr(db),
-                        "Parameters of an `#[executable]` function can't be `ref`.".into(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            terminal_ref.stable_ptr(db),
+                            "Parameters of an `#[executable]` function can't be `ref`.".into(),
+                        )
+                        .with_error_code(error_code!(E3308)),
+                    );
                 }
             }
             builder.add_modified(
@@ -115,6 +115,9 @@ pub struct CrateSettings {
 
     #[serde(default)]
     pub experimental_features: ExperimentalFeaturesConfig,
+    /// The levels of lints in the crate, by lint name, lint group name or error code.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lints: BTreeMap<String, LintLevel>,
 }
 
 /// Tracked function to return the default settings for a crate.
@@ -173,6 +176,19 @@ impl Edition {
     }
 }
 
+/// The level of a lint, determining how its diagnostics are reported.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The diagnostics of the lint are not reported.
+    Allow,
+    /// The diagnostics of the lint are reported as warnings.
+    #[default]
+    Warn,
+    /// The diagnostics of the lint are reported as errors.
+    Deny,
+}
+
 /// The settings for a dependency.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct DependencySettings {
@@ -485,6 +501,7 @@ pub fn init_dev_corelib(db: &mut dyn salsa::Database, core_lib_dir: PathBuf) {
             edition: Edition::V2024_07,
             version: Version::parse(CORELIB_VERSION).ok(),
             cfg_set: Default::default(),
+            lints: Default::default(),
             dependencies: Default::default(),
             experimental_features: ExperimentalFeaturesConfig {
                 negative_impls: true,
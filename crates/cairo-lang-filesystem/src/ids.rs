@@ -221,6 +221,7 @@ pub struct VirtualFileInput {
     pub code_mappings: Arc<[CodeMapping]>,
     pub kind: FileKind,
     pub original_item_removed: bool,
+    pub plugin: Option<String>,
 }
 
 impl VirtualFileInput {
@@ -232,6 +233,7 @@ impl VirtualFileInput {
             code_mappings: self.code_mappings,
             kind: self.kind,
             original_item_removed: self.original_item_removed,
+            plugin: self.plugin,
         }
     }
 }
@@ -247,6 +249,8 @@ pub struct VirtualFile<'db> {
     /// Relevant only for virtual files created during macros expansion.
     /// This field is used by `cairo-language-server` for optimization purposes.
     pub original_item_removed: bool,
+    /// The name of the plugin that generated this file, if it was generated by a plugin.
+    pub plugin: Option<String>,
 }
 impl<'db> VirtualFile<'db> {
     fn full_path(&self, db: &'db dyn Database) -> String {
@@ -266,6 +270,7 @@ impl<'db> VirtualFile<'db> {
             code_mappings: self.code_mappings,
             kind: self.kind,
             original_item_removed: self.original_item_removed,
+            plugin: self.plugin,
         }
     }
 }
@@ -298,6 +303,16 @@ impl<'db> FileLongId<'db> {
             FileLongId::External(_) => FileKind::Module,
         }
     }
+    /// The name of the plugin that generated the file, if it was generated by a plugin.
+    pub fn plugin(&self, db: &'db dyn Database) -> Option<String> {
+        match self {
+            FileLongId::OnDisk(_) => None,
+            FileLongId::Virtual(vf) => vf.plugin.clone(),
+            FileLongId::External(external_id) => {
+                get_external_files(db).ext_as_virtual(db, *external_id).plugin
+            }
+        }
+    }
 
     pub fn into_file_input(&self, db: &dyn Database) -> FileInput {
         match self {
@@ -325,6 +340,11 @@ impl<'db> FileId<'db> {
     pub fn kind(self, db: &dyn Database) -> FileKind {
         self.long(db).kind()
     }
+
+    /// The name of the plugin that generated the file, if it was generated by a plugin.
+    pub fn plugin(self, db: &dyn Database) -> Option<String> {
+        self.long(db).plugin(db)
+    }
 }
 
 define_short_id!(StrId, Arc<str>, FilesGroup);
@@ -19,6 +19,7 @@ fn test_file<'db>(db: &'db FilesDatabaseForTesting) -> FileId<'db> {
         code_mappings: [].into(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     })
     .intern(db)
 }
@@ -160,6 +160,7 @@ impl FormattableInput for String {
             code_mappings: [].into(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         })
         .intern(db))
     }
@@ -180,6 +181,7 @@ impl FormattableInput for StdinFmt {
             code_mappings: [].into(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         })
         .intern(db))
     }
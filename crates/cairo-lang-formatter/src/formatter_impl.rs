@@ -133,6 +133,7 @@ impl UseTree {
             code_mappings: [].into(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         })
         .intern(db);
 
@@ -51,6 +51,7 @@ pub fn format_string(db: &dyn Database, content: String) -> String {
         code_mappings: [].into(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     })
     .intern(db);
     let mut diagnostics = DiagnosticsBuilder::default();
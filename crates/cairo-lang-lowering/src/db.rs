@@ -34,6 +34,7 @@ use crate::graph_algorithms::feedback_set::flag_add_withdraw_gas;
 use crate::ids::{ConcreteFunctionWithBodyId, FunctionId, FunctionLongId, GenericOrSpecialized};
 use crate::inline::get_inline_diagnostics;
 use crate::inline::statements_weights::{ApproxCasmInlineWeight, InlineWeight};
+use crate::lint::{LintPassLongId, builtin_lint_passes, set_lint_passes};
 use crate::lower::{MultiLowering, lower_semantic_function};
 use crate::optimizations::config::OptimizationConfig;
 use crate::optimizations::scrub_units::scrub_units;
@@ -49,11 +50,13 @@ use crate::{
 pub struct LoweringGroupInput {
     #[returns(ref)]
     pub optimization_config: Option<OptimizationConfig>,
+    #[returns(ref)]
+    pub lint_passes: Option<Vec<LintPassLongId>>,
 }
 
 #[salsa::tracked(returns(ref))]
 pub fn lowering_group_input(db: &dyn Database) -> LoweringGroupInput {
-    LoweringGroupInput::new(db, None)
+    LoweringGroupInput::new(db, None, None)
 }
 
 fn optimization_config(db: &dyn Database) -> &OptimizationConfig {
@@ -163,6 +166,28 @@ pub trait LoweringGroup:
         file_id: FileId<'db>,
     ) -> Maybe<Diagnostics<'db, LoweringDiagnostic<'db>>>;
 
+    // ### Queries related to lint passes ###
+
+    /// Returns the lint passes run on the functions with a body.
+    #[salsa::invoke(crate::lint::lint_passes)]
+    #[salsa::transparent]
+    fn lint_passes(&self) -> &[LintPassLongId];
+
+    /// Aggregates the diagnostics of the lint passes on a semantic function - along with all its
+    /// generated functions.
+    #[salsa::invoke(crate::lint::semantic_function_lint_diagnostics)]
+    fn semantic_function_lint_diagnostics<'db>(
+        &'db self,
+        function_id: defs::ids::FunctionWithBodyId<'db>,
+    ) -> Maybe<Diagnostics<'db, LoweringDiagnostic<'db>>>;
+
+    /// Aggregates module level diagnostics of the lint passes.
+    #[salsa::invoke(crate::lint::module_lint_diagnostics)]
+    fn module_lint_diagnostics<'db>(
+        &'db self,
+        module_id: ModuleId<'db>,
+    ) -> Maybe<Diagnostics<'db, LoweringDiagnostic<'db>>>;
+
     // ### Queries related to implicits ###
 
     /// Returns all the implicit parameters that the function requires (according to both its
@@ -379,6 +404,7 @@ pub fn init_lowering_group(
             .with_moveable_functions(moveable_functions)
             .with_inlining_strategy(inlining_strategy),
     ));
+    set_lint_passes(db, builtin_lint_passes());
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -1,13 +1,17 @@
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, DiagnosticsBuilder,
-    Severity,
+    Applicability, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote,
+    DiagnosticsBuilder, ErrorCode, Severity, Suggestion, TextEdit, error_code,
 };
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib::LiteralError;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::expr::inference::InferenceError;
+use cairo_lang_semantic::lint::lint_by_name;
+use cairo_lang_semantic::suggestions::indentation;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
+use salsa::Database;
 
 use crate::Location;
 
@@ -82,6 +86,7 @@ impl<'db> DiagnosticEntry<'db> for LoweringDiagnostic<'db> {
             LoweringDiagnosticKind::EmptyRepeatedElementFixedSizeArray => {
                 "Fixed size array repeated element size must be greater than 0.".into()
             }
+            LoweringDiagnosticKind::Lint { message, .. } => message.clone(),
         }
     }
 
@@ -91,7 +96,8 @@ impl<'db> DiagnosticEntry<'db> for LoweringDiagnostic<'db> {
             | LoweringDiagnosticKind::MatchError(MatchError {
                 kind: _,
                 error: MatchDiagnostic::UnreachableMatchArm,
-            }) => Severity::Warning,
+            })
+            | LoweringDiagnosticKind::Lint { .. } => Severity::Warning,
             _ => Severity::Error,
         }
     }
@@ -107,11 +113,61 @@ impl<'db> DiagnosticEntry<'db> for LoweringDiagnostic<'db> {
         self.location.stable_location.diagnostic_location(db)
     }
 
+    fn suggestions(&self, db: &'db Self::DbType) -> Vec<Suggestion<'db>> {
+        match &self.kind {
+            LoweringDiagnosticKind::MatchError(MatchError {
+                kind: MatchKind::Match,
+                error: MatchDiagnostic::MissingMatchArm(variant),
+            }) => add_match_arm(db, self.location.stable_location.syntax_node(db), variant)
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn error_code(&self) -> Option<ErrorCode> {
+        self.kind.error_code()
+    }
+
+    fn lint(&self) -> Option<&str> {
+        match &self.kind {
+            LoweringDiagnosticKind::Lint { lint, .. } => Some(lint),
+            _ => None,
+        }
+    }
+
     fn is_same_kind(&self, other: &Self) -> bool {
         other.kind == self.kind
     }
 }
 
+/// Returns the suggestion to add an arm for the given missing variant to the match expression
+/// containing the given node. The body of the added arm is a placeholder.
+fn add_match_arm<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    variant: &str,
+) -> Option<Suggestion<'db>> {
+    let expr_match =
+        node.ancestors_with_self(db).find_map(|node| node.cast::<ast::ExprMatch>(db))?;
+    let arms = expr_match.arms(db).as_syntax_node();
+    // The children of the arms list alternate between the arms and their separators.
+    let children = arms.get_children(db);
+    let last_arm = children.iter().step_by(2).next_back()?;
+    let separator = if children.len() % 2 == 1 { "," } else { "" };
+    Some(
+        Suggestion::new(format!("Add a match arm for `{variant}`"), Applicability::HasPlaceholders)
+            .with_edit(TextEdit::insert(
+                node.stable_ptr(db).file_id(db),
+                arms.span_end_without_trivia(db),
+                format!(
+                    "{separator}\n{}{variant} => panic!(\"Not implemented.\"),",
+                    indentation(db, *last_arm)
+                ),
+            )),
+    )
+}
+
 impl<'db> MatchError<'db> {
     fn format(&self) -> String {
         match (&self.error, &self.kind) {
@@ -193,11 +249,20 @@ impl<'db> MatchError<'db> {
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, salsa::Update)]
 pub enum LoweringDiagnosticKind<'db> {
-    Unreachable { block_end_ptr: SyntaxStablePtrId<'db> },
-    VariableMoved { inference_error: InferenceError<'db> },
-    VariableNotDropped { drop_err: InferenceError<'db>, destruct_err: InferenceError<'db> },
+    Unreachable {
+        block_end_ptr: SyntaxStablePtrId<'db>,
+    },
+    VariableMoved {
+        inference_error: InferenceError<'db>,
+    },
+    VariableNotDropped {
+        drop_err: InferenceError<'db>,
+        destruct_err: InferenceError<'db>,
+    },
     MatchError(MatchError<'db>),
-    DesnappingANonCopyableType { inference_error: InferenceError<'db> },
+    DesnappingANonCopyableType {
+        inference_error: InferenceError<'db>,
+    },
     UnexpectedError,
     CannotInlineFunctionThatMightCallItself,
     MemberPathLoop,
@@ -207,6 +272,37 @@ pub enum LoweringDiagnosticKind<'db> {
     EmptyRepeatedElementFixedSizeArray,
     UnsupportedPattern,
     Unsupported,
+    /// A warning of a lint, reported by a lint pass.
+    Lint {
+        lint: String,
+        message: String,
+    },
+}
+
+impl<'db> LoweringDiagnosticKind<'db> {
+    /// Returns the code identifying the kind of the diagnostic. Codes are never changed or reused,
+    /// so new kinds must be given new codes.
+    ///
+    /// Warnings of lints are given the code of their lint, and have no code if their lint has none.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        Some(match self {
+            Self::Unreachable { .. } => error_code!(E2001),
+            Self::VariableMoved { .. } => error_code!(E2002),
+            Self::VariableNotDropped { .. } => error_code!(E2003),
+            Self::MatchError(match_err) => match_err.error.error_code(),
+            Self::DesnappingANonCopyableType { .. } => error_code!(E2004),
+            Self::UnexpectedError => error_code!(E2005),
+            Self::CannotInlineFunctionThatMightCallItself => error_code!(E2006),
+            Self::MemberPathLoop => error_code!(E2007),
+            Self::NoPanicFunctionCycle => error_code!(E2008),
+            Self::LiteralError(_) => error_code!(E2009),
+            Self::FixedSizeArrayNonCopyableType => error_code!(E2010),
+            Self::EmptyRepeatedElementFixedSizeArray => error_code!(E2011),
+            Self::UnsupportedPattern => error_code!(E2012),
+            Self::Unsupported => error_code!(E2013),
+            Self::Lint { lint, .. } => return lint_by_name(lint)?.codes.first().copied(),
+        })
+    }
 }
 
 /// Error in a match-like construct.
@@ -266,3 +362,22 @@ pub enum MatchDiagnostic {
     NonExhaustiveMatchValue,
     UnsupportedNumericInLetCondition,
 }
+
+impl MatchDiagnostic {
+    /// Returns the code identifying the kind of the diagnostic, regardless of the construct it
+    /// occurred in.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::UnsupportedMatchedType(_) => error_code!(E2101),
+            Self::UnsupportedMatchedValueTuple => error_code!(E2102),
+            Self::UnsupportedMatchArmNotAVariant => error_code!(E2103),
+            Self::UnsupportedMatchArmNotATuple => error_code!(E2104),
+            Self::UnreachableMatchArm => error_code!(E2105),
+            Self::MissingMatchArm(_) => error_code!(E2106),
+            Self::UnsupportedMatchArmNotALiteral => error_code!(E2107),
+            Self::UnsupportedMatchArmNonSequential => error_code!(E2108),
+            Self::NonExhaustiveMatchValue => error_code!(E2109),
+            Self::UnsupportedNumericInLetCondition => error_code!(E2110),
+        }
+    }
+}
@@ -13,6 +13,7 @@ pub mod graph_algorithms;
 pub mod ids;
 pub mod implicits;
 pub mod inline;
+pub mod lint;
 pub mod lower;
 pub mod objects;
 pub mod optimizations;
@@ -0,0 +1,132 @@
+use cairo_lang_defs as defs;
+use cairo_lang_semantic::corelib::try_extract_bounded_int_type_ranges;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Expr, ExprFunctionCallArg, TypeId};
+use cairo_lang_syntax::attribute::consts::ABSURD_EXTREME_COMPARISONS;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+use super::{LintContext, LintPass};
+
+/// Reports comparisons of bounded integers with the minimum or maximum of their type, that are
+/// always true or always false, such as `x >= 0` for an unsigned `x`.
+#[derive(Debug)]
+pub struct AbsurdExtremeComparisons;
+
+/// A comparison operator.
+#[derive(Clone, Copy, PartialEq)]
+enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl LintPass for AbsurdExtremeComparisons {
+    fn lints(&self) -> Vec<&'static str> {
+        vec![ABSURD_EXTREME_COMPARISONS]
+    }
+
+    fn check_semantic<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: defs::ids::FunctionWithBodyId<'db>,
+    ) {
+        let db = ctx.db;
+        let Ok(body) = db.function_body(function_id) else {
+            return;
+        };
+        let info = db.core_info();
+        for (_, expr) in body.arenas.exprs.iter() {
+            let Expr::FunctionCall(call) = expr else {
+                continue;
+            };
+            let GenericFunctionId::Impl(impl_function) =
+                call.function.get_concrete(db).generic_function
+            else {
+                continue;
+            };
+            let comparison = match impl_function.function {
+                function if function == info.lt_fn => Comparison::Lt,
+                function if function == info.le_fn => Comparison::Le,
+                function if function == info.gt_fn => Comparison::Gt,
+                function if function == info.ge_fn => Comparison::Ge,
+                _ => continue,
+            };
+            let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] = call.args[..]
+            else {
+                continue;
+            };
+            let (lhs, rhs) = (&body.arenas.exprs[lhs], &body.arenas.exprs[rhs]);
+            let Some((min, max)) = int_type_range(db, lhs.ty()) else {
+                continue;
+            };
+            // The comparison is normalized to have the literal on its right side.
+            let (comparison, value) = match (lhs, rhs) {
+                (_, Expr::Literal(literal)) => (comparison, &literal.value),
+                (Expr::Literal(literal), _) => (comparison.flipped(), &literal.value),
+                _ => continue,
+            };
+            let (result, bound) = match comparison {
+                Comparison::Lt if *value == min => (false, "minimum"),
+                Comparison::Ge if *value == min => (true, "minimum"),
+                Comparison::Gt if *value == max => (false, "maximum"),
+                Comparison::Le if *value == max => (true, "maximum"),
+                _ => continue,
+            };
+            ctx.report(
+                ABSURD_EXTREME_COMPARISONS,
+                call.stable_ptr,
+                format!(
+                    "Comparison is always {result}, as `{value}` is the {bound} value of type \
+                     `{}`.",
+                    lhs.ty().format(db)
+                ),
+            );
+        }
+    }
+}
+
+impl Comparison {
+    /// Returns the comparison with its operands swapped.
+    fn flipped(self) -> Self {
+        match self {
+            Self::Lt => Self::Gt,
+            Self::Le => Self::Ge,
+            Self::Gt => Self::Lt,
+            Self::Ge => Self::Le,
+        }
+    }
+}
+
+/// Returns the minimum and maximum values of the given type, if it is a bounded integer type.
+fn int_type_range<'db>(db: &'db dyn SemanticGroup, ty: TypeId<'db>) -> Option<(BigInt, BigInt)> {
+    let info = db.core_info();
+    let range = |min: BigInt, max: BigInt| Some((min, max));
+    if ty == info.u8 {
+        range(u8::MIN.into(), u8::MAX.into())
+    } else if ty == info.u16 {
+        range(u16::MIN.into(), u16::MAX.into())
+    } else if ty == info.u32 {
+        range(u32::MIN.into(), u32::MAX.into())
+    } else if ty == info.u64 {
+        range(u64::MIN.into(), u64::MAX.into())
+    } else if ty == info.u128 {
+        range(u128::MIN.into(), u128::MAX.into())
+    } else if ty == info.u256 {
+        range(BigInt::zero(), (BigInt::one() << 256) - 1)
+    } else if ty == info.i8 {
+        range(i8::MIN.into(), i8::MAX.into())
+    } else if ty == info.i16 {
+        range(i16::MIN.into(), i16::MAX.into())
+    } else if ty == info.i32 {
+        range(i32::MIN.into(), i32::MAX.into())
+    } else if ty == info.i64 {
+        range(i64::MIN.into(), i64::MAX.into())
+    } else if ty == info.i128 {
+        range(i128::MIN.into(), i128::MAX.into())
+    } else {
+        try_extract_bounded_int_type_ranges(db, ty)
+    }
+}
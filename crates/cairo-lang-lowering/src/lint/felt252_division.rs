@@ -0,0 +1,65 @@
+use cairo_lang_defs as defs;
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_semantic::{Expr, ExprFunctionCallArg};
+use cairo_lang_syntax::attribute::consts::FELT252_DIVISION;
+use num_integer::Integer;
+use num_traits::Zero;
+
+use super::{LintContext, LintPass};
+
+/// Reports divisions of `felt252` literals the divisor does not divide, which look like integer
+/// divisions: as `felt252_div` is a field division, their result is not the integer quotient.
+///
+/// Divisions of other values are not reported, as field divisions of them are usually intended.
+#[derive(Debug)]
+pub struct Felt252Division;
+
+impl LintPass for Felt252Division {
+    fn lints(&self) -> Vec<&'static str> {
+        vec![FELT252_DIVISION]
+    }
+
+    fn check_semantic<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: defs::ids::FunctionWithBodyId<'db>,
+    ) {
+        let db = ctx.db;
+        let Ok(body) = db.function_body(function_id) else {
+            return;
+        };
+        for (_, expr) in body.arenas.exprs.iter() {
+            let Expr::FunctionCall(call) = expr else {
+                continue;
+            };
+            let GenericFunctionId::Extern(extern_function) =
+                call.function.get_concrete(db).generic_function
+            else {
+                continue;
+            };
+            if extern_function.full_path(db) != "core::felt252_div" {
+                continue;
+            }
+            let [ExprFunctionCallArg::Value(lhs), ExprFunctionCallArg::Value(rhs)] = call.args[..]
+            else {
+                continue;
+            };
+            let (Expr::Literal(lhs), Expr::Literal(rhs)) =
+                (&body.arenas.exprs[lhs], &body.arenas.exprs[rhs])
+            else {
+                continue;
+            };
+            if rhs.value.is_zero() || lhs.value.is_multiple_of(&rhs.value) {
+                continue;
+            }
+            ctx.report(
+                FELT252_DIVISION,
+                call.stable_ptr,
+                "`felt252_div` is a field division, so the result of dividing literals the \
+                 divisor does not divide is not their integer quotient. Consider an integer type \
+                 for integer division.",
+            );
+        }
+    }
+}
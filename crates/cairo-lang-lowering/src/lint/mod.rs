@@ -0,0 +1,238 @@
+//! Lint passes: analyses of functions with a body, over their semantic model and their lowered
+//! representation, reporting warnings of lints.
+//!
+//! The passes are set by [set_lint_passes], and run on the functions of a module by
+//! [LoweringGroup::module_lint_diagnostics]. The levels of their lints are configured like those
+//! of any other lint (see [cairo_lang_semantic::lint]), except that the lints of the built-in
+//! passes are allowed by default.
+
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use cairo_lang_defs as defs;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{ModuleId, ModuleItemId};
+use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
+use cairo_lang_semantic::lint::{lint_by_name, set_declared_lints};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_utils::Intern;
+use salsa::{Database, Setter};
+
+use crate::db::{LoweringGroup, lowering_group_input};
+use crate::diagnostic::{
+    LoweringDiagnostic, LoweringDiagnosticKind, LoweringDiagnostics, LoweringDiagnosticsBuilder,
+};
+use crate::{Location, Lowered, ids};
+
+mod absurd_extreme_comparisons;
+mod felt252_division;
+mod needless_ref_params;
+mod redundant_clones;
+mod unbounded_loops;
+mod unused_syscall_results;
+
+pub use absurd_extreme_comparisons::AbsurdExtremeComparisons;
+pub use felt252_division::Felt252Division;
+pub use needless_ref_params::NeedlessRefParams;
+pub use redundant_clones::RedundantClones;
+pub use unbounded_loops::UnboundedLoops;
+pub use unused_syscall_results::UnusedSyscallResults;
+
+#[cfg(test)]
+#[path = "test.rs"]
+mod test;
+
+/// A pass checking functions with a body, reporting warnings of its lints.
+pub trait LintPass: Debug + Sync + Send {
+    /// Returns the names of the lints the pass reports warnings of.
+    fn lints(&self) -> Vec<&'static str>;
+
+    /// Checks the semantic model of a function with a body.
+    fn check_semantic<'db>(
+        &self,
+        _ctx: &mut LintContext<'db, '_>,
+        _function_id: defs::ids::FunctionWithBodyId<'db>,
+    ) {
+    }
+
+    /// Checks the lowered representation of a function with a body, before its optimizations. It
+    /// is called for the functions generated for a semantic function as well, such as loops.
+    fn check_lowered<'db>(
+        &self,
+        _ctx: &mut LintContext<'db, '_>,
+        _function_id: ids::FunctionWithBodyId<'db>,
+        _lowered: &Lowered<'db>,
+    ) {
+    }
+}
+
+/// A lint pass, identified by pointer.
+#[derive(Clone, Debug)]
+pub struct LintPassLongId(pub Arc<dyn LintPass>);
+
+// `PartialEq` and `Hash` cannot be derived on `Arc<dyn ...>`,
+// but pointer-based equality and hash semantics are enough in this case.
+impl PartialEq for LintPassLongId {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for LintPassLongId {}
+
+impl Hash for LintPassLongId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+/// The context of a lint pass checking a function.
+pub struct LintContext<'db, 'a> {
+    pub db: &'db dyn LoweringGroup,
+    diagnostics: &'a mut LoweringDiagnostics<'db>,
+}
+impl<'db> LintContext<'db, '_> {
+    /// Reports a warning of the given lint at the given node.
+    pub fn report(
+        &mut self,
+        lint: &str,
+        stable_ptr: impl Into<SyntaxStablePtrId<'db>>,
+        message: impl Into<String>,
+    ) {
+        self.report_by_location(
+            lint,
+            Location::new(StableLocation::new(stable_ptr.into())),
+            message,
+        )
+    }
+
+    /// Reports a warning of the given lint at the given location.
+    pub fn report_by_location(
+        &mut self,
+        lint: &str,
+        location: Location<'db>,
+        message: impl Into<String>,
+    ) {
+        self.diagnostics.report_by_location(
+            location,
+            LoweringDiagnosticKind::Lint { lint: lint.into(), message: message.into() },
+        );
+    }
+}
+
+/// Returns the lint passes of the compiler.
+pub fn builtin_lint_passes() -> Vec<Arc<dyn LintPass>> {
+    vec![
+        Arc::new(RedundantClones),
+        Arc::new(AbsurdExtremeComparisons),
+        Arc::new(Felt252Division),
+        Arc::new(UnusedSyscallResults),
+        Arc::new(NeedlessRefParams),
+        Arc::new(UnboundedLoops),
+    ]
+}
+
+/// Sets the lint passes run on the functions of the database. The lints of the passes that are not
+/// lints of the compiler are declared, so they may be referred to by lint level attributes.
+pub fn set_lint_passes(db: &mut dyn Database, passes: Vec<Arc<dyn LintPass>>) {
+    let declared_lints = passes
+        .iter()
+        .flat_map(|pass| pass.lints())
+        .filter(|lint| lint_by_name(lint).is_none())
+        .map(|lint| lint.to_string())
+        .collect();
+    set_declared_lints(db, declared_lints);
+    let passes = passes.into_iter().map(LintPassLongId).collect();
+    lowering_group_input(db).set_lint_passes(db).to(Some(passes));
+}
+
+/// Query implementation of [LoweringGroup::lint_passes].
+pub fn lint_passes(db: &dyn Database) -> &[LintPassLongId] {
+    lowering_group_input(db).lint_passes(db).as_deref().unwrap_or_default()
+}
+
+/// Query implementation of [LoweringGroup::semantic_function_lint_diagnostics].
+pub fn semantic_function_lint_diagnostics<'db>(
+    db: &'db dyn LoweringGroup,
+    semantic_function_id: defs::ids::FunctionWithBodyId<'db>,
+) -> Maybe<Diagnostics<'db, LoweringDiagnostic<'db>>> {
+    let mut diagnostics = DiagnosticsBuilder::default();
+    // Functions with errors are not linted, as their semantic model and lowering are partial.
+    db.function_body_diagnostics(semantic_function_id).check_error_free()?;
+    let multi_lowering = db.priv_function_with_body_multi_lowering(semantic_function_id)?;
+    let function_ids = [ids::FunctionWithBodyLongId::Semantic(semantic_function_id)]
+        .into_iter()
+        .chain(multi_lowering.generated_lowerings.keys().map(|key| {
+            ids::FunctionWithBodyLongId::Generated { parent: semantic_function_id, key: *key }
+        }));
+    let lowerings = function_ids
+        .map(|function_id| {
+            let function_id = function_id.intern(db);
+            Ok((function_id, db.function_with_body_lowering(function_id)?))
+        })
+        .collect::<Maybe<Vec<_>>>()?;
+    if lowerings.iter().any(|(_, lowered)| lowered.diagnostics.check_error_free().is_err()) {
+        return Ok(diagnostics.build());
+    }
+
+    let mut ctx = LintContext { db, diagnostics: &mut diagnostics };
+    for pass in db.lint_passes() {
+        pass.0.check_semantic(&mut ctx, semantic_function_id);
+        for (function_id, lowered) in &lowerings {
+            pass.0.check_lowered(&mut ctx, *function_id, lowered);
+        }
+    }
+    Ok(diagnostics.build())
+}
+
+/// Query implementation of [LoweringGroup::module_lint_diagnostics].
+pub fn module_lint_diagnostics<'db>(
+    db: &'db dyn LoweringGroup,
+    module_id: ModuleId<'db>,
+) -> Maybe<Diagnostics<'db, LoweringDiagnostic<'db>>> {
+    let mut diagnostics = DiagnosticsBuilder::default();
+    let mut add_function_diagnostics = |function_id| {
+        if let Ok(function_diagnostics) = db.semantic_function_lint_diagnostics(function_id) {
+            diagnostics.extend(function_diagnostics);
+        }
+    };
+    for item in module_id.module_data(db)?.items(db).iter() {
+        match item {
+            ModuleItemId::FreeFunction(free_function) => {
+                add_function_diagnostics(defs::ids::FunctionWithBodyId::Free(*free_function));
+            }
+            ModuleItemId::Trait(trait_id) => {
+                for trait_func in db.trait_functions(*trait_id)?.values() {
+                    if matches!(db.trait_function_body(*trait_func), Ok(Some(_))) {
+                        add_function_diagnostics(defs::ids::FunctionWithBodyId::Trait(*trait_func));
+                    }
+                }
+            }
+            ModuleItemId::Impl(impl_def_id) => {
+                for impl_func in db.impl_functions(*impl_def_id)?.values() {
+                    add_function_diagnostics(defs::ids::FunctionWithBodyId::Impl(*impl_func));
+                }
+            }
+            ModuleItemId::Constant(_)
+            | ModuleItemId::Submodule(_)
+            | ModuleItemId::Use(_)
+            | ModuleItemId::Struct(_)
+            | ModuleItemId::Enum(_)
+            | ModuleItemId::TypeAlias(_)
+            | ModuleItemId::ImplAlias(_)
+            | ModuleItemId::ExternType(_)
+            | ModuleItemId::ExternFunction(_)
+            | ModuleItemId::MacroDeclaration(_) => {}
+        }
+    }
+    for macro_call in db.module_macro_calls_ids(module_id)?.iter() {
+        if let Ok(macro_module_id) = db.macro_call_module_id(*macro_call)
+            && let Ok(lint_diagnostics) = db.module_lint_diagnostics(macro_module_id)
+        {
+            diagnostics.extend(lint_diagnostics);
+        }
+    }
+    Ok(diagnostics.build())
+}
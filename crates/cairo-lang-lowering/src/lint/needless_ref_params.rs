@@ -0,0 +1,139 @@
+use cairo_lang_defs as defs;
+use cairo_lang_semantic::Mutability;
+use cairo_lang_syntax::attribute::consts::NEEDLESS_REF_PARAMS;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
+use itertools::Itertools;
+
+use super::{LintContext, LintPass};
+use crate::ids::FunctionWithBodyLongId;
+use crate::{BlockEnd, Lowered, Statement, VariableId, ids};
+
+/// Reports `ref` parameters of free functions that are returned unmodified by all the returns of
+/// the function, so they may be passed by value, or as a snapshot. Parameters whose name starts
+/// with `_` are not reported, as they are unused on purpose.
+///
+/// Functions of traits and impls are not checked, as their signatures are determined by their
+/// trait, and neither are functions with attributes, such as entry points, whose signatures may be
+/// determined by their callers.
+#[derive(Debug)]
+pub struct NeedlessRefParams;
+
+impl LintPass for NeedlessRefParams {
+    fn lints(&self) -> Vec<&'static str> {
+        vec![NEEDLESS_REF_PARAMS]
+    }
+
+    fn check_lowered<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: ids::FunctionWithBodyId<'db>,
+        lowered: &Lowered<'db>,
+    ) {
+        let db = ctx.db;
+        let FunctionWithBodyLongId::Semantic(
+            semantic_function_id @ defs::ids::FunctionWithBodyId::Free(_),
+        ) = function_id.long(db)
+        else {
+            return;
+        };
+        if !db
+            .function_with_body_attributes(*semantic_function_id)
+            .is_ok_and(|attrs| attrs.is_empty())
+        {
+            return;
+        }
+        let Ok(signature) = db.function_with_body_signature(*semantic_function_id) else {
+            return;
+        };
+        let returns = lowered
+            .blocks
+            .iter()
+            .filter_map(|(_, block)| match &block.end {
+                BlockEnd::Return(vars, _) => Some(vars),
+                _ => None,
+            })
+            .collect_vec();
+        // The parameters of a function that never returns are not checked.
+        if returns.is_empty() {
+            return;
+        }
+        let ref_params = signature
+            .params
+            .iter()
+            .zip(&lowered.parameters)
+            .filter(|(param, _)| param.mutability == Mutability::Reference);
+        for (ref_index, (param, var)) in ref_params.enumerate() {
+            if param.name.starts_with('_') {
+                continue;
+            }
+            let unmodified = unmodified_variables(lowered, *var);
+            // The returned values of `ref` parameters precede the returned value of the function.
+            if returns.iter().all(|vars| unmodified.contains(&vars[ref_index].var_id)) {
+                ctx.report(
+                    NEEDLESS_REF_PARAMS,
+                    param.stable_ptr,
+                    format!(
+                        "Parameter `{}` is never modified, so it does not need to be passed by \
+                         `ref`.",
+                        param.name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the variables holding the value of the given variable, unmodified: the variable itself,
+/// the originals left by snapshots of such variables, the structs reconstructed from the members of
+/// such variables, and the variables that are only remapped from such variables.
+fn unmodified_variables(lowered: &Lowered<'_>, var: VariableId) -> UnorderedHashSet<VariableId> {
+    let mut unmodified = UnorderedHashSet::from_iter([var]);
+    let mut remappings = OrderedHashMap::<VariableId, Vec<VariableId>>::default();
+    let mut snapshots = vec![];
+    let mut destructures = OrderedHashMap::<Vec<VariableId>, VariableId>::default();
+    let mut constructs = vec![];
+    for (_, block) in lowered.blocks.iter() {
+        for statement in &block.statements {
+            match statement {
+                Statement::Snapshot(snapshot) => {
+                    snapshots.push((snapshot.input.var_id, snapshot.original()));
+                }
+                Statement::StructDestructure(destructure) => {
+                    destructures.insert(destructure.outputs.clone(), destructure.input.var_id);
+                }
+                Statement::StructConstruct(construct) => {
+                    let members = construct.inputs.iter().map(|input| input.var_id).collect_vec();
+                    constructs.push((members, construct.output));
+                }
+                _ => {}
+            }
+        }
+        if let BlockEnd::Goto(_, remapping) = &block.end {
+            for (dst, src) in remapping.iter() {
+                remappings.entry(*dst).or_default().push(src.var_id);
+            }
+        }
+    }
+    loop {
+        let mut changed = false;
+        for (input, original) in &snapshots {
+            if unmodified.contains(input) {
+                changed |= unmodified.insert(*original);
+            }
+        }
+        for (members, output) in &constructs {
+            if destructures.get(members).is_some_and(|input| unmodified.contains(input)) {
+                changed |= unmodified.insert(*output);
+            }
+        }
+        for (dst, srcs) in remappings.iter() {
+            if srcs.iter().all(|src| unmodified.contains(src)) {
+                changed |= unmodified.insert(*dst);
+            }
+        }
+        if !changed {
+            return unmodified;
+        }
+    }
+}
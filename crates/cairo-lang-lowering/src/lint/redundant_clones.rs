@@ -0,0 +1,134 @@
+use cairo_lang_defs as defs;
+use cairo_lang_defs::ids::{NamedLanguageElementId, TopLevelLanguageElementId};
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::GenericFunctionId;
+use cairo_lang_syntax::attribute::consts::REDUNDANT_CLONES;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+
+use super::{LintContext, LintPass};
+use crate::db::LoweringGroup;
+use crate::ids::FunctionLongId;
+use crate::{BlockEnd, Lowered, Statement, VariableId, ids};
+
+/// Reports clones of values that are not used afterwards, which may be moved instead.
+///
+/// The value is cloned through a snapshot of it, so the clone is redundant if the original value
+/// left by the snapshot is never used, and the snapshot is used by the clone only. Clones of values
+/// that are snapshots in the code are not reported, as the clone is the only way to own them.
+#[derive(Debug)]
+pub struct RedundantClones;
+
+impl LintPass for RedundantClones {
+    fn lints(&self) -> Vec<&'static str> {
+        vec![REDUNDANT_CLONES]
+    }
+
+    fn check_lowered<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: ids::FunctionWithBodyId<'db>,
+        lowered: &Lowered<'db>,
+    ) {
+        let semantic_function_id = function_id.base_semantic_function(ctx.db);
+        let uses = variable_uses(lowered);
+        let uses_of = |var: VariableId| uses.get(&var).copied().unwrap_or_default();
+        for (_, block) in lowered.blocks.iter() {
+            for statement in &block.statements {
+                let Statement::Snapshot(snapshot) = statement else {
+                    continue;
+                };
+                let original = snapshot.original();
+                if uses_of(original) != 0
+                    || uses_of(snapshot.snapshot()) != 1
+                    || lowered.variables[original].info.copyable.is_ok()
+                {
+                    continue;
+                }
+                let Some(clone_call) = block.statements.iter().find_map(|statement| {
+                    let Statement::Call(call) = statement else {
+                        return None;
+                    };
+                    (is_clone(ctx.db, call.function)
+                        && matches!(&call.inputs[..], [input] if input.var_id == snapshot.snapshot()))
+                    .then_some(call)
+                }) else {
+                    continue;
+                };
+                let location = clone_call.location.long(ctx.db).clone();
+                if !has_owned_receiver(
+                    ctx.db,
+                    semantic_function_id,
+                    location.stable_location.stable_ptr(),
+                ) {
+                    continue;
+                }
+                ctx.report_by_location(
+                    REDUNDANT_CLONES,
+                    location,
+                    "Redundant clone: the cloned value is not used afterwards, so it can be moved \
+                     instead.",
+                );
+            }
+        }
+    }
+}
+
+/// Returns the number of uses of each variable of the lowered function.
+fn variable_uses(lowered: &Lowered<'_>) -> UnorderedHashMap<VariableId, usize> {
+    let mut uses = UnorderedHashMap::<VariableId, usize>::default();
+    let mut add_use = |var: VariableId| *uses.entry(var).or_default() += 1;
+    for (_, block) in lowered.blocks.iter() {
+        for statement in &block.statements {
+            statement.inputs().iter().for_each(|input| add_use(input.var_id));
+        }
+        match &block.end {
+            BlockEnd::Return(vars, _) => vars.iter().for_each(|var| add_use(var.var_id)),
+            BlockEnd::Panic(var) => add_use(var.var_id),
+            BlockEnd::Goto(_, remapping) => {
+                remapping.values().for_each(|var| add_use(var.var_id));
+            }
+            BlockEnd::Match { info } => info.inputs().iter().for_each(|var| add_use(var.var_id)),
+            BlockEnd::NotSet => {}
+        }
+    }
+    uses
+}
+
+/// Returns whether the given function is `Clone::clone`.
+fn is_clone<'db>(db: &'db dyn LoweringGroup, function: ids::FunctionId<'db>) -> bool {
+    let FunctionLongId::Semantic(function) = function.long(db) else {
+        return false;
+    };
+    let GenericFunctionId::Impl(impl_function) = function.get_concrete(db).generic_function else {
+        return false;
+    };
+    impl_function.function.name(db) == "clone"
+        && impl_function.function.trait_id(db).full_path(db) == "core::clone::Clone"
+}
+
+/// Returns whether the given node is a method call whose receiver is not a snapshot, so it is
+/// snapshotted implicitly by the call.
+fn has_owned_receiver<'db>(
+    db: &'db dyn LoweringGroup,
+    function_id: defs::ids::FunctionWithBodyId<'db>,
+    stable_ptr: SyntaxStablePtrId<'db>,
+) -> bool {
+    let Some(method_call) = stable_ptr.lookup(db).cast::<ast::ExprBinary<'db>>(db) else {
+        return false;
+    };
+    let semantic_db: &dyn SemanticGroup = db;
+    let Ok(call) = db.lookup_expr_by_ptr(function_id, method_call.stable_ptr(db).into()) else {
+        return false;
+    };
+    let semantic::Expr::FunctionCall(call) = semantic_db.expr_semantic(function_id, call) else {
+        return false;
+    };
+    let Some(semantic::ExprFunctionCallArg::Value(receiver)) = call.args.first() else {
+        return false;
+    };
+    matches!(semantic_db.expr_semantic(function_id, *receiver), semantic::Expr::Snapshot(_))
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::ids::NamedLanguageElementId;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::flag::Flag;
+use cairo_lang_filesystem::ids::FlagLongId;
+use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_syntax::node::TypedStablePtr;
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_test_utils::verify_diagnostics_expectation;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::chain;
+use pretty_assertions::assert_eq;
+
+use super::{LintContext, LintPass, builtin_lint_passes, set_lint_passes};
+use crate::db::LoweringGroup;
+use crate::test_utils::LoweringDatabaseForTesting;
+
+cairo_lang_test_utils::test_file_test!(
+    lint,
+    "src/lint/test_data",
+    {
+        absurd_extreme_comparisons: "absurd_extreme_comparisons",
+        felt252_division: "felt252_division",
+        needless_ref_params: "needless_ref_params",
+        redundant_clones: "redundant_clones",
+        unbounded_loops: "unbounded_loops",
+        unused_syscall_results: "unused_syscall_results",
+    },
+    test_lint_passes
+);
+
+fn test_lint_passes(
+    inputs: &OrderedHashMap<String, String>,
+    args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let db = &mut if args.get("add_withdraw_gas").is_some_and(|value| value == "false") {
+        // Inputs may only be set on a database that is not shared.
+        let mut db = LoweringDatabaseForTesting::new();
+        db.set_flag(
+            FlagLongId("add_withdraw_gas".into()),
+            Some(Arc::new(Flag::AddWithdrawGas(false))),
+        );
+        db
+    } else {
+        LoweringDatabaseForTesting::default()
+    };
+    let (test_module, semantic_diagnostics) =
+        setup_test_module(db, inputs["module_code"].as_str()).split();
+    let lint_diagnostics =
+        db.module_lint_diagnostics(test_module.module_id).unwrap_or_default().format(db);
+    let error = verify_diagnostics_expectation(args, &lint_diagnostics);
+    TestRunnerResult {
+        outputs: OrderedHashMap::from([
+            ("semantic_diagnostics".into(), semantic_diagnostics),
+            ("lint_diagnostics".into(), lint_diagnostics),
+        ]),
+        error,
+    }
+}
+
+/// A lint pass reporting functions named `todo`.
+#[derive(Debug)]
+struct TodoFunctions;
+
+impl LintPass for TodoFunctions {
+    fn lints(&self) -> Vec<&'static str> {
+        vec!["todo_functions"]
+    }
+
+    fn check_semantic<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: cairo_lang_defs::ids::FunctionWithBodyId<'db>,
+    ) {
+        let cairo_lang_defs::ids::FunctionWithBodyId::Free(free_function_id) = function_id else {
+            return;
+        };
+        if free_function_id.name(ctx.db) == "todo" {
+            ctx.report(
+                "todo_functions",
+                free_function_id.stable_ptr(ctx.db).untyped(),
+                "Function is not implemented.",
+            );
+        }
+    }
+}
+
+#[test]
+fn test_third_party_lint_pass() {
+    let db = &mut LoweringDatabaseForTesting::new();
+    set_lint_passes(db, chain!(builtin_lint_passes(), [Arc::new(TodoFunctions) as _]).collect());
+    let (test_module, semantic_diagnostics) = setup_test_module(
+        db,
+        "
+            fn todo() {}
+
+            #[allow(todo_functions)]
+            fn done() {}
+        ",
+    )
+    .split();
+    // The lint is declared, so it is not reported as an unknown lint.
+    assert_eq!(semantic_diagnostics, "");
+    assert_eq!(
+        db.module_lint_diagnostics(test_module.module_id).unwrap().format(db),
+        indoc::indoc! {"
+            warning: Function is not implemented.
+             --> lib.cairo:2:13
+                        fn todo() {}
+                        ^^^^^^^^^^^^
+
+        "}
+    );
+}
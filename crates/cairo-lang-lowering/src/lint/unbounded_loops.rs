@@ -0,0 +1,66 @@
+use cairo_lang_defs as defs;
+use cairo_lang_semantic::Expr;
+use cairo_lang_syntax::attribute::consts::UNBOUNDED_LOOPS;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use salsa::Database;
+
+use super::{LintContext, LintPass};
+use crate::graph_algorithms::feedback_set::flag_add_withdraw_gas;
+
+/// Reports loops without a `break`, a `return` or an error propagation exiting them, when gas is
+/// not withdrawn, as in executables. Such loops run until the program runs out of steps, or
+/// panics.
+#[derive(Debug)]
+pub struct UnboundedLoops;
+
+impl LintPass for UnboundedLoops {
+    fn lints(&self) -> Vec<&'static str> {
+        vec![UNBOUNDED_LOOPS]
+    }
+
+    fn check_semantic<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: defs::ids::FunctionWithBodyId<'db>,
+    ) {
+        let db = ctx.db;
+        if flag_add_withdraw_gas(db) {
+            return;
+        }
+        let Ok(body) = db.function_body(function_id) else {
+            return;
+        };
+        for (_, expr) in body.arenas.exprs.iter() {
+            let Expr::Loop(expr_loop) = expr else {
+                continue;
+            };
+            let loop_body = body.arenas.exprs[expr_loop.body].stable_ptr().lookup(db);
+            if !may_exit(db, loop_body.as_syntax_node(), false) {
+                ctx.report(
+                    UNBOUNDED_LOOPS,
+                    expr_loop.stable_ptr,
+                    "Loop is unbounded: it never exits, and gas is not withdrawn to stop it.",
+                );
+            }
+        }
+    }
+}
+
+/// Returns whether the given node of the body of a loop may exit the loop. Breaks of nested loops
+/// do not exit the loop, and neither do breaks or returns in closures.
+fn may_exit(db: &dyn Database, node: SyntaxNode<'_>, in_nested_loop: bool) -> bool {
+    match node.kind(db) {
+        SyntaxKind::StatementBreak => !in_nested_loop,
+        SyntaxKind::StatementReturn | SyntaxKind::ExprErrorPropagate => true,
+        SyntaxKind::ExprClosure => false,
+        kind => {
+            let in_nested_loop = in_nested_loop
+                || matches!(
+                    kind,
+                    SyntaxKind::ExprLoop | SyntaxKind::ExprWhile | SyntaxKind::ExprFor
+                );
+            node.get_children(db).iter().any(|child| may_exit(db, *child, in_nested_loop))
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use cairo_lang_defs as defs;
+use cairo_lang_semantic::corelib::{
+    ErrorPropagationType, core_array_felt252_ty, unwrap_error_propagation_type,
+};
+use cairo_lang_semantic::{ConcreteTypeId, Expr, Pattern, Statement, TypeLongId};
+use cairo_lang_syntax::attribute::consts::UNUSED_SYSCALL_RESULTS;
+
+use super::{LintContext, LintPass};
+
+/// Reports syscall results that are bound to `_`, ignoring the failure of the syscall.
+///
+/// A call is considered a syscall if it returns a `SyscallResult`, i.e. a `Result` whose error is
+/// an `Array<felt252>`, as syscalls and contract calls through dispatchers do. Results discarded as
+/// statements are already reported as unused `#[must_use]` values.
+#[derive(Debug)]
+pub struct UnusedSyscallResults;
+
+impl LintPass for UnusedSyscallResults {
+    fn lints(&self) -> Vec<&'static str> {
+        vec![UNUSED_SYSCALL_RESULTS]
+    }
+
+    fn check_semantic<'db>(
+        &self,
+        ctx: &mut LintContext<'db, '_>,
+        function_id: defs::ids::FunctionWithBodyId<'db>,
+    ) {
+        let db = ctx.db;
+        let Ok(body) = db.function_body(function_id) else {
+            return;
+        };
+        for (_, statement) in body.arenas.statements.iter() {
+            let Statement::Let(statement_let) = statement else {
+                continue;
+            };
+            if !matches!(body.arenas.patterns[statement_let.pattern], Pattern::Otherwise(_)) {
+                continue;
+            }
+            let Expr::FunctionCall(call) = &body.arenas.exprs[statement_let.expr] else {
+                continue;
+            };
+            // Only enums may be `Result` types.
+            if !matches!(call.ty.long(db), TypeLongId::Concrete(ConcreteTypeId::Enum(_))) {
+                continue;
+            }
+            let Some(ErrorPropagationType::Result { err_variant, .. }) =
+                unwrap_error_propagation_type(db, call.ty)
+            else {
+                continue;
+            };
+            if err_variant.ty != core_array_felt252_ty(db) {
+                continue;
+            }
+            ctx.report(
+                UNUSED_SYSCALL_RESULTS,
+                call.stable_ptr,
+                "The result of a syscall is discarded, ignoring its failure. Handle the result, \
+                 or unwrap it with `unwrap_syscall`.",
+            );
+        }
+    }
+}
@@ -3,7 +3,9 @@ use std::collections::HashMap;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::LanguageElementId;
-use cairo_lang_diagnostics::{DiagnosticNote, DiagnosticsBuilder};
+use cairo_lang_diagnostics::{
+    Applicability, DiagnosticNote, DiagnosticsBuilder, apply_suggestions,
+};
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::test_utils::{setup_test_expr, setup_test_function, setup_test_module};
@@ -148,7 +150,7 @@ a = a * 3
     assert_eq!(
         builder.build().format(db),
         indoc::indoc! {"
-error: Cannot inline a function that might call itself.
+error[E2006]: Cannot inline a function that might call itself.
  --> lib.cairo:1:1-3:4
   fn test_func() { let mut a = 5; {
  _^
@@ -165,6 +167,44 @@ a = a * 3
     );
 }
 
+#[test]
+fn test_missing_match_arm_suggestions() {
+    let db = &mut LoweringDatabaseForTesting::default();
+    let function_code = indoc::indoc! {"
+        fn foo(a: Option<Option<felt252>>) -> felt252 {
+            match a {
+                Some(Some(x)) => x,
+                None => 0,
+            }
+        }
+    "};
+    let test_function = setup_test_function(db, function_code, "foo", "").unwrap();
+
+    let suggestions = db
+        .module_lowering_diagnostics(test_function.module_id)
+        .unwrap()
+        .format_with_severity(db, &Default::default())
+        .into_iter()
+        .flat_map(|diagnostic| diagnostic.details().unwrap().suggestions.clone())
+        .collect_vec();
+    assert_eq!(
+        suggestions.iter().map(|s| (s.applicability, s.message.as_str())).collect_vec(),
+        [(Applicability::HasPlaceholders, "Add a match arm for `Some(None)`")]
+    );
+    assert_eq!(
+        apply_suggestions(function_code, &suggestions).0,
+        indoc::indoc! {"
+            fn foo(a: Option<Option<felt252>>) -> felt252 {
+                match a {
+                    Some(Some(x)) => x,
+                    None => 0,
+                    Some(None) => panic!(\"Not implemented.\"),
+                }
+            }
+        "}
+    );
+}
+
 #[test]
 fn test_sizes() {
     let db = &mut LoweringDatabaseForTesting::default();
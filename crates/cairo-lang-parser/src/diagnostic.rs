@@ -1,4 +1,4 @@
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{DiagnosticEntry, ErrorCode, error_code};
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_syntax::node::kind::SyntaxKind;
@@ -129,6 +129,46 @@ pub enum ParserDiagnosticKind {
     LowPrecedenceOperatorInIfLet { op: SyntaxKind },
 }
 
+impl ParserDiagnosticKind {
+    /// Returns the code identifying the kind of the diagnostic. Codes are never changed or reused,
+    /// so new kinds must be given new codes.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::SkippedElement { .. } => error_code!(E1001),
+            Self::MissingToken(_) => error_code!(E1002),
+            Self::MissingExpression => error_code!(E1003),
+            Self::MissingPathSegment => error_code!(E1004),
+            Self::MissingTypeClause => error_code!(E1005),
+            Self::MissingTypeExpression => error_code!(E1006),
+            Self::MissingWrappedArgList => error_code!(E1007),
+            Self::MissingPattern => error_code!(E1008),
+            Self::MissingMacroRuleParamKind => error_code!(E1009),
+            Self::InvalidParamKindInMacroExpansion => error_code!(E1010),
+            Self::InvalidParamKindInMacroRule => error_code!(E1011),
+            Self::ExpectedInToken => error_code!(E1012),
+            Self::ItemInlineMacroWithoutBang { .. } => error_code!(E1013),
+            Self::ReservedIdentifier { .. } => error_code!(E1014),
+            Self::UnderscoreNotAllowedAsIdentifier => error_code!(E1015),
+            Self::MissingLiteralSuffix => error_code!(E1016),
+            Self::InvalidNumericLiteralValue => error_code!(E1017),
+            Self::IllegalStringEscaping => error_code!(E1018),
+            Self::ShortStringMustBeAscii => error_code!(E1019),
+            Self::StringMustBeAscii => error_code!(E1020),
+            Self::UnterminatedShortString => error_code!(E1021),
+            Self::UnterminatedString => error_code!(E1022),
+            Self::VisibilityWithoutItem => error_code!(E1023),
+            Self::AttributesWithoutItem => error_code!(E1024),
+            Self::AttributesWithoutTraitItem => error_code!(E1025),
+            Self::AttributesWithoutImplItem => error_code!(E1026),
+            Self::AttributesWithoutStatement => error_code!(E1027),
+            Self::DisallowedTrailingSeparatorOr => error_code!(E1028),
+            Self::ConsecutiveMathOperators { .. } => error_code!(E1029),
+            Self::ExpectedSemicolonOrBody => error_code!(E1030),
+            Self::LowPrecedenceOperatorInIfLet { .. } => error_code!(E1031),
+        }
+    }
+}
+
 impl<'a> DiagnosticEntry<'a> for ParserDiagnostic<'a> {
     type DbType = dyn Database;
 
@@ -248,6 +288,10 @@ Did you mean to write `{identifier}!{left}...{right}'?",
         cairo_lang_diagnostics::DiagnosticLocation { file_id: self.file_id, span: self.span }
     }
 
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(self.kind.error_code())
+    }
+
     fn is_same_kind(&self, other: &Self) -> bool {
         other.kind == self.kind
     }
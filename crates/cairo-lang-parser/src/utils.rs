@@ -65,6 +65,7 @@ impl SimpleParserDatabase {
             code_mappings: [].into(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         })
         .intern(self);
         get_syntax_root_and_diagnostics(self, file)
@@ -90,6 +91,7 @@ impl SimpleParserDatabase {
             code_mappings: Default::default(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         })
         .intern(self);
         let mut diagnostics = DiagnosticsBuilder::default();
@@ -116,6 +118,7 @@ impl SimpleParserDatabase {
             code_mappings: Default::default(),
             kind: FileKind::Module,
             original_item_removed: false,
+            plugin: None,
         };
         let file_id = FileLongId::Virtual(vfs).intern(self);
         let mut diagnostics = DiagnosticsBuilder::default();
@@ -1,6 +1,7 @@
 use cairo_lang_defs::extract_macro_single_unnamed_arg;
 use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
 use cairo_lang_defs::plugin_utils::{PluginResultTrait, not_legacy_macro_diagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast};
 use salsa::Database;
@@ -35,17 +36,21 @@ impl MacroPlugin for CompileErrorPlugin {
                     item_ast_ptr
                 );
                 let ast::Expr::String(err_message) = compilation_error_arg.clone() else {
-                    return PluginResult::diagnostic_only(PluginDiagnostic::error_with_inner_span(
-                        db,
-                        item_ast_ptr,
-                        compilation_error_arg.as_syntax_node(),
-                        "`compile_error!` argument must be an unnamed string argument.".to_string(),
-                    ));
+                    return PluginResult::diagnostic_only(
+                        PluginDiagnostic::error_with_inner_span(
+                            db,
+                            item_ast_ptr,
+                            compilation_error_arg.as_syntax_node(),
+                            "`compile_error!` argument must be an unnamed string argument."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!(E3021)),
+                    );
                 };
-                return PluginResult::diagnostic_only(PluginDiagnostic::error(
-                    item_ast_ptr,
-                    err_message.text(db).to_string(),
-                ));
+                return PluginResult::diagnostic_only(
+                    PluginDiagnostic::error(item_ast_ptr, err_message.text(db).to_string())
+                        .with_error_code(error_code!(E3022)),
+                );
             }
         }
         PluginResult { code: None, diagnostics: vec![], remove_original_item: false }
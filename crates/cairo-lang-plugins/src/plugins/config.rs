@@ -4,6 +4,7 @@ use cairo_lang_defs::patcher::PatchBuilder;
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_syntax::attribute::structured::{
     Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -230,10 +231,13 @@ fn parse_predicate_item<'a>(
             match operator {
                 "not" => {
                     if args.len() != 1 {
-                        diagnostics.push(PluginDiagnostic::error(
-                            call.stable_ptr(db),
-                            "`not` operator expects exactly one argument.".into(),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                call.stable_ptr(db),
+                                "`not` operator expects exactly one argument.".into(),
+                            )
+                            .with_error_code(error_code!(E3023)),
+                        );
                         None
                     } else {
                         Some(PredicateTree::Not(Box::new(parse_predicate_item(
@@ -245,10 +249,13 @@ fn parse_predicate_item<'a>(
                 }
                 "and" => {
                     if args.len() < 2 {
-                        diagnostics.push(PluginDiagnostic::error(
-                            call.stable_ptr(db),
-                            "`and` operator expects at least two arguments.".into(),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                call.stable_ptr(db),
+                                "`and` operator expects at least two arguments.".into(),
+                            )
+                            .with_error_code(error_code!(E3024)),
+                        );
                         None
                     } else {
                         Some(PredicateTree::And(
@@ -260,10 +267,13 @@ fn parse_predicate_item<'a>(
                 }
                 "or" => {
                     if args.len() < 2 {
-                        diagnostics.push(PluginDiagnostic::error(
-                            call.stable_ptr(db),
-                            "`or` operator expects at least two arguments.".into(),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                call.stable_ptr(db),
+                                "`or` operator expects at least two arguments.".into(),
+                            )
+                            .with_error_code(error_code!(E3025)),
+                        );
                         None
                     } else {
                         Some(PredicateTree::Or(
@@ -274,19 +284,25 @@ fn parse_predicate_item<'a>(
                     }
                 }
                 _ => {
-                    diagnostics.push(PluginDiagnostic::error(
-                        call.stable_ptr(db),
-                        format!("Unsupported operator: `{operator}`."),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            call.stable_ptr(db),
+                            format!("Unsupported operator: `{operator}`."),
+                        )
+                        .with_error_code(error_code!(E3026)),
+                    );
                     None
                 }
             }
         }
         None => {
-            diagnostics.push(PluginDiagnostic::error(
-                item.arg.stable_ptr(db).untyped(),
-                "Invalid configuration argument.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    item.arg.stable_ptr(db).untyped(),
+                    "Invalid configuration argument.".into(),
+                )
+                .with_error_code(error_code!(E3027)),
+            );
             None
         }
     }
@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
 use indoc::formatdoc;
@@ -27,17 +28,23 @@ pub fn handle_default<'db>(
                 Some((variant, variant.attributes.find_attr(db, DEFAULT_ATTR)?))
             });
             let Some((default_variant, _)) = default_variants.next() else {
-                diagnostics.push(PluginDiagnostic::error(
-                    derived.stable_ptr(db),
-                    "derive `Default` for enum only supported with a default variant.".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        derived.stable_ptr(db),
+                        "derive `Default` for enum only supported with a default variant.".into(),
+                    )
+                    .with_error_code(error_code!(E3032)),
+                );
                 return None;
             };
             for (_, extra_default_attr) in default_variants {
-                diagnostics.push(PluginDiagnostic::error(
-                    extra_default_attr.stable_ptr(db),
-                    "Multiple variants annotated with `#[default]`".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        extra_default_attr.stable_ptr(db),
+                        "Multiple variants annotated with `#[default]`".into(),
+                    )
+                    .with_error_code(error_code!(E3033)),
+                );
             }
             let default_variant_name = &default_variant.name;
             let imp = default_variant.impl_name(DEFAULT_TRAIT);
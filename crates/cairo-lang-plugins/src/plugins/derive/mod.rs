@@ -2,6 +2,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
@@ -41,10 +42,13 @@ impl MacroPlugin for DerivePlugin {
                 Some(info) => info,
                 None => {
                     let maybe_error = item_ast.find_attr(db, DERIVE_ATTR).map(|derive_attr| {
-                        vec![PluginDiagnostic::error(
-                            derive_attr.as_syntax_node().stable_ptr(db),
-                            "`derive` may only be applied to `struct`s and `enum`s".to_string(),
-                        )]
+                        vec![
+                            PluginDiagnostic::error(
+                                derive_attr.as_syntax_node().stable_ptr(db),
+                                "`derive` may only be applied to `struct`s and `enum`s".to_string(),
+                            )
+                            .with_error_code(error_code!(E3028)),
+                        ]
                     });
 
                     return PluginResult {
@@ -88,8 +92,10 @@ fn generate_derive_code_for_type<'db>(
         let attr = attr.structurize(db);
 
         if attr.args.is_empty() {
-            diagnostics
-                .push(PluginDiagnostic::error(attr.args_stable_ptr, "Expected args.".into()));
+            diagnostics.push(
+                PluginDiagnostic::error(attr.args_stable_ptr, "Expected args.".into())
+                    .with_error_code(error_code!(E3029)),
+            );
             continue;
         }
 
@@ -99,8 +105,10 @@ fn generate_derive_code_for_type<'db>(
                 ..
             } = arg
             else {
-                diagnostics
-                    .push(PluginDiagnostic::error(arg.arg.stable_ptr(db), "Expected path.".into()));
+                diagnostics.push(
+                    PluginDiagnostic::error(arg.arg.stable_ptr(db), "Expected path.".into())
+                        .with_error_code(error_code!(E3030)),
+                );
                 continue;
             };
 
@@ -117,10 +125,13 @@ fn generate_derive_code_for_type<'db>(
                 "Serde" => Some(serde::handle_serde(&info)),
                 _ => {
                     if !metadata.declared_derives.contains(derived) {
-                        diagnostics.push(PluginDiagnostic::error(
-                            derived_path.stable_ptr(db),
-                            format!("Unknown derive `{derived}` - a plugin might be missing."),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                derived_path.stable_ptr(db),
+                                format!("Unknown derive `{derived}` - a plugin might be missing."),
+                            )
+                            .with_error_code(error_code!(E3031)),
+                        );
                     }
                     None
                 }
@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
 use cairo_lang_syntax::node::helpers::{GetIdentifier, QueryAttrs};
 use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
@@ -43,71 +44,93 @@ fn get_diagnostics<'a, Item: QueryAttrs<'a>>(
     item.query_attr(db, DOC_ATTR).for_each(|attr| {
         let args = attr.clone().structurize(db).args;
         if args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                format!("Expected arguments. Supported args: {HIDDEN_ATTR}, {GROUP_ATTR}."),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr(db),
+                    format!("Expected arguments. Supported args: {HIDDEN_ATTR}, {GROUP_ATTR}."),
+                )
+                .with_error_code(error_code!(E3006)),
+            );
             return;
         }
         args.iter().for_each(|arg| match &arg.variant {
             AttributeArgVariant::Unnamed(value) => {
                 let ast::Expr::Path(path) = value else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        value.stable_ptr(db),
-                        format!("Expected identifier. Supported identifiers: {HIDDEN_ATTR}."),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            value.stable_ptr(db),
+                            format!("Expected identifier. Supported identifiers: {HIDDEN_ATTR}."),
+                        )
+                        .with_error_code(error_code!(E3007)),
+                    );
                     return;
                 };
                 let Some([ast::PathSegment::Simple(segment)]) =
                     path.segments(db).elements(db).collect_array()
                 else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        path.stable_ptr(db),
-                        format!(
-                            "Wrong type of argument. Currently only {HIDDEN_ATTR_SYNTAX} is \
-                             supported."
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            path.stable_ptr(db),
+                            format!(
+                                "Wrong type of argument. Currently only {HIDDEN_ATTR_SYNTAX} is \
+                                 supported."
+                            ),
+                        )
+                        .with_error_code(error_code!(E3008)),
+                    );
                     return;
                 };
                 if segment.identifier(db) != HIDDEN_ATTR {
-                    diagnostics.push(PluginDiagnostic::error(
-                        path.stable_ptr(db),
-                        format!(
-                            "Wrong type of argument. Currently only: {HIDDEN_ATTR_SYNTAX}, \
-                             {GROUP_ATTR_SYNTAX}  are supported."
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            path.stable_ptr(db),
+                            format!(
+                                "Wrong type of argument. Currently only: {HIDDEN_ATTR_SYNTAX}, \
+                                 {GROUP_ATTR_SYNTAX}  are supported."
+                            ),
+                        )
+                        .with_error_code(error_code!(E3009)),
+                    );
                 }
             }
             AttributeArgVariant::Named { name, value } => match value {
                 ast::Expr::String(_) => {
                     if name.text != GROUP_ATTR {
-                        diagnostics.push(PluginDiagnostic::error(
-                            arg.arg.stable_ptr(db),
-                            format!(
-                                "This argument is not supported. Supported args: {HIDDEN_ATTR}, \
-                                 {GROUP_ATTR}."
-                            ),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                arg.arg.stable_ptr(db),
+                                format!(
+                                    "This argument is not supported. Supported args: \
+                                     {HIDDEN_ATTR}, {GROUP_ATTR}."
+                                ),
+                            )
+                            .with_error_code(error_code!(E3010)),
+                        );
                     }
                 }
                 _ => {
-                    diagnostics.push(PluginDiagnostic::error(
-                        value.stable_ptr(db),
-                        format!(
-                            "Wrong type of argument. Currently only {GROUP_ATTR_SYNTAX} is \
-                             supported."
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            value.stable_ptr(db),
+                            format!(
+                                "Wrong type of argument. Currently only {GROUP_ATTR_SYNTAX} is \
+                                 supported."
+                            ),
+                        )
+                        .with_error_code(error_code!(E3011)),
+                    );
                 }
             },
-            _ => diagnostics.push(PluginDiagnostic::error(
-                arg.arg.stable_ptr(db),
-                format!(
-                    "This argument is not supported. Supported args: {HIDDEN_ATTR}, {GROUP_ATTR}."
-                ),
-            )),
+            _ => diagnostics.push(
+                PluginDiagnostic::error(
+                    arg.arg.stable_ptr(db),
+                    format!(
+                        "This argument is not supported. Supported args: {HIDDEN_ATTR}, \
+                         {GROUP_ATTR}."
+                    ),
+                )
+                .with_error_code(error_code!(E3010)),
+            ),
         });
     });
     if diagnostics.is_empty() { None } else { Some(diagnostics) }
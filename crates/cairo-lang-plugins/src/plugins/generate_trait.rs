@@ -4,6 +4,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
 use cairo_lang_syntax::node::helpers::{BodyItems, GenericParamEx, QueryAttrs};
 use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast};
@@ -29,10 +30,13 @@ impl MacroPlugin for GenerateTraitPlugin {
                 let mut diagnostics = vec![];
 
                 if let Some(attr) = module_item.find_attr(db, GENERATE_TRAIT_ATTR) {
-                    diagnostics.push(PluginDiagnostic::warning(
-                        attr.stable_ptr(db),
-                        "`generate_trait` may only be applied to `impl`s".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::warning(
+                            attr.stable_ptr(db),
+                            "`generate_trait` may only be applied to `impl`s".to_string(),
+                        )
+                        .with_error_code(error_code!(E3012)),
+                    );
                 }
 
                 PluginResult { diagnostics, ..PluginResult::default() }
@@ -56,10 +60,13 @@ fn generate_trait_for_impl<'db>(
     let Some([trait_ast_segment]) = trait_ast.segments(db).elements(db).collect_array() else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                trait_ast.stable_ptr(db),
-                "Generated trait must have a single element path.".to_string(),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    trait_ast.stable_ptr(db),
+                    "Generated trait must have a single element path.".to_string(),
+                )
+                .with_error_code(error_code!(E3013)),
+            ],
             remove_original_item: false,
         };
     };
@@ -90,10 +97,13 @@ fn generate_trait_for_impl<'db>(
                 }
             }
             _ => {
-                diagnostics.push(PluginDiagnostic::error(
-                    attr_arg.arg.stable_ptr(db),
-                    "Expected an argument with the name `trait_attrs`.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        attr_arg.arg.stable_ptr(db),
+                        "Expected an argument with the name `trait_attrs`.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3014)),
+                );
             }
         }
     }
@@ -149,20 +159,26 @@ fn generate_trait_for_impl<'db>(
         ast::PathSegment::Missing(_) => {
             return PluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    trait_ast.stable_ptr(db),
-                    "Generated trait can not have a missing path segment.".to_string(),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        trait_ast.stable_ptr(db),
+                        "Generated trait can not have a missing path segment.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3015)),
+                ],
                 remove_original_item: false,
             };
         }
     };
     if !generic_params_match {
-        diagnostics.push(PluginDiagnostic::error(
-            trait_ast.stable_ptr(db),
-            "Generated trait must have generic args matching the impl's generic params."
-                .to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                trait_ast.stable_ptr(db),
+                "Generated trait must have generic args matching the impl's generic params."
+                    .to_string(),
+            )
+            .with_error_code(error_code!(E3016)),
+        );
     }
     match impl_ast.body(db) {
         ast::MaybeImplBody::None(semicolon) => {
@@ -251,11 +267,15 @@ fn generate_trait_for_impl<'db>(
                         });
                         builder.add_str(";\n");
                     }
-                    _ => diagnostics.push(PluginDiagnostic::error(
-                        item.stable_ptr(db),
-                        "Only functions, types, and constants are supported in #[generate_trait]."
-                            .to_string(),
-                    )),
+                    _ => diagnostics.push(
+                        PluginDiagnostic::error(
+                            item.stable_ptr(db),
+                            "Only functions, types, and constants are supported in \
+                             #[generate_trait]."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!(E3017)),
+                    ),
                 }
             }
             builder.add_node(body.rbrace(db).as_syntax_node());
@@ -2,6 +2,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
@@ -61,10 +62,13 @@ fn generate_panicable_code<'db>(
     };
     let mut diagnostics = vec![];
     if let Some(extra_attr) = attrs.next() {
-        diagnostics.push(PluginDiagnostic::error(
-            extra_attr.stable_ptr(db),
-            "`#[panic_with]` cannot be applied multiple times to the same item.".into(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                extra_attr.stable_ptr(db),
+                "`#[panic_with]` cannot be applied multiple times to the same item.".into(),
+            )
+            .with_error_code(error_code!(E3018)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     }
 
@@ -72,10 +76,13 @@ fn generate_panicable_code<'db>(
     let Some((inner_ty, success_variant, failure_variant)) =
         extract_success_ty_and_variants(db, &signature)
     else {
-        diagnostics.push(PluginDiagnostic::error(
-            signature.ret_ty(db).stable_ptr(db),
-            "Currently only wrapping functions returning an Option<T> or Result<T, E>".into(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                signature.ret_ty(db).stable_ptr(db),
+                "Currently only wrapping functions returning an Option<T> or Result<T, E>".into(),
+            )
+            .with_error_code(error_code!(E3019)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     };
 
@@ -83,10 +90,13 @@ fn generate_panicable_code<'db>(
     let attr = attr.structurize(db);
 
     let Some((err_value, panicable_name)) = parse_arguments(db, &attr) else {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr,
-            "Failed to extract panic data attribute".into(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr,
+                "Failed to extract panic data attribute".into(),
+            )
+            .with_error_code(error_code!(E3020)),
+        );
         return PluginResult { code: None, diagnostics, remove_original_item: false };
     };
     builder.add_node(visibility.as_syntax_node());
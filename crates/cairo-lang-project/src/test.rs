@@ -1,4 +1,4 @@
-use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig};
+use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig, LintLevel};
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 
@@ -22,6 +22,7 @@ fn test_serde() {
                 dependencies: Default::default(),
                 experimental_features: ExperimentalFeaturesConfig::default(),
                 cfg_set: Default::default(),
+                lints: Default::default(),
             },
             override_map: [
                 (
@@ -33,6 +34,7 @@ fn test_serde() {
                         dependencies: Default::default(),
                         experimental_features: ExperimentalFeaturesConfig::default(),
                         cfg_set: Default::default(),
+                        lints: Default::default(),
                     },
                 ),
                 (
@@ -49,6 +51,12 @@ fn test_serde() {
                             user_defined_inline_macros: false,
                         },
                         cfg_set: Default::default(),
+                        lints: [
+                            ("unused_variables".into(), LintLevel::Allow),
+                            ("E2001".into(), LintLevel::Deny),
+                        ]
+                        .into_iter()
+                        .collect(),
                     },
                 ),
             ]
@@ -97,6 +105,10 @@ fn test_serde() {
             associated_item_constraints = false
             coupons = false
             user_defined_inline_macros = false
+
+            [config.override.crate3.lints]
+            E2001 = "deny"
+            unused_variables = "allow"
         "# }
     );
     assert_eq!(config, toml::from_str(&serialized).unwrap());
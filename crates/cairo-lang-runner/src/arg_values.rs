@@ -0,0 +1,164 @@
+//! Generation of argument values of Sierra types, and their encoding as CASM arguments.
+//!
+//! Shared by the differential testing of the Sierra simulator and the fuzzing of tests, which both
+//! run functions with generated arguments.
+
+use cairo_lang_runnable_utils::builder::RunnableBuilder;
+use cairo_lang_sierra::extensions::ConcreteType;
+use cairo_lang_sierra::extensions::core::CoreTypeConcrete;
+use cairo_lang_sierra::extensions::enm::EnumConcreteType;
+use cairo_lang_sierra::extensions::starknet::StarknetTypeConcrete;
+use cairo_lang_sierra::extensions::structure::StructConcreteType;
+use cairo_lang_sierra::extensions::types::InfoAndTypeConcreteType;
+use cairo_lang_sierra::extensions::utils::Range;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_sierra_to_casm::invocations::enm::get_variant_selector;
+use itertools::chain;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use rand::Rng;
+use starknet_types_core::felt::{CAIRO_PRIME_BIGINT, Felt as Felt252};
+
+use crate::Arg;
+
+#[cfg(test)]
+#[path = "arg_values_test.rs"]
+mod test;
+
+/// Returns the range of values of the numeric type `ty`, if it is one.
+///
+/// Numeric types are `felt252`, the felt252 based Starknet types, and the integer types.
+pub fn numeric_range(builder: &RunnableBuilder, ty: &ConcreteTypeId) -> Option<Range> {
+    Some(match builder.registry().get_type(ty).ok()? {
+        CoreTypeConcrete::Felt252(_) => Range::half_open(0, CAIRO_PRIME_BIGINT.clone()),
+        CoreTypeConcrete::Starknet(
+            StarknetTypeConcrete::ClassHash(_)
+            | StarknetTypeConcrete::ContractAddress(_)
+            | StarknetTypeConcrete::StorageAddress(_),
+        ) => Range::half_open(0, BigInt::one() << 251),
+        CoreTypeConcrete::Starknet(StarknetTypeConcrete::StorageBaseAddress(_)) => {
+            Range::half_open(0, (BigInt::one() << 251) - 256)
+        }
+        info => Range::from_type_info(info.info()).ok()?,
+    })
+}
+
+/// Generates a random integer in `range`, biased toward the edges of the range and toward its
+/// simplest integer.
+pub fn random_int(range: &Range, rng: &mut impl Rng) -> BigInt {
+    match rng.random_range(0..4) {
+        0 => range.lower.clone(),
+        1 => &range.upper - 1,
+        2 => simplest_int(range),
+        _ => {
+            &range.lower
+                + BigInt::from_bytes_be(num_bigint::Sign::Plus, &rng.random::<[u8; 32]>())
+                    % range.size()
+        }
+    }
+}
+
+/// Returns the simplest integer in `range`, which is zero if it is in the range.
+pub fn simplest_int(range: &Range) -> BigInt {
+    if range.lower.is_positive() {
+        range.lower.clone()
+    } else if range.upper.is_positive() {
+        BigInt::zero()
+    } else {
+        &range.upper - 1
+    }
+}
+
+/// Returns the value of the numeric type `ty` with the given numeric value.
+pub fn numeric_value(builder: &RunnableBuilder, ty: &ConcreteTypeId, value: BigInt) -> CoreValue {
+    match builder.registry().get_type(ty).unwrap() {
+        CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. })
+        | CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => {
+            numeric_value(builder, ty, value)
+        }
+        CoreTypeConcrete::Felt252(_) | CoreTypeConcrete::Starknet(_) => {
+            CoreValue::Felt252(value.into())
+        }
+        CoreTypeConcrete::Uint8(_) => CoreValue::Uint8(value.try_into().unwrap()),
+        CoreTypeConcrete::Uint16(_) => CoreValue::Uint16(value.try_into().unwrap()),
+        CoreTypeConcrete::Uint32(_) => CoreValue::Uint32(value.try_into().unwrap()),
+        CoreTypeConcrete::Uint64(_) => CoreValue::Uint64(value.try_into().unwrap()),
+        CoreTypeConcrete::Uint128(_) => CoreValue::Uint128(value.try_into().unwrap()),
+        CoreTypeConcrete::Sint8(_) => CoreValue::Sint8(value.try_into().unwrap()),
+        CoreTypeConcrete::Sint16(_) => CoreValue::Sint16(value.try_into().unwrap()),
+        CoreTypeConcrete::Sint32(_) => CoreValue::Sint32(value.try_into().unwrap()),
+        CoreTypeConcrete::Sint64(_) => CoreValue::Sint64(value.try_into().unwrap()),
+        CoreTypeConcrete::Sint128(_) => CoreValue::Sint128(value.try_into().unwrap()),
+        _ => CoreValue::BoundedInt(value),
+    }
+}
+
+/// Returns the numeric value of an integer value.
+pub fn int_value_to_bigint(value: &CoreValue) -> Option<BigInt> {
+    Some(match value {
+        CoreValue::Uint8(value) => (*value).into(),
+        CoreValue::Uint16(value) => (*value).into(),
+        CoreValue::Uint32(value) => (*value).into(),
+        CoreValue::Uint64(value) => (*value).into(),
+        CoreValue::Uint128(value) => (*value).into(),
+        CoreValue::Sint8(value) => (*value).into(),
+        CoreValue::Sint16(value) => (*value).into(),
+        CoreValue::Sint32(value) => (*value).into(),
+        CoreValue::Sint64(value) => (*value).into(),
+        CoreValue::Sint128(value) => (*value).into(),
+        CoreValue::BoundedInt(value) => value.clone(),
+        _ => return None,
+    })
+}
+
+/// Returns the CASM arguments representing `value` of `ty`, by the memory layout of `ty`, or
+/// `None` if `value` is not a value of `ty`.
+pub fn value_to_args(
+    builder: &RunnableBuilder,
+    ty: &ConcreteTypeId,
+    value: &CoreValue,
+) -> Option<Vec<Arg>> {
+    Some(match (builder.registry().get_type(ty).ok()?, value) {
+        (_, CoreValue::Felt252(value)) => vec![Arg::Value(*value)],
+        (_, CoreValue::EcPoint(x, y)) => vec![Arg::Value(*x), Arg::Value(*y)],
+        (
+            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. })
+            | CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }),
+            value,
+        ) => value_to_args(builder, ty, value)?,
+        (CoreTypeConcrete::Array(InfoAndTypeConcreteType { ty, .. }), CoreValue::Array(values)) => {
+            vec![Arg::Array(
+                values
+                    .iter()
+                    .map(|value| value_to_args(builder, ty, value))
+                    .collect::<Option<Vec<_>>>()?
+                    .concat(),
+            )]
+        }
+        (
+            CoreTypeConcrete::Struct(StructConcreteType { members, .. }),
+            CoreValue::Struct(values),
+        ) if members.len() == values.len() => members
+            .iter()
+            .zip(values)
+            .map(|(ty, value)| value_to_args(builder, ty, value))
+            .collect::<Option<Vec<_>>>()?
+            .concat(),
+        (
+            CoreTypeConcrete::Enum(EnumConcreteType { variants, .. }),
+            CoreValue::Enum { value, index },
+        ) => {
+            let variant = variants.get(*index)?;
+            let selector = get_variant_selector(variants.len(), *index).unwrap();
+            let padding = (builder.type_size(ty) - 1 - builder.type_size(variant)) as usize;
+            chain!(
+                [Arg::Value(selector.into())],
+                std::iter::repeat_n(Arg::Value(Felt252::ZERO), padding),
+                value_to_args(builder, variant, value)?,
+            )
+            .collect()
+        }
+        (_, value) => vec![Arg::Value(int_value_to_bigint(value)?.into())],
+    })
+}
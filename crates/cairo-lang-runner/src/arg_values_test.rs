@@ -0,0 +1,27 @@
+use cairo_lang_sierra::extensions::utils::Range;
+use num_bigint::BigInt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use test_case::test_case;
+
+use super::{random_int, simplest_int};
+
+#[test_case(0, 256, 0; "unsigned")]
+#[test_case(-128, 128, 0; "signed")]
+#[test_case(1, 256, 1; "positive")]
+#[test_case(-10, -5, -6; "negative")]
+fn test_simplest_int(lower: i64, upper: i64, expected: i64) {
+    assert_eq!(simplest_int(&Range::half_open(lower, upper)), BigInt::from(expected));
+}
+
+#[test]
+fn test_random_int() {
+    let range = Range::half_open(-128, 128);
+    let mut rng = StdRng::seed_from_u64(0);
+    let values: Vec<BigInt> = (0..100).map(|_| random_int(&range, &mut rng)).collect();
+    assert!(values.iter().all(|value| range.lower <= *value && *value < range.upper));
+    // The edges of the range and zero are generated with a high probability.
+    for edge in [-128, 127, 0] {
+        assert!(values.contains(&BigInt::from(edge)));
+    }
+}
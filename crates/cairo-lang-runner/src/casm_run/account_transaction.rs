@@ -0,0 +1,278 @@
+//! Simulation of account transactions: an invoke transaction is validated by the `__validate__`
+//! entry point of its sender account, and then executed by its `__execute__` entry point.
+
+use cairo_lang_sierra::ids::FunctionId;
+use num_traits::ToPrimitive;
+use starknet_types_core::felt::Felt as Felt252;
+use thiserror::Error;
+
+use super::contract_address::calculate_contract_address;
+use super::transaction_hash::calculate_invoke_v1_hash;
+use super::{EXECUTE_ENTRY_POINT_SELECTOR, TxInfo, format_for_panic, read_array_result_as_vec};
+use crate::{
+    Arg, DEFAULT_AVAILABLE_GAS, RunResultStarknet, RunResultValue, RunnerError, SierraCasmRunner,
+    StarknetExecutionResources, StarknetState,
+};
+
+#[cfg(test)]
+#[path = "account_transaction_test.rs"]
+mod test;
+
+/// The selector of the `__validate__` entry point of accounts.
+const VALIDATE_ENTRY_POINT_SELECTOR: Felt252 = Felt252::from_hex_unchecked(
+    "0x162da33a4585851fe8d3af3c2a9c60b557814e221e0d4f30ff0b2189d9c7775",
+);
+
+/// The value returned by `__validate__` for a valid transaction - the short string `VALID`.
+const VALIDATED: Felt252 = Felt252::from_hex_unchecked("0x56414c4944");
+
+/// The gas available to `__validate__`, which is bounded separately from the execution.
+const VALIDATE_AVAILABLE_GAS: usize = 100_000_000;
+
+/// An error rejecting a transaction, which leaves the state unchanged. The exception is a
+/// [TransactionError::RunnerError] of `__execute__`, where the transaction was already validated.
+#[derive(Debug, Error)]
+pub enum TransactionError {
+    #[error(transparent)]
+    RunnerError(#[from] RunnerError),
+    #[error("No contract is deployed at {0:#x}.")]
+    ContractNotDeployed(Felt252),
+    #[error("A contract is already deployed at {0:#x}.")]
+    ContractAlreadyDeployed(Felt252),
+    #[error("Class {0:#x} is not declared.")]
+    ClassNotDeclared(Felt252),
+    #[error("Contract {address:#x} has no `{name}` entry point.")]
+    EntryPointNotFound { address: Felt252, name: &'static str },
+    #[error("Invalid nonce {actual:#x} for account {account:#x}, expected {expected:#x}.")]
+    InvalidNonce { account: Felt252, expected: Felt252, actual: Felt252 },
+    #[error("Transaction validation failed: {0}")]
+    ValidationFailed(String),
+    #[error("Transaction validation returned {0:?}, instead of `VALID`.")]
+    InvalidValidationResult(Vec<Felt252>),
+    #[error("Constructor failed: {0}")]
+    ConstructorFailed(String),
+}
+
+/// An invoke transaction, sent from an account contract.
+#[derive(Clone, Debug, Default)]
+pub struct InvokeTransaction {
+    /// The address of the account sending the transaction.
+    pub sender_address: Felt252,
+    /// The calldata of the `__validate__` and `__execute__` entry points of the account.
+    pub calldata: Vec<Felt252>,
+    /// The signature of the transaction, available to the account in the transaction info.
+    pub signature: Vec<Felt252>,
+    /// The nonce of the transaction, which must match the nonce of the account.
+    pub nonce: Felt252,
+    /// The max fee of the transaction.
+    pub max_fee: Felt252,
+}
+
+/// The result of an accepted invoke transaction - validated, and then either executed or reverted.
+pub struct InvokeTransactionResult {
+    /// The hash of the transaction.
+    pub transaction_hash: Felt252,
+    /// The values returned by `__execute__`, or its panic data if the execution reverted.
+    pub execution: RunResultValue,
+    /// The resources used by `__validate__`.
+    pub validate_resources: StarknetExecutionResources,
+    /// The resources used by `__execute__`.
+    pub execute_resources: StarknetExecutionResources,
+    /// The gas consumed by `__validate__` and `__execute__`.
+    pub gas_consumed: usize,
+}
+
+impl SierraCasmRunner {
+    /// Runs an invoke transaction on the given state. The transaction is validated by the
+    /// `__validate__` entry point of the sender account and executed by its `__execute__` entry
+    /// point, both called by the sequencer with the transaction info of the transaction.
+    ///
+    /// A transaction that fails validation is rejected, and leaves the state unchanged. Once
+    /// validated, the transaction is included in the current block and the nonce of the account
+    /// is bumped, and the changes of `__execute__` are kept only if it succeeds. This holds even if
+    /// `__execute__` fails to run, in which case its error is returned.
+    pub fn run_invoke_transaction(
+        &self,
+        tx: &InvokeTransaction,
+        state: &mut StarknetState,
+    ) -> Result<InvokeTransactionResult, TransactionError> {
+        let class_hash = *state
+            .deployed_contracts
+            .get(&tx.sender_address)
+            .ok_or(TransactionError::ContractNotDeployed(tx.sender_address))?;
+        let (class_runner, contract_info) = self
+            .find_contract_class(&class_hash)
+            .ok_or(TransactionError::ClassNotDeclared(class_hash))?;
+        let entry_point = |selector, name| {
+            contract_info
+                .externals
+                .get(&selector)
+                .ok_or(TransactionError::EntryPointNotFound { address: tx.sender_address, name })
+        };
+        let validate = entry_point(VALIDATE_ENTRY_POINT_SELECTOR, "__validate__")?;
+        let execute = entry_point(EXECUTE_ENTRY_POINT_SELECTOR, "__execute__")?;
+        let expected_nonce = state.nonce(tx.sender_address);
+        if tx.nonce != expected_nonce {
+            return Err(TransactionError::InvalidNonce {
+                account: tx.sender_address,
+                expected: expected_nonce,
+                actual: tx.nonce,
+            });
+        }
+
+        let chain_id = state.exec_info.tx_info.chain_id;
+        let transaction_hash = calculate_invoke_v1_hash(
+            &tx.sender_address,
+            &tx.calldata,
+            &tx.max_fee,
+            &chain_id,
+            &tx.nonce,
+        );
+        let mut tx_state = state.clone();
+        let old_tx_info = std::mem::replace(
+            &mut tx_state.exec_info.tx_info,
+            TxInfo {
+                version: Felt252::ONE,
+                account_contract_address: tx.sender_address,
+                max_fee: tx.max_fee,
+                signature: tx.signature.clone(),
+                transaction_hash,
+                chain_id,
+                nonce: tx.nonce,
+                ..TxInfo::default()
+            },
+        );
+        let old_addrs = tx_state.open_caller_context((tx.sender_address, Felt252::ZERO));
+
+        let validation = self.run_entry_point(
+            class_runner,
+            validate,
+            tx.calldata.clone(),
+            VALIDATE_AVAILABLE_GAS,
+            tx_state,
+        )?;
+        match &validation.value {
+            RunResultValue::Success(value) => {
+                let validated = read_array_result_as_vec(&validation.memory, value);
+                if validated != [VALIDATED] {
+                    return Err(TransactionError::InvalidValidationResult(validated));
+                }
+            }
+            RunResultValue::Panic(panic_data) => {
+                return Err(TransactionError::ValidationFailed(format_for_panic(
+                    panic_data.iter().cloned(),
+                )));
+            }
+        }
+        let mut validated_state = validation.starknet_state;
+        validated_state.nonces.insert(tx.sender_address, tx.nonce + Felt252::ONE);
+        let include_transaction = |mut final_state: StarknetState| {
+            final_state.close_caller_context(old_addrs);
+            final_state.exec_info.tx_info = old_tx_info;
+            final_state.block_transactions.push(transaction_hash);
+            final_state
+        };
+
+        let execution = match self.run_entry_point(
+            class_runner,
+            execute,
+            tx.calldata.clone(),
+            DEFAULT_AVAILABLE_GAS,
+            validated_state.clone(),
+        ) {
+            Ok(execution) => execution,
+            Err(err) => {
+                *state = include_transaction(validated_state);
+                return Err(err.into());
+            }
+        };
+        let (execution_value, final_state) = match execution.value {
+            RunResultValue::Success(value) => (
+                RunResultValue::Success(read_array_result_as_vec(&execution.memory, &value)),
+                execution.starknet_state,
+            ),
+            RunResultValue::Panic(panic_data) => {
+                (RunResultValue::Panic(panic_data), validated_state)
+            }
+        };
+        *state = include_transaction(final_state);
+
+        let gas_consumed = |available_gas: usize, gas_counter: Option<Felt252>| {
+            available_gas - gas_counter.and_then(|gas| gas.to_usize()).unwrap_or_default()
+        };
+        Ok(InvokeTransactionResult {
+            transaction_hash,
+            execution: execution_value,
+            gas_consumed: gas_consumed(VALIDATE_AVAILABLE_GAS, validation.gas_counter)
+                + gas_consumed(DEFAULT_AVAILABLE_GAS, execution.gas_counter),
+            validate_resources: validation.used_resources,
+            execute_resources: execution.used_resources,
+        })
+    }
+
+    /// Deploys a contract of a class registered in the runner from a zero deployer address, as
+    /// done when setting up the accounts sending transactions. Returns the address of the deployed
+    /// contract.
+    pub fn deploy_contract(
+        &self,
+        class_hash: Felt252,
+        salt: Felt252,
+        constructor_calldata: Vec<Felt252>,
+        state: &mut StarknetState,
+    ) -> Result<Felt252, TransactionError> {
+        let (class_runner, contract_info) = self
+            .find_contract_class(&class_hash)
+            .ok_or(TransactionError::ClassNotDeclared(class_hash))?;
+        let address =
+            calculate_contract_address(&salt, &class_hash, &constructor_calldata, &Felt252::ZERO);
+        if state.deployed_contracts.contains_key(&address) {
+            return Err(TransactionError::ContractAlreadyDeployed(address));
+        }
+        let mut deployed_state = state.clone();
+        deployed_state.deployed_contracts.insert(address, class_hash);
+        if let Some(constructor) = &contract_info.constructor {
+            let old_addrs = deployed_state.open_caller_context((address, Felt252::ZERO));
+            let run = self.run_entry_point(
+                class_runner,
+                constructor,
+                constructor_calldata,
+                DEFAULT_AVAILABLE_GAS,
+                deployed_state,
+            )?;
+            if let RunResultValue::Panic(panic_data) = run.value {
+                return Err(TransactionError::ConstructorFailed(format_for_panic(
+                    panic_data.into_iter(),
+                )));
+            }
+            deployed_state = run.starknet_state;
+            deployed_state.close_caller_context(old_addrs);
+        }
+        *state = deployed_state;
+        Ok(address)
+    }
+
+    /// Runs an entry point of a registered class with the given calldata.
+    fn run_entry_point(
+        &self,
+        class_runner: &SierraCasmRunner,
+        entry_point: &FunctionId,
+        calldata: Vec<Felt252>,
+        available_gas: usize,
+        starknet_state: StarknetState,
+    ) -> Result<RunResultStarknet, RunnerError> {
+        let function = class_runner
+            .builder
+            .registry()
+            .get_function(entry_point)
+            .expect("Entrypoint exists, but not found.");
+        let (mut hint_processor, ctx) = class_runner.prepare_starknet_context(
+            function,
+            vec![Arg::Array(calldata.into_iter().map(Arg::Value).collect())],
+            Some(available_gas),
+            starknet_state,
+        )?;
+        // Classes are always looked up in the registry of this runner.
+        hint_processor.runner = Some(self);
+        class_runner.run_function_with_prepared_starknet_context(function, &mut hint_processor, ctx)
+    }
+}
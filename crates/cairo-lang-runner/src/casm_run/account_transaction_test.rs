@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::{DebugReplacer, SierraIdReplacer};
+use cairo_lang_starknet::contract::{find_contracts, get_contracts_info};
+use cairo_lang_starknet::starknet_plugin_suite;
+use indoc::indoc;
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::{InvokeTransaction, TransactionError};
+use crate::casm_run::transaction_hash::calculate_invoke_v1_hash;
+use crate::{RunResultValue, SierraCasmRunner, StarknetState};
+
+/// An account whose signature is its public key, remembering the last value it executed with.
+/// Executing with 0 panics, and executing with 1 fails to run due to an unknown cheatcode.
+const ACCOUNT_CODE: &str = indoc! {"
+    #[starknet::contract(account)]
+    mod account {
+        use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+        use starknet::{VALIDATED, get_caller_address, get_tx_info};
+
+        #[storage]
+        struct Storage {
+            public_key: felt252,
+            last_value: felt252,
+        }
+
+        #[constructor]
+        fn constructor(ref self: ContractState, public_key: felt252) {
+            self.public_key.write(public_key);
+        }
+
+        #[external(v0)]
+        fn __validate__(self: @ContractState, value: felt252) -> felt252 {
+            let caller: felt252 = get_caller_address().into();
+            assert(caller == 0, 'Unexpected caller');
+            let signature = get_tx_info().unbox().signature;
+            assert(signature == [self.public_key.read()].span(), 'Invalid signature');
+            VALIDATED
+        }
+
+        #[external(v0)]
+        fn __execute__(ref self: ContractState, value: felt252) -> Array<felt252> {
+            assert(value != 0, 'Zero value');
+            if value == 1 {
+                starknet::testing::cheatcode::<'unknown_cheatcode'>([].span());
+            }
+            let previous_value = self.last_value.read();
+            self.last_value.write(value);
+            let tx_info = get_tx_info().unbox();
+            array![previous_value, tx_info.nonce, tx_info.transaction_hash]
+        }
+    }
+"};
+
+const PUBLIC_KEY: Felt252 = Felt252::from_hex_unchecked("0x7");
+
+/// Compiles the account, and returns a runner registering it, and its class hash.
+fn setup_account_runner() -> (SierraCasmRunner, Felt252) {
+    let db = RootDatabase::builder()
+        .with_default_plugin_suite(starknet_plugin_suite())
+        .detect_corelib()
+        .with_cfg(CfgSet::from_iter([Cfg::kv("target", "test")]))
+        .build()
+        .unwrap();
+    let test_module = setup_test_module(&db, ACCOUNT_CODE).unwrap();
+    let crate_input = test_module.crate_id.long(&db).clone().into_crate_input(&db);
+    DiagnosticsReporter::stderr().with_crates(&[crate_input]).ensure(&db).unwrap();
+    let mut sierra_program =
+        Arc::unwrap_or_clone(db.get_sierra_program(vec![test_module.crate_id]).unwrap()).program;
+    let replacer = DebugReplacer { db: &db };
+    replacer.enrich_function_names(&mut sierra_program);
+    let contracts = find_contracts(&db, &[test_module.crate_id]);
+    let contracts_info = get_contracts_info(&db, contracts, &replacer).unwrap();
+    let class_hash = *contracts_info.keys().next().unwrap();
+    let runner = SierraCasmRunner::new(
+        replacer.apply(&sierra_program),
+        Some(Default::default()),
+        contracts_info,
+        None,
+    )
+    .unwrap();
+    (runner, class_hash)
+}
+
+fn invoke(sender_address: Felt252, value: u64, nonce: u64) -> InvokeTransaction {
+    InvokeTransaction {
+        sender_address,
+        calldata: vec![value.into()],
+        signature: vec![PUBLIC_KEY],
+        nonce: nonce.into(),
+        max_fee: Felt252::ZERO,
+    }
+}
+
+#[test]
+fn test_invoke_transaction() {
+    let (runner, class_hash) = setup_account_runner();
+    let mut state = StarknetState::default();
+    let account =
+        runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state).unwrap();
+
+    let result = runner.run_invoke_transaction(&invoke(account, 5, 0), &mut state).unwrap();
+    let expected_hash =
+        calculate_invoke_v1_hash(&account, &[5.into()], &Felt252::ZERO, &Felt252::ZERO, &0.into());
+    assert_eq!(result.transaction_hash, expected_hash);
+    assert_eq!(
+        result.execution,
+        RunResultValue::Success(vec![3.into(), 0.into(), 0.into(), expected_hash])
+    );
+    assert!(result.gas_consumed > 0);
+    // Both the caller address and the signature are read from the execution info.
+    assert_eq!(result.validate_resources.syscalls["GetExecutionInfo"], 2);
+    assert_eq!(state.nonce(account), 1.into());
+
+    let result = runner.run_invoke_transaction(&invoke(account, 6, 1), &mut state).unwrap();
+    // The returned values are the serialized array returned by `__execute__`.
+    let RunResultValue::Success(values) = result.execution else { panic!("Execution failed.") };
+    assert_eq!(values[..3], [3.into(), 5.into(), 1.into()]);
+    assert_eq!(state.nonce(account), 2.into());
+}
+
+#[test]
+fn test_rejected_transactions() {
+    let (runner, class_hash) = setup_account_runner();
+    let mut state = StarknetState::default();
+    let account =
+        runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state).unwrap();
+
+    assert!(matches!(
+        runner.run_invoke_transaction(&invoke(account, 5, 1), &mut state),
+        Err(TransactionError::InvalidNonce { expected, actual, .. })
+            if expected == 0.into() && actual == 1.into()
+    ));
+    let tx = InvokeTransaction { signature: vec![8.into()], ..invoke(account, 5, 0) };
+    assert_eq!(
+        runner.run_invoke_transaction(&tx, &mut state).err().unwrap().to_string(),
+        "Transaction validation failed: Panicked with 0x496e76616c6964207369676e6174757265 \
+         ('Invalid signature')."
+    );
+    assert!(matches!(
+        runner.run_invoke_transaction(&invoke(1.into(), 5, 0), &mut state),
+        Err(TransactionError::ContractNotDeployed(_))
+    ));
+    // Rejected transactions do not bump the nonce.
+    assert_eq!(state.nonce(account), 0.into());
+}
+
+#[test]
+fn test_reverted_transaction() {
+    let (runner, class_hash) = setup_account_runner();
+    let mut state = StarknetState::default();
+    let account =
+        runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state).unwrap();
+    runner.run_invoke_transaction(&invoke(account, 5, 0), &mut state).unwrap();
+
+    let result = runner.run_invoke_transaction(&invoke(account, 0, 1), &mut state).unwrap();
+    assert_eq!(
+        result.execution,
+        RunResultValue::Panic(vec![Felt252::from_bytes_be_slice(b"Zero value")])
+    );
+    // The nonce is bumped, but the changes of the execution are reverted.
+    assert_eq!(state.nonce(account), 2.into());
+    let result = runner.run_invoke_transaction(&invoke(account, 6, 2), &mut state).unwrap();
+    let RunResultValue::Success(values) = result.execution else { panic!("Execution failed.") };
+    assert_eq!(values[1], 5.into());
+}
+
+#[test]
+fn test_failed_execution() {
+    let (runner, class_hash) = setup_account_runner();
+    let mut state = StarknetState::default();
+    let account =
+        runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state).unwrap();
+
+    assert!(matches!(
+        runner.run_invoke_transaction(&invoke(account, 1, 0), &mut state),
+        Err(TransactionError::RunnerError(_))
+    ));
+    // The transaction was validated, so the nonce is bumped and the transaction is included.
+    assert_eq!(state.nonce(account), 1.into());
+    assert_eq!(state.block_transactions.len(), 1);
+    runner.run_invoke_transaction(&invoke(account, 5, 1), &mut state).unwrap();
+}
+
+#[test]
+fn test_run_block() {
+    let (runner, class_hash) = setup_account_runner();
+    let mut state = StarknetState::default();
+    let account =
+        runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state).unwrap();
+
+    let results = runner.run_block(
+        &[invoke(account, 5, 0), invoke(account, 6, 5), invoke(account, 7, 1)],
+        30,
+        &mut state,
+    );
+    assert!(matches!(results[1], Err(TransactionError::InvalidNonce { .. })));
+    // Only the accepted transactions are included in the block.
+    let accepted_hashes = [&results[0], &results[2]]
+        .map(|result| result.as_ref().map(|result| result.transaction_hash).unwrap());
+    assert_eq!(state.block(0).unwrap().transaction_hashes, accepted_hashes);
+    assert_eq!(state.block_number(), 1);
+    assert_eq!(state.block_timestamp(), 30);
+    assert_eq!(state.nonce(account), 2.into());
+}
+
+#[test]
+fn test_deploy_contract() {
+    let (runner, class_hash) = setup_account_runner();
+    let mut state = StarknetState::default();
+    runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state).unwrap();
+    assert!(matches!(
+        runner.deploy_contract(class_hash, Felt252::ZERO, vec![PUBLIC_KEY], &mut state),
+        Err(TransactionError::ContractAlreadyDeployed(_))
+    ));
+    assert!(matches!(
+        runner.deploy_contract(1.into(), Felt252::ZERO, vec![], &mut state),
+        Err(TransactionError::ClassNotDeclared(_))
+    ));
+}
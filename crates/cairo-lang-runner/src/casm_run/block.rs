@@ -0,0 +1,125 @@
+//! Simulation of Starknet blocks: transactions are run in the current block, which is then closed,
+//! recorded in the block history, and followed by the next block.
+
+use num_traits::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt as Felt252;
+use starknet_types_core::hash::{Pedersen, StarkHash};
+
+use super::account_transaction::{InvokeTransaction, InvokeTransactionResult, TransactionError};
+use crate::{SierraCasmRunner, StarknetState};
+
+#[cfg(test)]
+#[path = "block_test.rs"]
+mod test;
+
+/// The number of blocks a block must be behind the current block for its hash to be available to
+/// `get_block_hash_syscall`, as in Starknet.
+pub const BLOCK_HASH_FINALITY_OFFSET: u64 = 10;
+
+/// The maximal number of blocks advanced by a single `advance_blocks` cheatcode, as each advanced
+/// block is recorded in the block history.
+pub const MAX_ADVANCED_BLOCKS: u64 = 10000;
+
+/// A closed block in the block history of a simulated state.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockRecord {
+    /// The timestamp of the block.
+    pub timestamp: u64,
+    /// The address of the sequencer of the block.
+    pub sequencer_address: Felt252,
+    /// The hashes of the transactions run in the block, in order.
+    pub transaction_hashes: Vec<Felt252>,
+    /// The mock hash of the block - derived from the other fields of the block and the hash of its
+    /// parent, as the simulation has no real block headers.
+    pub block_hash: Felt252,
+}
+
+impl StarknetState {
+    /// Returns the number of the current block.
+    pub fn block_number(&self) -> u64 {
+        self.exec_info.block_info.block_number.to_u64().expect("Block number is not a u64.")
+    }
+
+    /// Returns the timestamp of the current block.
+    pub fn block_timestamp(&self) -> u64 {
+        self.exec_info.block_info.block_timestamp.to_u64().expect("Block timestamp is not a u64.")
+    }
+
+    /// Returns the closed block with the given number, if it is in the block history.
+    pub fn block(&self, block_number: u64) -> Option<&BlockRecord> {
+        self.block_history.get(&block_number)
+    }
+
+    /// Closes the current block, recording it in the block history, and starts the next block,
+    /// `block_time` seconds after the current one. Returns the hash of the closed block.
+    ///
+    /// Panics if the number or the timestamp of the next block is out of the `u64` range.
+    pub fn advance_block(&mut self, block_time: u64) -> Felt252 {
+        let block_number = self.block_number();
+        let timestamp = self.block_timestamp();
+        let sequencer_address = self.exec_info.block_info.sequencer_address;
+        let transaction_hashes = std::mem::take(&mut self.block_transactions);
+        let parent_hash = block_number
+            .checked_sub(1)
+            .and_then(|parent| self.block_history.get(&parent))
+            .map(|parent| parent.block_hash)
+            .unwrap_or_default();
+        let block_hash = Pedersen::hash_array(&[
+            block_number.into(),
+            parent_hash,
+            timestamp.into(),
+            sequencer_address,
+            Pedersen::hash_array(&transaction_hashes),
+        ]);
+        let next_block_number = block_number.checked_add(1).expect("Block number overflow.");
+        let next_timestamp = timestamp.checked_add(block_time).expect("Block timestamp overflow.");
+        self.block_history.insert(
+            block_number,
+            BlockRecord { timestamp, sequencer_address, transaction_hashes, block_hash },
+        );
+        self.exec_info.block_info.block_number = next_block_number.into();
+        self.exec_info.block_info.block_timestamp = next_timestamp.into();
+        block_hash
+    }
+
+    /// Returns the hash of a block as served by `get_block_hash_syscall`: the hash of a block
+    /// explicitly set by `set_block_hash`, or of a block in the block history that is at least
+    /// [BLOCK_HASH_FINALITY_OFFSET] blocks behind the current one.
+    pub(crate) fn get_block_hash(&self, block_number: u64) -> Result<Felt252, &'static [u8]> {
+        if let Some(block_hash) = self.block_hash.get(&block_number) {
+            return Ok(*block_hash);
+        }
+        // The offset is only enforced once blocks are simulated, as without a history all the
+        // hashes are explicitly set.
+        if !self.block_history.is_empty()
+            && self
+                .block_number()
+                .checked_sub(BLOCK_HASH_FINALITY_OFFSET)
+                .is_none_or(|last_final_block| block_number > last_final_block)
+        {
+            return Err(b"Block number out of range");
+        }
+        self.block_history
+            .get(&block_number)
+            .map(|block| block.block_hash)
+            .ok_or(b"GET_BLOCK_HASH_NOT_SET")
+    }
+}
+
+impl SierraCasmRunner {
+    /// Runs the given transactions in the current block of the state, and then advances to the
+    /// next block, `block_time` seconds later. Returns the results of the transactions, where
+    /// rejected transactions are not included in the block.
+    pub fn run_block(
+        &self,
+        transactions: &[InvokeTransaction],
+        block_time: u64,
+        state: &mut StarknetState,
+    ) -> Vec<Result<InvokeTransactionResult, TransactionError>> {
+        let results =
+            transactions.iter().map(|tx| self.run_invoke_transaction(tx, state)).collect();
+        state.advance_block(block_time);
+        results
+    }
+}
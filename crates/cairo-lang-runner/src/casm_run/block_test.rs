@@ -0,0 +1,43 @@
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::BLOCK_HASH_FINALITY_OFFSET;
+use crate::StarknetState;
+
+#[test]
+fn test_advance_block() {
+    let mut state = StarknetState::default();
+    state.exec_info.block_info.block_timestamp = 1000.into();
+    state.block_transactions.push(Felt252::from(0x123));
+    let first_hash = state.advance_block(30);
+    let second_hash = state.advance_block(30);
+    assert_eq!(state.block_number(), 2);
+    assert_eq!(state.block_timestamp(), 1060);
+
+    let first = state.block(0).unwrap();
+    assert_eq!(first.timestamp, 1000);
+    assert_eq!(first.transaction_hashes, [Felt252::from(0x123)]);
+    assert_eq!(first.block_hash, first_hash);
+    let second = state.block(1).unwrap();
+    assert_eq!(second.timestamp, 1030);
+    assert!(second.transaction_hashes.is_empty());
+    assert_ne!(second_hash, first_hash);
+    assert!(state.block(2).is_none());
+}
+
+#[test]
+fn test_get_block_hash() {
+    let mut state = StarknetState::default();
+    state.block_hash.insert(100, Felt252::from(0xabc));
+    // Without a block history, only the explicitly set hashes are available.
+    assert_eq!(state.get_block_hash(100), Ok(Felt252::from(0xabc)));
+    assert_eq!(state.get_block_hash(0), Err(b"GET_BLOCK_HASH_NOT_SET".as_slice()));
+
+    let first_hash = state.advance_block(1);
+    for _ in 1..BLOCK_HASH_FINALITY_OFFSET {
+        state.advance_block(1);
+    }
+    assert_eq!(state.get_block_hash(0), Ok(first_hash));
+    assert_eq!(state.get_block_hash(1), Err(b"Block number out of range".as_slice()));
+    assert_eq!(state.get_block_hash(100), Ok(Felt252::from(0xabc)));
+    assert_eq!(state.get_block_hash(u64::MAX), Err(b"Block number out of range".as_slice()));
+}
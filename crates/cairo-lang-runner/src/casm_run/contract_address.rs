@@ -1,3 +1,8 @@
+//! The calculation of the addresses of deployed Starknet contracts.
+//!
+//! The runner deploys contracts at the same addresses as Starknet, so off-chain tooling can
+//! predict the addresses of contracts deployed in tests.
+
 use starknet_types_core::felt::{Felt as Felt252, NonZeroFelt as NonZeroFelt252};
 use starknet_types_core::hash::{Pedersen, StarkHash};
 
@@ -29,3 +34,17 @@ pub fn calculate_contract_address(
     ])
     .mod_floor(&ADDR_BOUND)
 }
+
+/// Calculates the address of a contract deployed by `deploy_syscall` from the contract at
+/// `caller_address`. The deployer address is zero if `deploy_from_zero` is set, and otherwise the
+/// address of the deploying contract.
+pub fn calculate_deploy_syscall_address(
+    salt: &Felt252,
+    class_hash: &Felt252,
+    constructor_calldata: &[Felt252],
+    caller_address: &Felt252,
+    deploy_from_zero: bool,
+) -> Felt252 {
+    let deployer_address = if deploy_from_zero { &Felt252::ZERO } else { caller_address };
+    calculate_contract_address(salt, class_hash, constructor_calldata, deployer_address)
+}
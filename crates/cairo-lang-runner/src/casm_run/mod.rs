@@ -1,6 +1,6 @@
 use std::any::Any;
 use std::borrow::Cow;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::ops::{Shl, Sub};
 use std::vec::IntoIter;
 
@@ -39,20 +39,30 @@ use num_bigint::{BigInt, BigUint};
 use num_integer::{ExtendedGcd, Integer};
 use num_traits::{Signed, ToPrimitive, Zero};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::{Felt as Felt252, NonZeroFelt};
 use {ark_secp256k1 as secp256k1, ark_secp256r1 as secp256r1};
 
-use self::contract_address::calculate_contract_address;
+use self::block::{BlockRecord, MAX_ADVANCED_BLOCKS};
+use self::contract_address::calculate_deploy_syscall_address;
 use self::dict_manager::DictSquashExecScope;
+use self::transaction_hash::calculate_meta_tx_v0_hash;
 use crate::short_string::{as_cairo_short_string, as_cairo_short_string_ex};
-use crate::{Arg, RunResultValue, SierraCasmRunner, StarknetExecutionResources, args_size};
+use crate::{
+    Arg, DEFAULT_AVAILABLE_GAS, RunResultValue, SierraCasmRunner, StarknetExecutionResources,
+    args_size,
+};
 
 #[cfg(test)]
 mod test;
 
+pub mod account_transaction;
+pub mod block;
 mod circuit;
-mod contract_address;
+pub mod contract_address;
 mod dict_manager;
+pub mod state_dump;
+mod transaction_hash;
 
 /// Convert a Hint to the cairo-vm class HintParams by canonically serializing it to a string.
 pub fn hint_to_hint_params(hint: &Hint) -> HintParams {
@@ -132,20 +142,42 @@ type L2ToL1Message = (Felt252, Vec<Felt252>);
 
 /// Execution scope for starknet related data.
 /// All values will be 0 and by default if not setup by the test.
-#[derive(Clone, Default)]
+///
+/// The state can be dumped and loaded, see [StarknetState::dump] - the maps are ordered for a
+/// stable serialization.
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct StarknetState {
     /// The values of addresses in the simulated storage per contract.
-    storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    storage: BTreeMap<Felt252, BTreeMap<Felt252, Felt252>>,
     /// A mapping from contract address to class hash.
-    deployed_contracts: HashMap<Felt252, Felt252>,
+    deployed_contracts: BTreeMap<Felt252, Felt252>,
     /// A mapping from contract address to logs.
-    logs: HashMap<Felt252, ContractLogs>,
+    logs: BTreeMap<Felt252, ContractLogs>,
     /// The simulated execution info.
     exec_info: ExecutionInfo,
     /// A mock history, mapping block number to the class hash.
-    block_hash: HashMap<u64, Felt252>,
+    block_hash: BTreeMap<u64, Felt252>,
+    /// The nonces of the accounts that sent transactions, mapping account address to nonce.
+    nonces: BTreeMap<Felt252, Felt252>,
+    /// The simulated blocks closed before the current block, mapping block number to the block.
+    block_history: BTreeMap<u64, BlockRecord>,
+    /// The hashes of the transactions run in the current block, in order.
+    block_transactions: Vec<Felt252>,
+    /// The mocked calls, mapping the called target and entry point selector to the mocked result.
+    /// Mocks are set up by the running code, and are not part of a dumped state.
+    #[serde(skip)]
+    mocked_calls: HashMap<(MockedCallTarget, Felt252), MockedCall>,
+    /// The events emitted by all contracts during the run, in order of emission, along with the
+    /// addresses of their emitters. Collected for event spies, and not part of a dumped state.
+    #[serde(skip)]
+    emitted_events: Vec<(Felt252, Log)>,
 }
 impl StarknetState {
+    /// Returns the nonce of the given account - the number of transactions it sent.
+    pub fn nonce(&self, account: Felt252) -> Felt252 {
+        self.nonces.get(&account).copied().unwrap_or_default()
+    }
+
     /// Replaces the addresses in the context.
     pub fn open_caller_context(
         &mut self,
@@ -166,10 +198,47 @@ impl StarknetState {
         self.exec_info.contract_address = old_contract_address;
         self.exec_info.caller_address = old_caller_address;
     }
+
+    /// Returns the mocked result of a call to the given target and entry point selector, if any,
+    /// counting the call against the limit of the mock.
+    fn take_mocked_call(
+        &mut self,
+        target: MockedCallTarget,
+        selector: Felt252,
+    ) -> Option<Result<Vec<Felt252>, Vec<Felt252>>> {
+        let key = (target, selector);
+        let mocked_call = self.mocked_calls.get_mut(&key)?;
+        let result = mocked_call.result.clone();
+        if let Some(calls_left) = &mut mocked_call.calls_left {
+            *calls_left -= 1;
+            if *calls_left == 0 {
+                self.mocked_calls.remove(&key);
+            }
+        }
+        Some(result)
+    }
+}
+
+/// The target of a mocked call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum MockedCallTarget {
+    /// A `call_contract_syscall` to the contract at the given address.
+    Contract(Felt252),
+    /// A `library_call_syscall` to the class with the given hash.
+    Library(Felt252),
+}
+
+/// A mocked result of calls to an entry point.
+#[derive(Clone, Debug)]
+struct MockedCall {
+    /// The data returned by the calls, or the revert reason if the calls revert.
+    result: Result<Vec<Felt252>, Vec<Felt252>>,
+    /// The number of calls left before the mock is removed, or `None` if unlimited.
+    calls_left: Option<usize>,
 }
 
 /// Object storing logs for a contract.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct ContractLogs {
     /// Events.
     events: VecDeque<Log>,
@@ -178,7 +247,7 @@ struct ContractLogs {
 }
 
 /// Copy of the cairo `ExecutionInfo` struct.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct ExecutionInfo {
     block_info: BlockInfo,
     tx_info: TxInfo,
@@ -188,7 +257,7 @@ struct ExecutionInfo {
 }
 
 /// Copy of the cairo `BlockInfo` struct.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct BlockInfo {
     block_number: Felt252,
     block_timestamp: Felt252,
@@ -196,7 +265,7 @@ struct BlockInfo {
 }
 
 /// Copy of the cairo `TxInfo` struct.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct TxInfo {
     version: Felt252,
     account_contract_address: Felt252,
@@ -214,7 +283,7 @@ struct TxInfo {
 }
 
 /// Copy of the cairo `ResourceBounds` struct.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct ResourceBounds {
     resource: Felt252,
     max_amount: Felt252,
@@ -340,6 +409,7 @@ mod gas_costs {
     const STEP: usize = 100;
     const RANGE_CHECK: usize = 70;
     const BITWISE: usize = 594;
+    const PEDERSEN: usize = 4050;
 
     /// Entry point initial gas cost enforced by the compiler.
     /// Should match `ENTRY_POINT_COST` at `crates/cairo-lang-starknet/src/casm_contract_class.rs`.
@@ -358,6 +428,10 @@ mod gas_costs {
     pub const KECCAK_ROUND_COST: usize = 180000;
     pub const SHA256_PROCESS_BLOCK: usize = 1852 * STEP + 65 * RANGE_CHECK + 1115 * BITWISE;
     pub const LIBRARY_CALL: usize = CALL_CONTRACT;
+    pub const META_TX_V0: usize = CALL_CONTRACT;
+    /// Charged per calldata element of a meta transaction, for hashing it into the transaction
+    /// hash.
+    pub const META_TX_V0_CALLDATA_FACTOR: usize = 8 * STEP + PEDERSEN;
     pub const REPLACE_CLASS: usize = 50 * STEP;
     pub const SECP256K1_ADD: usize = 254 * STEP + 29 * RANGE_CHECK;
     pub const SECP256K1_GET_POINT_FROM_X: usize = 260 * STEP + 29 * RANGE_CHECK;
@@ -374,6 +448,12 @@ mod gas_costs {
     pub const STORAGE_WRITE: usize = 50 * STEP;
 }
 
+/// The selector of the `__execute__` entry point of accounts, the only entry point meta
+/// transactions may call.
+const EXECUTE_ENTRY_POINT_SELECTOR: Felt252 = Felt252::from_hex_unchecked(
+    "0x15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5ad",
+);
+
 /// Deducts gas from the given gas counter, or fails the syscall if there is not enough gas.
 macro_rules! deduct_gas {
     ($gas:ident, $amount:ident) => {
@@ -845,8 +925,15 @@ impl CairoHintProcessor<'_> {
             "GetClassHashAt" => execute_handle_helper(&mut |system_buffer, gas_counter| {
                 self.get_class_hash_at(gas_counter, system_buffer.next_felt252()?.into_owned())
             }),
-            "MetaTxV0" => execute_handle_helper(&mut |_system_buffer, _gas_counter| {
-                panic!("Meta transaction is not supported.")
+            "MetaTxV0" => execute_handle_helper(&mut |system_buffer, gas_counter| {
+                self.meta_tx_v0(
+                    gas_counter,
+                    system_buffer.next_felt252()?.into_owned(),
+                    system_buffer.next_felt252()?.into_owned(),
+                    system_buffer.next_arr()?,
+                    system_buffer.next_arr()?,
+                    system_buffer,
+                )
             }),
             _ => panic!("Unknown selector for system call!"),
         }
@@ -899,10 +986,9 @@ impl CairoHintProcessor<'_> {
         block_number: u64,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, GET_BLOCK_HASH);
-        if let Some(block_hash) = self.starknet_state.block_hash.get(&block_number) {
-            Ok(SyscallResult::Success(vec![block_hash.into()]))
-        } else {
-            fail_syscall!(b"GET_BLOCK_HASH_NOT_SET");
+        match self.starknet_state.get_block_hash(block_number) {
+            Ok(block_hash) => Ok(SyscallResult::Success(vec![block_hash.into()])),
+            Err(reason) => fail_syscall!(reason),
         }
     }
 
@@ -973,6 +1059,7 @@ impl CairoHintProcessor<'_> {
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, EMIT_EVENT);
         let contract = self.starknet_state.exec_info.contract_address;
+        self.starknet_state.emitted_events.push((contract, (keys.clone(), data.clone())));
         self.starknet_state.logs.entry(contract).or_default().events.push_back((keys, data));
         Ok(SyscallResult::Success(vec![]))
     }
@@ -1000,7 +1087,7 @@ impl CairoHintProcessor<'_> {
         &mut self,
         gas_counter: &mut usize,
         class_hash: Felt252,
-        _contract_address_salt: Felt252,
+        contract_address_salt: Felt252,
         calldata: Vec<Felt252>,
         deploy_from_zero: bool,
         vm: &mut dyn VMWrapper,
@@ -1008,21 +1095,19 @@ impl CairoHintProcessor<'_> {
         deduct_gas!(gas_counter, DEPLOY);
 
         // Assign the starknet address of the contract.
-        let deployer_address = if deploy_from_zero {
-            Felt252::zero()
-        } else {
-            self.starknet_state.exec_info.contract_address
-        };
-        let deployed_contract_address = calculate_contract_address(
-            &_contract_address_salt,
+        let caller_address = self.starknet_state.exec_info.contract_address;
+        let deployer_address = if deploy_from_zero { Felt252::zero() } else { caller_address };
+        let deployed_contract_address = calculate_deploy_syscall_address(
+            &contract_address_salt,
             &class_hash,
             &calldata,
-            &deployer_address,
+            &caller_address,
+            deploy_from_zero,
         );
 
         // Prepare runner for running the constructor.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
+        let Some((class_runner, contract_info)) = runner.find_contract_class(&class_hash) else {
             fail_syscall!(b"CLASS_HASH_NOT_FOUND");
         };
 
@@ -1042,7 +1127,7 @@ impl CairoHintProcessor<'_> {
             let old_addrs = self
                 .starknet_state
                 .open_caller_context((deployed_contract_address, deployer_address));
-            let res = self.call_entry_point(gas_counter, runner, constructor, calldata, vm);
+            let res = self.call_entry_point(gas_counter, class_runner, constructor, calldata, vm);
             self.starknet_state.close_caller_context(old_addrs);
             match res {
                 Ok(value) => value,
@@ -1077,7 +1162,87 @@ impl CairoHintProcessor<'_> {
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, CALL_CONTRACT);
+        if let Some(result) = self
+            .starknet_state
+            .take_mocked_call(MockedCallTarget::Contract(contract_address), selector)
+        {
+            return mocked_call_result(result, vm);
+        }
+        let caller_address = self.starknet_state.exec_info.contract_address;
+        self.call_deployed_contract(
+            gas_counter,
+            contract_address,
+            caller_address,
+            selector,
+            calldata,
+            vm,
+        )
+    }
+
+    /// Executes the `meta_tx_v0_syscall` syscall.
+    fn meta_tx_v0(
+        &mut self,
+        gas_counter: &mut usize,
+        contract_address: Felt252,
+        selector: Felt252,
+        calldata: Vec<Felt252>,
+        signature: Vec<Felt252>,
+        vm: &mut dyn VMWrapper,
+    ) -> Result<SyscallResult, HintError> {
+        deduct_gas!(gas_counter, META_TX_V0);
+        for _ in &calldata {
+            deduct_gas!(gas_counter, META_TX_V0_CALLDATA_FACTOR);
+        }
+        // Meta transactions are only supported for the execution of old accounts.
+        if selector != EXECUTE_ENTRY_POINT_SELECTOR {
+            fail_syscall!(b"Invalid argument");
+        }
+        if let Some(result) = self
+            .starknet_state
+            .take_mocked_call(MockedCallTarget::Contract(contract_address), selector)
+        {
+            return mocked_call_result(result, vm);
+        }
+
+        // The called contract, and the contracts it calls, see a version-0 transaction.
+        let chain_id = self.starknet_state.exec_info.tx_info.chain_id;
+        let meta_tx_info = TxInfo {
+            version: Felt252::ZERO,
+            account_contract_address: contract_address,
+            signature,
+            transaction_hash: calculate_meta_tx_v0_hash(
+                &contract_address,
+                &selector,
+                &calldata,
+                &chain_id,
+            ),
+            chain_id,
+            ..TxInfo::default()
+        };
+        let old_tx_info =
+            std::mem::replace(&mut self.starknet_state.exec_info.tx_info, meta_tx_info);
+        let res = self.call_deployed_contract(
+            gas_counter,
+            contract_address,
+            Felt252::ZERO,
+            selector,
+            calldata,
+            vm,
+        );
+        self.starknet_state.exec_info.tx_info = old_tx_info;
+        res
+    }
 
+    /// Calls an external entry point of a deployed contract, on behalf of the given caller.
+    fn call_deployed_contract(
+        &mut self,
+        gas_counter: &mut usize,
+        contract_address: Felt252,
+        caller_address: Felt252,
+        selector: Felt252,
+        calldata: Vec<Felt252>,
+        vm: &mut dyn VMWrapper,
+    ) -> Result<SyscallResult, HintError> {
         // Get the class hash of the contract.
         let Some(class_hash) = self.starknet_state.deployed_contracts.get(&contract_address) else {
             fail_syscall!([b"CONTRACT_NOT_DEPLOYED", b"ENTRYPOINT_FAILED"]);
@@ -1085,9 +1250,8 @@ impl CairoHintProcessor<'_> {
 
         // Prepare runner for running the ctor.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let contract_info = runner
-            .starknet_contracts_info
-            .get(class_hash)
+        let (class_runner, contract_info) = runner
+            .find_contract_class(class_hash)
             .expect("Deployed contract not found in registry.");
 
         // Call the function.
@@ -1095,11 +1259,8 @@ impl CairoHintProcessor<'_> {
             fail_syscall!([b"ENTRYPOINT_NOT_FOUND", b"ENTRYPOINT_FAILED"]);
         };
 
-        let old_addrs = self.starknet_state.open_caller_context((
-            contract_address,
-            self.starknet_state.exec_info.contract_address,
-        ));
-        let res = self.call_entry_point(gas_counter, runner, entry_point, calldata, vm);
+        let old_addrs = self.starknet_state.open_caller_context((contract_address, caller_address));
+        let res = self.call_entry_point(gas_counter, class_runner, entry_point, calldata, vm);
         self.starknet_state.close_caller_context(old_addrs);
 
         match res {
@@ -1112,6 +1273,34 @@ impl CairoHintProcessor<'_> {
         }
     }
 
+    /// Runs the L1 handler of a deployed contract, as done for an L1 to L2 message. The calldata
+    /// starts with the address of the L1 sender. Returns the panic data of the handler on failure.
+    fn call_l1_handler(
+        &mut self,
+        contract_address: Felt252,
+        selector: Felt252,
+        calldata: Vec<Felt252>,
+        vm: &mut dyn VMWrapper,
+    ) -> Result<(), Vec<Felt252>> {
+        let Some(class_hash) = self.starknet_state.deployed_contracts.get(&contract_address) else {
+            return Err(vec![Felt252::from_bytes_be_slice(b"CONTRACT_NOT_DEPLOYED")]);
+        };
+        let runner = self.runner.expect("Runner is needed for starknet.");
+        let (class_runner, contract_info) = runner
+            .find_contract_class(class_hash)
+            .expect("Deployed contract not found in registry.");
+        let Some(entry_point) = contract_info.l1_handlers.get(&selector) else {
+            return Err(vec![Felt252::from_bytes_be_slice(b"ENTRYPOINT_NOT_FOUND")]);
+        };
+
+        // L1 handlers are invoked by the sequencer, and not by another contract.
+        let old_addrs = self.starknet_state.open_caller_context((contract_address, Felt252::ZERO));
+        let mut gas_counter = DEFAULT_AVAILABLE_GAS;
+        let res = self.call_entry_point(&mut gas_counter, class_runner, entry_point, calldata, vm);
+        self.starknet_state.close_caller_context(old_addrs);
+        res.map(|_| ())
+    }
+
     /// Executes the `library_call_syscall` syscall.
     fn library_call(
         &mut self,
@@ -1122,9 +1311,14 @@ impl CairoHintProcessor<'_> {
         vm: &mut dyn VMWrapper,
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, LIBRARY_CALL);
+        if let Some(result) =
+            self.starknet_state.take_mocked_call(MockedCallTarget::Library(class_hash), selector)
+        {
+            return mocked_call_result(result, vm);
+        }
         // Prepare runner for running the call.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
+        let Some((class_runner, contract_info)) = runner.find_contract_class(&class_hash) else {
             fail_syscall!(b"CLASS_HASH_NOT_DECLARED")
         };
 
@@ -1132,7 +1326,7 @@ impl CairoHintProcessor<'_> {
         let Some(entry_point) = contract_info.externals.get(&selector) else {
             fail_syscall!([b"ENTRYPOINT_NOT_FOUND", b"ENTRYPOINT_FAILED"]);
         };
-        match self.call_entry_point(gas_counter, runner, entry_point, calldata, vm) {
+        match self.call_entry_point(gas_counter, class_runner, entry_point, calldata, vm) {
             Ok((res_data_start, res_data_end)) => {
                 Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]))
             }
@@ -1150,11 +1344,11 @@ impl CairoHintProcessor<'_> {
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, REPLACE_CLASS);
         // Validating the class hash was declared as one of the starknet contracts.
-        if !self
+        if self
             .runner
             .expect("Runner is needed for starknet.")
-            .starknet_contracts_info
-            .contains_key(&new_class)
+            .find_contract_class(&new_class)
+            .is_none()
         {
             fail_syscall!(b"CLASS_HASH_NOT_FOUND");
         };
@@ -1180,22 +1374,23 @@ impl CairoHintProcessor<'_> {
         Ok(SyscallResult::Success(vec![MaybeRelocatable::Int(class_hash)]))
     }
 
-    /// Executes the entry point with the given calldata.
+    /// Executes the entry point with the given calldata, in the program of `class_runner` - the
+    /// runner of the class containing the entry point.
     fn call_entry_point(
         &mut self,
         gas_counter: &mut usize,
-        runner: &SierraCasmRunner,
+        class_runner: &SierraCasmRunner,
         entry_point: &FunctionId,
         calldata: Vec<Felt252>,
         vm: &mut dyn VMWrapper,
     ) -> Result<(Relocatable, Relocatable), Vec<Felt252>> {
-        let function = runner
+        let function = class_runner
             .builder
             .registry()
             .get_function(entry_point)
             .expect("Entrypoint exists, but not found.");
-        let res = runner
-            .run_function_with_starknet_context(
+        let (mut hint_processor, ctx) = class_runner
+            .prepare_starknet_context(
                 function,
                 vec![Arg::Array(calldata.into_iter().map(Arg::Value).collect())],
                 // The costs of the relevant syscall include `ENTRY_POINT_INITIAL_BUDGET` so we
@@ -1204,6 +1399,12 @@ impl CairoHintProcessor<'_> {
                 self.starknet_state.clone(),
             )
             .expect("Internal runner error.");
+        // Classes are always looked up in the registry of the original runner, including classes
+        // running in their own program.
+        hint_processor.runner = self.runner;
+        let res = class_runner
+            .run_function_with_prepared_starknet_context(function, &mut hint_processor, ctx)
+            .expect("Internal runner error.");
         self.syscalls_used_resources += res.used_resources;
         *gas_counter = res.gas_counter.unwrap().to_usize().unwrap();
         match res.value {
@@ -1297,6 +1498,38 @@ impl CairoHintProcessor<'_> {
                 })?;
                 self.starknet_state.block_hash.insert(block_number.to_u64().unwrap(), block_hash);
             }
+            "advance_blocks" => {
+                let [n_blocks, block_time] = vec_as_array(inputs, || {
+                    format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with exactly \
+                         two elements",
+                    )
+                })?;
+                let (Some(n_blocks), Some(block_time)) = (n_blocks.to_u64(), block_time.to_u64())
+                else {
+                    Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: the number of blocks and the block \
+                         time must be u64 values",
+                    ))))?
+                };
+                let state = &self.starknet_state;
+                let in_range = n_blocks <= MAX_ADVANCED_BLOCKS
+                    && state.block_number().checked_add(n_blocks).is_some()
+                    && n_blocks
+                        .checked_mul(block_time)
+                        .and_then(|duration| state.block_timestamp().checked_add(duration))
+                        .is_some();
+                if !in_range {
+                    Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: at most {MAX_ADVANCED_BLOCKS} \
+                         blocks can be advanced, and the block number and timestamp must stay u64 \
+                         values",
+                    ))))?;
+                }
+                for _ in 0..n_blocks {
+                    self.starknet_state.advance_block(block_time);
+                }
+            }
             "pop_log" => {
                 let contract_logs = self.starknet_state.logs.get_mut(&as_single_input(inputs)?);
                 if let Some((keys, data)) =
@@ -1308,6 +1541,49 @@ impl CairoHintProcessor<'_> {
                     res_segment.write_data(data.iter())?;
                 }
             }
+            "spy_events" => {
+                res_segment.write(self.starknet_state.emitted_events.len())?;
+            }
+            "spied_events" => {
+                let start = as_single_input(inputs)?.to_usize().ok_or_else(|| {
+                    HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: invalid spy",
+                    )))
+                })?;
+                let events = self.starknet_state.emitted_events.get(start..).unwrap_or_default();
+                res_segment.write(events.len())?;
+                for (from, (keys, data)) in events {
+                    res_segment.write(*from)?;
+                    res_segment.write(keys.len())?;
+                    res_segment.write_data(keys.iter())?;
+                    res_segment.write(data.len())?;
+                    res_segment.write_data(data.iter())?;
+                }
+            }
+            "call_l1_handler" => {
+                let [contract_address, entry_point_selector, from_address, payload @ ..] =
+                    inputs.as_slice()
+                else {
+                    Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass the contract address, the \
+                         entry point selector, the L1 sender address and the payload",
+                    ))))?
+                };
+                let calldata = [*from_address].into_iter().chain(payload.iter().cloned()).collect();
+                match self.call_l1_handler(
+                    *contract_address,
+                    *entry_point_selector,
+                    calldata,
+                    &mut *res_segment.vm,
+                ) {
+                    Ok(()) => res_segment.write(0)?,
+                    Err(panic_data) => {
+                        res_segment.write(1)?;
+                        res_segment.write(panic_data.len())?;
+                        res_segment.write_data(panic_data.iter())?;
+                    }
+                }
+            }
             "pop_l2_to_l1_message" => {
                 let contract_logs = self.starknet_state.logs.get_mut(&as_single_input(inputs)?);
                 if let Some((to_address, payload)) = contract_logs
@@ -1318,6 +1594,88 @@ impl CairoHintProcessor<'_> {
                     res_segment.write_data(payload.iter())?;
                 }
             }
+            "precalculate_address" => {
+                let [class_hash, contract_address_salt, deploy_from_zero, calldata @ ..] =
+                    inputs.as_slice()
+                else {
+                    Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass the class hash, the salt, \
+                         whether to deploy from zero and the constructor calldata",
+                    ))))?
+                };
+                res_segment.write(calculate_deploy_syscall_address(
+                    contract_address_salt,
+                    class_hash,
+                    calldata,
+                    &self.starknet_state.exec_info.contract_address,
+                    !deploy_from_zero.is_zero(),
+                ))?;
+            }
+            "store" => {
+                let [contract_address, key, values @ ..] = inputs.as_slice() else {
+                    Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass the contract address, the \
+                         storage key and the values to store",
+                    ))))?
+                };
+                let contract_storage =
+                    self.starknet_state.storage.entry(*contract_address).or_default();
+                for (key, value) in (0..).map(|offset| key + Felt252::from(offset)).zip(values) {
+                    contract_storage.insert(key, *value);
+                }
+            }
+            "load" => {
+                let [contract_address, key, size] = vec_as_array(inputs, || {
+                    format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with exactly \
+                         three elements",
+                    )
+                })?;
+                let contract_storage = self.starknet_state.storage.get(&contract_address);
+                let size = size.to_usize().ok_or_else(|| {
+                    HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: invalid size",
+                    )))
+                })?;
+                for offset in 0..size {
+                    let value = contract_storage
+                        .and_then(|contract_storage| {
+                            contract_storage.get(&(key + Felt252::from(offset)))
+                        })
+                        .cloned()
+                        .unwrap_or_default();
+                    res_segment.write(value)?;
+                }
+            }
+            "mock_call" => {
+                let (key, mocked_call) = parse_mock_call(inputs).ok_or_else(|| {
+                    HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: pass the serialized target, \
+                         selector, result and optional call count",
+                    )))
+                })?;
+                if mocked_call.calls_left == Some(0) {
+                    Err(HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: the call count must be positive, or \
+                         `None` to mock all the following calls",
+                    ))))?;
+                }
+                self.starknet_state.mocked_calls.insert(key, mocked_call);
+            }
+            "clear_mock_call" => {
+                let [kind, target, entry_point_selector] = vec_as_array(inputs, || {
+                    format!(
+                        "`{selector}` cheatcode invalid args: pass span of an array with exactly \
+                         three elements",
+                    )
+                })?;
+                let target = parse_mocked_call_target(kind, target).ok_or_else(|| {
+                    HintError::CustomHint(Box::from(format!(
+                        "`{selector}` cheatcode invalid args: invalid call kind",
+                    )))
+                })?;
+                self.starknet_state.mocked_calls.remove(&(target, entry_point_selector));
+            }
             _ => Err(HintError::CustomHint(Box::from(format!(
                 "Unknown cheatcode selector: {selector}"
             ))))?,
@@ -1426,6 +1784,57 @@ fn vec_as_array<const COUNT: usize>(
     inputs.try_into().map_err(|_| HintError::CustomHint(Box::from(err_msg())))
 }
 
+/// Returns the result of a mocked call, writing the returned data to a new segment.
+fn mocked_call_result(
+    result: Result<Vec<Felt252>, Vec<Felt252>>,
+    vm: &mut dyn VMWrapper,
+) -> Result<SyscallResult, HintError> {
+    match result {
+        Ok(data) => {
+            let (res_data_start, res_data_end) = segment_with_data(vm, data.into_iter())?;
+            Ok(SyscallResult::Success(vec![res_data_start.into(), res_data_end.into()]))
+        }
+        Err(mut revert_reason) => {
+            fail_syscall!(revert_reason, b"ENTRYPOINT_FAILED");
+        }
+    }
+}
+
+/// Parses the target of a mocked call, given the kind of the call - `0` for `call_contract_syscall`
+/// and `1` for `library_call_syscall`.
+fn parse_mocked_call_target(kind: Felt252, target: Felt252) -> Option<MockedCallTarget> {
+    match kind.to_u8()? {
+        0 => Some(MockedCallTarget::Contract(target)),
+        1 => Some(MockedCallTarget::Library(target)),
+        _ => None,
+    }
+}
+
+/// Parses the inputs of the `mock_call` cheatcode - the serialized call kind, target, entry point
+/// selector, `Option<u32>` call count limit and `Result<Span<felt252>, Span<felt252>>` result.
+fn parse_mock_call(inputs: Vec<Felt252>) -> Option<((MockedCallTarget, Felt252), MockedCall)> {
+    let mut inputs = inputs.into_iter();
+    let target = parse_mocked_call_target(inputs.next()?, inputs.next()?)?;
+    let selector = inputs.next()?;
+    let calls_left = match inputs.next()?.to_u8()? {
+        0 => Some(inputs.next()?.to_usize()?),
+        1 => None,
+        _ => return None,
+    };
+    let is_err = match inputs.next()?.to_u8()? {
+        0 => false,
+        1 => true,
+        _ => return None,
+    };
+    let len = inputs.next()?.to_usize()?;
+    let data = inputs.collect_vec();
+    if data.len() != len {
+        return None;
+    }
+    let result = if is_err { Err(data) } else { Ok(data) };
+    Some(((target, selector), MockedCall { result, calls_left }))
+}
+
 /// Executes the `keccak_syscall` syscall.
 fn keccak(gas_counter: &mut usize, data: Vec<Felt252>) -> Result<SyscallResult, HintError> {
     deduct_gas!(gas_counter, KECCAK);
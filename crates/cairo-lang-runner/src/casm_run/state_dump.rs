@@ -0,0 +1,107 @@
+//! Dumping and loading of a [StarknetState], for reusing the state of a run in later runs.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::StarknetState;
+
+#[cfg(test)]
+#[path = "state_dump_test.rs"]
+mod test;
+
+/// The version of the format of dumped states, bumped on incompatible changes of the format.
+const STATE_DUMP_VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum StateDumpError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Encode(#[from] bincode::error::EncodeError),
+    #[error(transparent)]
+    Decode(#[from] bincode::error::DecodeError),
+    #[error("Unsupported state dump version {0}, expected version {STATE_DUMP_VERSION}.")]
+    UnsupportedVersion(u32),
+}
+
+/// A dumped state, along with the version of its format.
+#[derive(Serialize, Deserialize)]
+struct StateDump<State> {
+    version: u32,
+    state: State,
+}
+
+/// The version of a dumped state, deserialized ahead of the state.
+#[derive(Deserialize)]
+struct StateDumpVersion {
+    version: u32,
+}
+
+/// The format of a dumped state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateDumpFormat {
+    /// A JSON object, with the felts as hex strings.
+    Json,
+    /// A compact binary encoding.
+    Binary,
+}
+impl StateDumpFormat {
+    /// Returns the format of a dump file by its extension - JSON for `.json` files, and binary
+    /// otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        if path.extension().is_some_and(|extension| extension == "json") {
+            Self::Json
+        } else {
+            Self::Binary
+        }
+    }
+}
+
+impl StarknetState {
+    /// Serializes the state in the given format.
+    pub fn to_bytes(&self, format: StateDumpFormat) -> Result<Vec<u8>, StateDumpError> {
+        let dump = StateDump { version: STATE_DUMP_VERSION, state: self };
+        Ok(match format {
+            StateDumpFormat::Json => serde_json::to_vec_pretty(&dump)?,
+            StateDumpFormat::Binary => {
+                bincode::serde::encode_to_vec(&dump, bincode::config::standard())?
+            }
+        })
+    }
+
+    /// Deserializes a state serialized by [StarknetState::to_bytes] in the given format.
+    pub fn from_bytes(bytes: &[u8], format: StateDumpFormat) -> Result<Self, StateDumpError> {
+        // The version is checked first, as the state of other versions may fail to deserialize.
+        let version = match format {
+            StateDumpFormat::Json => serde_json::from_slice::<StateDumpVersion>(bytes)?.version,
+            StateDumpFormat::Binary => {
+                bincode::serde::decode_from_slice(bytes, bincode::config::standard())?.0
+            }
+        };
+        if version != STATE_DUMP_VERSION {
+            return Err(StateDumpError::UnsupportedVersion(version));
+        }
+        let dump: StateDump<Self> = match format {
+            StateDumpFormat::Json => serde_json::from_slice(bytes)?,
+            StateDumpFormat::Binary => {
+                bincode::serde::decode_from_slice(bytes, bincode::config::standard())?.0
+            }
+        };
+        Ok(dump.state)
+    }
+
+    /// Dumps the state into a file, in the format given by its extension - see
+    /// [StateDumpFormat::from_path].
+    pub fn dump(&self, path: &Path) -> Result<(), StateDumpError> {
+        Ok(std::fs::write(path, self.to_bytes(StateDumpFormat::from_path(path))?)?)
+    }
+
+    /// Loads a state dumped into a file by [StarknetState::dump].
+    pub fn load(path: &Path) -> Result<Self, StateDumpError> {
+        Self::from_bytes(&std::fs::read(path)?, StateDumpFormat::from_path(path))
+    }
+}
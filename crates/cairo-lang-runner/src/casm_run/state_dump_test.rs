@@ -0,0 +1,75 @@
+use indoc::indoc;
+use starknet_types_core::felt::Felt as Felt252;
+use test_case::test_case;
+
+use super::{StateDumpError, StateDumpFormat};
+use crate::StarknetState;
+
+/// Returns a state with a value in each of its parts.
+fn example_state() -> StarknetState {
+    let mut state = StarknetState::default();
+    state.storage.entry(Felt252::from(0x10)).or_default().insert(Felt252::from(1), 5.into());
+    state.deployed_contracts.insert(Felt252::from(0x10), Felt252::from(0x1234));
+    state
+        .logs
+        .entry(Felt252::from(0x10))
+        .or_default()
+        .events
+        .push_back((vec![Felt252::from(1)], vec![Felt252::from(2)]));
+    state.exec_info.block_info.block_number = 7.into();
+    state.exec_info.tx_info.signature = vec![Felt252::from(3)];
+    state.block_hash.insert(6, Felt252::from(0xabc));
+    state
+}
+
+#[test_case(StateDumpFormat::Json; "json")]
+#[test_case(StateDumpFormat::Binary; "binary")]
+fn test_roundtrip(format: StateDumpFormat) {
+    let bytes = example_state().to_bytes(format).unwrap();
+    let state = StarknetState::from_bytes(&bytes, format).unwrap();
+    assert_eq!(state.to_bytes(format).unwrap(), bytes);
+}
+
+#[test]
+fn test_json_format() {
+    let json = String::from_utf8(example_state().to_bytes(StateDumpFormat::Json).unwrap()).unwrap();
+    let block_info = &serde_json::from_str::<serde_json::Value>(&json).unwrap()["state"]
+        ["exec_info"]["block_info"];
+    assert_eq!(
+        serde_json::to_string_pretty(block_info).unwrap(),
+        indoc! {r#"
+            {
+              "block_number": "0x7",
+              "block_timestamp": "0x0",
+              "sequencer_address": "0x0"
+            }"#}
+    );
+    assert!(json.starts_with(indoc! {r#"
+        {
+          "version": 1,
+          "state": {
+            "storage": {
+              "0x10": {
+                "0x1": "0x5"
+              }
+            },
+            "deployed_contracts": {
+              "0x10": "0x1234"
+            },
+    "#}));
+}
+
+#[test]
+fn test_unsupported_version() {
+    let json = br#"{"version": 0, "state": {}}"#;
+    assert!(matches!(
+        StarknetState::from_bytes(json, StateDumpFormat::Json),
+        Err(StateDumpError::UnsupportedVersion(0))
+    ));
+}
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(StateDumpFormat::from_path("state.json".as_ref()), StateDumpFormat::Json);
+    assert_eq!(StateDumpFormat::from_path("state.bin".as_ref()), StateDumpFormat::Binary);
+}
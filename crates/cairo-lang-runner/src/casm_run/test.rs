@@ -11,7 +11,9 @@ use starknet_types_core::felt::Felt as Felt252;
 use test_case::test_case;
 
 use super::format_for_debug;
-use crate::casm_run::contract_address::calculate_contract_address;
+use crate::casm_run::contract_address::{
+    calculate_contract_address, calculate_deploy_syscall_address,
+};
 use crate::casm_run::{RunFunctionResult, run_function};
 use crate::short_string::{as_cairo_short_string, as_cairo_short_string_ex};
 use crate::{CairoHintProcessor, StarknetState, build_hints_dict};
@@ -485,3 +487,34 @@ fn test_calculate_contract_address() {
         deployed_contract_address
     );
 }
+
+#[test]
+fn test_calculate_contract_address_known_vector() {
+    // The same vector is checked by `get_contract_address` of starknet-rs.
+    let class_hash = Felt252::from_hex_unchecked(
+        "0x750cd490a7cd1572411169eaa8be292325990d33c5d4733655fe6b926985062",
+    );
+    let salt = Felt252::from_hex_unchecked(
+        "0x18a7a329d1d85b621350f2b5fc9c64b2e57dfe708525f0aff2c90de1e5b9c8",
+    );
+    assert_eq!(
+        calculate_contract_address(&salt, &class_hash, &[Felt252::ONE], &Felt252::ZERO),
+        Felt252::from_hex_unchecked(
+            "0xda27ef7c3869c3a6cc6a0f7bf07a51c3e590825adba8a51cae27d815839eec"
+        )
+    );
+}
+
+#[test]
+fn test_calculate_deploy_syscall_address() {
+    let (salt, class_hash, caller_address) = (Felt252::from(1), Felt252::from(2), Felt252::from(3));
+    let calldata = [Felt252::from(4)];
+    assert_eq!(
+        calculate_deploy_syscall_address(&salt, &class_hash, &calldata, &caller_address, false),
+        calculate_contract_address(&salt, &class_hash, &calldata, &caller_address)
+    );
+    assert_eq!(
+        calculate_deploy_syscall_address(&salt, &class_hash, &calldata, &caller_address, true),
+        calculate_contract_address(&salt, &class_hash, &calldata, &Felt252::ZERO)
+    );
+}
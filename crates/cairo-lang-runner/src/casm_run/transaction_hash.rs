@@ -0,0 +1,52 @@
+use starknet_types_core::felt::Felt as Felt252;
+use starknet_types_core::hash::{Pedersen, StarkHash};
+
+/// Cairo string of "invoke"
+const INVOKE_PREFIX: Felt252 = Felt252::from_hex_unchecked("0x696e766f6b65");
+
+/// Calculates the hash of the version-0 invoke transaction replacing the transaction hash during a
+/// meta transaction, as defined in
+/// <https://docs.starknet.io/architecture-and-concepts/network-architecture/transactions/#v0_hash_calculation>.
+pub fn calculate_meta_tx_v0_hash(
+    contract_address: &Felt252,
+    entry_point_selector: &Felt252,
+    calldata: &[Felt252],
+    chain_id: &Felt252,
+) -> Felt252 {
+    let calldata_hash = Pedersen::hash_array(calldata);
+    Pedersen::hash_array(&[
+        INVOKE_PREFIX,
+        // The version.
+        Felt252::ZERO,
+        *contract_address,
+        *entry_point_selector,
+        calldata_hash,
+        // The max fee.
+        Felt252::ZERO,
+        *chain_id,
+    ])
+}
+
+/// Calculates the hash of a version-1 invoke transaction, as defined in
+/// <https://docs.starknet.io/architecture-and-concepts/network-architecture/transactions/#v1_deprecated_hash_calculation>.
+pub fn calculate_invoke_v1_hash(
+    sender_address: &Felt252,
+    calldata: &[Felt252],
+    max_fee: &Felt252,
+    chain_id: &Felt252,
+    nonce: &Felt252,
+) -> Felt252 {
+    let calldata_hash = Pedersen::hash_array(calldata);
+    Pedersen::hash_array(&[
+        INVOKE_PREFIX,
+        // The version.
+        Felt252::ONE,
+        *sender_address,
+        // The entry point selector, which is always zero for invoke transactions since version 1.
+        Felt252::ZERO,
+        calldata_hash,
+        *max_fee,
+        *chain_id,
+        *nonce,
+    ])
+}
@@ -0,0 +1,304 @@
+//! A Debug Adapter Protocol server for a [DebugSession].
+//!
+//! The messages are read from and written to the given streams, typically stdin and stdout, with
+//! the `Content-Length` header framing of the protocol. The program is already loaded when the
+//! server starts, so `launch` and `attach` requests just acknowledge it.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use super::{Breakpoint, DebugSession, StopReason};
+
+/// The id of the single thread of the debugged program.
+const THREAD_ID: u64 = 1;
+/// The variables reference of the variables of the current statement.
+const VARIABLES_REFERENCE: u64 = 1;
+/// The variables reference of the VM registers.
+const REGISTERS_REFERENCE: u64 = 2;
+
+/// Serves Debug Adapter Protocol requests read from `input` for the session, until the client
+/// disconnects or the input ends.
+pub fn run_dap_server(
+    session: &mut DebugSession<'_>,
+    input: impl BufRead,
+    output: impl Write,
+) -> io::Result<()> {
+    DapServer {
+        session,
+        output,
+        seq: 0,
+        stop_on_entry: false,
+        source_breakpoints: HashMap::new(),
+        function_breakpoints: vec![],
+        finished: false,
+    }
+    .serve(input)
+}
+
+struct DapServer<'s, 'a, W> {
+    session: &'s mut DebugSession<'a>,
+    output: W,
+    /// The sequence number of the last message sent.
+    seq: u64,
+    /// Whether to stop at the entry of the program, as requested on launch.
+    stop_on_entry: bool,
+    /// The ids of the breakpoints set for each source path.
+    source_breakpoints: HashMap<String, Vec<usize>>,
+    /// The ids of the function breakpoints.
+    function_breakpoints: Vec<usize>,
+    /// Whether the run finished, after which the program can no longer be resumed or stepped.
+    finished: bool,
+}
+
+impl<W: Write> DapServer<'_, '_, W> {
+    /// Handles requests until the client disconnects or the input ends.
+    fn serve(&mut self, mut input: impl BufRead) -> io::Result<()> {
+        while let Some(request) = read_message(&mut input)? {
+            let command = request["command"].as_str().unwrap_or_default().to_string();
+            let args = &request["arguments"];
+            let resumes = matches!(command.as_str(), "continue" | "next" | "stepIn" | "stepOut");
+            let body = match command.as_str() {
+                _ if resumes && self.finished => Err("The run is finished.".to_string()),
+                "initialize" => Ok(json!({
+                    "supportsConfigurationDoneRequest": true,
+                    "supportsFunctionBreakpoints": true,
+                    "supportsSteppingGranularity": true,
+                })),
+                "launch" | "attach" => {
+                    self.stop_on_entry = args["stopOnEntry"].as_bool().unwrap_or(false);
+                    Ok(Value::Null)
+                }
+                "setBreakpoints" => Ok(self.set_breakpoints(args)),
+                "setFunctionBreakpoints" => Ok(self.set_function_breakpoints(args)),
+                "configurationDone" => Ok(Value::Null),
+                "threads" => Ok(json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] })),
+                "stackTrace" => Ok(self.stack_trace()),
+                "scopes" => Ok(self.scopes(args)),
+                "variables" => Ok(self.variables(args)),
+                "continue" => Ok(json!({ "allThreadsContinued": true })),
+                "next" | "stepIn" | "stepOut" | "disconnect" | "terminate" => Ok(Value::Null),
+                _ => Err(format!("Unsupported command `{command}`.")),
+            };
+            let failed = body.is_err();
+            self.respond(&request, body)?;
+            if failed {
+                continue;
+            }
+
+            let instruction_granularity = args["granularity"].as_str() == Some("instruction");
+            let reason = match command.as_str() {
+                "initialize" => {
+                    self.send_event("initialized", Value::Null)?;
+                    continue;
+                }
+                "configurationDone" if self.stop_on_entry => {
+                    self.send_stopped("entry", vec![])?;
+                    continue;
+                }
+                "configurationDone" | "continue" => self.session.resume(),
+                "next" | "stepIn" if instruction_granularity => self.session.step_instruction(),
+                "next" => self.session.step_over(),
+                "stepIn" => self.session.step_statement(),
+                "stepOut" => self.session.step_out(),
+                "disconnect" | "terminate" => return Ok(()),
+                _ => continue,
+            };
+            match reason {
+                StopReason::Step => self.send_stopped("step", vec![])?,
+                StopReason::Breakpoint(ids) => self.send_stopped("breakpoint", ids)?,
+                StopReason::Finished => {
+                    self.finished = true;
+                    self.send_event("exited", json!({ "exitCode": 0 }))?;
+                    self.send_event("terminated", Value::Null)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the line breakpoints of a source file.
+    fn set_breakpoints(&mut self, args: &Value) -> Value {
+        let path = args["source"]["path"].as_str().unwrap_or_default().to_string();
+        for id in self.source_breakpoints.remove(&path).unwrap_or_default() {
+            self.session.remove_breakpoint(id);
+        }
+        let lines = args["breakpoints"].as_array().into_iter().flatten().map(|bp| &bp["line"]);
+        let mut ids = vec![];
+        let breakpoints = lines
+            .filter_map(Value::as_u64)
+            .map(|line| {
+                let breakpoint = Breakpoint::Line { path: path.clone(), line: line as usize };
+                let verified = self.session.is_resolvable(&breakpoint);
+                let id = self.session.add_breakpoint(breakpoint);
+                ids.push(id);
+                json!({ "id": id, "verified": verified, "line": line })
+            })
+            .collect::<Vec<_>>();
+        self.source_breakpoints.insert(path, ids);
+        json!({ "breakpoints": breakpoints })
+    }
+
+    /// Replaces the function breakpoints.
+    fn set_function_breakpoints(&mut self, args: &Value) -> Value {
+        for id in std::mem::take(&mut self.function_breakpoints) {
+            self.session.remove_breakpoint(id);
+        }
+        let names = args["breakpoints"].as_array().into_iter().flatten().map(|bp| &bp["name"]);
+        let breakpoints = names
+            .filter_map(Value::as_str)
+            .map(|name| {
+                let breakpoint = Breakpoint::Function(name.to_string());
+                let verified = self.session.is_resolvable(&breakpoint);
+                let id = self.session.add_breakpoint(breakpoint);
+                self.function_breakpoints.push(id);
+                json!({ "id": id, "verified": verified })
+            })
+            .collect::<Vec<_>>();
+        json!({ "breakpoints": breakpoints })
+    }
+
+    /// Returns the call stack.
+    fn stack_trace(&self) -> Value {
+        let frames = self
+            .session
+            .backtrace()
+            .into_iter()
+            .enumerate()
+            .map(|(id, frame)| {
+                let mut value = json!({
+                    "id": id,
+                    "name": frame.function,
+                    "line": 0,
+                    "column": 0,
+                });
+                if let Some((path, span)) = frame.location {
+                    let name = Path::new(&path.0).file_name().map(|name| name.to_string_lossy());
+                    value["source"] = json!({ "name": name, "path": path.0 });
+                    value["line"] = json!(span.start.line + 1);
+                    value["column"] = json!(span.start.col + 1);
+                    value["endLine"] = json!(span.end.line + 1);
+                    value["endColumn"] = json!(span.end.col + 1);
+                }
+                value
+            })
+            .collect::<Vec<_>>();
+        json!({ "stackFrames": frames, "totalFrames": frames.len() })
+    }
+
+    /// Returns the scopes of a frame. Only the innermost frame has variables.
+    fn scopes(&self, args: &Value) -> Value {
+        if args["frameId"].as_u64().unwrap_or_default() != 0 {
+            return json!({ "scopes": [] });
+        }
+        json!({ "scopes": [
+            { "name": "Variables", "variablesReference": VARIABLES_REFERENCE, "expensive": false },
+            { "name": "Registers", "variablesReference": REGISTERS_REFERENCE, "expensive": false },
+        ]})
+    }
+
+    /// Returns the variables of a scope.
+    fn variables(&mut self, args: &Value) -> Value {
+        let variables = match args["variablesReference"].as_u64() {
+            Some(VARIABLES_REFERENCE) => self
+                .session
+                .variables()
+                .into_iter()
+                .map(|var| {
+                    json!({
+                        "name": var.name(),
+                        "value": var.value(),
+                        "type": var.ty.to_string(),
+                        "variablesReference": 0,
+                    })
+                })
+                .collect(),
+            Some(REGISTERS_REFERENCE) => {
+                let entry = self.session.current();
+                [("pc", entry.pc), ("ap", entry.ap), ("fp", entry.fp)]
+                    .into_iter()
+                    .map(|(name, value)| {
+                        json!({ "name": name, "value": value.to_string(), "variablesReference": 0 })
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        };
+        json!({ "variables": variables })
+    }
+
+    /// Sends a `stopped` event.
+    fn send_stopped(&mut self, reason: &str, hit_breakpoint_ids: Vec<usize>) -> io::Result<()> {
+        self.send_event(
+            "stopped",
+            json!({
+                "reason": reason,
+                "threadId": THREAD_ID,
+                "allThreadsStopped": true,
+                "hitBreakpointIds": hit_breakpoint_ids,
+            }),
+        )
+    }
+
+    /// Sends the response to a request.
+    fn respond(&mut self, request: &Value, body: Result<Value, String>) -> io::Result<()> {
+        let mut response = json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": body.is_ok(),
+        });
+        match body {
+            Ok(Value::Null) => {}
+            Ok(body) => response["body"] = body,
+            Err(message) => response["message"] = json!(message),
+        }
+        self.send(response)
+    }
+
+    /// Sends an event.
+    fn send_event(&mut self, event: &str, body: Value) -> io::Result<()> {
+        let mut message = json!({ "type": "event", "event": event });
+        if !body.is_null() {
+            message["body"] = body;
+        }
+        self.send(message)
+    }
+
+    /// Sends a message with the next sequence number.
+    fn send(&mut self, mut message: Value) -> io::Result<()> {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        let content = message.to_string();
+        write!(self.output, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+        self.output.flush()
+    }
+}
+
+/// Reads a single message, returning `None` at the end of the input.
+fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim();
+        if header.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let mut content = vec![0; content_length.unwrap()];
+    input.read_exact(&mut content)?;
+    serde_json::from_slice(&content).map(Some).map_err(io::Error::other)
+}
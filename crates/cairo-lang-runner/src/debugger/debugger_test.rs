@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::program_generator::{
+    SierraProgramWithDebug, get_program_variable_names,
+};
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use indoc::indoc;
+use serde_json::{Value, json};
+
+use super::dap::run_dap_server;
+use super::repl::run_repl;
+use super::{Breakpoint, DebugSession, DebugSources, RecordedRun, StopReason};
+use crate::{SierraCasmRunner, StarknetState};
+
+const CAIRO_CODE: &str = indoc! {"
+    #[inline(never)]
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+    fn main() -> felt252 {
+        let x = add(1, 2);
+        let y = add(x, 3);
+        y * 2
+    }
+"};
+
+/// Compiles the given Cairo code, and records a run of its `main` function.
+fn record(cairo_code: &str) -> (SierraCasmRunner, RecordedRun, DebugSources) {
+    let db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let test_module = setup_test_module(&db, cairo_code).unwrap();
+    let crate_input = test_module.crate_id.long(&db).clone().into_crate_input(&db);
+    DiagnosticsReporter::stderr().with_crates(&[crate_input]).ensure(&db).unwrap();
+    let SierraProgramWithDebug { program, debug_info } =
+        Arc::unwrap_or_clone(db.get_sierra_program(vec![test_module.crate_id]).unwrap());
+    let sources = DebugSources {
+        code_locations: debug_info
+            .statements_locations
+            .extract_statements_source_code_locations(&db),
+        functions: debug_info.statements_locations.extract_statements_functions(&db),
+        program: None,
+        variable_names: get_program_variable_names(&db, &program),
+    };
+    let runner = SierraCasmRunner::new(
+        replace_sierra_ids_in_program(&db, &program),
+        None,
+        OrderedHashMap::default(),
+        None,
+    )
+    .unwrap();
+    let func = runner.find_function("::main").unwrap();
+    let run = RecordedRun::record(&runner, func, vec![], None, StarknetState::default()).unwrap();
+    (runner, run, sources)
+}
+
+/// Returns the names of the functions in the call stack of the session.
+fn stack(session: &DebugSession<'_>) -> Vec<String> {
+    session.backtrace().into_iter().map(|frame| frame.function).collect()
+}
+
+#[test]
+fn session() {
+    let (runner, run, sources) = record(CAIRO_CODE);
+    let mut session = DebugSession::new(runner.builder(), &run, &sources);
+    assert_eq!(stack(&session), ["test::main"]);
+
+    assert!(session.is_resolvable(&Breakpoint::parse("lib.cairo:3")));
+    assert!(!session.is_resolvable(&Breakpoint::parse("lib.cairo:100")));
+    assert!(!session.is_resolvable(&Breakpoint::parse("other.cairo:3")));
+    assert!(session.is_resolvable(&Breakpoint::parse("add")));
+    assert!(!session.is_resolvable(&Breakpoint::parse("sub")));
+
+    assert_eq!(session.add_breakpoint(Breakpoint::parse("test::add")), 0);
+    assert_eq!(session.add_breakpoint(Breakpoint::parse("lib.cairo:8")), 1);
+    assert_eq!(session.resume(), StopReason::Breakpoint(vec![0]));
+    assert_eq!(stack(&session), ["test::add", "test::main"]);
+    let values: Vec<_> = session.variables().iter().map(|var| var.value()).collect();
+    assert_eq!(values, ["3"]);
+    assert_eq!(session.resume(), StopReason::Breakpoint(vec![0]));
+    let values: Vec<_> = session.variables().iter().map(|var| var.value()).collect();
+    assert_eq!(values, ["6"]);
+    assert_eq!(session.resume(), StopReason::Breakpoint(vec![1]));
+    assert_eq!(stack(&session), ["test::main"]);
+    assert!(session.remove_breakpoint(0));
+    assert!(!session.remove_breakpoint(0));
+    assert_eq!(session.resume(), StopReason::Finished);
+    assert!(session.is_finished());
+
+    // Stepping over never enters `add`, while stepping into does, until stepping out of it.
+    // Variables bound to Cairo variables are named by them.
+    let mut session = DebugSession::new(runner.builder(), &run, &sources);
+    let mut names = vec![];
+    while session.step_over() == StopReason::Step {
+        assert_eq!(stack(&session), ["test::main"]);
+        names.extend(session.variables().iter().map(|var| var.name()));
+    }
+    assert!(names.contains(&"x [3]".to_string()), "`x` not found in: {names:?}");
+    let mut session = DebugSession::new(runner.builder(), &run, &sources);
+    while stack(&session).len() == 1 {
+        assert_eq!(session.step_statement(), StopReason::Step);
+    }
+    assert_eq!(stack(&session), ["test::add", "test::main"]);
+    assert_eq!(session.step_out(), StopReason::Step);
+    assert_eq!(stack(&session), ["test::main"]);
+
+    // Stepping by instructions goes through the entire run.
+    let mut session = DebugSession::new(runner.builder(), &run, &sources);
+    let first_step = session.steps();
+    let mut n_instructions = 1;
+    while session.step_instruction() == StopReason::Step {
+        n_instructions += 1;
+    }
+    assert_eq!(session.steps() - first_step, n_instructions);
+}
+
+#[test]
+fn repl() {
+    let (runner, run, sources) = record(CAIRO_CODE);
+    let mut session = DebugSession::new(runner.builder(), &run, &sources);
+    let mut output = vec![];
+    run_repl(&mut session, "b add\nb missing\nc\nbt\nvars\nd 0\nc\nfoo\n".as_bytes(), &mut output)
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    for expected in [
+        "Breakpoint 0 added.",
+        "Warning: `missing` does not match any code.",
+        "Hit breakpoint 0.",
+        "#0 test::add (statement #",
+        "#1 test::main (statement #",
+        ": felt252 = 3",
+        "Breakpoint 0 removed.",
+        "The run is finished",
+        "Unknown command `foo`.",
+    ] {
+        assert!(output.contains(expected), "`{expected}` not found in:\n{output}");
+    }
+}
+
+#[test]
+fn dap() {
+    let (runner, run, sources) = record(CAIRO_CODE);
+    let mut session = DebugSession::new(runner.builder(), &run, &sources);
+    let requests = [
+        json!({"command": "initialize", "arguments": {}}),
+        json!({"command": "launch", "arguments": {}}),
+        json!({"command": "setFunctionBreakpoints", "arguments": {
+            "breakpoints": [{"name": "add"}, {"name": "missing"}],
+        }}),
+        json!({"command": "configurationDone"}),
+        json!({"command": "stackTrace", "arguments": {"threadId": 1}}),
+        json!({"command": "variables", "arguments": {"variablesReference": 1}}),
+        json!({"command": "stepOut", "arguments": {"threadId": 1}}),
+        json!({"command": "setFunctionBreakpoints", "arguments": {"breakpoints": []}}),
+        json!({"command": "continue", "arguments": {"threadId": 1}}),
+        json!({"command": "next", "arguments": {"threadId": 1}}),
+        json!({"command": "disconnect"}),
+    ];
+    let mut input = vec![];
+    for (seq, mut request) in requests.into_iter().enumerate() {
+        request["seq"] = json!(seq + 1);
+        request["type"] = json!("request");
+        let content = request.to_string();
+        input.extend(format!("Content-Length: {}\r\n\r\n{content}", content.len()).bytes());
+    }
+    let mut output = vec![];
+    run_dap_server(&mut session, input.as_slice(), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let messages: Vec<Value> = output
+        .split("Content-Length: ")
+        .skip(1)
+        .map(|message| serde_json::from_str(message.split_once("\r\n\r\n").unwrap().1).unwrap())
+        .collect();
+    let summary: Vec<String> = messages
+        .iter()
+        .map(|message| match message["type"].as_str().unwrap() {
+            "response" => format!("{}: {}", message["command"], message["success"]),
+            _ => format!("event {}", message["event"]),
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            r#""initialize": true"#,
+            r#"event "initialized""#,
+            r#""launch": true"#,
+            r#""setFunctionBreakpoints": true"#,
+            r#""configurationDone": true"#,
+            r#"event "stopped""#,
+            r#""stackTrace": true"#,
+            r#""variables": true"#,
+            r#""stepOut": true"#,
+            r#"event "stopped""#,
+            r#""setFunctionBreakpoints": true"#,
+            r#""continue": true"#,
+            r#"event "exited""#,
+            r#"event "terminated""#,
+            r#""next": false"#,
+            r#""disconnect": true"#,
+        ]
+    );
+    assert_eq!(
+        messages[3]["body"]["breakpoints"],
+        json!([{"id": 0, "verified": true}, {"id": 1, "verified": false}])
+    );
+    assert_eq!(messages[5]["body"]["reason"], "breakpoint");
+    let frames = &messages[6]["body"]["stackFrames"];
+    assert_eq!(frames[0]["name"], "test::add");
+    assert_eq!(frames[1]["name"], "test::main");
+    assert_eq!(frames[1]["source"]["path"], "lib.cairo");
+    assert_eq!(frames[1]["line"], 6);
+    assert_eq!(messages[7]["body"]["variables"][0]["value"], "3");
+    assert_eq!(messages[9]["body"]["reason"], "step");
+    assert_eq!(messages[14]["message"], "The run is finished.");
+}
@@ -0,0 +1,574 @@
+//! A replay debugger for runs of Sierra programs.
+//!
+//! The function is first fully run by the VM while recording its trace and memory. The recorded
+//! run is then replayed by a [DebugSession], which maps the CASM instructions back to the Sierra
+//! statements and the Cairo code that generated them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use cairo_lang_casm::cell_expression::{CellExpression, CellOperator};
+use cairo_lang_casm::operand::{CellRef, DerefOrImmediate, Register};
+use cairo_lang_runnable_utils::builder::RunnableBuilder;
+use cairo_lang_sierra::ids::{ConcreteTypeId, VarId};
+use cairo_lang_sierra::program::{Function, GenStatement, Program, Statement, StatementIdx};
+use cairo_lang_sierra_generator::statements_code_locations::{
+    SourceCodeSpan, SourceFileFullPath, StatementsSourceCodeLocations,
+};
+use cairo_lang_sierra_generator::statements_functions::StatementsFunctions;
+use cairo_lang_sierra_to_casm::compiler::StatementKindDebugInfo;
+use cairo_lang_sierra_to_casm::references::ReferenceValue;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
+use itertools::Itertools;
+use starknet_types_core::felt::Felt as Felt252;
+
+use crate::casm_run::{self, RunFunctionResult};
+use crate::{Arg, RunnerError, SierraCasmRunner, StarknetState, initialize_vm};
+
+pub mod dap;
+pub mod repl;
+
+#[cfg(test)]
+#[path = "debugger_test.rs"]
+mod test;
+
+/// A recorded run of a function on the VM.
+#[derive(Debug)]
+pub struct RecordedRun {
+    /// The relocated trace of the run.
+    pub trace: Vec<RelocatedTraceEntry>,
+    /// The relocated memory at the end of the run.
+    pub memory: Vec<Option<Felt252>>,
+    /// The offset in memory where the CASM program was loaded.
+    pub load_offset: usize,
+}
+
+impl RecordedRun {
+    /// Runs the function in the context of a given starknet state, recording the run for
+    /// debugging.
+    pub fn record(
+        runner: &SierraCasmRunner,
+        func: &Function,
+        args: Vec<Arg>,
+        available_gas: Option<usize>,
+        starknet_state: StarknetState,
+    ) -> Result<Self, RunnerError> {
+        let (mut hint_processor, ctx) =
+            runner.prepare_starknet_context(func, args, available_gas, starknet_state)?;
+        let data_len = ctx.bytecode.len();
+        let RunFunctionResult { memory, relocated_trace, .. } = casm_run::run_function(
+            ctx.bytecode.iter(),
+            ctx.builtins,
+            |vm| initialize_vm(vm, data_len),
+            &mut hint_processor,
+            ctx.hints_dict,
+        )?;
+        // The execution ends with the `ret` instruction at the end of the header, and the real
+        // program starts right after it.
+        let load_offset = relocated_trace.last().unwrap().pc + 1;
+        Ok(Self { trace: relocated_trace, memory, load_offset })
+    }
+}
+
+/// The Cairo level debug information of a Sierra program.
+#[derive(Clone, Debug, Default)]
+pub struct DebugSources {
+    /// The locations in the Cairo code that generated each Sierra statement.
+    pub code_locations: StatementsSourceCodeLocations,
+    /// The functions containing the Cairo code that generated each Sierra statement.
+    pub functions: StatementsFunctions,
+    /// The Sierra program with debug names, for display. If not provided, the program of the
+    /// builder is displayed.
+    pub program: Option<Program>,
+    /// The names of the Cairo variables represented by Sierra variables, for each function by the
+    /// index of its entry point statement.
+    pub variable_names: OrderedHashMap<StatementIdx, OrderedHashMap<VarId, String>>,
+}
+
+/// A breakpoint of a debug session.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// Breaks on entering a line of a Cairo source file. The path matches any file ending with
+    /// it, and the line is 1 based.
+    Line { path: String, line: usize },
+    /// Breaks on entering a function, given by its full path or a suffix of it.
+    Function(String),
+}
+
+impl Breakpoint {
+    /// Parses a breakpoint of the form `<path>:<line>` or `<function path>`.
+    pub fn parse(s: &str) -> Self {
+        if let Some((path, line)) = s.rsplit_once(':')
+            && !path.ends_with(':')
+            && let Ok(line) = line.parse()
+        {
+            return Self::Line { path: path.to_string(), line };
+        }
+        Self::Function(s.to_string())
+    }
+}
+
+impl std::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Breakpoint::Line { path, line } => write!(f, "{path}:{line}"),
+            Breakpoint::Function(path) => write!(f, "{path}"),
+        }
+    }
+}
+
+/// The reason a debug session stopped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StopReason {
+    /// A step was completed.
+    Step,
+    /// The given breakpoints were hit.
+    Breakpoint(Vec<usize>),
+    /// The run is finished.
+    Finished,
+}
+
+/// A frame of the call stack of a debug session.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StackFrame {
+    /// The name of the function of the frame.
+    pub function: String,
+    /// The Sierra statement currently executed by the frame.
+    pub statement_idx: StatementIdx,
+    /// The location of the Cairo code of the statement, if known.
+    pub location: Option<(SourceFileFullPath, SourceCodeSpan)>,
+}
+
+/// A Sierra variable used by the current statement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Variable {
+    /// The Sierra variable.
+    pub id: VarId,
+    /// The type of the variable.
+    pub ty: ConcreteTypeId,
+    /// The name of the Cairo variable represented by the Sierra variable, if known.
+    pub cairo_name: Option<String>,
+    /// The Cairo code that introduced the variable, if known.
+    pub origin: Option<String>,
+    /// The memory cells of the variable, `None` for cells that could not be evaluated.
+    pub cells: Vec<Option<Felt252>>,
+}
+
+impl Variable {
+    /// The name of the variable for display.
+    pub fn name(&self) -> String {
+        match (&self.cairo_name, &self.origin) {
+            (Some(cairo_name), _) => format!("{cairo_name} {}", self.id),
+            (None, Some(origin)) => format!("{} `{origin}`", self.id),
+            (None, None) => self.id.to_string(),
+        }
+    }
+
+    /// The value of the variable for display.
+    pub fn value(&self) -> String {
+        let mut cells = self.cells.iter().map(|cell| match cell {
+            Some(value) => value.to_string(),
+            None => "?".to_string(),
+        });
+        if self.cells.len() == 1 { cells.collect() } else { format!("({})", cells.join(", ")) }
+    }
+}
+
+/// A frame of the VM call stack, tracked while replaying the trace.
+#[derive(Clone, Copy, Debug)]
+struct VmFrame {
+    /// The fp of the frame.
+    fp: usize,
+    /// The index in the trace of the call instruction that created the frame.
+    call_position: Option<usize>,
+}
+
+/// A replay debugging session of a recorded run.
+pub struct DebugSession<'a> {
+    builder: &'a RunnableBuilder,
+    run: &'a RecordedRun,
+    sources: &'a DebugSources,
+    /// The current index in the trace.
+    position: usize,
+    /// The VM call stack at the current position.
+    frames: Vec<VmFrame>,
+    /// The breakpoints of the session, by id. Removed breakpoints are `None`.
+    breakpoints: Vec<Option<Breakpoint>>,
+    /// The contents of the source files read so far, `None` if not readable.
+    source_files: HashMap<String, Option<String>>,
+}
+
+impl<'a> DebugSession<'a> {
+    /// Creates a new session, stopped at the first instruction of the debugged function.
+    pub fn new(
+        builder: &'a RunnableBuilder,
+        run: &'a RecordedRun,
+        sources: &'a DebugSources,
+    ) -> Self {
+        let mut session = Self {
+            builder,
+            run,
+            sources,
+            position: 0,
+            frames: vec![VmFrame { fp: run.trace[0].fp, call_position: None }],
+            breakpoints: vec![],
+            source_files: HashMap::new(),
+        };
+        while session.statement_idx().is_none() && session.advance() {}
+        session
+    }
+
+    /// Adds a breakpoint, returning its id.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> usize {
+        self.breakpoints.push(Some(breakpoint));
+        self.breakpoints.len() - 1
+    }
+
+    /// Removes the breakpoint with the given id. Returns whether it existed.
+    pub fn remove_breakpoint(&mut self, id: usize) -> bool {
+        self.breakpoints.get_mut(id).and_then(Option::take).is_some()
+    }
+
+    /// Returns the existing breakpoints with their ids.
+    pub fn breakpoints(&self) -> impl Iterator<Item = (usize, &Breakpoint)> {
+        self.breakpoints.iter().enumerate().filter_map(|(id, bp)| Some((id, bp.as_ref()?)))
+    }
+
+    /// Returns whether any Sierra statement of the program may hit the breakpoint.
+    pub fn is_resolvable(&self, breakpoint: &Breakpoint) -> bool {
+        (0..self.program().statements.len())
+            .any(|idx| self.statement_matches(StatementIdx(idx), breakpoint))
+    }
+
+    /// Returns whether the recorded run is finished.
+    pub fn is_finished(&self) -> bool {
+        self.statement_idx().is_none()
+    }
+
+    /// Returns the current trace entry.
+    pub fn current(&self) -> &RelocatedTraceEntry {
+        &self.run.trace[self.position]
+    }
+
+    /// Returns the number of instructions executed so far.
+    pub fn steps(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the pc of the current instruction, relative to the start of the CASM program.
+    pub fn program_pc(&self) -> Option<usize> {
+        self.pc_at(self.position)
+    }
+
+    /// Returns the Sierra statement of the current instruction, or `None` if the run is finished.
+    pub fn statement_idx(&self) -> Option<StatementIdx> {
+        self.statement_at(self.position)
+    }
+
+    /// Returns the Sierra statement currently executed.
+    pub fn statement(&self) -> Option<&'a Statement> {
+        let program = self.program();
+        self.statement_idx().map(|idx| &program.statements[idx.0])
+    }
+
+    /// Returns the locations of the Cairo code of the current statement, innermost first.
+    pub fn source_locations(&self) -> &'a [(SourceFileFullPath, SourceCodeSpan, bool)] {
+        self.statement_idx().map(|idx| self.locations_of(idx)).unwrap_or_default()
+    }
+
+    /// Reads a relocated memory cell.
+    pub fn memory(&self, address: usize) -> Option<Felt252> {
+        self.run.memory.get(address).copied().flatten()
+    }
+
+    /// Advances by a single CASM instruction.
+    pub fn step_instruction(&mut self) -> StopReason {
+        if self.advance() && !self.is_finished() { StopReason::Step } else { StopReason::Finished }
+    }
+
+    /// Advances to the start of the next Sierra statement, entering function calls.
+    pub fn step_statement(&mut self) -> StopReason {
+        self.run_until(|_, _| true)
+    }
+
+    /// Advances to the start of the next Sierra statement of the current function or its callers.
+    pub fn step_over(&mut self) -> StopReason {
+        let depth = self.frames.len();
+        self.run_until(|session, _| session.frames.len() <= depth)
+    }
+
+    /// Advances until the current function returns.
+    pub fn step_out(&mut self) -> StopReason {
+        let depth = self.frames.len();
+        self.run_until(|session, _| session.frames.len() < depth)
+    }
+
+    /// Advances until a breakpoint is hit or the run is finished.
+    pub fn resume(&mut self) -> StopReason {
+        let mut hit = vec![];
+        self.run_until(|session, prev| {
+            hit = session.hit_breakpoints(prev);
+            !hit.is_empty()
+        });
+        if hit.is_empty() { StopReason::Finished } else { StopReason::Breakpoint(hit) }
+    }
+
+    /// Returns the call stack, innermost frame first.
+    pub fn backtrace(&self) -> Vec<StackFrame> {
+        let mut positions = vec![self.position];
+        positions.extend(self.frames.iter().rev().filter_map(|frame| frame.call_position));
+        positions
+            .into_iter()
+            .filter_map(|position| {
+                let statement_idx = self.statement_at(position)?;
+                let location = self
+                    .locations_of(statement_idx)
+                    .first()
+                    .map(|(path, span, _)| (path.clone(), span.clone()));
+                Some(StackFrame {
+                    function: self.function_name(statement_idx),
+                    statement_idx,
+                    location,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the variables used by the current statement, with their values at its start.
+    pub fn variables(&mut self) -> Vec<Variable> {
+        let Some(statement_idx) = self.statement_idx() else {
+            return vec![];
+        };
+        let mut start = self.position;
+        while start > 0 && self.statement_at(start - 1) == Some(statement_idx) {
+            start -= 1;
+        }
+        let entry = self.run.trace[start].clone();
+        let debug_info =
+            &self.builder.casm_program().debug_info.sierra_statement_info[statement_idx.0];
+        let (ids, ref_values): (&[VarId], &[ReferenceValue]) =
+            match (self.statement().unwrap(), &debug_info.additional_kind_info) {
+                (GenStatement::Invocation(invocation), StatementKindDebugInfo::Invoke(info)) => {
+                    (&invocation.args, &info.ref_values)
+                }
+                (GenStatement::Return(ids), StatementKindDebugInfo::Return(info)) => {
+                    (ids, &info.ref_values)
+                }
+                _ => unreachable!("Statement and debug info kinds must match."),
+            };
+        let sources = self.sources;
+        let names = self
+            .function_of(statement_idx)
+            .and_then(|func| sources.variable_names.get(&func.entry_point));
+        ids.iter()
+            .zip(ref_values)
+            .map(|(id, ref_value)| Variable {
+                id: id.clone(),
+                ty: self.named_type(&ref_value.ty),
+                cairo_name: names.and_then(|names| names.get(id)).cloned(),
+                origin: ref_value
+                    .introduction_point
+                    .source_statement_idx
+                    .and_then(|idx| self.source_snippet(idx)),
+                cells: ref_value
+                    .expression
+                    .cells
+                    .iter()
+                    .map(|cell| self.eval_cell(cell, &entry))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Advances by a single trace entry, keeping track of the call stack.
+    /// Returns false if the end of the trace was reached.
+    fn advance(&mut self) -> bool {
+        let Some(next) = self.run.trace.get(self.position + 1) else {
+            return false;
+        };
+        if next.fp != self.current().fp {
+            if let Some(depth) = self.frames.iter().rposition(|frame| frame.fp == next.fp) {
+                self.frames.truncate(depth + 1);
+            } else {
+                self.frames.push(VmFrame { fp: next.fp, call_position: Some(self.position) });
+            }
+        }
+        self.position += 1;
+        true
+    }
+
+    /// Advances to the start of the next Sierra statement for which `stop` returns true.
+    /// `stop` is given the session and the statement of the previous instruction.
+    fn run_until(
+        &mut self,
+        mut stop: impl FnMut(&mut Self, Option<StatementIdx>) -> bool,
+    ) -> StopReason {
+        while self.advance() {
+            let Some(statement_idx) = self.statement_idx() else {
+                return StopReason::Finished;
+            };
+            let prev_entry = &self.run.trace[self.position - 1];
+            let prev = self.statement_at(self.position - 1);
+            let start_offset = self.statement_start_offset(statement_idx);
+            let is_statement_start = prev != Some(statement_idx)
+                || (self.program_pc() == Some(start_offset) && prev_entry.pc >= self.current().pc);
+            if is_statement_start && stop(self, prev) {
+                return StopReason::Step;
+            }
+        }
+        StopReason::Finished
+    }
+
+    /// Returns the ids of the breakpoints hit at the current statement, given the statement of the
+    /// previous instruction.
+    fn hit_breakpoints(&self, prev: Option<StatementIdx>) -> Vec<usize> {
+        let Some(statement_idx) = self.statement_idx() else {
+            return vec![];
+        };
+        // A new call enters the breakpoint even if the caller matches it as well.
+        let is_call_entry = self.frames.last().unwrap().call_position == Some(self.position - 1);
+        self.breakpoints()
+            .filter(|(_, breakpoint)| {
+                // Stop only on entering the matching code.
+                self.statement_matches(statement_idx, breakpoint)
+                    && (is_call_entry
+                        || !prev.is_some_and(|prev| self.statement_matches(prev, breakpoint)))
+            })
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Returns whether the statement matches the breakpoint.
+    fn statement_matches(&self, statement_idx: StatementIdx, breakpoint: &Breakpoint) -> bool {
+        match breakpoint {
+            Breakpoint::Line { path, line } => {
+                self.locations_of(statement_idx).iter().any(|(file, span, _)| {
+                    Path::new(&file.0).ends_with(path) && span.start.line + 1 == *line
+                })
+            }
+            Breakpoint::Function(path) => {
+                let suffix = format!("::{path}");
+                let matches = |name: &String| *name == *path || name.ends_with(&suffix);
+                // Match the Sierra function, as well as the Cairo functions inlined into it.
+                matches(&self.function_name(statement_idx))
+                    || self
+                        .sources
+                        .functions
+                        .statements_to_functions_map
+                        .get(&statement_idx)
+                        .is_some_and(|functions| functions.iter().any(matches))
+            }
+        }
+    }
+
+    /// Returns the pc of the trace entry at the given position, relative to the start of the CASM
+    /// program.
+    fn pc_at(&self, position: usize) -> Option<usize> {
+        let pc = self.run.trace[position].pc.checked_sub(self.run.load_offset)?;
+        let statement_info = &self.builder.casm_program().debug_info.sierra_statement_info;
+        // Skip the footer, which is past the end of the last statement.
+        (pc < statement_info.last()?.end_offset).then_some(pc)
+    }
+
+    /// Returns the Sierra statement of the trace entry at the given position.
+    fn statement_at(&self, position: usize) -> Option<StatementIdx> {
+        self.pc_at(position).map(|pc| self.builder.casm_program().sierra_statement_index_by_pc(pc))
+    }
+
+    /// Returns the offset of the first CASM instruction of a statement.
+    fn statement_start_offset(&self, statement_idx: StatementIdx) -> usize {
+        self.builder.casm_program().debug_info.sierra_statement_info[statement_idx.0].start_offset
+    }
+
+    /// Returns the locations of the Cairo code of a statement.
+    fn locations_of(
+        &self,
+        statement_idx: StatementIdx,
+    ) -> &'a [(SourceFileFullPath, SourceCodeSpan, bool)] {
+        self.sources
+            .code_locations
+            .statements_to_code_location_map
+            .get(&statement_idx)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the displayed Sierra program.
+    fn program(&self) -> &'a Program {
+        self.sources.program.as_ref().unwrap_or_else(|| self.builder.sierra_program())
+    }
+
+    /// Returns the type id with its debug name in the displayed program, if it has one.
+    fn named_type(&self, ty: &ConcreteTypeId) -> ConcreteTypeId {
+        let decl = self.program().type_declarations.iter().find(|decl| decl.id.id == ty.id);
+        decl.map_or_else(|| ty.clone(), |decl| decl.id.clone())
+    }
+
+    /// Returns the Sierra function containing a statement.
+    fn function_of(&self, statement_idx: StatementIdx) -> Option<&'a Function> {
+        self.program()
+            .funcs
+            .iter()
+            .filter(|func| func.entry_point <= statement_idx)
+            .max_by_key(|func| func.entry_point)
+    }
+
+    /// Returns the name of the Sierra function containing a statement.
+    fn function_name(&self, statement_idx: StatementIdx) -> String {
+        self.function_of(statement_idx).map(|func| func.id.to_string()).unwrap_or_default()
+    }
+
+    /// Returns the single line Cairo code of a statement, if it is readable.
+    fn source_snippet(&mut self, statement_idx: StatementIdx) -> Option<String> {
+        let (path, span, _) = self.locations_of(statement_idx).first()?;
+        if span.start.line != span.end.line {
+            return None;
+        }
+        let content = self
+            .source_files
+            .entry(path.0.clone())
+            .or_insert_with(|| fs::read_to_string(&path.0).ok())
+            .as_ref()?;
+        let line = content.lines().nth(span.start.line)?;
+        line.get(span.start.col..span.end.col).map(str::to_string)
+    }
+
+    /// Evaluates a cell expression at the given trace entry.
+    fn eval_cell(&self, cell: &CellExpression, entry: &RelocatedTraceEntry) -> Option<Felt252> {
+        let cell_ref = |cell_ref: &CellRef| -> Option<Felt252> {
+            self.memory(self.cell_address(cell_ref, entry)?)
+        };
+        match cell {
+            CellExpression::Deref(cell) => cell_ref(cell),
+            CellExpression::DoubleDeref(cell, offset) => {
+                let address = usize::try_from(cell_ref(cell)?.to_biguint()).ok()?;
+                self.memory(address.checked_add_signed(*offset as isize)?)
+            }
+            CellExpression::Immediate(value) => Some(Felt252::from(value)),
+            CellExpression::BinOp { op, a, b } => {
+                let a = cell_ref(a)?;
+                let b = match b {
+                    DerefOrImmediate::Deref(cell) => cell_ref(cell)?,
+                    DerefOrImmediate::Immediate(value) => Felt252::from(&value.value),
+                };
+                Some(match op {
+                    CellOperator::Add => a + b,
+                    CellOperator::Sub => a - b,
+                    CellOperator::Mul => a * b,
+                    CellOperator::Div => a.field_div(&b.try_into().ok()?),
+                })
+            }
+        }
+    }
+
+    /// Returns the relocated address of a cell reference at the given trace entry.
+    fn cell_address(&self, cell_ref: &CellRef, entry: &RelocatedTraceEntry) -> Option<usize> {
+        let base = match cell_ref.register {
+            Register::AP => entry.ap,
+            Register::FP => entry.fp,
+        };
+        base.checked_add_signed(cell_ref.offset as isize)
+    }
+}
@@ -0,0 +1,161 @@
+//! A line based command interface for a [DebugSession].
+
+use std::io::{self, BufRead, Write};
+
+use itertools::Itertools;
+
+use super::{Breakpoint, DebugSession, StopReason};
+
+const HELP: &str = "\
+Commands:
+  break <path>:<line> | break <function>   Adds a breakpoint (alias: b).
+  delete <id>                               Removes a breakpoint (alias: d).
+  breakpoints                               Lists the breakpoints.
+  continue                                  Runs until a breakpoint is hit (alias: c).
+  step                                      Steps to the next Sierra statement (alias: s).
+  next                                      Steps over function calls (alias: n).
+  finish                                    Runs until the current function returns.
+  stepi                                     Steps a single CASM instruction (alias: si).
+  vars                                      Prints the variables of the statement (alias: v).
+  backtrace                                 Prints the call stack (alias: bt).
+  where                                     Prints the current location (alias: w).
+  registers                                 Prints the VM registers (alias: r).
+  memory <address> [count]                  Prints memory cells (alias: m).
+  quit                                      Quits the debugger (alias: q).";
+
+/// Runs the commands read from `input` on the session, until the input ends or `quit` is read.
+pub fn run_repl(
+    session: &mut DebugSession<'_>,
+    input: impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(output, "Type `help` for the list of commands.")?;
+    print_location(session, output)?;
+    let mut lines = input.lines();
+    loop {
+        write!(output, "(cairo-debug) ")?;
+        output.flush()?;
+        let Some(line) = lines.next() else {
+            writeln!(output)?;
+            return Ok(());
+        };
+        let line = line?;
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+        let operands: Vec<&str> = words.collect();
+        match (command, operands.as_slice()) {
+            ("help" | "h", []) => writeln!(output, "{HELP}")?,
+            ("break" | "b", [location]) => {
+                let breakpoint = Breakpoint::parse(location);
+                if !session.is_resolvable(&breakpoint) {
+                    writeln!(output, "Warning: `{breakpoint}` does not match any code.")?;
+                }
+                let id = session.add_breakpoint(breakpoint);
+                writeln!(output, "Breakpoint {id} added.")?;
+            }
+            ("delete" | "d", [id]) => match id.parse() {
+                Ok(id) if session.remove_breakpoint(id) => {
+                    writeln!(output, "Breakpoint {id} removed.")?
+                }
+                _ => writeln!(output, "No breakpoint `{id}`.")?,
+            },
+            ("breakpoints", []) => {
+                for (id, breakpoint) in session.breakpoints() {
+                    writeln!(output, "{id}: {breakpoint}")?;
+                }
+            }
+            ("continue" | "c", []) => {
+                let reason = session.resume();
+                print_stop(session, reason, output)?;
+            }
+            ("step" | "s", []) => {
+                let reason = session.step_statement();
+                print_stop(session, reason, output)?;
+            }
+            ("next" | "n", []) => {
+                let reason = session.step_over();
+                print_stop(session, reason, output)?;
+            }
+            ("finish", []) => {
+                let reason = session.step_out();
+                print_stop(session, reason, output)?;
+            }
+            ("stepi" | "si", []) => {
+                let reason = session.step_instruction();
+                print_stop(session, reason, output)?;
+            }
+            ("vars" | "v", []) => {
+                for var in session.variables() {
+                    writeln!(output, "{}: {} = {}", var.name(), var.ty, var.value())?;
+                }
+            }
+            ("backtrace" | "bt", []) => {
+                for (i, frame) in session.backtrace().into_iter().enumerate() {
+                    write!(output, "#{i} {} (statement #{})", frame.function, frame.statement_idx)?;
+                    if let Some((path, span)) = frame.location {
+                        write!(
+                            output,
+                            " at {}:{}:{}",
+                            path.0,
+                            span.start.line + 1,
+                            span.start.col + 1
+                        )?;
+                    }
+                    writeln!(output)?;
+                }
+            }
+            ("where" | "w", []) => print_location(session, output)?,
+            ("registers" | "r", []) => {
+                let entry = session.current();
+                writeln!(output, "pc: {}, ap: {}, fp: {}", entry.pc, entry.ap, entry.fp)?;
+            }
+            ("memory" | "m", [address, count @ ..]) if count.len() <= 1 => {
+                let (Ok(address), Ok(count)) =
+                    (address.parse::<usize>(), count.first().map_or(Ok(1), |c| c.parse::<usize>()))
+                else {
+                    writeln!(output, "Invalid memory range.")?;
+                    continue;
+                };
+                for address in address..address + count {
+                    match session.memory(address) {
+                        Some(value) => writeln!(output, "[{address}] = {value}")?,
+                        None => writeln!(output, "[{address}] = _")?,
+                    }
+                }
+            }
+            ("quit" | "q", []) => return Ok(()),
+            _ => {
+                writeln!(output, "Unknown command `{line}`. Type `help` for the list of commands.")?
+            }
+        }
+    }
+}
+
+/// Prints the reason the session stopped and the current location.
+fn print_stop(
+    session: &DebugSession<'_>,
+    reason: StopReason,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    if let StopReason::Breakpoint(ids) = reason {
+        writeln!(output, "Hit breakpoint {}.", ids.iter().join(", "))?;
+    }
+    print_location(session, output)
+}
+
+/// Prints the current location of the session.
+fn print_location(session: &DebugSession<'_>, output: &mut impl Write) -> io::Result<()> {
+    let (Some(statement_idx), Some(statement)) = (session.statement_idx(), session.statement())
+    else {
+        return writeln!(output, "The run is finished, after {} steps.", session.steps());
+    };
+    let pc = session.program_pc().unwrap();
+    writeln!(output, "Statement #{statement_idx} (pc {pc}): {statement}")?;
+    for (i, (path, span, _)) in session.source_locations().iter().enumerate() {
+        let prefix = if i == 0 { "at" } else { "inlined into" };
+        writeln!(output, "  {prefix} {}:{}:{}", path.0, span.start.line + 1, span.start.col + 1)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,628 @@
+//! Differential testing of the Sierra simulator against the CASM runner.
+//!
+//! Runs functions of a Sierra program both through [`cairo_lang_sierra::simulation`] and through
+//! [`SierraCasmRunner`], and compares their returned values, panics and remaining gas. A
+//! difference between the two means that either the lowering of some libfunc in
+//! `cairo-lang-sierra-to-casm`, or its specification in the simulator, is wrong.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use cairo_lang_runnable_utils::builder::RunnableBuilder;
+use cairo_lang_sierra::extensions::NamedType;
+use cairo_lang_sierra::extensions::circuit::CircuitTypeConcrete;
+use cairo_lang_sierra::extensions::core::CoreTypeConcrete;
+use cairo_lang_sierra::extensions::enm::{EnumConcreteType, EnumType};
+use cairo_lang_sierra::extensions::gas::CostTokenType;
+use cairo_lang_sierra::extensions::starknet::StarknetTypeConcrete;
+use cairo_lang_sierra::extensions::structure::StructConcreteType;
+use cairo_lang_sierra::extensions::types::InfoAndTypeConcreteType;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{Function, GenericArg, Program, StatementIdx};
+use cairo_lang_sierra::simulation;
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_sierra_to_casm::invocations::enm::get_variant_selector;
+use cairo_lang_utils::casts::IntoOrPanic;
+use cairo_lang_utils::extract_matches;
+use itertools::Itertools;
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
+use rand::Rng;
+use starknet_types_core::felt::{CAIRO_PRIME_BIGINT, Felt as Felt252};
+use thiserror::Error;
+
+use crate::arg_values::{
+    int_value_to_bigint, numeric_range, numeric_value, random_int, value_to_args,
+};
+use crate::{RunResultValue, RunnerError, SierraCasmRunner, StarknetState, token_gas_cost};
+
+#[cfg(test)]
+#[path = "differential_test.rs"]
+mod test;
+
+/// The maximal length of a randomly generated array.
+const MAX_RANDOM_ARRAY_LEN: usize = 4;
+
+/// The stack size of the thread running the simulation.
+const SIMULATION_STACK_SIZE: usize = 1 << 30;
+
+#[derive(Debug, Error)]
+pub enum DifferentialError {
+    #[error(transparent)]
+    RunnerError(#[from] RunnerError),
+    #[error("Type `{0}` is not supported as a function argument.")]
+    UnsupportedArgType(ConcreteTypeId),
+    #[error("Type `{0}` is not supported as a function return type.")]
+    UnsupportedReturnType(ConcreteTypeId),
+    #[error("Not enough values to build the function arguments.")]
+    MissingArgValues,
+    #[error("{0} unused values after building the function arguments.")]
+    UnusedArgValues(usize),
+    #[error("Value `{value}` is invalid for type `{ty}`.")]
+    InvalidArgValue { ty: ConcreteTypeId, value: Felt252 },
+}
+
+/// The outcome of running a function by one of the execution engines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// The run completed successfully, returning the non-implicit values.
+    Success { values: Vec<CoreValue>, gas_counter: Option<i64> },
+    /// The run panicked, carrying the panic data.
+    Panic { data: Vec<Felt252>, gas_counter: Option<i64> },
+    /// The run failed to complete.
+    Error(String),
+}
+impl fmt::Display for RunOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, values, gas_counter) = match self {
+            RunOutcome::Success { values, gas_counter } => {
+                ("returned", format!("{values:?}"), gas_counter)
+            }
+            RunOutcome::Panic { data, gas_counter } => {
+                ("panicked with", format!("{data:?}"), gas_counter)
+            }
+            RunOutcome::Error(err) => return write!(f, "failed with: {err}"),
+        };
+        write!(f, "{kind} {values}")?;
+        if let Some(gas_counter) = gas_counter {
+            write!(f, " (remaining gas: {gas_counter})")?;
+        }
+        Ok(())
+    }
+}
+
+/// The report of a differential run of a single function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DifferentialReport {
+    /// The name of the ran function.
+    pub function: String,
+    /// The non-implicit inputs the function was called with.
+    pub inputs: Vec<CoreValue>,
+    /// The outcome of the Sierra simulation.
+    pub simulation: RunOutcome,
+    /// The outcome of the CASM run.
+    pub casm: RunOutcome,
+}
+impl DifferentialReport {
+    /// Returns whether both engines agree on the outcome of the run.
+    ///
+    /// Two failed runs are considered to agree, as the engines fail with different errors.
+    pub fn is_match(&self) -> bool {
+        match (&self.simulation, &self.casm) {
+            (RunOutcome::Error(_), RunOutcome::Error(_)) => true,
+            (simulation, casm) => simulation == casm,
+        }
+    }
+}
+impl fmt::Display for DifferentialReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { function, inputs, simulation, casm } = self;
+        let status = if self.is_match() { "match" } else { "MISMATCH" };
+        writeln!(f, "{status}: `{function}` with inputs {inputs:?}")?;
+        writeln!(f, "  simulation {simulation}")?;
+        writeln!(f, "  casm       {casm}")
+    }
+}
+
+/// Runner of functions of a Sierra program by both the Sierra simulator and the CASM runner.
+pub struct DifferentialRunner {
+    /// The runner used for the CASM runs.
+    runner: SierraCasmRunner,
+    /// The gas withdrawn or deposited by each gas related statement, for the simulation.
+    statement_gas_info: HashMap<StatementIdx, i64>,
+}
+impl DifferentialRunner {
+    /// Creates a runner for `program`. Gas usage is checked if the program requires a gas counter.
+    pub fn new(program: Program) -> Result<Self, DifferentialError> {
+        let metadata_config = program.requires_gas_counter().then(Default::default);
+        let runner = SierraCasmRunner::new(program, metadata_config, Default::default(), None)?;
+        let mut statement_gas_info = HashMap::<StatementIdx, i64>::new();
+        for ((idx, token_type), value) in
+            runner.builder().metadata().gas_info.variable_values.iter()
+        {
+            if *token_type == CostTokenType::Const
+                || CostTokenType::iter_precost().contains(token_type)
+            {
+                *statement_gas_info.entry(*idx).or_default() +=
+                    value * token_gas_cost(*token_type) as i64;
+            }
+        }
+        Ok(Self { runner, statement_gas_info })
+    }
+
+    /// Returns the Sierra program of the runner.
+    pub fn program(&self) -> &Program {
+        self.builder().sierra_program()
+    }
+
+    /// Finds the first function ending with `name_suffix`.
+    pub fn find_function(&self, name_suffix: &str) -> Result<&Function, DifferentialError> {
+        Ok(self.runner.find_function(name_suffix)?)
+    }
+
+    /// Returns the types of the non-implicit parameters of `func`.
+    pub fn arg_types<'a>(&self, func: &'a Function) -> Vec<&'a ConcreteTypeId> {
+        func.signature.param_types.iter().filter(|ty| self.is_user_type(ty)).collect()
+    }
+
+    /// Returns whether all the non-implicit parameters of `func` can be built as arguments.
+    pub fn supports_args_of(&self, func: &Function) -> bool {
+        self.arg_types(func).into_iter().all(|ty| self.is_arg_type_supported(ty))
+    }
+
+    /// Generates random arguments for `func`.
+    pub fn random_args(
+        &self,
+        func: &Function,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<CoreValue>, DifferentialError> {
+        self.arg_types(func).into_iter().map(|ty| self.random_value(ty, rng)).collect()
+    }
+
+    /// Builds the arguments for `func` from their Cairo serialization.
+    pub fn args_from_serialized(
+        &self,
+        func: &Function,
+        felts: &[Felt252],
+    ) -> Result<Vec<CoreValue>, DifferentialError> {
+        let mut felts = felts.iter();
+        let args = self
+            .arg_types(func)
+            .into_iter()
+            .map(|ty| self.deserialize_value(ty, &mut felts))
+            .collect::<Result<Vec<_>, _>>()?;
+        match felts.len() {
+            0 => Ok(args),
+            unused => Err(DifferentialError::UnusedArgValues(unused)),
+        }
+    }
+
+    /// Runs `func` with the non-implicit arguments `args` by both engines.
+    pub fn run(
+        &self,
+        func: &Function,
+        args: Vec<CoreValue>,
+        available_gas: Option<usize>,
+    ) -> Result<DifferentialReport, DifferentialError> {
+        let initial_gas = self.runner.get_initial_available_gas(func, available_gas)?;
+        let casm_args = args
+            .iter()
+            .zip(self.arg_types(func))
+            .map(|(value, ty)| {
+                value_to_args(self.builder(), ty, value)
+                    .ok_or_else(|| DifferentialError::UnsupportedArgType(ty.clone()))
+            })
+            .flatten_ok()
+            .collect::<Result<Vec<_>, _>>()?;
+        let simulation = self.run_simulation(func, &args, initial_gas);
+        let casm = match self.runner.run_function_with_starknet_context(
+            func,
+            casm_args,
+            available_gas,
+            StarknetState::default(),
+        ) {
+            Ok(result) => {
+                let gas_counter = result.gas_counter.map(felt_to_i64);
+                match result.value {
+                    RunResultValue::Success(values) => RunOutcome::Success {
+                        values: self.read_casm_values(func, &values, &result.memory)?,
+                        gas_counter,
+                    },
+                    RunResultValue::Panic(data) => RunOutcome::Panic { data, gas_counter },
+                }
+            }
+            Err(err) => RunOutcome::Error(err.to_string()),
+        };
+        Ok(DifferentialReport { function: func.id.to_string(), inputs: args, simulation, casm })
+    }
+
+    /// Runs `func` by the Sierra simulator.
+    fn run_simulation(
+        &self,
+        func: &Function,
+        args: &[CoreValue],
+        initial_gas: usize,
+    ) -> RunOutcome {
+        let mut args = args.iter().cloned();
+        let inputs = func
+            .signature
+            .param_types
+            .iter()
+            .map(|ty| {
+                if self.is_user_type(ty) {
+                    args.next().unwrap()
+                } else {
+                    implicit_value(self.get_type(ty), initial_gas)
+                }
+            })
+            .collect();
+        // The simulation recurses on every function call, so it is run on a thread with a larger
+        // stack to support deeply recursive functions.
+        let result = std::thread::scope(|s| {
+            std::thread::Builder::new()
+                .stack_size(SIMULATION_STACK_SIZE)
+                .spawn_scoped(s, || {
+                    simulation::run(self.program(), &self.statement_gas_info, &func.id, inputs)
+                })
+                .expect("Failed to spawn the simulation thread.")
+                .join()
+        });
+        let outputs = match result {
+            Ok(Ok(outputs)) => outputs,
+            Ok(Err(err)) => return RunOutcome::Error(format!("{err:?}")),
+            Err(_) => return RunOutcome::Error("The simulation panicked.".into()),
+        };
+        let mut gas_counter = None;
+        let mut values = vec![];
+        for output in outputs {
+            match output {
+                CoreValue::GasBuiltin(gas) => gas_counter = Some(gas),
+                CoreValue::RangeCheck
+                | CoreValue::RangeCheck96
+                | CoreValue::Bitwise
+                | CoreValue::Pedersen
+                | CoreValue::Poseidon
+                | CoreValue::EcOp
+                | CoreValue::SegmentArena
+                | CoreValue::AddMod
+                | CoreValue::MulMod
+                | CoreValue::System => {}
+                value => values.push(value),
+            }
+        }
+        if self.panic_wrapped_type(func).is_some() {
+            let Ok::<[CoreValue; 1], _>([CoreValue::Enum { value, index }]) = values.try_into()
+            else {
+                return RunOutcome::Error("Unexpected panic wrapper value.".into());
+            };
+            if index == 0 {
+                return RunOutcome::Success { values: vec![*value], gas_counter };
+            }
+            let CoreValue::Struct(members) = *value else {
+                return RunOutcome::Error("Unexpected panic value.".into());
+            };
+            let Some(CoreValue::Array(data)) = members.into_iter().last() else {
+                return RunOutcome::Error("Unexpected panic value.".into());
+            };
+            let data =
+                data.into_iter().map(|felt| extract_matches!(felt, CoreValue::Felt252)).collect();
+            return RunOutcome::Panic { data, gas_counter };
+        }
+        RunOutcome::Success { values, gas_counter }
+    }
+
+    /// Reads the returned values of a successful CASM run of `func`.
+    fn read_casm_values(
+        &self,
+        func: &Function,
+        values: &[Felt252],
+        memory: &[Option<Felt252>],
+    ) -> Result<Vec<CoreValue>, DifferentialError> {
+        let ret_types = match self.panic_wrapped_type(func) {
+            Some(ty) => vec![ty],
+            None => func
+                .signature
+                .ret_types
+                .iter()
+                .filter(|ty| self.is_user_type(ty))
+                .cloned()
+                .collect(),
+        };
+        let mut values = values.iter();
+        ret_types.iter().map(|ty| self.read_memory_value(ty, &mut values, memory)).collect()
+    }
+
+    /// Returns the inner type of the return type of `func`, if it is wrapped in a `PanicResult`.
+    fn panic_wrapped_type(&self, func: &Function) -> Option<ConcreteTypeId> {
+        func.signature.ret_types.iter().find_map(|ty| {
+            let long_id = self.builder().type_long_id(ty);
+            let [GenericArg::UserType(ut), GenericArg::Type(inner), ..] =
+                long_id.generic_args.as_slice()
+            else {
+                return None;
+            };
+            (long_id.generic_id == EnumType::ID
+                && ut.debug_name.as_ref()?.starts_with("core::panics::PanicResult::"))
+            .then(|| inner.clone())
+        })
+    }
+
+    /// Returns whether `ty` is a non-implicit type.
+    fn is_user_type(&self, ty: &ConcreteTypeId) -> bool {
+        self.builder().is_user_arg_type(&self.builder().type_long_id(ty).generic_id)
+    }
+
+    /// Returns the builder of the CASM runner.
+    fn builder(&self) -> &RunnableBuilder {
+        self.runner.builder()
+    }
+
+    /// Returns the concrete type of `ty`.
+    fn get_type(&self, ty: &ConcreteTypeId) -> &CoreTypeConcrete {
+        self.builder().registry().get_type(ty).unwrap()
+    }
+
+    /// Returns whether values of `ty` can be used as function arguments.
+    fn is_arg_type_supported(&self, ty: &ConcreteTypeId) -> bool {
+        match self.get_type(ty) {
+            CoreTypeConcrete::Felt252(_)
+            | CoreTypeConcrete::Bytes31(_)
+            | CoreTypeConcrete::EcPoint(_)
+            | CoreTypeConcrete::Starknet(
+                StarknetTypeConcrete::ClassHash(_)
+                | StarknetTypeConcrete::ContractAddress(_)
+                | StarknetTypeConcrete::StorageBaseAddress(_)
+                | StarknetTypeConcrete::StorageAddress(_),
+            ) => true,
+            CoreTypeConcrete::Array(InfoAndTypeConcreteType { ty, .. })
+            | CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. })
+            | CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => {
+                self.is_arg_type_supported(ty)
+            }
+            CoreTypeConcrete::Struct(StructConcreteType { members: tys, .. })
+            | CoreTypeConcrete::Enum(EnumConcreteType { variants: tys, .. }) => {
+                tys.iter().all(|ty| self.is_arg_type_supported(ty))
+            }
+            _ => numeric_range(self.builder(), ty).is_some(),
+        }
+    }
+
+    /// Generates a random value of type `ty`, biased toward the edges of its range.
+    fn random_value(
+        &self,
+        ty: &ConcreteTypeId,
+        rng: &mut impl Rng,
+    ) -> Result<CoreValue, DifferentialError> {
+        Ok(match self.get_type(ty) {
+            CoreTypeConcrete::Bytes31(_) => {
+                CoreValue::Felt252(Felt252::from_bytes_be_slice(&rng.random::<[u8; 31]>()))
+            }
+            CoreTypeConcrete::EcPoint(_) => {
+                // The generator of the STARK curve, or the point at infinity.
+                if rng.random_bool(0.5) {
+                    CoreValue::EcPoint(Felt252::ZERO, Felt252::ZERO)
+                } else {
+                    CoreValue::EcPoint(
+                        Felt252::from_hex_unchecked(
+                            "0x1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+                        ),
+                        Felt252::from_hex_unchecked(
+                            "0x5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+                        ),
+                    )
+                }
+            }
+            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. }) => loop {
+                let value = self.random_value(ty, rng)?;
+                if !is_zero_value(&value) {
+                    break value;
+                }
+            },
+            CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => {
+                self.random_value(ty, rng)?
+            }
+            CoreTypeConcrete::Array(InfoAndTypeConcreteType { ty, .. }) => CoreValue::Array(
+                (0..rng.random_range(0..=MAX_RANDOM_ARRAY_LEN))
+                    .map(|_| self.random_value(ty, rng))
+                    .collect::<Result<_, _>>()?,
+            ),
+            CoreTypeConcrete::Struct(StructConcreteType { members, .. }) => CoreValue::Struct(
+                members.iter().map(|ty| self.random_value(ty, rng)).collect::<Result<_, _>>()?,
+            ),
+            CoreTypeConcrete::Enum(EnumConcreteType { variants, .. }) if !variants.is_empty() => {
+                let index = rng.random_range(0..variants.len());
+                CoreValue::Enum {
+                    value: Box::new(self.random_value(&variants[index], rng)?),
+                    index,
+                }
+            }
+            _ => {
+                let range = numeric_range(self.builder(), ty)
+                    .ok_or_else(|| DifferentialError::UnsupportedArgType(ty.clone()))?;
+                numeric_value(self.builder(), ty, random_int(&range, rng))
+            }
+        })
+    }
+
+    /// Deserializes a value of type `ty` from its Cairo serialization.
+    fn deserialize_value<'a>(
+        &self,
+        ty: &ConcreteTypeId,
+        felts: &mut impl Iterator<Item = &'a Felt252>,
+    ) -> Result<CoreValue, DifferentialError> {
+        let mut next = || felts.next().cloned().ok_or(DifferentialError::MissingArgValues);
+        let invalid = |value| DifferentialError::InvalidArgValue { ty: ty.clone(), value };
+        Ok(match self.get_type(ty) {
+            CoreTypeConcrete::Felt252(_)
+            | CoreTypeConcrete::Bytes31(_)
+            | CoreTypeConcrete::Starknet(
+                StarknetTypeConcrete::ClassHash(_)
+                | StarknetTypeConcrete::ContractAddress(_)
+                | StarknetTypeConcrete::StorageBaseAddress(_)
+                | StarknetTypeConcrete::StorageAddress(_),
+            ) => CoreValue::Felt252(next()?),
+            CoreTypeConcrete::EcPoint(_) => CoreValue::EcPoint(next()?, next()?),
+            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty: inner, .. }) => {
+                let value = self.deserialize_value(inner, felts)?;
+                if is_zero_value(&value) {
+                    return Err(invalid(Felt252::ZERO));
+                }
+                value
+            }
+            CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => {
+                self.deserialize_value(ty, felts)?
+            }
+            CoreTypeConcrete::Array(InfoAndTypeConcreteType { ty: inner, .. }) => {
+                let len = next()?;
+                let len = len.to_usize().ok_or_else(|| invalid(len))?;
+                CoreValue::Array(
+                    (0..len)
+                        .map(|_| self.deserialize_value(inner, felts))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            CoreTypeConcrete::Struct(StructConcreteType { members, .. }) => CoreValue::Struct(
+                members
+                    .iter()
+                    .map(|ty| self.deserialize_value(ty, felts))
+                    .collect::<Result<_, _>>()?,
+            ),
+            CoreTypeConcrete::Enum(EnumConcreteType { variants, .. }) => {
+                let index = next()?;
+                let Some(variant) = index.to_usize().and_then(|index| variants.get(index)) else {
+                    return Err(invalid(index));
+                };
+                CoreValue::Enum {
+                    value: Box::new(self.deserialize_value(variant, felts)?),
+                    index: index.to_usize().unwrap(),
+                }
+            }
+            _ => {
+                let range = numeric_range(self.builder(), ty)
+                    .ok_or_else(|| DifferentialError::UnsupportedArgType(ty.clone()))?;
+                let felt = next()?;
+                let value = felt_to_signed(&felt);
+                if !(range.lower <= value && value < range.upper) {
+                    return Err(invalid(felt));
+                }
+                numeric_value(self.builder(), ty, value)
+            }
+        })
+    }
+
+    /// Reads a value of type `ty` from the returned cells of a CASM run.
+    ///
+    /// Values behind pointers, such as array elements and boxed values, are read from `memory`.
+    fn read_memory_value<'a>(
+        &self,
+        ty: &ConcreteTypeId,
+        cells: &mut impl Iterator<Item = &'a Felt252>,
+        memory: &'a [Option<Felt252>],
+    ) -> Result<CoreValue, DifferentialError> {
+        let unsupported = || DifferentialError::UnsupportedReturnType(ty.clone());
+        let mut next = || cells.next().cloned().ok_or_else(unsupported);
+        let read_at = |ty: &ConcreteTypeId, start: usize| {
+            let size = self.builder().type_size(ty) as usize;
+            let cells = memory[start..start + size].iter().map(|cell| cell.as_ref().unwrap());
+            self.read_memory_value(ty, &mut cells.collect_vec().into_iter(), memory)
+        };
+        Ok(match self.get_type(ty) {
+            CoreTypeConcrete::Felt252(_)
+            | CoreTypeConcrete::Bytes31(_)
+            | CoreTypeConcrete::Starknet(
+                StarknetTypeConcrete::ClassHash(_)
+                | StarknetTypeConcrete::ContractAddress(_)
+                | StarknetTypeConcrete::StorageBaseAddress(_)
+                | StarknetTypeConcrete::StorageAddress(_),
+            ) => CoreValue::Felt252(next()?),
+            CoreTypeConcrete::EcPoint(_) => CoreValue::EcPoint(next()?, next()?),
+            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. })
+            | CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => {
+                self.read_memory_value(ty, cells, memory)?
+            }
+            CoreTypeConcrete::Box(InfoAndTypeConcreteType { ty, .. }) => {
+                read_at(ty, next()?.to_usize().unwrap())?
+            }
+            CoreTypeConcrete::Nullable(InfoAndTypeConcreteType { ty, .. }) => {
+                let ptr = next()?.to_usize().unwrap();
+                CoreValue::Nullable(if ptr == 0 { None } else { Some(Box::new(read_at(ty, ptr)?)) })
+            }
+            CoreTypeConcrete::Array(InfoAndTypeConcreteType { ty, .. }) => {
+                let start = next()?.to_usize().unwrap();
+                let end = next()?.to_usize().unwrap();
+                let size = self.builder().type_size(ty) as usize;
+                CoreValue::Array(
+                    (start..end)
+                        .step_by(size.max(1))
+                        .map(|ptr| read_at(ty, ptr))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            CoreTypeConcrete::Struct(StructConcreteType { members, .. }) => CoreValue::Struct(
+                members
+                    .iter()
+                    .map(|ty| self.read_memory_value(ty, cells, memory))
+                    .collect::<Result<_, _>>()?,
+            ),
+            CoreTypeConcrete::Enum(EnumConcreteType { variants, .. }) => {
+                let selector = next()?.to_usize().unwrap();
+                let index = (0..variants.len())
+                    .find(|index| {
+                        get_variant_selector(variants.len(), *index).ok() == Some(selector)
+                    })
+                    .ok_or_else(unsupported)?;
+                let variant_size = self.builder().type_size(&variants[index]);
+                let padding = (self.builder().type_size(ty) - 1 - variant_size) as usize;
+                for _ in 0..padding {
+                    next()?;
+                }
+                CoreValue::Enum {
+                    value: Box::new(self.read_memory_value(&variants[index], cells, memory)?),
+                    index,
+                }
+            }
+            _ => {
+                numeric_range(self.builder(), ty).ok_or_else(unsupported)?;
+                numeric_value(self.builder(), ty, felt_to_signed(&next()?))
+            }
+        })
+    }
+}
+
+/// Returns the simulation value of an implicit of type `ty`.
+fn implicit_value(ty: &CoreTypeConcrete, initial_gas: usize) -> CoreValue {
+    match ty {
+        CoreTypeConcrete::GasBuiltin(_) => CoreValue::GasBuiltin(initial_gas.into_or_panic()),
+        CoreTypeConcrete::RangeCheck(_) => CoreValue::RangeCheck,
+        CoreTypeConcrete::RangeCheck96(_) => CoreValue::RangeCheck96,
+        CoreTypeConcrete::Bitwise(_) => CoreValue::Bitwise,
+        CoreTypeConcrete::Pedersen(_) => CoreValue::Pedersen,
+        CoreTypeConcrete::Poseidon(_) => CoreValue::Poseidon,
+        CoreTypeConcrete::EcOp(_) => CoreValue::EcOp,
+        CoreTypeConcrete::SegmentArena(_) => CoreValue::SegmentArena,
+        CoreTypeConcrete::Circuit(CircuitTypeConcrete::AddMod(_)) => CoreValue::AddMod,
+        CoreTypeConcrete::Circuit(CircuitTypeConcrete::MulMod(_)) => CoreValue::MulMod,
+        CoreTypeConcrete::Starknet(StarknetTypeConcrete::System(_)) => CoreValue::System,
+        _ => CoreValue::Uninitialized,
+    }
+}
+
+/// Returns whether `value` is a zero value, which is invalid as a `NonZero` value.
+fn is_zero_value(value: &CoreValue) -> bool {
+    match value {
+        CoreValue::Felt252(value) => *value == Felt252::ZERO,
+        CoreValue::Struct(members) => members.iter().all(is_zero_value),
+        value => int_value_to_bigint(value).is_some_and(|value| value.is_zero()),
+    }
+}
+
+/// Returns the signed representation of `felt`, in the range `(-PRIME/2, PRIME/2]`.
+fn felt_to_signed(felt: &Felt252) -> BigInt {
+    let value = felt.to_bigint();
+    if value > &*CAIRO_PRIME_BIGINT / 2 { value - &*CAIRO_PRIME_BIGINT } else { value }
+}
+
+/// Returns the value of a gas counter felt.
+fn felt_to_i64(felt: Felt252) -> i64 {
+    felt_to_signed(&felt).to_i64().unwrap()
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::replace_ids::replace_sierra_ids_in_program;
+use indoc::indoc;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::{DifferentialRunner, RunOutcome};
+
+/// Compiles the given Cairo code into a differential runner.
+fn differential_runner(cairo_code: &str) -> DifferentialRunner {
+    let db = RootDatabase::builder().detect_corelib().build().unwrap();
+    let test_module = setup_test_module(&db, cairo_code).unwrap();
+    let crate_input = test_module.crate_id.long(&db).clone().into_crate_input(&db);
+    DiagnosticsReporter::stderr().with_crates(&[crate_input]).ensure(&db).unwrap();
+    let program = db.get_sierra_program(vec![test_module.crate_id]).unwrap();
+    DifferentialRunner::new(replace_sierra_ids_in_program(
+        &db,
+        &Arc::unwrap_or_clone(program).program,
+    ))
+    .unwrap()
+}
+
+const CAIRO_CODE: &str = indoc! {"
+    fn add_u8(a: u8, b: u8) -> u8 {
+        a + b
+    }
+    fn mul_i16(a: i16, b: i16) -> i16 {
+        a * b
+    }
+    fn felt_ops(a: felt252, b: felt252) -> (felt252, bool) {
+        (a * b - a, a == b)
+    }
+    fn sum(values: Array<u32>) -> u64 {
+        let mut total = 0;
+        for value in values {
+            total += value.into();
+        }
+        total
+    }
+    fn wide_mul(a: u256, b: u128) -> u256 {
+        a * b.into()
+    }
+    fn unwrap_or(value: Option<u64>, default: u64) -> u64 {
+        match value {
+            Some(value) => value,
+            None => default,
+        }
+    }
+    fn divide(a: u32, b: NonZero<u32>) -> (u32, u32) {
+        DivRem::div_rem(a, b)
+    }
+"};
+
+#[test]
+fn random_args_match() {
+    let runner = differential_runner(CAIRO_CODE);
+    let mut rng = StdRng::seed_from_u64(0);
+    for name in ["add_u8", "mul_i16", "felt_ops", "sum", "wide_mul", "unwrap_or", "divide"] {
+        let func = runner.find_function(name).unwrap();
+        assert!(runner.supports_args_of(func));
+        for _ in 0..10 {
+            let args = runner.random_args(func, &mut rng).unwrap();
+            let report = runner.run(func, args, Some(u32::MAX as usize)).unwrap();
+            assert!(report.is_match(), "{report}");
+            assert!(!matches!(report.casm, RunOutcome::Error(_)), "{report}");
+        }
+    }
+}
+
+#[test]
+fn serialized_args() {
+    let runner = differential_runner(CAIRO_CODE);
+    let func = runner.find_function("add_u8").unwrap();
+    let args =
+        runner.args_from_serialized(func, &[Felt252::from(200), Felt252::from(100)]).unwrap();
+    assert_eq!(args, vec![CoreValue::Uint8(200), CoreValue::Uint8(100)]);
+    let report = runner.run(func, args, Some(u32::MAX as usize)).unwrap();
+    assert!(report.is_match(), "{report}");
+    assert!(matches!(report.casm, RunOutcome::Panic { .. }));
+
+    let func = runner.find_function("unwrap_or").unwrap();
+    let args = runner
+        .args_from_serialized(func, &[Felt252::from(0), Felt252::from(7), Felt252::from(3)])
+        .unwrap();
+    let report = runner.run(func, args, Some(u32::MAX as usize)).unwrap();
+    assert!(report.is_match(), "{report}");
+    assert!(matches!(
+        report.casm,
+        RunOutcome::Success { values, .. } if values == [CoreValue::Uint64(7)]
+    ));
+
+    assert!(runner.args_from_serialized(func, &[Felt252::from(2)]).is_err());
+    assert!(runner.args_from_serialized(func, &[Felt252::from(1), Felt252::from(3)]).is_ok());
+    assert!(
+        runner
+            .args_from_serialized(func, &[Felt252::from(1), Felt252::from(3), Felt252::ONE])
+            .is_err()
+    );
+}
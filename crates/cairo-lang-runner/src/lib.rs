@@ -5,11 +5,13 @@ use cairo_lang_casm::hints::Hint;
 use cairo_lang_runnable_utils::builder::{BuildError, EntryCodeConfig, RunnableBuilder};
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::extensions::enm::EnumType;
-use cairo_lang_sierra::extensions::gas::{CostTokenType, GasBuiltinType};
-use cairo_lang_sierra::ids::{ConcreteTypeId, GenericTypeId};
+use cairo_lang_sierra::extensions::gas::{CostTokenMap, CostTokenType, GasBuiltinType};
+use cairo_lang_sierra::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
 use cairo_lang_sierra::program::{Function, GenericArg};
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
 use cairo_lang_starknet::contract::ContractInfo;
+use cairo_lang_starknet_classes::casm_contract_class::ENTRY_POINT_COST;
+use cairo_lang_starknet_classes::contract_class::{ContractClass, ContractEntryPoint};
 use cairo_lang_utils::casts::IntoOrPanic;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::{extract_matches, require};
@@ -21,6 +23,7 @@ use cairo_vm::vm::runners::cairo_runner::{ExecutionResources, RunResources};
 use cairo_vm::vm::vm_core::VirtualMachine;
 use casm_run::hint_to_hint_params;
 pub use casm_run::{CairoHintProcessor, StarknetState};
+use itertools::chain;
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 use profiling::ProfilingInfo;
@@ -30,13 +33,20 @@ use thiserror::Error;
 use crate::casm_run::{RunFunctionResult, StarknetHintProcessor};
 use crate::profiling::ProfilerConfig;
 
+pub mod arg_values;
 pub mod casm_run;
 pub mod clap;
+pub mod debugger;
+pub mod differential;
 pub mod profiling;
 pub mod short_string;
 
 const MAX_STACK_TRACE_DEPTH_DEFAULT: usize = 100;
 
+/// The gas available to a run that is not limited explicitly - large enough for any reasonable
+/// run, while still running infinite loops out of gas. Matches the default gas of tests.
+pub const DEFAULT_AVAILABLE_GAS: usize = u32::MAX as usize;
+
 #[derive(Debug, Error)]
 pub enum RunnerError {
     #[error(transparent)]
@@ -49,6 +59,8 @@ pub enum RunnerError {
     ArgumentsSizeMismatch { expected: usize, actual: usize },
     #[error(transparent)]
     CairoRunError(#[from] Box<CairoRunError>),
+    #[error("Invalid contract class: {0}")]
+    InvalidContractClass(String),
 }
 
 /// The full result of a run with Starknet state.
@@ -179,6 +191,9 @@ pub struct SierraCasmRunner {
     builder: RunnableBuilder,
     /// Mapping from class_hash to contract info.
     starknet_contracts_info: OrderedHashMap<Felt252, ContractInfo>,
+    /// Mapping from class_hash to the runners of contract classes compiled separately from the
+    /// program, each running its own program.
+    contract_class_runners: OrderedHashMap<Felt252, SierraCasmRunner>,
     /// Whether to run the profiler when running using this runner.
     run_profiler: Option<ProfilingInfoCollectionConfig>,
 }
@@ -193,10 +208,78 @@ impl SierraCasmRunner {
         Ok(Self {
             builder: RunnableBuilder::new(sierra_program, metadata_config)?,
             starknet_contracts_info,
+            contract_class_runners: Default::default(),
             run_profiler,
         })
     }
 
+    /// Registers a contract class compiled separately from the program, e.g. loaded from a
+    /// `ContractClass` JSON file. The class runs in its own program, and is deployed and called by
+    /// the given class hash, which depends on the ABI string the class is declared with (see
+    /// [ContractClass::class_hash_with_abi]).
+    pub fn add_contract_class(
+        &mut self,
+        contract_class: &ContractClass,
+        class_hash: Felt252,
+    ) -> Result<(), RunnerError> {
+        let program = contract_class
+            .extract_sierra_program()
+            .map_err(|err| RunnerError::InvalidContractClass(err.to_string()))?;
+        let function_id = |entry_point: &ContractEntryPoint| {
+            program.funcs.get(entry_point.function_idx).map(|func| func.id.clone()).ok_or_else(
+                || {
+                    RunnerError::InvalidContractClass(format!(
+                        "Entry point function index {} is out of range.",
+                        entry_point.function_idx
+                    ))
+                },
+            )
+        };
+        let by_selector = |entry_points: &[ContractEntryPoint]| {
+            entry_points
+                .iter()
+                .map(|entry_point| {
+                    Ok((Felt252::from(&entry_point.selector), function_id(entry_point)?))
+                })
+                .collect::<Result<OrderedHashMap<_, _>, RunnerError>>()
+        };
+        let entry_points = &contract_class.entry_points_by_type;
+        let contract_info = ContractInfo {
+            constructor: entry_points.constructor.first().map(function_id).transpose()?,
+            externals: by_selector(&entry_points.external)?,
+            l1_handlers: by_selector(&entry_points.l1_handler)?,
+        };
+        // Entry points are charged for their initial cost, as in the compilation of the class.
+        let function_set_costs: OrderedHashMap<FunctionId, CostTokenMap<i32>> = chain!(
+            contract_info.constructor.iter(),
+            contract_info.externals.values(),
+            contract_info.l1_handlers.values()
+        )
+        .map(|id| (id.clone(), CostTokenMap::from_iter([(CostTokenType::Const, ENTRY_POINT_COST)])))
+        .collect();
+        let runner = SierraCasmRunner::new(
+            program,
+            Some(MetadataComputationConfig { function_set_costs, ..Default::default() }),
+            [(class_hash, contract_info)].into_iter().collect(),
+            None,
+        )?;
+        self.contract_class_runners.insert(class_hash, runner);
+        Ok(())
+    }
+
+    /// Returns the runner of the program containing the contract class with the given hash, and
+    /// the info of the class.
+    pub(crate) fn find_contract_class(
+        &self,
+        class_hash: &Felt252,
+    ) -> Option<(&SierraCasmRunner, &ContractInfo)> {
+        if let Some(contract_info) = self.starknet_contracts_info.get(class_hash) {
+            return Some((self, contract_info));
+        }
+        let runner = self.contract_class_runners.get(class_hash)?;
+        Some((runner, runner.starknet_contracts_info.get(class_hash)?))
+    }
+
     /// Runs the vm starting from a function in the context of a given starknet state.
     pub fn run_function_with_starknet_context(
         &self,
@@ -238,20 +321,25 @@ impl SierraCasmRunner {
         ty: &GenericTypeId,
         func: &Function,
     ) -> Option<ConcreteTypeId> {
-        let generic_args = &func
+        let (ret_type, long_id) = func
             .signature
             .ret_types
             .iter()
             .find_map(|rt| {
                 let long_id = self.builder.type_long_id(rt);
-                (long_id.generic_id == *ty).then_some(long_id)
+                (long_id.generic_id == *ty).then_some((rt, long_id))
             })
-            .unwrap()
-            .generic_args;
+            .unwrap();
+        let generic_args = &long_id.generic_args;
+        let is_panic_result = |debug_name: Option<&str>| {
+            debug_name.is_some_and(|name| name.starts_with("core::panics::PanicResult::"))
+        };
 
+        // Programs loaded from contract classes only have the debug names of concrete types.
         if *ty == EnumType::ID
-            && matches!(&generic_args[0], GenericArg::UserType(ut)
-                if ut.debug_name.as_ref().unwrap().starts_with("core::panics::PanicResult::"))
+            && (is_panic_result(ret_type.debug_name.as_deref())
+                || matches!(&generic_args[0], GenericArg::UserType(ut)
+                    if is_panic_result(ut.debug_name.as_deref())))
         {
             return Some(extract_matches!(&generic_args[1], GenericArg::Type).clone());
         }
@@ -305,7 +393,8 @@ impl SierraCasmRunner {
             Self::handle_main_return_value(inner_ty, values, &memory)
         };
 
-        let Self { builder, starknet_contracts_info: _, run_profiler } = self;
+        let Self { builder, starknet_contracts_info: _, contract_class_runners: _, run_profiler } =
+            self;
 
         // The real program starts right after the header.
         let load_offset = header_end + 1;
@@ -462,6 +551,11 @@ impl SierraCasmRunner {
         (results_data, gas_counter)
     }
 
+    /// Returns the builder of the runnable functions of the program.
+    pub fn builder(&self) -> &RunnableBuilder {
+        &self.builder
+    }
+
     /// Finds first function ending with `name_suffix`.
     pub fn find_function(&self, name_suffix: &str) -> Result<&Function, RunnerError> {
         Ok(self.builder.find_function(name_suffix)?)
@@ -43,6 +43,10 @@ pub struct ProfilingInfo {
     /// The number of steps in the trace that originated from each sierra statement.
     pub sierra_statement_weights: UnorderedHashMap<StatementIdx, usize>,
 
+    /// The number of times the execution entered each sierra statement, i.e. reached its first
+    /// CASM instruction. Statements without CASM instructions are never entered.
+    pub sierra_statement_hits: UnorderedHashMap<StatementIdx, usize>,
+
     /// A map of weights of each stack trace.
     /// The key is a function stack trace of an executed function. The stack trace is represented
     /// as a vector of indices of the functions in the stack (indices of the functions according to
@@ -93,6 +97,8 @@ impl ProfilingInfo {
         // runner). The header is not counted, and the footer is, but then the relevant
         // entry is removed.
         let mut sierra_statement_weights = UnorderedHashMap::default();
+        // The number of times each Sierra statement was entered.
+        let mut sierra_statement_hits = UnorderedHashMap::default();
         // Total weight of Sierra statements grouped by the respective (collapsed) user function
         // call stack.
         let mut scoped_sierra_statement_weights = OrderedHashMap::default();
@@ -125,6 +131,9 @@ impl ProfilingInfo {
             );
 
             *sierra_statement_weights.entry(sierra_statement_idx).or_insert(0) += 1;
+            if sierra_statement_info[sierra_statement_idx.0].start_offset == real_pc {
+                *sierra_statement_hits.entry(sierra_statement_idx).or_insert(0) += 1;
+            }
 
             if profiling_config.collect_scoped_sierra_statement_weights {
                 // The current stack trace, including the current function (recursive calls
@@ -185,6 +194,7 @@ impl ProfilingInfo {
 
         ProfilingInfo {
             sierra_statement_weights,
+            sierra_statement_hits,
             stack_trace_weights,
             scoped_sierra_statement_weights,
         }
@@ -15,7 +15,7 @@ use cairo_lang_defs::ids::{
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{CrateId, CrateInput, FileId, FileLongId, StrRef, Tracked};
-use cairo_lang_syntax::attribute::consts::{DEPRECATED_ATTR, UNUSED_IMPORTS, UNUSED_VARIABLES};
+use cairo_lang_syntax::attribute::consts::UNUSED_IMPORTS;
 use cairo_lang_syntax::attribute::structured::Attribute;
 use cairo_lang_syntax::node::{TypedStablePtr, ast};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -45,6 +45,7 @@ use crate::items::trt::{
 };
 use crate::items::us::{ImportedModules, SemanticUseEx, UseData};
 use crate::items::visibility::Visibility;
+use crate::lint::{LINT_GROUPS, LINTS, declared_lints};
 use crate::plugin::{AnalyzerPlugin, InternedPluginSuite, PluginSuite};
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, ResolverData};
 use crate::substitution::GenericSubstitution;
@@ -59,11 +60,14 @@ pub struct SemanticGroupInput {
     pub default_analyzer_plugins: Option<Vec<AnalyzerPluginLongId>>,
     #[returns(ref)]
     pub analyzer_plugin_overrides: Option<OrderedHashMap<CrateInput, Arc<[AnalyzerPluginLongId]>>>,
+    /// The names of the lints declared by lint passes, other than the lints of the compiler.
+    #[returns(ref)]
+    pub declared_lints: Option<Vec<String>>,
 }
 
 #[salsa::tracked(returns(ref))]
 pub fn semantic_group_input(db: &dyn Database) -> SemanticGroupInput {
-    SemanticGroupInput::new(db, None, None)
+    SemanticGroupInput::new(db, None, None, None)
 }
 
 fn default_analyzer_plugins_input(db: &dyn Database) -> &[AnalyzerPluginLongId] {
@@ -2122,7 +2126,7 @@ fn module_semantic_diagnostics<'db>(
         for diag in analyzer_plugin.diagnostics(db, module_id) {
             diagnostics.add(SemanticDiagnostic::new(
                 StableLocation::new(diag.stable_ptr),
-                SemanticDiagnosticKind::PluginDiagnostic(diag),
+                SemanticDiagnosticKind::PluginDiagnostic(diag.with_plugin(analyzer_plugin.name())),
             ));
         }
     }
@@ -2141,12 +2145,12 @@ fn crate_analyzer_plugins<'db>(
 }
 
 fn declared_allows(db: &dyn SemanticGroup, crate_id: CrateId<'_>) -> Arc<OrderedHashSet<String>> {
-    let base_lints = [DEPRECATED_ATTR, UNUSED_IMPORTS, UNUSED_VARIABLES];
-
     let crate_analyzer_plugins = db.crate_analyzer_plugins(crate_id);
 
     Arc::new(OrderedHashSet::from_iter(chain!(
-        base_lints.map(|attr| attr.into()),
+        LINTS.iter().map(|lint| lint.name.into()),
+        LINT_GROUPS.iter().map(|(group, _)| group.to_string()),
+        declared_lints(db).iter().cloned(),
         crate_analyzer_plugins.iter().flat_map(|plugin| plugin.long(db).declared_allows())
     )))
 }
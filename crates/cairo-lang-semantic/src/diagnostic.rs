@@ -10,15 +10,15 @@ use cairo_lang_defs::ids::{
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
     DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, DiagnosticsBuilder,
-    ErrorCode, Severity, error_code,
+    ErrorCode, Severity, Suggestion, error_code,
 };
 use cairo_lang_filesystem::db::Edition;
 use cairo_lang_filesystem::ids::StrRef;
 use cairo_lang_filesystem::span::TextWidth;
 use cairo_lang_parser::ParserDiagnostic;
 use cairo_lang_syntax as syntax;
-use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::{TypedStablePtr, ast};
 use itertools::Itertools;
 use syntax::node::ids::SyntaxStablePtrId;
 
@@ -29,7 +29,7 @@ use crate::items::feature_kind::FeatureMarkerDiagnostic;
 use crate::items::trt::ConcreteTraitTypeId;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem};
 use crate::types::peel_snapshots;
-use crate::{ConcreteTraitId, semantic};
+use crate::{ConcreteTraitId, semantic, suggestions};
 
 #[cfg(test)]
 #[path = "diagnostic_test.rs"]
@@ -544,7 +544,7 @@ impl<'db> DiagnosticEntry<'db> for SemanticDiagnostic<'db> {
             SemanticDiagnosticKind::InvalidMemberExpression => "Invalid member expression.".into(),
             SemanticDiagnosticKind::InvalidPath => "Invalid path.".into(),
             SemanticDiagnosticKind::RefArgNotAVariable => "ref argument must be a variable.".into(),
-            SemanticDiagnosticKind::RefArgNotMutable => {
+            SemanticDiagnosticKind::RefArgNotMutable(_) => {
                 "ref argument must be a mutable variable.".into()
             }
             SemanticDiagnosticKind::RefArgNotExplicit => {
@@ -553,7 +553,7 @@ impl<'db> DiagnosticEntry<'db> for SemanticDiagnostic<'db> {
             SemanticDiagnosticKind::ImmutableArgWithModifiers => {
                 "Argument to immutable parameter cannot have modifiers.".into()
             }
-            SemanticDiagnosticKind::AssignmentToImmutableVar => {
+            SemanticDiagnosticKind::AssignmentToImmutableVar(_) => {
                 "Cannot assign to an immutable variable.".into()
             }
             SemanticDiagnosticKind::InvalidLhsForAssignment => {
@@ -878,6 +878,12 @@ impl<'db> DiagnosticEntry<'db> for SemanticDiagnostic<'db> {
                 // TODO(orizi): Add information about the allowed arguments.
                 "`allow` attribute argument not supported.".into()
             }
+            SemanticDiagnosticKind::UnsupportedLintAttrArguments(attr) => {
+                format!("`{attr}` attribute argument not supported.")
+            }
+            SemanticDiagnosticKind::UnfulfilledLintExpectation(lint) => {
+                format!("Lint expectation `{lint}` is unfulfilled.")
+            }
             SemanticDiagnosticKind::UnsupportedPubArgument => "Unsupported `pub` argument.".into(),
             SemanticDiagnosticKind::UnknownStatementAttribute => {
                 "Unknown statement attribute.".into()
@@ -1102,7 +1108,9 @@ impl<'db> DiagnosticEntry<'db> for SemanticDiagnostic<'db> {
             | SemanticDiagnosticKind::UnusedConstant
             | SemanticDiagnosticKind::UnusedUse
             | SemanticDiagnosticKind::PatternMissingArgs(_)
-            | SemanticDiagnosticKind::UnsupportedAllowAttrArguments => Severity::Warning,
+            | SemanticDiagnosticKind::UnsupportedAllowAttrArguments
+            | SemanticDiagnosticKind::UnsupportedLintAttrArguments(_)
+            | SemanticDiagnosticKind::UnfulfilledLintExpectation(_) => Severity::Warning,
             SemanticDiagnosticKind::PluginDiagnostic(diag) => diag.severity,
             _ => Severity::Error,
         }
@@ -1117,7 +1125,42 @@ impl<'db> DiagnosticEntry<'db> for SemanticDiagnostic<'db> {
     }
 
     fn error_code(&self) -> Option<ErrorCode> {
-        self.kind.error_code()
+        Some(self.kind.error_code())
+    }
+
+    fn plugin(&self) -> Option<&str> {
+        match &self.kind {
+            SemanticDiagnosticKind::PluginDiagnostic(diag) => diag.plugin.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn suggestions(&self, db: &'db Self::DbType) -> Vec<Suggestion<'db>> {
+        match &self.kind {
+            SemanticDiagnosticKind::UnusedVariable => {
+                suggestions::prefix_unused_variable(db, self.stable_location.syntax_node(db))
+                    .into_iter()
+                    .collect()
+            }
+            SemanticDiagnosticKind::UnusedImport(use_id) => {
+                suggestions::remove_unused_import(db, use_id.stable_ptr(db).untyped().lookup(db))
+                    .into_iter()
+                    .collect()
+            }
+            SemanticDiagnosticKind::RefArgNotExplicit => {
+                suggestions::pass_argument_as_ref(db, self.stable_location.syntax_node(db))
+                    .into_iter()
+                    .collect()
+            }
+            SemanticDiagnosticKind::RefArgNotMutable(definition)
+            | SemanticDiagnosticKind::AssignmentToImmutableVar(definition) => {
+                suggestions::make_variable_mutable(db, definition.lookup(db)).into_iter().collect()
+            }
+            SemanticDiagnosticKind::CannotCallMethod { relevant_traits, .. } => {
+                suggestions::import_trait(db, self.stable_location.syntax_node(db), relevant_traits)
+            }
+            _ => vec![],
+        }
     }
 
     fn is_same_kind(&self, other: &Self) -> bool {
@@ -1334,10 +1377,10 @@ pub enum SemanticDiagnosticKind<'db> {
     NegativeImplsNotEnabled,
     NegativeImplsOnlyOnImpls,
     RefArgNotAVariable,
-    RefArgNotMutable,
+    RefArgNotMutable(SyntaxStablePtrId<'db>),
     RefArgNotExplicit,
     ImmutableArgWithModifiers,
-    AssignmentToImmutableVar,
+    AssignmentToImmutableVar(SyntaxStablePtrId<'db>),
     InvalidLhsForAssignment,
     InvalidMemberExpression,
     InvalidPath,
@@ -1447,6 +1490,8 @@ pub enum SemanticDiagnosticKind<'db> {
     UnsupportedImplicitPrecedenceArguments,
     UnsupportedFeatureAttrArguments,
     UnsupportedAllowAttrArguments,
+    UnsupportedLintAttrArguments(StrRef<'db>),
+    UnfulfilledLintExpectation(StrRef<'db>),
     UnsupportedPubArgument,
     UnknownStatementAttribute,
     InlineMacroNotFound(StrRef<'db>),
@@ -1517,19 +1562,217 @@ pub enum MultiArmExprKind {
 }
 
 impl<'db> SemanticDiagnosticKind<'db> {
-    pub fn error_code(&self) -> Option<ErrorCode> {
-        Some(match &self {
-            Self::UnusedVariable => error_code!(E0001),
-            Self::CannotCallMethod { .. } => {
-                error_code!(E0002)
-            }
-            Self::MissingMember(_) => error_code!(E0003),
-            Self::MissingItemsInImpl(_) => error_code!(E0004),
+    /// Returns the code identifying the kind of the diagnostic. Codes are never changed or reused,
+    /// so new kinds must be given new codes.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
             Self::ModuleFileNotFound(_) => error_code!(E0005),
-            Self::PathNotFound(_) => error_code!(E0006),
+            Self::Unsupported => error_code!(E0008),
+            Self::UnknownLiteral => error_code!(E0009),
+            Self::UnknownBinaryOperator => error_code!(E0010),
+            Self::UnknownTrait => error_code!(E0011),
+            Self::UnknownImpl => error_code!(E0012),
+            Self::UnexpectedElement { .. } => error_code!(E0013),
+            Self::UnknownType => error_code!(E0014),
+            Self::UnknownEnum => error_code!(E0015),
+            Self::LiteralError(_) => error_code!(E0016),
+            Self::NotAVariant => error_code!(E0017),
+            Self::NotAStruct => error_code!(E0018),
+            Self::NotAType => error_code!(E0019),
+            Self::NotATrait => error_code!(E0020),
+            Self::NotAnImpl => error_code!(E0021),
+            Self::ImplItemNotInTrait { .. } => error_code!(E0022),
+            Self::ImplicitImplNotInferred { .. } => error_code!(E0023),
+            Self::GenericsNotSupportedInItem { .. } => error_code!(E0024),
+            Self::UnexpectedGenericArgs => error_code!(E0025),
+            Self::UnknownMember => error_code!(E0026),
+            Self::CannotCreateInstancesOfPhantomTypes => error_code!(E0027),
+            Self::NonPhantomTypeContainingPhantomType => error_code!(E0028),
+            Self::MemberSpecifiedMoreThanOnce => error_code!(E0029),
+            Self::StructBaseStructExpressionNotLast => error_code!(E0030),
+            Self::StructBaseStructExpressionNoEffect => error_code!(E0031),
+            Self::ConstCycle => error_code!(E0032),
+            Self::UseCycle => error_code!(E0033),
+            Self::TypeAliasCycle => error_code!(E0034),
+            Self::ImplAliasCycle => error_code!(E0035),
+            Self::ImplRequirementCycle => error_code!(E0036),
+            Self::MissingMember(_) => error_code!(E0003),
+            Self::WrongNumberOfParameters { .. } => error_code!(E0037),
+            Self::WrongNumberOfArguments { .. } => error_code!(E0038),
+            Self::WrongParameterType { .. } => error_code!(E0039),
+            Self::VariantCtorNotImmutable => error_code!(E0040),
+            Self::TraitParamMutable { .. } => error_code!(E0041),
+            Self::ParameterShouldBeReference { .. } => error_code!(E0042),
+            Self::ParameterShouldNotBeReference { .. } => error_code!(E0043),
+            Self::WrongParameterName { .. } => error_code!(E0044),
+            Self::WrongGenericParamTraitForImplFunction { .. } => error_code!(E0045),
+            Self::WrongGenericParamKindForImplFunction { .. } => error_code!(E0046),
+            Self::WrongType { .. } => error_code!(E0047),
+            Self::InconsistentBinding => error_code!(E0048),
+            Self::WrongArgumentType { .. } => error_code!(E0049),
+            Self::WrongReturnType { .. } => error_code!(E0050),
+            Self::WrongExprType { .. } => error_code!(E0051),
+            Self::WrongNumberOfGenericParamsForImplFunction { .. } => error_code!(E0052),
+            Self::WrongReturnTypeForImpl { .. } => error_code!(E0053),
+            Self::AmbiguousTrait { .. } => error_code!(E0054),
+            Self::VariableNotFound(_) => error_code!(E0055),
+            Self::MissingVariableInPattern => error_code!(E0056),
+            Self::StructMemberRedefinition { .. } => error_code!(E0057),
+            Self::EnumVariantRedefinition { .. } => error_code!(E0058),
+            Self::InfiniteSizeType(_) => error_code!(E0059),
+            Self::ArrayOfZeroSizedElements(_) => error_code!(E0060),
+            Self::ParamNameRedefinition { .. } => error_code!(E0061),
+            Self::ConditionNotBool(_) => error_code!(E0062),
+            Self::IncompatibleArms { .. } => error_code!(E0063),
+            Self::TypeHasNoMembers { .. } => error_code!(E0064),
+            Self::CannotCallMethod { .. } => error_code!(E0002),
+            Self::NoSuchStructMember { .. } => error_code!(E0065),
             Self::NoSuchTypeMember { .. } => error_code!(E0007),
-            _ => return None,
-        })
+            Self::MemberNotVisible(_) => error_code!(E0066),
+            Self::NoSuchVariant { .. } => error_code!(E0067),
+            Self::ReturnTypeNotErrorPropagateType => error_code!(E0068),
+            Self::IncompatibleErrorPropagateType { .. } => error_code!(E0069),
+            Self::ErrorPropagateOnNonErrorType(_) => error_code!(E0070),
+            Self::UnhandledMustUseType(_) => error_code!(E0071),
+            Self::UnstableFeature { .. } => error_code!(E0072),
+            Self::DeprecatedFeature { .. } => error_code!(E0073),
+            Self::InternalFeature { .. } => error_code!(E0074),
+            Self::FeatureMarkerDiagnostic(_) => error_code!(E0075),
+            Self::UnhandledMustUseFunction => error_code!(E0076),
+            Self::UnusedVariable => error_code!(E0001),
+            Self::UnusedConstant => error_code!(E0077),
+            Self::UnusedUse => error_code!(E0078),
+            Self::MultipleConstantDefinition(_) => error_code!(E0079),
+            Self::MultipleDefinitionforBinding(_) => error_code!(E0080),
+            Self::MultipleGenericItemDefinition(_) => error_code!(E0081),
+            Self::UnsupportedUseItemInStatement => error_code!(E0082),
+            Self::ConstGenericParamNotSupported => error_code!(E0083),
+            Self::NegativeImplsNotEnabled => error_code!(E0084),
+            Self::NegativeImplsOnlyOnImpls => error_code!(E0085),
+            Self::RefArgNotAVariable => error_code!(E0086),
+            Self::RefArgNotMutable(_) => error_code!(E0087),
+            Self::RefArgNotExplicit => error_code!(E0088),
+            Self::ImmutableArgWithModifiers => error_code!(E0089),
+            Self::AssignmentToImmutableVar(_) => error_code!(E0090),
+            Self::InvalidLhsForAssignment => error_code!(E0091),
+            Self::InvalidMemberExpression => error_code!(E0092),
+            Self::InvalidPath => error_code!(E0093),
+            Self::PathNotFound(_) => error_code!(E0006),
+            Self::AmbiguousPath(_) => error_code!(E0094),
+            Self::UseSelfNonMulti => error_code!(E0095),
+            Self::UseSelfEmptyPath => error_code!(E0096),
+            Self::UseStarEmptyPath => error_code!(E0097),
+            Self::GlobalUsesNotSupportedInEdition(_) => error_code!(E0098),
+            Self::TraitInTraitMustBeExplicit => error_code!(E0099),
+            Self::ImplInImplMustBeExplicit => error_code!(E0100),
+            Self::TraitItemForbiddenInTheTrait => error_code!(E0101),
+            Self::TraitItemForbiddenInItsImpl => error_code!(E0102),
+            Self::ImplItemForbiddenInTheImpl => error_code!(E0103),
+            Self::SuperUsedInRootModule => error_code!(E0104),
+            Self::SuperUsedInMacroCallTopLevel => error_code!(E0105),
+            Self::ItemNotVisible(..) => error_code!(E0106),
+            Self::UnusedImport(_) => error_code!(E0107),
+            Self::RedundantModifier { .. } => error_code!(E0108),
+            Self::ReferenceLocalVariable => error_code!(E0109),
+            Self::UnexpectedEnumPattern(_) => error_code!(E0110),
+            Self::UnexpectedStructPattern(_) => error_code!(E0111),
+            Self::UnexpectedTuplePattern(_) => error_code!(E0112),
+            Self::UnexpectedFixedSizeArrayPattern(_) => error_code!(E0113),
+            Self::WrongNumberOfTupleElements { .. } => error_code!(E0114),
+            Self::WrongNumberOfFixedSizeArrayElements { .. } => error_code!(E0115),
+            Self::WrongEnum { .. } => error_code!(E0116),
+            Self::InvalidCopyTraitImpl(_) => error_code!(E0117),
+            Self::InvalidDropTraitImpl(_) => error_code!(E0118),
+            Self::InvalidImplItem(_) => error_code!(E0119),
+            Self::MissingItemsInImpl(_) => error_code!(E0004),
+            Self::PassPanicAsNopanic { .. } => error_code!(E0120),
+            Self::PassConstAsNonConst { .. } => error_code!(E0121),
+            Self::PanicableFromNonPanicable => error_code!(E0122),
+            Self::PanicableExternFunction => error_code!(E0123),
+            Self::MacroGeneratedCodeParserDiagnostic(parser_diagnostic) => {
+                parser_diagnostic.kind.error_code()
+            }
+            Self::PluginDiagnostic(diagnostic) => {
+                diagnostic.error_code.unwrap_or(error_code!(E0124))
+            }
+            Self::NameDefinedMultipleTimes(_) => error_code!(E0125),
+            Self::NonPrivateUseStar => error_code!(E0126),
+            Self::NamedArgumentsAreNotSupported => error_code!(E0127),
+            Self::ArgPassedToNegativeImpl => error_code!(E0128),
+            Self::UnnamedArgumentFollowsNamed => error_code!(E0129),
+            Self::NamedArgumentMismatch { .. } => error_code!(E0130),
+            Self::UnsupportedOutsideOfFunction(_) => error_code!(E0131),
+            Self::UnsupportedConstant => error_code!(E0132),
+            Self::FailedConstantCalculation => error_code!(E0133),
+            Self::ConstantCalculationDepthExceeded => error_code!(E0134),
+            Self::InnerFailedConstantCalculation(inner, _) => inner.kind.error_code(),
+            Self::DivisionByZero => error_code!(E0135),
+            Self::ExternTypeWithImplGenericsNotSupported => error_code!(E0136),
+            Self::MissingSemicolon => error_code!(E0137),
+            Self::TraitMismatch { .. } => error_code!(E0138),
+            Self::DerefNonRef { .. } => error_code!(E0139),
+            Self::InternalInferenceError(_) => error_code!(E0140),
+            Self::NoImplementationOfIndexOperator { .. } => error_code!(E0141),
+            Self::NoImplementationOfTrait { .. } => error_code!(E0142),
+            Self::CallExpressionRequiresFunction { .. } => error_code!(E0143),
+            Self::MultipleImplementationOfIndexOperator(_) => error_code!(E0144),
+            Self::UnsupportedInlineArguments => error_code!(E0145),
+            Self::RedundantInlineAttribute => error_code!(E0146),
+            Self::InlineAttrForExternFunctionNotAllowed => error_code!(E0147),
+            Self::InlineAlwaysWithImplGenericArgNotAllowed => error_code!(E0148),
+            Self::TailExpressionNotAllowedInLoop => error_code!(E0149),
+            Self::ContinueOnlyAllowedInsideALoop => error_code!(E0150),
+            Self::BreakOnlyAllowedInsideALoop => error_code!(E0151),
+            Self::BreakWithValueOnlyAllowedInsideALoop => error_code!(E0152),
+            Self::ErrorPropagateNotAllowedInsideALoop => error_code!(E0153),
+            Self::ImplicitPrecedenceAttrForExternFunctionNotAllowed => error_code!(E0154),
+            Self::RedundantImplicitPrecedenceAttribute => error_code!(E0155),
+            Self::UnsupportedImplicitPrecedenceArguments => error_code!(E0156),
+            Self::UnsupportedFeatureAttrArguments => error_code!(E0157),
+            Self::UnsupportedAllowAttrArguments => error_code!(E0158),
+            Self::UnsupportedPubArgument => error_code!(E0159),
+            Self::UnknownStatementAttribute => error_code!(E0160),
+            Self::InlineMacroNotFound(_) => error_code!(E0161),
+            Self::InlineMacroFailed(_) => error_code!(E0162),
+            Self::InlineMacroNoMatchingRule(_) => error_code!(E0163),
+            Self::MacroCallToNotAMacro(_) => error_code!(E0164),
+            Self::UnknownGenericParam(_) => error_code!(E0165),
+            Self::PositionalGenericAfterNamed => error_code!(E0166),
+            Self::GenericArgDuplicate(_) => error_code!(E0167),
+            Self::TooManyGenericArguments { .. } => error_code!(E0168),
+            Self::GenericArgOutOfOrder(_) => error_code!(E0169),
+            Self::CouponForExternFunctionNotAllowed => error_code!(E0170),
+            Self::CouponArgumentNoModifiers => error_code!(E0171),
+            Self::CouponsDisabled => error_code!(E0172),
+            Self::FixedSizeArrayTypeNonSingleType => error_code!(E0173),
+            Self::FixedSizeArrayTypeEmptySize => error_code!(E0174),
+            Self::FixedSizeArrayNonNumericSize => error_code!(E0175),
+            Self::FixedSizeArrayNonSingleValue => error_code!(E0176),
+            Self::FixedSizeArraySizeTooBig => error_code!(E0177),
+            Self::SelfNotSupportedInContext => error_code!(E0178),
+            Self::SelfMustBeFirst => error_code!(E0179),
+            Self::DollarNotSupportedInContext => error_code!(E0180),
+            Self::UnknownResolverModifier { .. } => error_code!(E0181),
+            Self::EmptyPathAfterResolverModifier => error_code!(E0182),
+            Self::DerefCycle { .. } => error_code!(E0183),
+            Self::CompilerTraitReImplementation { .. } => error_code!(E0184),
+            Self::ClosureInGlobalScope => error_code!(E0185),
+            Self::MaybeMissingColonColon => error_code!(E0186),
+            Self::CallingShadowedFunction { .. } => error_code!(E0187),
+            Self::RefClosureArgument => error_code!(E0188),
+            Self::RefClosureParam => error_code!(E0189),
+            Self::MustBeNextToTypeOrTrait { .. } => error_code!(E0190),
+            Self::MutableCapturedVariable => error_code!(E0191),
+            Self::NonTraitTypeConstrained { .. } => error_code!(E0192),
+            Self::DuplicateTypeConstraint { .. } => error_code!(E0193),
+            Self::TypeConstraintsSyntaxNotEnabled => error_code!(E0194),
+            Self::PatternMissingArgs(_) => error_code!(E0195),
+            Self::UndefinedMacroPlaceholder(_) => error_code!(E0196),
+            Self::UserDefinedInlineMacrosDisabled => error_code!(E0197),
+            Self::NonNeverLetElseType => error_code!(E0198),
+            Self::UnsupportedLintAttrArguments(_) => error_code!(E0199),
+            Self::UnfulfilledLintExpectation(_) => error_code!(E0200),
+        }
     }
 }
 
@@ -202,6 +202,8 @@ struct InlineMacroExpansion<'db> {
     pub content: Arc<str>,
     pub name: String,
     pub info: MacroExpansionInfo<'db>,
+    /// The name of the plugin that expanded the macro, if it is not a declarative macro.
+    pub plugin: Option<String>,
 }
 
 /// Context for computing the semantic model of expression trees.
@@ -684,7 +686,12 @@ fn expand_inline_macro<'db>(
             expansion_mappings: info.mappings.clone(),
             parent_macro_call_data,
         }));
-        Ok(InlineMacroExpansion { content: expanded_code.text, name: macro_name.to_string(), info })
+        Ok(InlineMacroExpansion {
+            content: expanded_code.text,
+            name: macro_name.to_string(),
+            info,
+            plugin: None,
+        })
     } else if let Some(macro_plugin_id) =
         ctx.db.crate_inline_macro_plugins(crate_id).get(&macro_name.to_string()).cloned()
     {
@@ -708,6 +715,7 @@ fn expand_inline_macro<'db>(
         );
         let mut diag_added = None;
         for diagnostic in result.diagnostics {
+            let diagnostic = diagnostic.with_plugin(macro_name.to_string());
             diag_added = match diagnostic.inner_span {
                 None => Some(
                     ctx.diagnostics.report(diagnostic.stable_ptr, PluginDiagnostic(diagnostic)),
@@ -733,6 +741,7 @@ fn expand_inline_macro<'db>(
                 kind: if code.is_unhygienic { MacroKind::Unhygienic } else { MacroKind::Plugin },
                 vars_to_expose: vec![],
             },
+            plugin: Some(macro_name.to_string()),
         })
     } else {
         let macro_name = syntax.path(db).as_syntax_node().get_text_without_trivia(db);
@@ -746,7 +755,7 @@ fn compute_expr_inline_macro_semantic<'db>(
     syntax: &ast::ExprInlineMacro<'db>,
 ) -> Maybe<Expr<'db>> {
     let prev_macro_call_data = ctx.resolver.macro_call_data.clone();
-    let InlineMacroExpansion { content, name, info } = expand_inline_macro(ctx, syntax)?;
+    let InlineMacroExpansion { content, name, info, plugin } = expand_inline_macro(ctx, syntax)?;
     let new_file_id = FileLongId::Virtual(VirtualFile {
         parent: Some(syntax.stable_ptr(ctx.db).untyped().file_id(ctx.db)),
         name,
@@ -754,6 +763,7 @@ fn compute_expr_inline_macro_semantic<'db>(
         code_mappings: info.mappings.clone(),
         kind: FileKind::Expr,
         original_item_removed: true,
+        plugin,
     })
     .intern(ctx.db);
     let expr_syntax = ctx.db.file_expr_syntax(new_file_id)?;
@@ -816,7 +826,7 @@ fn expand_macro_for_statement<'db>(
     statements_ids: &mut Vec<StatementId>,
 ) -> Maybe<Option<ExprAndId<'db>>> {
     let prev_macro_call_data = ctx.resolver.macro_call_data.clone();
-    let InlineMacroExpansion { content, name, info } = expand_inline_macro(ctx, syntax)?;
+    let InlineMacroExpansion { content, name, info, plugin } = expand_inline_macro(ctx, syntax)?;
     let new_file_id = FileLongId::Virtual(VirtualFile {
         parent: Some(syntax.stable_ptr(ctx.db).untyped().file_id(ctx.db)),
         name,
@@ -824,6 +834,7 @@ fn expand_macro_for_statement<'db>(
         code_mappings: info.mappings.clone(),
         kind: FileKind::StatementList,
         original_item_removed: true,
+        plugin,
     })
     .intern(ctx.db);
     let parser_diagnostics = ctx.db.file_syntax_diagnostics(new_file_id);
@@ -1040,8 +1051,12 @@ fn compute_expr_binary_semantic<'db>(
                 |actual_ty, expected_ty| WrongArgumentType { expected_ty, actual_ty },
             )?;
             // Verify the variable argument is mutable.
-            if !ctx.semantic_defs[&member_path.base_var()].is_mut() {
-                ctx.diagnostics.report(syntax.stable_ptr(db), AssignmentToImmutableVar);
+            let base_var = &ctx.semantic_defs[&member_path.base_var()];
+            if !base_var.is_mut() {
+                ctx.diagnostics.report(
+                    syntax.stable_ptr(db),
+                    AssignmentToImmutableVar(base_var.stable_ptr(db)),
+                );
             }
             Ok(Expr::Assignment(ExprAssignment {
                 ref_arg: member_path,
@@ -4042,8 +4057,9 @@ fn expr_function_call<'db>(
                 return Err(ctx.diagnostics.report(arg.deref(), RefArgNotAVariable));
             };
             // Verify the variable argument is mutable.
-            if !ctx.semantic_defs[&ref_arg.base_var()].is_mut() {
-                ctx.diagnostics.report(arg.deref(), RefArgNotMutable);
+            let base_var = &ctx.semantic_defs[&ref_arg.base_var()];
+            if !base_var.is_mut() {
+                ctx.diagnostics.report(arg.deref(), RefArgNotMutable(base_var.stable_ptr(ctx.db)));
             }
             // Verify that it is passed explicitly as 'ref'.
             if mutability != Mutability::Reference {
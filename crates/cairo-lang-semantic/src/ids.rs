@@ -28,6 +28,10 @@ impl AnalyzerPlugin for AnalyzerPluginLongId {
         // as the underlying plugin object.
         self.0.plugin_type_id()
     }
+
+    fn name(&self) -> String {
+        self.0.name()
+    }
 }
 
 // `PartialEq` and `Hash` cannot be derived on `Arc<dyn ...>`,
@@ -7,6 +7,7 @@ use cairo_lang_defs::plugin_utils::{
     PluginResultTrait, escape_node, not_legacy_macro_diagnostic, try_extract_unnamed_arg,
     unsupported_bracket_diagnostic,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_syntax::node::ast::WrappedArgList;
 use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
@@ -41,19 +42,28 @@ impl InlineMacroExprPlugin for AssertMacro {
         let Some(value) = arguments.next() else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    arguments_syntax.lparen(db).stable_ptr(db),
-                    format!("Macro `{}` requires at least 1 argument.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        arguments_syntax.lparen(db).stable_ptr(db),
+                        format!("Macro `{}` requires at least 1 argument.", Self::NAME),
+                    )
+                    .with_error_code(error_code!(E3034)),
+                ],
             };
         };
         let Some(value) = try_extract_unnamed_arg(db, &value) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    value.stable_ptr(db),
-                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        value.stable_ptr(db),
+                        format!(
+                            "Macro `{}` requires the first argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!(E3035)),
+                ],
             };
         };
         let f = "__formatter_for_assert_macro__";
@@ -4,6 +4,7 @@ use cairo_lang_defs::plugin::{
     PluginGeneratedFile,
 };
 use cairo_lang_defs::plugin_utils::{PluginResultTrait, not_legacy_macro_diagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::ids::{CodeMapping, CodeOrigin};
 use cairo_lang_filesystem::span::TextSpan;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
@@ -39,15 +40,18 @@ impl InlineMacroExprPlugin for ConstevalIntMacro {
         let mut diagnostics = vec![];
         const DEPRECATION_FEATURE: &str = r#""deprecated-consteval-int-macro""#;
         if !metadata.allowed_features.contains(DEPRECATION_FEATURE) {
-            diagnostics.push(PluginDiagnostic::warning(
-                syntax.stable_ptr(db),
-                format!(
-                    "Usage of deprecated macro `{}` with no `#[feature({DEPRECATION_FEATURE})]` \
-                     attribute. Note: Use simple calculations instead, as these are supported in \
-                     const context.",
-                    Self::NAME
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    syntax.stable_ptr(db),
+                    format!(
+                        "Usage of deprecated macro `{}` with no \
+                         `#[feature({DEPRECATION_FEATURE})]` attribute. Note: Use simple \
+                         calculations instead, as these are supported in const context.",
+                        Self::NAME
+                    ),
+                )
+                .with_error_code(error_code!(E3042)),
+            );
         }
         let code = compute_constant_expr(db, &constant_expression, &mut diagnostics, syntax);
         InlinePluginResult {
@@ -140,12 +144,15 @@ pub fn compute_constant_expr<'db>(
                     ^ compute_constant_expr(db, &bin_expr.rhs(db), diagnostics, macro_ast)?,
             ),
             _ => {
-                diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                    db,
-                    macro_ast.stable_ptr(db),
-                    bin_expr.as_syntax_node(),
-                    "Unsupported binary operator in consteval_int macro".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error_with_inner_span(
+                        db,
+                        macro_ast.stable_ptr(db),
+                        bin_expr.as_syntax_node(),
+                        "Unsupported binary operator in consteval_int macro".to_string(),
+                    )
+                    .with_error_code(error_code!(E3043)),
+                );
                 None
             }
         },
@@ -154,12 +161,15 @@ pub fn compute_constant_expr<'db>(
                 Some(-compute_constant_expr(db, &un_expr.expr(db), diagnostics, macro_ast)?)
             }
             _ => {
-                diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                    db,
-                    macro_ast.stable_ptr(db),
-                    un_expr.as_syntax_node(),
-                    "Unsupported unary operator in consteval_int macro".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error_with_inner_span(
+                        db,
+                        macro_ast.stable_ptr(db),
+                        un_expr.as_syntax_node(),
+                        "Unsupported unary operator in consteval_int macro".to_string(),
+                    )
+                    .with_error_code(error_code!(E3044)),
+                );
                 None
             }
         },
@@ -167,12 +177,15 @@ pub fn compute_constant_expr<'db>(
             compute_constant_expr(db, &paren_expr.expr(db), diagnostics, macro_ast)
         }
         _ => {
-            diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                db,
-                macro_ast.stable_ptr(db),
-                value.as_syntax_node(),
-                "Unsupported expression in consteval_int macro".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error_with_inner_span(
+                    db,
+                    macro_ast.stable_ptr(db),
+                    value.as_syntax_node(),
+                    "Unsupported expression in consteval_int macro".to_string(),
+                )
+                .with_error_code(error_code!(E3045)),
+            );
             None
         }
     }
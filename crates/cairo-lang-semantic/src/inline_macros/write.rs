@@ -8,6 +8,7 @@ use cairo_lang_defs::plugin::{
 use cairo_lang_defs::plugin_utils::{
     not_legacy_macro_diagnostic, try_extract_unnamed_arg, unsupported_bracket_diagnostic,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::span::{TextSpan, TextWidth};
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
@@ -182,6 +183,7 @@ impl<'db> FormattingInfo<'db> {
                 inner_span,
                 message.to_string(),
             )
+            .with_error_code(error_code!(E3036))
         };
         let Some(formatter_arg) = args_iter.next() else {
             return Err(vec![error_with_inner_span(
@@ -281,28 +283,34 @@ impl<'db> FormattingInfo<'db> {
                 let argument_info = match extract_placeholder_argument(&mut format_iter) {
                     Ok(argument_info) => argument_info,
                     Err(error_message) => {
-                        diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                            builder.db,
-                            self.macro_ast.stable_ptr(builder.db),
-                            self.format_string_arg.as_syntax_node(),
-                            format!("Invalid format string: {error_message}."),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error_with_inner_span(
+                                builder.db,
+                                self.macro_ast.stable_ptr(builder.db),
+                                self.format_string_arg.as_syntax_node(),
+                                format!("Invalid format string: {error_message}."),
+                            )
+                            .with_error_code(error_code!(E3037)),
+                        );
                         return;
                     }
                 };
                 match argument_info.source {
                     PlaceholderArgumentSource::Positional(positional) => {
                         let Some(arg) = self.args.get(positional) else {
-                            diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                                builder.db,
-                                self.macro_ast.stable_ptr(builder.db),
-                                self.format_string_arg.as_syntax_node(),
-                                format!(
-                                    "Invalid reference to positional argument {positional} (there \
-                                     are {} arguments).",
-                                    self.args.len()
-                                ),
-                            ));
+                            diagnostics.push(
+                                PluginDiagnostic::error_with_inner_span(
+                                    builder.db,
+                                    self.macro_ast.stable_ptr(builder.db),
+                                    self.format_string_arg.as_syntax_node(),
+                                    format!(
+                                        "Invalid reference to positional argument {positional} \
+                                         (there are {} arguments).",
+                                        self.args.len()
+                                    ),
+                                )
+                                .with_error_code(error_code!(E3038)),
+                            );
                             return;
                         };
                         arg_used[positional] = true;
@@ -361,28 +369,34 @@ impl<'db> FormattingInfo<'db> {
                     pending_chars.push('}');
                     format_iter.next();
                 } else {
-                    diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                        builder.db,
-                        self.macro_ast.stable_ptr(builder.db),
-                        self.format_string_arg.as_syntax_node(),
-                        "Closing `}` without a matching `{`.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error_with_inner_span(
+                            builder.db,
+                            self.macro_ast.stable_ptr(builder.db),
+                            self.format_string_arg.as_syntax_node(),
+                            "Closing `}` without a matching `{`.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3039)),
+                    );
                 }
             } else {
                 pending_chars.push(c);
             }
         }
         if missing_args > 0 {
-            diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                builder.db,
-                self.macro_ast.stable_ptr(builder.db),
-                self.format_string_arg.as_syntax_node(),
-                format!(
-                    "{} positional arguments in format string, but only {} arguments.",
-                    self.args.len() + missing_args,
-                    self.args.len()
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error_with_inner_span(
+                    builder.db,
+                    self.macro_ast.stable_ptr(builder.db),
+                    self.format_string_arg.as_syntax_node(),
+                    format!(
+                        "{} positional arguments in format string, but only {} arguments.",
+                        self.args.len() + missing_args,
+                        self.args.len()
+                    ),
+                )
+                .with_error_code(error_code!(E3040)),
+            );
             return;
         }
         if with_newline {
@@ -405,12 +419,15 @@ impl<'db> FormattingInfo<'db> {
         builder.add_str("}\n");
         for (position, used) in arg_used.into_iter().enumerate() {
             if !used {
-                diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                    builder.db,
-                    self.macro_ast.stable_ptr(builder.db),
-                    self.args[position].as_syntax_node(),
-                    "Unused argument.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error_with_inner_span(
+                        builder.db,
+                        self.macro_ast.stable_ptr(builder.db),
+                        self.args[position].as_syntax_node(),
+                        "Unused argument.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3041)),
+                );
             }
         }
     }
@@ -32,12 +32,12 @@ fn test_enum() {
     assert_eq!(
         diagnostics,
         indoc! {r#"
-        error: Redefinition of variant "a" on enum "test::A".
+        error[E0058]: Redefinition of variant "a" on enum "test::A".
          --> lib.cairo:5:5
             a: (),
             ^^^^^
 
-        error: Redefinition of variant "a" on enum "test::A".
+        error[E0058]: Redefinition of variant "a" on enum "test::A".
          --> lib.cairo:6:5
             a: ()
             ^^^^^
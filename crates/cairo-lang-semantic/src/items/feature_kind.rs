@@ -4,8 +4,8 @@ use cairo_lang_diagnostics::DiagnosticsBuilder;
 use cairo_lang_filesystem::db::{FilesGroup, default_crate_settings};
 use cairo_lang_filesystem::ids::{CrateId, StrRef};
 use cairo_lang_syntax::attribute::consts::{
-    ALLOW_ATTR, DEPRECATED_ATTR, FEATURE_ATTR, INTERNAL_ATTR, UNSTABLE_ATTR, UNUSED,
-    UNUSED_IMPORTS, UNUSED_VARIABLES,
+    ALLOW_ATTR, DENY_ATTR, DEPRECATED_ATTR, EXPECT_ATTR, FEATURE_ATTR, INTERNAL_ATTR,
+    UNSTABLE_ATTR, UNUSED_IMPORTS, WARN_ATTR,
 };
 use cairo_lang_syntax::attribute::structured::{
     self, AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -20,6 +20,7 @@ use salsa::Database;
 use crate::SemanticDiagnostic;
 use crate::db::SemanticGroup;
 use crate::diagnostic::{SemanticDiagnosticKind, SemanticDiagnostics, SemanticDiagnosticsBuilder};
+use crate::lint::{declared_lints_named, lint_by_code, lints_named};
 
 /// The kind of a feature for an item.
 #[derive(Clone, Debug, PartialEq, Eq, salsa::Update)]
@@ -139,6 +140,9 @@ pub struct FeatureConfig<'db> {
     pub allowed_features: OrderedHashSet<StrRef<'db>>,
     /// Which lints are allowed.
     pub allowed_lints: OrderedHashSet<StrRef<'db>>,
+    /// Which lints are given a level other than allowed, which overrides allowing them by an outer
+    /// item.
+    pub leveled_lints: OrderedHashSet<StrRef<'db>>,
 }
 
 impl<'db> FeatureConfig<'db> {
@@ -157,6 +161,11 @@ impl<'db> FeatureConfig<'db> {
                 restore.allowed_lints_to_remove.push(allow_lint);
             }
         }
+        for leveled_lint in other.leveled_lints {
+            if self.allowed_lints.swap_remove(&leveled_lint) {
+                restore.allowed_lints_to_add.push(leveled_lint);
+            }
+        }
         restore
     }
 
@@ -168,6 +177,9 @@ impl<'db> FeatureConfig<'db> {
         for allow_lint in restore.allowed_lints_to_remove {
             self.allowed_lints.swap_remove(&allow_lint);
         }
+        for allow_lint in restore.allowed_lints_to_add {
+            self.allowed_lints.insert(allow_lint);
+        }
     }
 }
 
@@ -178,6 +190,8 @@ pub struct FeatureConfigRestore<'db> {
     features_to_remove: Vec<StrRef<'db>>,
     /// The previous state of the allowed lints.
     allowed_lints_to_remove: Vec<StrRef<'db>>,
+    /// The allowed lints removed from the configuration by the override.
+    allowed_lints_to_add: Vec<StrRef<'db>>,
 }
 
 impl FeatureConfigRestore<'_> {
@@ -217,19 +231,43 @@ pub fn feature_config_from_ast_item<'db>(
         diagnostics,
         |value| {
             let allowed = value.as_syntax_node().get_text_without_trivia(db);
-            // Expand lint group UNUSED to include all `unused` lints.
-            if allowed == UNUSED {
-                let all_unused_lints = [UNUSED_VARIABLES, UNUSED_IMPORTS];
-                return all_unused_lints.iter().all(|&lint| {
+            // Lint groups are expanded to their lints. Error codes are handled when reporting the
+            // diagnostics, as they may refer to a part of the warnings of a lint.
+            if lint_by_code(allowed).is_some() {
+                return true;
+            }
+            if let Some(lints) = lints_named(allowed) {
+                for lint in lints {
                     let _already_allowed = config.allowed_lints.insert(lint.into());
-                    db.declared_allows(crate_id).contains(lint)
-                });
+                }
+                return true;
             }
 
             let _already_allowed = config.allowed_lints.insert(allowed.into());
             db.declared_allows(crate_id).contains(allowed)
         },
     );
+    for attr in [WARN_ATTR, DENY_ATTR, EXPECT_ATTR] {
+        process_feature_attr_kind(
+            db,
+            syntax,
+            attr,
+            || SemanticDiagnosticKind::UnsupportedLintAttrArguments(attr.into()),
+            diagnostics,
+            |value| {
+                // An error code may refer to a part of the warnings of a lint, so the whole lint
+                // is reported, and the level of each warning is resolved when reporting it.
+                let text = value.as_syntax_node().get_text_without_trivia(db);
+                let Some(lints) = declared_lints_named(db, text) else {
+                    return false;
+                };
+                for lint in lints {
+                    let _already_leveled = config.leveled_lints.insert(lint.into());
+                }
+                true
+            },
+        );
+    }
     config
 }
 
@@ -281,7 +319,11 @@ pub fn feature_config_from_item_and_parent_modules<'db>(
                 if all_declarations_pub {
                     let _already_allowed = allowed_lints.insert(UNUSED_IMPORTS.into());
                 }
-                break FeatureConfig { allowed_features: OrderedHashSet::default(), allowed_lints };
+                break FeatureConfig {
+                    allowed_features: OrderedHashSet::default(),
+                    allowed_lints,
+                    leveled_lints: OrderedHashSet::default(),
+                };
             }
             ModuleId::Submodule(id) => {
                 current_module_id = id.parent_module(defs_db);
@@ -115,6 +115,7 @@ pub fn priv_macro_call_data<'db>(
         code_mappings: expanded_code.code_mappings.clone(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     })
     .intern(db);
     let macro_call_module = ModuleId::MacroCall { id: macro_call_id, generated_file_id: new_file };
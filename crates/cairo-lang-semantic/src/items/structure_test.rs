@@ -33,12 +33,12 @@ fn test_struct() {
     assert_eq!(
         diagnostics,
         indoc! {r#"
-        error: Redefinition of member "a" on struct "test::A".
+        error[E0057]: Redefinition of member "a" on struct "test::A".
          --> lib.cairo:6:5
             a: (),
             ^^^^^
 
-        error: Redefinition of member "a" on struct "test::A".
+        error[E0057]: Redefinition of member "a" on struct "test::A".
          --> lib.cairo:7:5
             a: ()
             ^^^^^
@@ -11,11 +11,13 @@ pub mod ids;
 pub mod inline_macros;
 pub mod items;
 pub mod keyword;
+pub mod lint;
 pub mod lookup_item;
 pub mod lsp_helpers;
 pub mod plugin;
 pub mod resolve;
 pub mod substitution;
+pub mod suggestions;
 pub mod types;
 pub mod usage;
 
@@ -0,0 +1,347 @@
+//! Lints: named sets of warnings, whose level may be configured per crate in its settings, and per
+//! item by the `allow`, `warn`, `deny` and `expect` attributes.
+//!
+//! A lint may be referred to by its name, by the name of a lint group including it, or by the error
+//! code of one of its warnings. The innermost attribute applying to a warning determines its level,
+//! and the crate settings determine the level of warnings no attribute applies to. In both, an
+//! error code takes precedence over a lint name, which takes precedence over a group name. The
+//! lints of the lint passes of the compiler are allowed unless enabled by either, and the other
+//! lints are warned.
+//!
+//! Besides the lints of the compiler, lint passes may declare lints of their own, whose warnings
+//! have no error code, and are referred to by the lint name only.
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_diagnostics::{
+    DiagnosticLocation, Diagnostics, DiagnosticsBuilder, ErrorCode, error_code,
+};
+use cairo_lang_filesystem::db::{FilesGroup, LintLevel};
+use cairo_lang_filesystem::ids::{CrateId, FileId, FileKind, FileLongId};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::attribute::consts::{
+    ABSURD_EXTREME_COMPARISONS, ALLOW_ATTR, DENY_ATTR, DEPRECATED_ATTR, EXPECT_ATTR,
+    FELT252_DIVISION, IMPLICIT_SELF_PATHS, MISSING_PAYLOAD_PATTERNS, NEEDLESS_REF_PARAMS,
+    REDUNDANT_CLONES, SHADOWED_FUNCTION_CALLS, UNBOUNDED_LOOPS, UNFULFILLED_LINT_EXPECTATIONS,
+    UNKNOWN_LINTS, UNREACHABLE_CODE, UNREACHABLE_PATTERNS, UNSTABLE_FEATURES, UNUSED,
+    UNUSED_CONSTANTS, UNUSED_IMPORTS, UNUSED_MUST_USE, UNUSED_SYSCALL_RESULTS, UNUSED_VARIABLES,
+    WARN_ATTR,
+};
+use cairo_lang_syntax::attribute::structured::{AttributeArgVariant, AttributeStructurize};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode, ast};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use salsa::{Database, Setter};
+
+use crate::SemanticDiagnostic;
+use crate::db::{SemanticGroup, semantic_group_input};
+use crate::diagnostic::SemanticDiagnosticKind;
+
+#[cfg(test)]
+#[path = "lint_test.rs"]
+mod test;
+
+/// A named set of warnings.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lint {
+    pub name: &'static str,
+    /// The error codes of the warnings of the lint.
+    pub codes: &'static [ErrorCode],
+}
+
+/// All the lints of the compiler.
+pub const LINTS: &[Lint] = &[
+    Lint { name: UNUSED_VARIABLES, codes: &[error_code!(E0001)] },
+    Lint { name: UNUSED_IMPORTS, codes: &[error_code!(E0078), error_code!(E0107)] },
+    Lint { name: UNUSED_CONSTANTS, codes: &[error_code!(E0077)] },
+    Lint { name: UNUSED_MUST_USE, codes: &[error_code!(E0071), error_code!(E0076)] },
+    Lint { name: DEPRECATED_ATTR, codes: &[error_code!(E0073)] },
+    Lint { name: UNSTABLE_FEATURES, codes: &[error_code!(E0072)] },
+    Lint {
+        name: IMPLICIT_SELF_PATHS,
+        codes: &[
+            error_code!(E0099),
+            error_code!(E0100),
+            error_code!(E0101),
+            error_code!(E0102),
+            error_code!(E0103),
+        ],
+    },
+    Lint { name: SHADOWED_FUNCTION_CALLS, codes: &[error_code!(E0187)] },
+    Lint { name: MISSING_PAYLOAD_PATTERNS, codes: &[error_code!(E0195)] },
+    Lint { name: UNKNOWN_LINTS, codes: &[error_code!(E0158), error_code!(E0199)] },
+    Lint { name: UNFULFILLED_LINT_EXPECTATIONS, codes: &[error_code!(E0200)] },
+    // Reported by the lowering phase.
+    Lint { name: UNREACHABLE_CODE, codes: &[error_code!(E2001)] },
+    Lint { name: UNREACHABLE_PATTERNS, codes: &[error_code!(E2105)] },
+    // Reported by the lint passes of the lowering phase.
+    Lint { name: REDUNDANT_CLONES, codes: &[error_code!(E2014)] },
+    Lint { name: ABSURD_EXTREME_COMPARISONS, codes: &[error_code!(E2015)] },
+    Lint { name: FELT252_DIVISION, codes: &[error_code!(E2016)] },
+    Lint { name: UNUSED_SYSCALL_RESULTS, codes: &[error_code!(E2017)] },
+    Lint { name: NEEDLESS_REF_PARAMS, codes: &[error_code!(E2018)] },
+    Lint { name: UNBOUNDED_LOOPS, codes: &[error_code!(E2019)] },
+];
+
+/// The lints of the lint passes of the compiler, which are allowed by default: they point at code
+/// that is likely a mistake, rather than code that is invalid, so existing code must opt in.
+pub const ALLOWED_BY_DEFAULT_LINTS: &[&str] = &[
+    REDUNDANT_CLONES,
+    ABSURD_EXTREME_COMPARISONS,
+    FELT252_DIVISION,
+    UNUSED_SYSCALL_RESULTS,
+    NEEDLESS_REF_PARAMS,
+    UNBOUNDED_LOOPS,
+];
+
+/// The lint groups, with the names of the lints they include.
+pub const LINT_GROUPS: &[(&str, &[&str])] =
+    &[(UNUSED, &[UNUSED_VARIABLES, UNUSED_IMPORTS, UNUSED_CONSTANTS])];
+
+/// The attributes setting the level of lints.
+const LINT_LEVEL_ATTRS: [&str; 4] = [ALLOW_ATTR, WARN_ATTR, DENY_ATTR, EXPECT_ATTR];
+
+/// Returns the lint with the given name.
+pub fn lint_by_name(name: &str) -> Option<&'static Lint> {
+    LINTS.iter().find(|lint| lint.name == name)
+}
+
+/// Returns the lint of the warnings with the given error code.
+pub fn lint_by_code(code: &str) -> Option<&'static Lint> {
+    LINTS.iter().find(|lint| lint.codes.iter().any(|lint_code| lint_code.as_str() == code))
+}
+
+/// Returns the names of the lints the given name refers to: a lint, a lint group or the error code
+/// of a lint's warnings. Returns `None` if the name refers to none of them.
+pub fn lints_named(name: &str) -> Option<Vec<&'static str>> {
+    if let Some((_, lints)) = LINT_GROUPS.iter().find(|(group, _)| *group == name) {
+        return Some(lints.to_vec());
+    }
+    lint_by_name(name).or_else(|| lint_by_code(name)).map(|lint| vec![lint.name])
+}
+
+/// Returns the names of the lints declared by lint passes, other than the lints of the compiler.
+pub fn declared_lints(db: &dyn Database) -> &[String] {
+    semantic_group_input(db).declared_lints(db).as_deref().unwrap_or_default()
+}
+
+/// Sets the names of the lints declared by lint passes, other than the lints of the compiler.
+pub fn set_declared_lints(db: &mut dyn Database, lints: Vec<String>) {
+    semantic_group_input(db).set_declared_lints(db).to(Some(lints));
+}
+
+/// Returns the names of the lints the given name refers to, as [lints_named], or the name itself if
+/// it is of a lint declared by a lint pass.
+pub fn declared_lints_named<'a>(db: &'a dyn Database, name: &'a str) -> Option<Vec<&'a str>> {
+    if let Some(lints) = lints_named(name) {
+        return Some(lints);
+    }
+    declared_lints(db).iter().any(|lint| lint == name).then(|| vec![name])
+}
+
+/// Returns the level of the warnings of the given lint that no lint level setting applies to.
+pub fn default_lint_level(lint: &str) -> LintLevel {
+    if ALLOWED_BY_DEFAULT_LINTS.contains(&lint) { LintLevel::Allow } else { LintLevel::Warn }
+}
+
+/// Returns the name of the lint of a warning of the given lint or error code, along with the names
+/// the warning may be referred to by, from the most specific to the least: the code, the name of
+/// its lint and the names of the groups including it. The lint is found by the code if not given.
+/// Returns `None` if the warning is not of a lint.
+fn lint_keys(lint: Option<&str>, code: Option<ErrorCode>) -> Option<(&str, Vec<&str>)> {
+    let lint = match lint {
+        Some(lint) => lint,
+        None => lint_by_code(code?.as_str())?.name,
+    };
+    let groups = LINT_GROUPS.iter().filter(|(_, lints)| lints.contains(&lint));
+    Some((
+        lint,
+        code.map(|code| code.as_str())
+            .into_iter()
+            .chain([lint])
+            .chain(groups.map(|(group, _)| *group))
+            .collect(),
+    ))
+}
+
+/// Resolves the levels of the warnings of a crate, and tracks the `expect` attributes suppressing
+/// them.
+pub struct LintLevelResolver<'db> {
+    crate_id: CrateId<'db>,
+    /// The arguments of the `expect` attributes that suppressed a warning.
+    fulfilled_expectations: OrderedHashSet<SyntaxStablePtrId<'db>>,
+}
+impl<'db> LintLevelResolver<'db> {
+    pub fn new(crate_id: CrateId<'db>) -> Self {
+        Self { crate_id, fulfilled_expectations: Default::default() }
+    }
+
+    /// Returns the level of a warning of the given lint, or with the given error code if the lint
+    /// is not given, at the given user location. Returns `None` if the warning is not of a lint.
+    ///
+    /// An `expect` attribute suppresses the warning, so its level is [LintLevel::Allow].
+    pub fn level(
+        &mut self,
+        db: &'db dyn SemanticGroup,
+        lint: Option<&str>,
+        code: Option<ErrorCode>,
+        location: &DiagnosticLocation<'db>,
+    ) -> Option<LintLevel> {
+        let (lint, keys) = lint_keys(lint, code)?;
+        let mut node = module_file_syntax(db, location.file_id)
+            .map(|root| root.lookup_offset(db, location.span.start));
+        while let Some(current) = node {
+            for ancestor in current.ancestors_with_self(db) {
+                let Some((attr, arg)) = lint_level_attr(db, ancestor, &keys) else {
+                    continue;
+                };
+                return Some(match attr {
+                    ALLOW_ATTR => LintLevel::Allow,
+                    WARN_ATTR => LintLevel::Warn,
+                    DENY_ATTR => LintLevel::Deny,
+                    _ => {
+                        self.fulfilled_expectations.insert(arg);
+                        LintLevel::Allow
+                    }
+                });
+            }
+            // Continue from the declaration of the module in the file, if it is not inline.
+            node = declaring_module_item(db, current.stable_ptr(db).file_id(db));
+        }
+        let lints = db.crate_config(self.crate_id).map(|config| &config.settings.lints);
+        Some(
+            keys.iter()
+                .find_map(|key| lints.and_then(|lints| lints.get(*key)))
+                .copied()
+                .unwrap_or_else(|| default_lint_level(lint)),
+        )
+    }
+
+    /// Returns the diagnostics of the arguments of the `expect` attributes in the crate's files
+    /// that suppressed no warning.
+    pub fn unfulfilled_expectations(
+        &self,
+        db: &'db dyn SemanticGroup,
+    ) -> Diagnostics<'db, SemanticDiagnostic<'db>> {
+        let mut diagnostics = DiagnosticsBuilder::default();
+        let files: OrderedHashSet<FileId<'db>> = db
+            .crate_modules(self.crate_id)
+            .iter()
+            .filter_map(|module_id| db.module_files(*module_id).ok())
+            .flatten()
+            .copied()
+            .filter(|file_id| is_user_file(db, *file_id))
+            .collect();
+        for file_id in files {
+            let Some(root) = module_file_syntax(db, file_id) else {
+                continue;
+            };
+            for node in root.descendants(db) {
+                if node.kind(db) != SyntaxKind::Attribute {
+                    continue;
+                }
+                let attr = ast::Attribute::from_syntax_node(db, node).structurize(db);
+                if attr.id != EXPECT_ATTR {
+                    continue;
+                }
+                let Some(arg) = single_lint_arg(db, &attr) else {
+                    continue;
+                };
+                if declared_lints_named(db, arg.text).is_some()
+                    && !self.fulfilled_expectations.contains(&arg.stable_ptr)
+                {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::new(arg.stable_ptr),
+                        SemanticDiagnosticKind::UnfulfilledLintExpectation(arg.text.into()),
+                    ));
+                }
+            }
+        }
+        diagnostics.build()
+    }
+}
+
+/// A single unnamed argument of a lint level attribute.
+struct LintArg<'db> {
+    text: &'db str,
+    stable_ptr: SyntaxStablePtrId<'db>,
+}
+
+/// Returns the single unnamed argument of the given attribute, if it has one.
+fn single_lint_arg<'db>(
+    db: &'db dyn Database,
+    attr: &cairo_lang_syntax::attribute::structured::Attribute<'db>,
+) -> Option<LintArg<'db>> {
+    let [arg] = &attr.args[..] else {
+        return None;
+    };
+    let AttributeArgVariant::Unnamed(value) = &arg.variant else {
+        return None;
+    };
+    let node = value.as_syntax_node();
+    Some(LintArg { text: node.get_text_without_trivia(db), stable_ptr: node.stable_ptr(db) })
+}
+
+/// Returns the lint level attribute of the given node that refers to the most specific of the
+/// given keys, along with its argument. Of several such attributes, the last one is returned.
+fn lint_level_attr<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    keys: &[&str],
+) -> Option<(&'static str, SyntaxStablePtrId<'db>)> {
+    let attr_list = node
+        .get_children(db)
+        .iter()
+        .find(|child| child.kind(db) == SyntaxKind::AttributeList)
+        .map(|child| ast::AttributeList::from_syntax_node(db, *child))?;
+    let attrs = attr_list
+        .elements(db)
+        .filter_map(|attr| {
+            let attr = attr.structurize(db);
+            let name = LINT_LEVEL_ATTRS.into_iter().find(|name| attr.id == *name)?;
+            Some((name, single_lint_arg(db, &attr)?))
+        })
+        .collect::<Vec<_>>();
+    keys.iter().find_map(|key| {
+        attrs
+            .iter()
+            .rev()
+            .find(|(_, arg)| arg.text == *key)
+            .map(|(name, arg)| (*name, arg.stable_ptr))
+    })
+}
+
+/// Returns the item declaring the module whose main file is the given file, if it is declared by a
+/// `mod` item without a body.
+fn declaring_module_item<'db>(
+    db: &'db dyn SemanticGroup,
+    file_id: FileId<'db>,
+) -> Option<SyntaxNode<'db>> {
+    db.file_modules(file_id).ok()?.iter().find_map(|module_id| {
+        let ModuleId::Submodule(submodule_id) = module_id else {
+            return None;
+        };
+        let item = submodule_id.stable_ptr(db).untyped();
+        (item.file_id(db) != file_id && db.module_main_file(*module_id) == Ok(file_id))
+            .then(|| item.lookup(db))
+    })
+}
+
+/// Returns the syntax of the given file, if it is a module file.
+fn module_file_syntax<'db>(db: &'db dyn Database, file_id: FileId<'db>) -> Option<SyntaxNode<'db>> {
+    if file_id.kind(db) != FileKind::Module {
+        return None;
+    }
+    Some(db.file_module_syntax(file_id).ok()?.as_syntax_node())
+}
+
+/// Returns whether the given file holds user code, rather than code generated by a plugin.
+fn is_user_file(db: &dyn Database, file_id: FileId<'_>) -> bool {
+    match file_id.long(db) {
+        FileLongId::OnDisk(_) => true,
+        FileLongId::Virtual(virtual_file) => virtual_file.parent.is_none(),
+        FileLongId::External(_) => false,
+    }
+}
@@ -0,0 +1,78 @@
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use super::{LINT_GROUPS, LINTS, lint_by_name, lints_named};
+use crate::test_utils::{
+    SemanticDatabaseForTesting, get_crate_semantic_diagnostics, setup_test_crate_ex,
+};
+
+#[test]
+fn test_lints_registry() {
+    let mut codes = OrderedHashSet::<_>::default();
+    for lint in LINTS {
+        for code in lint.codes {
+            assert!(codes.insert(*code), "Code {code} is of several lints.");
+        }
+    }
+    for (group, lints) in LINT_GROUPS {
+        assert!(lint_by_name(group).is_none(), "Group `{group}` is named as a lint.");
+        for lint in *lints {
+            assert!(lint_by_name(lint).is_some(), "Group `{group}` includes unknown `{lint}`.");
+        }
+    }
+}
+
+#[test]
+fn test_lints_named() {
+    assert_eq!(
+        lints_named("unused"),
+        Some(vec!["unused_variables", "unused_imports", "unused_constants"])
+    );
+    assert_eq!(lints_named("unused_must_use"), Some(vec!["unused_must_use"]));
+    assert_eq!(lints_named("E0107"), Some(vec!["unused_imports"]));
+    assert_eq!(lints_named("E0006"), None);
+    assert_eq!(lints_named("no_such_lint"), None);
+}
+
+#[test]
+fn test_lint_level_attributes() {
+    let db = &SemanticDatabaseForTesting::default();
+    let crate_id = setup_test_crate_ex(
+        db,
+        indoc! {"
+            #[allow(unused_variables)]
+            mod inner {
+                fn allowed() {
+                    let a = 1;
+                }
+
+                #[expect(unused_variables)]
+                fn expected() {
+                    let b = 1;
+                }
+            }
+
+            #[deny(no_such_lint)]
+            fn unknown() {}
+        "},
+        Some("edition = \"2024_07\""),
+        None,
+    );
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+        indoc! {"
+            warning[E0199]: `deny` attribute argument not supported.
+             --> lib.cairo:13:7
+            #[deny(no_such_lint)]
+                  ^^^^^^^^^^^^^^
+
+            warning[E0001]: Unused variable. Consider ignoring by prefixing with `_`.
+             --> lib.cairo:9:13
+                    let b = 1;
+                        ^
+
+        "}
+    );
+}
@@ -2,7 +2,9 @@ use std::any::{self, Any};
 use std::sync::Arc;
 
 use cairo_lang_defs::ids::{InlineMacroExprPluginId, MacroPluginId, ModuleId};
-use cairo_lang_defs::plugin::{InlineMacroExprPlugin, MacroPlugin, NamedPlugin, PluginDiagnostic};
+use cairo_lang_defs::plugin::{
+    InlineMacroExprPlugin, MacroPlugin, NamedPlugin, PluginDiagnostic, plugin_type_name,
+};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::db::SemanticGroup;
@@ -31,6 +33,12 @@ pub trait AnalyzerPlugin: std::fmt::Debug + Sync + Send + Any {
     fn plugin_type_id(&self) -> any::TypeId {
         self.type_id()
     }
+
+    /// The name of the plugin, reported with the diagnostics it originates.
+    /// Defaults to the name of the plugin type.
+    fn name(&self) -> String {
+        plugin_type_name::<Self>()
+    }
 }
 
 /// A suite of plugins.
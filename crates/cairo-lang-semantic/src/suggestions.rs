@@ -0,0 +1,213 @@
+//! Suggested fixes of semantic diagnostics.
+
+use cairo_lang_diagnostics::{Applicability, DiagnosticLocation, Suggestion, TextEdit};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode, ast};
+use salsa::Database;
+
+#[cfg(test)]
+#[path = "suggestions_test.rs"]
+mod test;
+
+/// Returns the suggestion to prefix an unused variable with `_`, given the node defining it.
+pub fn prefix_unused_variable<'db>(
+    db: &'db dyn Database,
+    definition: SyntaxNode<'db>,
+) -> Option<Suggestion<'db>> {
+    let file_id = definition.stable_ptr(db).file_id(db);
+    let mut suggestion =
+        Suggestion::new("Prefix the variable with `_`", Applicability::MachineApplicable);
+    let name = match definition.kind(db) {
+        SyntaxKind::Param => definition.cast::<ast::Param>(db)?.name(db),
+        SyntaxKind::TerminalIdentifier => {
+            if let Some(pattern) = definition.parent_of_type::<ast::PatternIdentifier>(db) {
+                // A shorthand member of a struct pattern is named after the member, which must
+                // then be given explicitly.
+                let pattern_node = pattern.as_syntax_node();
+                if pattern_node.parent_kind(db) == Some(SyntaxKind::PatternStructParamList) {
+                    suggestion = suggestion.with_edit(TextEdit::insert(
+                        file_id,
+                        pattern_node.span_start_without_trivia(db),
+                        format!("{}: ", pattern.name(db).text(db)),
+                    ));
+                }
+            }
+            definition.cast::<ast::TerminalIdentifier>(db)?
+        }
+        _ => return None,
+    };
+    Some(suggestion.with_edit(TextEdit::insert(
+        file_id,
+        name.as_syntax_node().span_start_without_trivia(db),
+        "_",
+    )))
+}
+
+/// Returns the suggestion to make a variable mutable, given the node defining it.
+pub fn make_variable_mutable<'db>(
+    db: &'db dyn Database,
+    definition: SyntaxNode<'db>,
+) -> Option<Suggestion<'db>> {
+    let (modifiers, name) = match definition.kind(db) {
+        SyntaxKind::Param => {
+            let param = definition.cast::<ast::Param>(db)?;
+            (Some(param.modifiers(db)), param.name(db))
+        }
+        // A variable without modifiers may also be parsed as a path of a single segment.
+        SyntaxKind::TerminalIdentifier => (
+            definition
+                .parent_of_type::<ast::PatternIdentifier>(db)
+                .map(|pattern| pattern.modifiers(db)),
+            definition.cast::<ast::TerminalIdentifier>(db)?,
+        ),
+        _ => return None,
+    };
+    // A variable with a modifier is already either `mut` or `ref`.
+    if modifiers.is_some_and(|modifiers| !modifiers.elements_vec(db).is_empty()) {
+        return None;
+    }
+    Some(
+        Suggestion::new(
+            format!("Make `{}` mutable", name.text(db)),
+            Applicability::MachineApplicable,
+        )
+        .with_edit(TextEdit::insert(
+            definition.stable_ptr(db).file_id(db),
+            name.as_syntax_node().span_start_without_trivia(db),
+            "mut ",
+        )),
+    )
+}
+
+/// Returns the suggestion to pass an argument as `ref`, given the expression of the argument.
+pub fn pass_argument_as_ref<'db>(
+    db: &'db dyn Database,
+    arg_expr: SyntaxNode<'db>,
+) -> Option<Suggestion<'db>> {
+    // The expression is inside an argument clause, inside the argument.
+    let arg = arg_expr.parent(db)?.parent_of_type::<ast::Arg>(db)?;
+    if !arg.modifiers(db).elements_vec(db).is_empty() {
+        return None;
+    }
+    Some(Suggestion::new("Pass the argument as `ref`", Applicability::MachineApplicable).with_edit(
+        TextEdit::insert(
+            arg_expr.stable_ptr(db).file_id(db),
+            arg.as_syntax_node().span_start_without_trivia(db),
+            "ref ",
+        ),
+    ))
+}
+
+/// Returns the suggestion to remove an unused import, given the leaf of its use path.
+///
+/// The path is removed from the innermost list of paths containing other paths, or the whole `use`
+/// item is removed if there is no such list.
+pub fn remove_unused_import<'db>(
+    db: &'db dyn Database,
+    leaf: SyntaxNode<'db>,
+) -> Option<Suggestion<'db>> {
+    let mut node = leaf;
+    let span = loop {
+        let parent = node.parent(db)?;
+        match parent.kind(db) {
+            SyntaxKind::UsePathSingle => node = parent,
+            SyntaxKind::UsePathList => {
+                // The children of a list alternate between the paths and their separators.
+                let children = parent.get_children(db);
+                if children.len() <= 2 {
+                    node = parent.parent(db)?;
+                    continue;
+                }
+                let index = children.iter().position(|child| *child == node)?;
+                break match children.get(index + 2) {
+                    Some(next) => TextSpan::new(
+                        node.span_start_without_trivia(db),
+                        next.span_start_without_trivia(db),
+                    ),
+                    // The last path is removed along with the separator preceding it.
+                    None => TextSpan::new(
+                        children[index - 2].span_end_without_trivia(db),
+                        node.span_end_without_trivia(db),
+                    ),
+                };
+            }
+            SyntaxKind::ItemUse => break lines_span(db, parent),
+            _ => return None,
+        }
+    };
+    Some(Suggestion::new("Remove the unused import", Applicability::MachineApplicable).with_edit(
+        TextEdit::remove(DiagnosticLocation { file_id: leaf.stable_ptr(db).file_id(db), span }),
+    ))
+}
+
+/// Returns the suggestions to import one of the given traits, in the module containing the given
+/// node. The import is only machine applicable if it is the only suggested one.
+pub fn import_trait<'db>(
+    db: &'db dyn Database,
+    node: SyntaxNode<'db>,
+    trait_paths: &[String],
+) -> Vec<Suggestion<'db>> {
+    let Some(items) = node.ancestor_of_type::<ast::ModuleItemList>(db) else {
+        return vec![];
+    };
+    // The imports are added before the first item that is not the module's documentation, and
+    // before its leading comments.
+    let Some(first_item) = items
+        .elements(db)
+        .find(|item| !matches!(item, ast::ModuleItem::HeaderDoc(_)))
+        .map(|item| item.as_syntax_node())
+    else {
+        return vec![];
+    };
+    let file_id = node.stable_ptr(db).file_id(db);
+    let indentation = indentation(db, first_item);
+    let applicability = if trait_paths.len() == 1 {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    trait_paths
+        .iter()
+        .map(|trait_path| {
+            Suggestion::new(format!("Import `{trait_path}`"), applicability).with_edit(
+                TextEdit::insert(
+                    file_id,
+                    first_item.span(db).start,
+                    format!("{indentation}use {trait_path};\n"),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Returns the indentation of the line the given node starts in, assuming it is the first node in
+/// the line.
+pub fn indentation(db: &dyn Database, node: SyntaxNode<'_>) -> String {
+    let file_id = node.stable_ptr(db).file_id(db);
+    let column = node
+        .span_start_without_trivia(db)
+        .position_in_file(db, file_id)
+        .map_or(0, |position| position.col);
+    " ".repeat(column)
+}
+
+/// Returns the span of the lines the given node spans, including the newline ending them, if the
+/// node is the only code in these lines. Otherwise, returns the span of the node.
+pub fn lines_span(db: &dyn Database, node: SyntaxNode<'_>) -> TextSpan {
+    let span = node.span_without_trivia(db);
+    let Some(content) = db.file_content(node.stable_ptr(db).file_id(db)) else {
+        return span;
+    };
+    let content: &str = content.long(db).as_ref();
+    let before = content[..span.start.as_u32() as usize].trim_end_matches([' ', '\t']);
+    let after = content[span.end.as_u32() as usize..].trim_start_matches([' ', '\t', '\r']);
+    if !(before.is_empty() || before.ends_with('\n'))
+        || !(after.is_empty() || after.starts_with('\n'))
+    {
+        return span;
+    }
+    let end = content.len() - after.strip_prefix('\n').unwrap_or(after).len();
+    TextSpan::new(TextOffset::from_str(before), TextOffset::from_str(&content[..end]))
+}
@@ -0,0 +1,161 @@
+use cairo_lang_diagnostics::{Applicability, apply_suggestions};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_log::test;
+
+use crate::test_utils::{
+    SemanticDatabaseForTesting, get_crate_semantic_diagnostics, setup_test_crate_ex,
+};
+
+/// Returns the given code with the machine-applicable suggestions of its diagnostics applied,
+/// along with the descriptions of all the suggestions.
+fn fix(content: &str) -> (String, Vec<String>) {
+    let db = &SemanticDatabaseForTesting::default();
+    let crate_id = setup_test_crate_ex(db, content, Some("edition = \"2024_07\""), None);
+    let diagnostics = get_crate_semantic_diagnostics(db, crate_id);
+    let suggestions = diagnostics
+        .format_with_severity(db, &Default::default())
+        .into_iter()
+        .flat_map(|diagnostic| diagnostic.details().unwrap().suggestions.clone())
+        .collect::<Vec<_>>();
+    let descriptions =
+        suggestions.iter().map(|s| format!("{}: {}", s.applicability, s.message)).collect();
+    let (fixed, _) = apply_suggestions(
+        content,
+        suggestions.iter().filter(|s| s.applicability == Applicability::MachineApplicable),
+    );
+    (fixed, descriptions)
+}
+
+#[test]
+fn test_variable_suggestions() {
+    let (fixed, descriptions) = fix(indoc! {"
+        struct Point {
+            x: felt252,
+            y: felt252,
+        }
+
+        fn foo(a: felt252, ref b: felt252) {
+            b += a;
+        }
+
+        fn bar(p: Point) {
+            let x = 3;
+            let Point { x, y } = p;
+            let z = 4;
+            z = 5;
+            foo(y, z);
+        }
+    "});
+    assert_eq!(
+        fixed,
+        indoc! {"
+            struct Point {
+                x: felt252,
+                y: felt252,
+            }
+
+            fn foo(a: felt252, ref b: felt252) {
+                b += a;
+            }
+
+            fn bar(p: Point) {
+                let _x = 3;
+                let Point { x: _x, y } = p;
+                let mut z = 4;
+                z = 5;
+                foo(y, ref z);
+            }
+        "}
+    );
+    assert_eq!(
+        descriptions,
+        [
+            "machine-applicable: Prefix the variable with `_`",
+            "machine-applicable: Make `z` mutable",
+            "machine-applicable: Make `z` mutable",
+            "machine-applicable: Pass the argument as `ref`",
+            "machine-applicable: Prefix the variable with `_`",
+        ]
+    );
+}
+
+#[test]
+fn test_unused_import_suggestions() {
+    let (fixed, descriptions) = fix(indoc! {"
+        use core::num::traits::{Zero, One};
+        use core::num::traits::Bounded;
+
+        fn foo() -> u32 {
+            One::one()
+        }
+    "});
+    assert_eq!(
+        fixed,
+        indoc! {"
+            use core::num::traits::{One};
+
+            fn foo() -> u32 {
+                One::one()
+            }
+        "}
+    );
+    assert_eq!(
+        descriptions,
+        [
+            "machine-applicable: Remove the unused import",
+            "machine-applicable: Remove the unused import"
+        ]
+    );
+}
+
+#[test]
+fn test_trait_import_suggestions() {
+    let (fixed, descriptions) = fix(indoc! {"
+        //! Module documentation.
+
+        mod a {
+            pub trait MyTrait {
+                fn foo(self: u32) -> u32;
+            }
+            impl MyImpl of MyTrait {
+                fn foo(self: u32) -> u32 {
+                    self
+                }
+            }
+        }
+
+        mod b {
+            /// Documentation of `bar`.
+            fn bar() -> u32 {
+                3_u32.foo()
+            }
+        }
+    "});
+    assert_eq!(
+        fixed,
+        indoc! {"
+            //! Module documentation.
+
+            mod a {
+                pub trait MyTrait {
+                    fn foo(self: u32) -> u32;
+                }
+                impl MyImpl of MyTrait {
+                    fn foo(self: u32) -> u32 {
+                        self
+                    }
+                }
+            }
+
+            mod b {
+                use crate::a::MyTrait;
+                /// Documentation of `bar`.
+                fn bar() -> u32 {
+                    3_u32.foo()
+                }
+            }
+        "}
+    );
+    assert_eq!(descriptions, ["machine-applicable: Import `crate::a::MyTrait`"]);
+}
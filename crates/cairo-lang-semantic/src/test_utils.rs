@@ -114,6 +114,7 @@ pub fn setup_test_crate_ex<'a>(
         code_mappings: [].into(),
         kind: FileKind::Module,
         original_item_removed: false,
+        plugin: None,
     });
 
     let settings: CrateSettings = if let Some(crate_settings) = crate_settings {
@@ -131,6 +132,7 @@ pub fn setup_test_crate_ex<'a>(
                 user_defined_inline_macros: true,
             },
             cfg_set: Default::default(),
+            lints: Default::default(),
         }
     };
 
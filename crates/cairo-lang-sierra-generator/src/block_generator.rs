@@ -4,14 +4,15 @@ mod test;
 
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_lowering as lowering;
 use cairo_lang_lowering::BlockId;
+use cairo_lang_sierra as sierra;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, enumerate, zip_eq};
 use lowering::borrow_check::analysis::StatementLocation;
 use lowering::{MatchArm, VarUsage};
 use sierra::extensions::lib_func::SierraApChange;
 use sierra::program;
-use {cairo_lang_lowering as lowering, cairo_lang_sierra as sierra};
 
 use crate::block_generator::sierra::ids::ConcreteLibfuncId;
 use crate::expr_generator_context::ExprGeneratorContext;
@@ -107,11 +108,28 @@ enum BlockGenStackElement<'db> {
 pub fn generate_function_statements<'db>(
     mut context: ExprGeneratorContext<'db, '_>,
 ) -> Maybe<Vec<StatementWithLocation<'db>>> {
+    generate_function_blocks(&mut context)?;
+    Ok(context.statements())
+}
+
+/// Generates Sierra statements for a function from the given [ExprGeneratorContext], as
+/// [generate_function_statements].
+///
+/// Returns the names of the Cairo variables represented by the Sierra variables of the function.
+pub fn generate_function_variable_names<'db>(
+    mut context: ExprGeneratorContext<'db, '_>,
+) -> Maybe<OrderedHashMap<sierra::ids::VarId, String>> {
+    generate_function_blocks(&mut context)?;
+    Ok(context.variable_names())
+}
+
+/// Generates the Sierra statements of the blocks of a function into the given context.
+fn generate_function_blocks(context: &mut ExprGeneratorContext<'_, '_>) -> Maybe<()> {
     let mut block_gen_stack = vec![BlockGenStackElement::Block(BlockId::root())];
     while let Some(element) = block_gen_stack.pop() {
         match element {
             BlockGenStackElement::Block(block_id) => {
-                generate_block_code(&mut context, &mut block_gen_stack, block_id)?
+                generate_block_code(context, &mut block_gen_stack, block_id)?
             }
             BlockGenStackElement::Statement(statement) => context.push_statement(statement),
             BlockGenStackElement::Config { starting_cairo_location, ap_tracking_state } => {
@@ -120,7 +138,7 @@ pub fn generate_function_statements<'db>(
             }
         }
     }
-    Ok(context.statements())
+    Ok(())
 }
 
 /// Generates Sierra for a given [lowering::Block].
@@ -9,6 +9,7 @@ use cairo_lang_sierra::extensions::lib_func::SierraApChange;
 use cairo_lang_sierra::extensions::{ConcreteType, GenericTypeEx};
 use cairo_lang_sierra::ids::ConcreteTypeId;
 use cairo_lang_utils::Upcast;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use lowering::ids::ConcreteFunctionWithBodyId;
 use {cairo_lang_lowering as lowering, cairo_lang_semantic as semantic};
 
@@ -200,6 +201,14 @@ pub trait SierraGenGroup: LoweringGroup + for<'db> Upcast<'db, dyn LoweringGroup
         function_id: ConcreteFunctionWithBodyId<'db>,
     ) -> Maybe<Arc<pre_sierra::Function<'db>>>;
 
+    /// Returns the names of the Cairo variables represented by the Sierra variables of the code of
+    /// a given function with body (as [Self::function_with_body_sierra]), for debugging.
+    #[salsa::invoke(function_generator::function_variable_names)]
+    fn function_variable_names<'db>(
+        &'db self,
+        function_id: ConcreteFunctionWithBodyId<'db>,
+    ) -> Maybe<Arc<OrderedHashMap<cairo_lang_sierra::ids::VarId, String>>>;
+
     /// Private query to generate a dummy function for a given function with body.
     #[salsa::invoke(function_generator::priv_get_dummy_function)]
     fn priv_get_dummy_function<'db>(
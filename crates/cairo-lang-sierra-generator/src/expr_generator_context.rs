@@ -4,6 +4,9 @@ use cairo_lang_lowering as lowering;
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::extensions::uninitialized::UninitializedType;
 use cairo_lang_sierra::program::{ConcreteTypeLongId, GenericArg};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode, ast};
 use cairo_lang_utils::Intern;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
@@ -89,6 +92,46 @@ impl<'db, 'a> ExprGeneratorContext<'db, 'a> {
         sierra_var
     }
 
+    /// Returns the names of the Cairo variables represented by the allocated Sierra variables -
+    /// the Sierra variables of the parameters and of the variables bound by patterns.
+    pub fn variable_names(&self) -> OrderedHashMap<cairo_lang_sierra::ids::VarId, String> {
+        self.variables
+            .iter_sorted_by_key(|(_, sierra_var)| sierra_var.id)
+            .filter_map(|(var, sierra_var)| {
+                let SierraGenVar::LoweringVar(lowering_var) = var else { return None };
+                let location = self.lowered.variables[*lowering_var].location;
+                let node = location.long(self.db).stable_location.syntax_node(self.db);
+                let name = match node.kind(self.db) {
+                    SyntaxKind::TerminalIdentifier => {
+                        ast::TerminalIdentifier::from_syntax_node(self.db, node).text(self.db)
+                    }
+                    SyntaxKind::PatternIdentifier => {
+                        ast::PatternIdentifier::from_syntax_node(self.db, node)
+                            .name(self.db)
+                            .text(self.db)
+                    }
+                    // A plain identifier pattern, such as `x` in `let x = ...;`.
+                    SyntaxKind::ExprPath
+                        if matches!(
+                            node.parent_kind(self.db),
+                            Some(
+                                SyntaxKind::StatementLet
+                                    | SyntaxKind::PatternList
+                                    | SyntaxKind::PatternListOr
+                                    | SyntaxKind::PatternEnumInnerPattern
+                                    | SyntaxKind::PatternStructParamWithExpr
+                            )
+                        ) =>
+                    {
+                        ast::ExprPath::from_syntax_node(self.db, node).identifier(self.db)
+                    }
+                    _ => return None,
+                };
+                Some((sierra_var.clone(), name.to_string()))
+            })
+            .collect()
+    }
+
     /// Same as [Self::get_sierra_variable] except that it operates of a list of variables.
     pub fn get_sierra_variables(
         &mut self,
@@ -13,7 +13,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use itertools::{Itertools, zip_eq};
 
-use crate::block_generator::generate_function_statements;
+use crate::block_generator::{generate_function_statements, generate_function_variable_names};
 use crate::db::SierraGenGroup;
 use crate::expr_generator_context::ExprGeneratorContext;
 use crate::lifetime::{SierraGenVar, find_variable_lifetime};
@@ -71,7 +71,6 @@ fn get_function_ap_change_and_code<'db>(
     lowered_function: &Lowered<'db>,
     analyze_ap_change_result: AnalyzeApChangesResult,
 ) -> Maybe<Arc<pre_sierra::Function<'db>>> {
-    let root_block = lowered_function.blocks.root_block()?;
     let AnalyzeApChangesResult { known_ap_change, local_variables, ap_tracking_configuration } =
         analyze_ap_change_result;
 
@@ -85,6 +84,58 @@ fn get_function_ap_change_and_code<'db>(
         &lifetime,
         ap_tracking_configuration,
     );
+    let FunctionPrologue { label_id, parameters, sierra_local_variables } =
+        generate_function_prologue(
+            &mut context,
+            lowered_function,
+            &local_variables,
+            known_ap_change,
+        )?;
+
+    // Generate the function's code.
+    let statements = generate_function_statements(context)?;
+
+    let statements = add_store_statements(
+        db,
+        statements,
+        &|concrete_lib_func_id: ConcreteLibfuncId| -> LibfuncInfo {
+            LibfuncInfo { signature: get_libfunc_signature(db, concrete_lib_func_id) }
+        },
+        sierra_local_variables,
+        &parameters,
+    );
+
+    // TODO(spapini): Don't intern objects for the semantic model outside the crate. These should
+    // be regarded as private.
+    Ok(pre_sierra::Function {
+        id: db.intern_sierra_function(function_id.function_id(db)?),
+        body: statements,
+        entry_point: label_id,
+        parameters,
+    }
+    .into())
+}
+
+/// The beginning of the code of a function with body, generated before its blocks.
+struct FunctionPrologue<'db> {
+    /// The label of the function's body.
+    label_id: pre_sierra::LabelId<'db>,
+    /// The parameters of the function.
+    parameters: Vec<cairo_lang_sierra::program::Param>,
+    /// The allocated local variables of the function.
+    sierra_local_variables: LocalVariables,
+}
+
+/// Generates the beginning of the code of a function with body into the given context: its label,
+/// its parameters and the allocation of its local variables.
+fn generate_function_prologue<'db>(
+    context: &mut ExprGeneratorContext<'db, '_>,
+    lowered_function: &Lowered<'db>,
+    local_variables: &OrderedHashSet<lowering::VariableId>,
+    known_ap_change: bool,
+) -> Maybe<FunctionPrologue<'db>> {
+    let db = context.get_db();
+    let root_block = lowered_function.blocks.root_block()?;
 
     // If the function starts with `revoke_ap_tracking` then we can avoid
     // the first `disable_ap_tracking`.
@@ -113,7 +164,7 @@ fn get_function_ap_change_and_code<'db>(
 
     context.push_statement(label);
 
-    let sierra_local_variables = allocate_local_variables(&mut context, &local_variables)?;
+    let sierra_local_variables = allocate_local_variables(context, local_variables)?;
 
     // Revoking ap tracking as the first non-local command for unknown ap-change function, to allow
     // proper ap-equation solving. TODO(orizi): Fix the solver to not require this constraint.
@@ -126,28 +177,30 @@ fn get_function_ap_change_and_code<'db>(
         context.set_ap_tracking(false);
     }
 
-    // Generate the function's code.
-    let statements = generate_function_statements(context)?;
+    Ok(FunctionPrologue { label_id, parameters, sierra_local_variables })
+}
 
-    let statements = add_store_statements(
+/// Query implementation of [SierraGenGroup::function_variable_names].
+pub fn function_variable_names<'db>(
+    db: &'db dyn SierraGenGroup,
+    function_id: ConcreteFunctionWithBodyId<'db>,
+) -> Maybe<Arc<OrderedHashMap<cairo_lang_sierra::ids::VarId, String>>> {
+    let lowered_function = &*db.lowered_body(function_id, LoweringStage::Final)?;
+    let AnalyzeApChangesResult { known_ap_change, local_variables, ap_tracking_configuration } =
+        analyze_ap_changes(db, lowered_function)?;
+    let lifetime = find_variable_lifetime(lowered_function, &local_variables)?;
+
+    // The code is generated as for the function's Sierra, so the same Sierra variables are
+    // allocated.
+    let mut context = ExprGeneratorContext::new(
         db,
-        statements,
-        &|concrete_lib_func_id: ConcreteLibfuncId| -> LibfuncInfo {
-            LibfuncInfo { signature: get_libfunc_signature(db, concrete_lib_func_id) }
-        },
-        sierra_local_variables,
-        &parameters,
+        lowered_function,
+        function_id,
+        &lifetime,
+        ap_tracking_configuration,
     );
-
-    // TODO(spapini): Don't intern objects for the semantic model outside the crate. These should
-    // be regarded as private.
-    Ok(pre_sierra::Function {
-        id: db.intern_sierra_function(function_id.function_id(db)?),
-        body: statements,
-        entry_point: label_id,
-        parameters,
-    }
-    .into())
+    generate_function_prologue(&mut context, lowered_function, &local_variables, known_ap_change)?;
+    Ok(generate_function_variable_names(context)?.into())
 }
 
 /// Query implementation of [SierraGenGroup::priv_get_dummy_function].
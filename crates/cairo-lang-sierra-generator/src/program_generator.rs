@@ -9,8 +9,9 @@ use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_lowering::ids::ConcreteFunctionWithBodyId;
 use cairo_lang_sierra::extensions::GenericLibfuncEx;
 use cairo_lang_sierra::extensions::core::CoreLibfunc;
-use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId};
+use cairo_lang_sierra::ids::{ConcreteLibfuncId, ConcreteTypeId, VarId};
 use cairo_lang_sierra::program::{self, DeclaredTypeInfo, Program, StatementIdx};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
@@ -293,6 +294,23 @@ pub fn get_sierra_program_for_functions<'db>(
     }))
 }
 
+/// Returns the names of the Cairo variables represented by Sierra variables in the given program,
+/// generated by the database, for each function by the index of its entry point statement.
+pub fn get_program_variable_names(
+    db: &dyn SierraGenGroup,
+    program: &Program,
+) -> OrderedHashMap<StatementIdx, OrderedHashMap<VarId, String>> {
+    program
+        .funcs
+        .iter()
+        .filter_map(|func| {
+            let function_id = db.lookup_sierra_function(func.id.clone()).body(db).ok()??;
+            let names = db.function_variable_names(function_id).ok()?;
+            Some((func.entry_point, names.as_ref().clone()))
+        })
+        .collect()
+}
+
 /// Given a list of functions and statements, generates a Sierra program.
 /// Returns the program and the locations of the statements in the program.
 pub fn assemble_program<'db>(
@@ -39,12 +39,26 @@ pub struct StatementsSourceCodeLocations {
         HashMap<StatementIdx, Vec<(SourceFileFullPath, SourceCodeSpan, bool)>>,
 }
 
+/// The key of the annotations containing the [StatementsSourceCodeLocations].
+const ANNOTATIONS_KEY: &str = "github.com/software-mansion/cairo-coverage";
+
 impl From<StatementsSourceCodeLocations> for Annotations {
     fn from(value: StatementsSourceCodeLocations) -> Self {
         let mapping = serde_json::to_value(value.statements_to_code_location_map).unwrap();
         OrderedHashMap::from([(
-            "github.com/software-mansion/cairo-coverage".to_string(),
+            ANNOTATIONS_KEY.to_string(),
             serde_json::Value::from_iter([("statements_code_locations", mapping)]),
         )])
     }
 }
+
+impl StatementsSourceCodeLocations {
+    /// Extracts the locations from the annotations of a Sierra program's debug info.
+    /// Returns `None` if the annotations do not contain valid locations.
+    pub fn from_annotations(annotations: &Annotations) -> Option<Self> {
+        let mapping = annotations.get(ANNOTATIONS_KEY)?.get("statements_code_locations")?;
+        Some(Self {
+            statements_to_code_location_map: serde_json::from_value(mapping.clone()).ok()?,
+        })
+    }
+}
@@ -1,33 +1,82 @@
 use std::collections::HashMap;
 
 use cairo_lang_utils::extract_matches;
-use num_bigint::BigInt;
-use num_integer::Integer;
-use num_traits::{One, ToPrimitive, Zero};
-use starknet_types_core::felt::{Felt as Felt252, NonZeroFelt as NonZeroFelt252};
+use itertools::{chain, repeat_n};
+use num_bigint::{BigInt, BigUint, ToBigInt};
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use starknet_types_core::curve::AffinePoint;
+use starknet_types_core::felt::{
+    CAIRO_PRIME_BIGINT, Felt as Felt252, NonZeroFelt as NonZeroFelt252,
+};
+use starknet_types_core::hash::{Pedersen, Poseidon, StarkHash};
 
 use super::LibfuncSimulationError;
+use super::starknet::{
+    Secp256Curve, StarknetSyscallHandler, SyscallResult, felt252_span, u256_value,
+};
 use super::value::CoreValue;
-use crate::extensions::array::ArrayConcreteLibfunc;
+use crate::extensions::array::{ArrayConcreteLibfunc, ConcreteMultiPopLibfunc};
+use crate::extensions::blake::BlakeConcreteLibfunc;
 use crate::extensions::boolean::BoolConcreteLibfunc;
-use crate::extensions::core::CoreConcreteLibfunc;
+use crate::extensions::bounded_int::{
+    BoundedIntConcreteLibfunc, BoundedIntConstrainConcreteLibfunc, BoundedIntTrimConcreteLibfunc,
+};
+use crate::extensions::bytes31::Bytes31ConcreteLibfunc;
+use crate::extensions::casts::{CastConcreteLibfunc, DowncastConcreteLibfunc};
+use crate::extensions::circuit::{
+    CircuitConcreteLibfunc, CircuitInfo, CircuitTypeConcrete, ConcreteCircuit,
+    ConcreteGetOutputLibFunc, ConcreteU96LimbsLessThanGuaranteeVerifyLibfunc,
+};
+use crate::extensions::const_type::{
+    ConstAsBoxConcreteLibfunc, ConstAsImmediateConcreteLibfunc, ConstConcreteLibfunc,
+    ConstConcreteType,
+};
+use crate::extensions::consts::SignatureAndConstConcreteLibfunc;
+use crate::extensions::core::{CoreConcreteLibfunc, CoreTypeConcrete};
+use crate::extensions::coupon::CouponConcreteLibfunc;
 use crate::extensions::ec::EcConcreteLibfunc;
-use crate::extensions::enm::{EnumConcreteLibfunc, EnumInitConcreteLibfunc};
+use crate::extensions::enm::{
+    EnumConcreteLibfunc, EnumFromBoundedIntConcreteLibfunc, EnumInitConcreteLibfunc,
+};
 use crate::extensions::felt252::{
     Felt252BinaryOpConcreteLibfunc, Felt252BinaryOperationConcrete, Felt252BinaryOperator,
     Felt252Concrete, Felt252ConstConcreteLibfunc, Felt252OperationWithConstConcreteLibfunc,
 };
-use crate::extensions::felt252_dict::Felt252DictConcreteLibfunc;
+use crate::extensions::felt252_dict::{
+    Felt252DictConcreteLibfunc, Felt252DictEntryConcreteLibfunc,
+};
 use crate::extensions::function_call::SignatureAndFunctionConcreteLibfunc;
 use crate::extensions::gas::GasConcreteLibfunc;
-use crate::extensions::int::unsigned::{
-    Uint8Concrete, Uint16Concrete, Uint32Concrete, Uint64Concrete,
-};
+use crate::extensions::gas_reserve::GasReserveConcreteLibfunc;
+use crate::extensions::int::signed::{SintConcrete, SintTraits};
+use crate::extensions::int::signed128::Sint128Concrete;
+use crate::extensions::int::unsigned::{UintConcrete, UintTraits};
 use crate::extensions::int::unsigned128::Uint128Concrete;
-use crate::extensions::int::{IntConstConcreteLibfunc, IntOperator};
+use crate::extensions::int::unsigned256::Uint256Concrete;
+use crate::extensions::int::unsigned512::Uint512Concrete;
+use crate::extensions::int::{
+    IntConstConcreteLibfunc, IntMulTraits, IntOperationConcreteLibfunc, IntOperator, IntTraits,
+};
+use crate::extensions::is_zero::IsZeroTraits;
+use crate::extensions::lib_func::SignatureAndTypeConcreteLibfunc;
 use crate::extensions::mem::MemConcreteLibfunc;
-use crate::extensions::structure::StructConcreteLibfunc;
-use crate::ids::FunctionId;
+use crate::extensions::nullable::NullableConcreteLibfunc;
+use crate::extensions::pedersen::PedersenConcreteLibfunc;
+use crate::extensions::poseidon::PoseidonConcreteLibfunc;
+use crate::extensions::qm31::{QM31BinaryOpConcreteLibfunc, QM31BinaryOperator, QM31Concrete};
+use crate::extensions::range::IntRangeConcreteLibfunc;
+use crate::extensions::squashed_felt252_dict::SquashedFelt252DictConcreteLibfunc;
+use crate::extensions::starknet::secp256::{
+    Secp256ConcreteLibfunc, Secp256OpConcreteLibfunc, Secp256Trait,
+};
+use crate::extensions::starknet::testing::{CheatcodeConcreteLibfunc, TestingConcreteLibfunc};
+use crate::extensions::starknet::{StarknetConcreteLibfunc, StarknetTypeConcrete};
+use crate::extensions::structure::{StructConcreteLibfunc, StructConcreteType};
+use crate::extensions::utils::Range;
+use crate::extensions::{ConcreteLibfunc, ConcreteType};
+use crate::ids::{ConcreteTypeId, FunctionId};
+use crate::program::GenericArg;
 
 /// Helper macro to take the inputs and return an error if the number of inputs is wrong, or the
 /// type of the expected inputs is wrong. Usage:
@@ -43,20 +92,36 @@ macro_rules! take_inputs {
     };
 }
 
+/// A function resolving the concrete types of the program, used for libfuncs that depend on type
+/// information (consts, dict default values, circuits, etc.).
+type GetType<'a, 'b> = dyn Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete> + 'b;
+
 /// Simulates the run of a single libfunc. Returns the value representations of the outputs, and
 /// the chosen branch given the inputs.
 ///
+/// `get_type` resolves concrete types of the program, for libfuncs whose behavior depends on their
+/// types.
+/// `syscall_handler` handles the Starknet syscalls and cheatcodes.
 /// `simulate_function` is a function that simulates running of a user function. It is provided here
 /// for the case where the extensions need to use it.
 pub fn simulate<
+    'a,
     GetStatementGasInfo: Fn() -> Option<i64>,
-    SimulateFunction: Fn(&FunctionId, Vec<CoreValue>) -> Result<Vec<CoreValue>, LibfuncSimulationError>,
+    GetTypeFn: Fn(&ConcreteTypeId) -> Option<&'a CoreTypeConcrete>,
+    SimulateFunction: Fn(
+        &FunctionId,
+        Vec<CoreValue>,
+        &mut dyn StarknetSyscallHandler,
+    ) -> Result<Vec<CoreValue>, LibfuncSimulationError>,
 >(
     libfunc: &CoreConcreteLibfunc,
     inputs: Vec<CoreValue>,
     get_statement_gas_info: GetStatementGasInfo,
+    get_type: GetTypeFn,
+    syscall_handler: &mut dyn StarknetSyscallHandler,
     simulate_function: SimulateFunction,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let get_type: &GetType<'a, '_> = &get_type;
     Ok(match libfunc {
         CoreConcreteLibfunc::Drop(_) => {
             let [_] = take_inputs(inputs)?;
@@ -66,137 +131,66 @@ pub fn simulate<
             let [value] = take_inputs(inputs)?;
             (vec![value.clone(), value], 0)
         }
-        CoreConcreteLibfunc::Ec(libfunc) => {
-            match libfunc {
-                EcConcreteLibfunc::TryNew(_) => {
-                    take_inputs!(let [CoreValue::Felt252(x), CoreValue::Felt252(y)] = inputs);
-                    const BETA: Felt252 = Felt252::from_hex_unchecked(
-                        "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
-                    );
-                    // If the point is on the curve use the fallthrough branch and return the
-                    // point.
-                    if y * y == x * x * x + x + BETA {
-                        (vec![CoreValue::EcPoint(x, y)], 0)
-                    } else {
-                        (vec![], 1)
-                    }
-                }
-                EcConcreteLibfunc::UnwrapPoint(_) => {
-                    take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
-                    (vec![CoreValue::Felt252(x), CoreValue::Felt252(y)], 0)
-                }
-                _ => unimplemented!(),
-            }
-        }
+        CoreConcreteLibfunc::Ec(libfunc) => simulate_ec_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::FunctionCall(SignatureAndFunctionConcreteLibfunc {
             function, ..
         })
-        | CoreConcreteLibfunc::CouponCall(SignatureAndFunctionConcreteLibfunc {
+        | CoreConcreteLibfunc::DummyFunctionCall(SignatureAndFunctionConcreteLibfunc {
+            function,
+            ..
+        }) => (simulate_function(&function.id, inputs, syscall_handler)?, 0),
+        CoreConcreteLibfunc::CouponCall(SignatureAndFunctionConcreteLibfunc {
             function, ..
-        }) => (simulate_function(&function.id, inputs)?, 0),
-        CoreConcreteLibfunc::Gas(GasConcreteLibfunc::WithdrawGas(_)) => {
-            let count = get_statement_gas_info()
-                .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)] = inputs);
-            if gas_counter >= count {
-                // Have enough gas - return reduced counter and jump to success branch.
-                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0)
-            } else {
-                // Don't have enough gas - return the same counter and jump to failure branch.
-                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)], 1)
-            }
+        }) => {
+            // The last input is the coupon, which is not passed to the function.
+            let mut inputs = inputs;
+            let Some(CoreValue::Coupon) = inputs.pop() else {
+                return Err(LibfuncSimulationError::WrongArgType);
+            };
+            (simulate_function(&function.id, inputs, syscall_handler)?, 0)
         }
-        CoreConcreteLibfunc::Gas(GasConcreteLibfunc::RedepositGas(_)) => {
-            let count = get_statement_gas_info()
-                .ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
-            take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
-            (vec![CoreValue::GasBuiltin(gas_counter + count)], 0)
+        CoreConcreteLibfunc::Gas(libfunc) => {
+            simulate_gas_libfunc(libfunc, inputs, get_statement_gas_info)?
         }
-        CoreConcreteLibfunc::Gas(GasConcreteLibfunc::GetAvailableGas(_)) => {
-            take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
-            (vec![CoreValue::GasBuiltin(gas_counter), CoreValue::Uint128(gas_counter as u128)], 0)
+        CoreConcreteLibfunc::GasReserve(GasReserveConcreteLibfunc::Create(_)) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter), CoreValue::Uint128(amount)
+            ] = inputs);
+            match i64::try_from(amount) {
+                Ok(amount) if gas_counter >= amount => (
+                    vec![
+                        CoreValue::RangeCheck,
+                        CoreValue::GasBuiltin(gas_counter - amount),
+                        CoreValue::GasReserve(amount as u128),
+                    ],
+                    0,
+                ),
+                _ => (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)], 1),
+            }
         }
-        CoreConcreteLibfunc::Gas(
-            GasConcreteLibfunc::BuiltinWithdrawGas(_)
-            | GasConcreteLibfunc::GetBuiltinCosts(_)
-            | GasConcreteLibfunc::GetUnspentGas(_),
-        ) => {
-            unimplemented!("Simulation of the builtin cost functionality is not implemented yet.")
+        CoreConcreteLibfunc::GasReserve(GasReserveConcreteLibfunc::Utilize(_)) => {
+            take_inputs!(let [CoreValue::GasBuiltin(gas_counter), CoreValue::GasReserve(amount)] = inputs);
+            (vec![CoreValue::GasBuiltin(gas_counter + amount as i64)], 0)
         }
         CoreConcreteLibfunc::BranchAlign(_) => {
             let [] = take_inputs(inputs)?;
             get_statement_gas_info().ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo)?;
             (vec![], 0)
         }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::New(_)) => {
-            let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Array(vec![])], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SpanFromTuple(_)) => {
-            take_inputs!(let [CoreValue::Struct(members)] = inputs);
-            (vec![CoreValue::Array(members)], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::TupleFromSpan(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Append(_)) => {
-            take_inputs!(let [CoreValue::Array(mut arr), element] = inputs);
-            arr.push(element);
-            (vec![CoreValue::Array(arr)], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::PopFront(_)) => {
-            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
-            if arr.is_empty() {
-                (vec![CoreValue::Array(arr)], 1)
-            } else {
-                let front = arr.remove(0);
-                (vec![CoreValue::Array(arr), front], 0)
-            }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::PopFrontConsume(_)) => {
-            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
-            if arr.is_empty() { (vec![CoreValue::Array(arr)], 1) } else { (vec![arr.remove(0)], 0) }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Get(_)) => {
-            take_inputs!(
-                let [CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Uint32(idx)] = inputs
-            );
-            match arr.get(idx as usize).cloned() {
-                Some(element) => (vec![CoreValue::RangeCheck, element], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
-            }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Slice(_)) => {
-            take_inputs!(let [
-                CoreValue::RangeCheck,
-                CoreValue::Array(arr),
-                CoreValue::Uint32(start),
-                CoreValue::Uint32(length),
-            ] = inputs);
-            match arr.get(start as usize..(start + length) as usize) {
-                Some(elements) => {
-                    (vec![CoreValue::RangeCheck, CoreValue::Array(elements.to_vec())], 0)
-                }
-                None => (vec![CoreValue::RangeCheck], 1),
-            }
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::Len(_)) => {
-            take_inputs!(let [CoreValue::Array(arr)] = inputs);
-            (vec![CoreValue::Uint32(arr.len() as u32)], 0)
-        }
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotPopFront(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotPopBack(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotMultiPopFront(_)) => todo!(),
-        CoreConcreteLibfunc::Array(ArrayConcreteLibfunc::SnapshotMultiPopBack(_)) => todo!(),
-        CoreConcreteLibfunc::Uint8(libfunc) => simulate_u8_libfunc(libfunc, inputs)?,
-        CoreConcreteLibfunc::Uint16(libfunc) => simulate_u16_libfunc(libfunc, inputs)?,
-        CoreConcreteLibfunc::Uint32(libfunc) => simulate_u32_libfunc(libfunc, inputs)?,
-        CoreConcreteLibfunc::Uint64(libfunc) => simulate_u64_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Array(libfunc) => simulate_array_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Uint8(libfunc) => simulate_uint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Uint16(libfunc) => simulate_uint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Uint32(libfunc) => simulate_uint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Uint64(libfunc) => simulate_uint_libfunc(libfunc, inputs, get_type)?,
         CoreConcreteLibfunc::Uint128(libfunc) => simulate_u128_libfunc(libfunc, inputs)?,
-        CoreConcreteLibfunc::Sint8(_)
-        | CoreConcreteLibfunc::Sint16(_)
-        | CoreConcreteLibfunc::Sint32(_)
-        | CoreConcreteLibfunc::Sint64(_)
-        | CoreConcreteLibfunc::Sint128(_) => {
-            unimplemented!("Simulation of signed integer libfuncs is not implemented yet.")
+        CoreConcreteLibfunc::Uint256(libfunc) => simulate_u256_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Uint512(libfunc) => simulate_u512_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::Sint8(libfunc) => simulate_sint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Sint16(libfunc) => simulate_sint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Sint32(libfunc) => simulate_sint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Sint64(libfunc) => simulate_sint_libfunc(libfunc, inputs, get_type)?,
+        CoreConcreteLibfunc::Sint128(libfunc) => {
+            simulate_sint128_libfunc(libfunc, inputs, get_type)?
         }
         CoreConcreteLibfunc::Bool(libfunc) => simulate_bool_libfunc(libfunc, inputs)?,
         CoreConcreteLibfunc::Felt252(libfunc) => simulate_felt252_libfunc(libfunc, inputs)?,
@@ -210,7 +204,8 @@ pub fn simulate<
         }
         CoreConcreteLibfunc::Mem(MemConcreteLibfunc::FinalizeLocals(_))
         | CoreConcreteLibfunc::UnconditionalJump(_)
-        | CoreConcreteLibfunc::ApTracking(_) => {
+        | CoreConcreteLibfunc::ApTracking(_)
+        | CoreConcreteLibfunc::Trace(_) => {
             let [] = take_inputs(inputs)?;
             (vec![], 0)
         }
@@ -236,7 +231,16 @@ pub fn simulate<
             take_inputs!(let [CoreValue::Enum { value, index }] = inputs);
             (vec![*value], index)
         }
-        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::FromBoundedInt(_)) => todo!(),
+        CoreConcreteLibfunc::Enum(EnumConcreteLibfunc::FromBoundedInt(
+            EnumFromBoundedIntConcreteLibfunc { n_variants, .. },
+        )) => {
+            let [value] = take_inputs(inputs)?;
+            let index = take_int(&value)?
+                .to_usize()
+                .filter(|index| index < n_variants)
+                .ok_or(LibfuncSimulationError::WrongArgType)?;
+            (vec![CoreValue::Enum { value: Box::new(CoreValue::Struct(vec![])), index }], 0)
+        }
         CoreConcreteLibfunc::Struct(StructConcreteLibfunc::Construct(_)) => {
             (vec![CoreValue::Struct(inputs)], 0)
         }
@@ -247,29 +251,105 @@ pub fn simulate<
             (members, 0)
         }
         CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::New(_)) => {
-            let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Dict(HashMap::new())], 0)
+            take_inputs!(let [CoreValue::SegmentArena] = inputs);
+            (vec![CoreValue::SegmentArena, CoreValue::Dict(HashMap::new())], 0)
         }
         CoreConcreteLibfunc::Felt252Dict(Felt252DictConcreteLibfunc::Squash(_)) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Dict(dict)] = inputs);
+            take_inputs!(let [
+                CoreValue::RangeCheck,
+                CoreValue::GasBuiltin(gas_counter),
+                CoreValue::SegmentArena,
+                CoreValue::Dict(dict),
+            ] = inputs);
             // Returning the same dict since it is exactly the same as the squashed one.
-            (vec![CoreValue::RangeCheck, CoreValue::Dict(dict)], 0)
-        }
-        CoreConcreteLibfunc::Felt252SquashedDict(_) => {
-            unimplemented!("Simulation of Felt252SquashedDict is not implemented yet.");
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::GasBuiltin(gas_counter),
+                    CoreValue::SegmentArena,
+                    CoreValue::Dict(dict),
+                ],
+                0,
+            )
         }
-        CoreConcreteLibfunc::Pedersen(_) => {
-            unimplemented!("Simulation of the Pedersen hash function is not implemented yet.");
+        CoreConcreteLibfunc::Felt252DictEntry(Felt252DictEntryConcreteLibfunc::Get(
+            SignatureAndTypeConcreteLibfunc { ty, .. },
+        )) => {
+            take_inputs!(let [CoreValue::Dict(dict), CoreValue::Felt252(key)] = inputs);
+            let value = match dict.get(&key) {
+                Some(value) => value.clone(),
+                None => default_value(get_type, ty)?,
+            };
+            (vec![CoreValue::Felt252DictEntry { dict, key }, value], 0)
+        }
+        CoreConcreteLibfunc::Felt252DictEntry(Felt252DictEntryConcreteLibfunc::Finalize(_)) => {
+            take_inputs!(let [CoreValue::Felt252DictEntry { mut dict, key }, value] = inputs);
+            dict.insert(key, value);
+            (vec![CoreValue::Dict(dict)], 0)
+        }
+        CoreConcreteLibfunc::Felt252SquashedDict(
+            SquashedFelt252DictConcreteLibfunc::IntoEntries(SignatureAndTypeConcreteLibfunc {
+                ty,
+                ..
+            }),
+        ) => {
+            take_inputs!(let [CoreValue::Dict(dict)] = inputs);
+            let default = default_value(get_type, ty)?;
+            let mut entries: Vec<_> = dict.into_iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+            (
+                vec![CoreValue::Array(
+                    entries
+                        .into_iter()
+                        .map(|(key, last)| {
+                            CoreValue::Struct(vec![CoreValue::Felt252(key), default.clone(), last])
+                        })
+                        .collect(),
+                )],
+                0,
+            )
         }
-        CoreConcreteLibfunc::Poseidon(_) => {
-            unimplemented!("Simulation of the Poseidon hash function is not implemented yet.");
+        CoreConcreteLibfunc::Pedersen(PedersenConcreteLibfunc::PedersenHash(_)) => {
+            take_inputs!(let [
+                CoreValue::Pedersen, CoreValue::Felt252(lhs), CoreValue::Felt252(rhs)
+            ] = inputs);
+            (vec![CoreValue::Pedersen, CoreValue::Felt252(Pedersen::hash(&lhs, &rhs))], 0)
         }
-        CoreConcreteLibfunc::Starknet(_) => {
-            unimplemented!("Simulation of the Starknet functionalities is not implemented yet.")
+        CoreConcreteLibfunc::Poseidon(PoseidonConcreteLibfunc::HadesPermutation(_)) => {
+            take_inputs!(let [
+                CoreValue::Poseidon,
+                CoreValue::Felt252(s0),
+                CoreValue::Felt252(s1),
+                CoreValue::Felt252(s2),
+            ] = inputs);
+            let mut state = [s0, s1, s2];
+            Poseidon::hades_permutation(&mut state);
+            (chain!([CoreValue::Poseidon], state.map(CoreValue::Felt252)).collect(), 0)
         }
-        CoreConcreteLibfunc::Nullable(_) => {
-            unimplemented!("Simulation of nullable is not implemented yet.")
+        CoreConcreteLibfunc::Starknet(libfunc) => {
+            simulate_starknet_libfunc(libfunc, inputs, syscall_handler)?
         }
+        CoreConcreteLibfunc::Nullable(libfunc) => match libfunc {
+            NullableConcreteLibfunc::Null(_) => {
+                let [] = take_inputs(inputs)?;
+                (vec![CoreValue::Nullable(None)], 0)
+            }
+            NullableConcreteLibfunc::NullableFromBox(_) => {
+                let [value] = take_inputs(inputs)?;
+                (vec![CoreValue::Nullable(Some(Box::new(value)))], 0)
+            }
+            NullableConcreteLibfunc::MatchNullable(_) => {
+                take_inputs!(let [CoreValue::Nullable(value)] = inputs);
+                match value {
+                    None => (vec![], 0),
+                    Some(value) => (vec![*value], 1),
+                }
+            }
+            NullableConcreteLibfunc::ForwardSnapshot(_) => {
+                take_inputs!(let [value @ CoreValue::Nullable(_)] = inputs);
+                (vec![value], 0)
+            }
+        },
         CoreConcreteLibfunc::Debug(_) => {
             take_inputs!(let [CoreValue::Array(arr)] = inputs);
             let mut bytes = Vec::new();
@@ -288,146 +368,463 @@ pub fn simulate<
             let [value] = take_inputs(inputs)?;
             (vec![value.clone(), value], 0)
         }
-        CoreConcreteLibfunc::Cast(_) => unimplemented!(),
-        CoreConcreteLibfunc::Felt252DictEntry(_) => unimplemented!(),
-        CoreConcreteLibfunc::Uint256(_) => unimplemented!(),
-        CoreConcreteLibfunc::Uint512(_) => unimplemented!(),
-        CoreConcreteLibfunc::Bytes31(_) => unimplemented!(),
-        CoreConcreteLibfunc::Const(_) => unimplemented!(),
-        CoreConcreteLibfunc::Coupon(_) => unimplemented!(),
-        CoreConcreteLibfunc::BoundedInt(_) => unimplemented!(),
-        CoreConcreteLibfunc::Circuit(_) => unimplemented!(),
-        CoreConcreteLibfunc::IntRange(_) => unimplemented!(),
-        CoreConcreteLibfunc::Blake(_) => unimplemented!(),
-        CoreConcreteLibfunc::Trace(_) => unimplemented!(),
-        CoreConcreteLibfunc::QM31(_) => unimplemented!(),
-        CoreConcreteLibfunc::UnsafePanic(_) => unimplemented!(),
-        CoreConcreteLibfunc::DummyFunctionCall(_) => unimplemented!(),
-        CoreConcreteLibfunc::GasReserve(_) => unimplemented!(),
+        CoreConcreteLibfunc::Cast(CastConcreteLibfunc::Downcast(DowncastConcreteLibfunc {
+            to_ty,
+            to_range,
+            ..
+        })) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let value = take_int(&value)?;
+            if to_range.lower <= value && value < to_range.upper {
+                (vec![CoreValue::RangeCheck, int_from_type(get_type, to_ty, value)?], 0)
+            } else {
+                (vec![CoreValue::RangeCheck], 1)
+            }
+        }
+        CoreConcreteLibfunc::Cast(CastConcreteLibfunc::Upcast(libfunc)) => {
+            let [value] = take_inputs(inputs)?;
+            (vec![int_from_type(get_type, output_ty(libfunc, 0, 0), take_int(&value)?)?], 0)
+        }
+        CoreConcreteLibfunc::Bytes31(libfunc) => match libfunc {
+            Bytes31ConcreteLibfunc::Const(SignatureAndConstConcreteLibfunc { c, .. }) => {
+                let [] = take_inputs(inputs)?;
+                (vec![CoreValue::Felt252(c.into())], 0)
+            }
+            Bytes31ConcreteLibfunc::ToFelt252(_) => {
+                take_inputs!(let [CoreValue::Felt252(value)] = inputs);
+                (vec![CoreValue::Felt252(value)], 0)
+            }
+            Bytes31ConcreteLibfunc::TryFromFelt252(_) => {
+                take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
+                if value.to_biguint().bits() <= 248 {
+                    (vec![CoreValue::RangeCheck, CoreValue::Felt252(value)], 0)
+                } else {
+                    (vec![CoreValue::RangeCheck], 1)
+                }
+            }
+        },
+        CoreConcreteLibfunc::Const(
+            ConstConcreteLibfunc::AsBox(ConstAsBoxConcreteLibfunc { const_type, .. })
+            | ConstConcreteLibfunc::AsImmediate(ConstAsImmediateConcreteLibfunc {
+                const_type, ..
+            }),
+        ) => {
+            let [] = take_inputs(inputs)?;
+            (vec![const_value(get_type, const_type)?], 0)
+        }
+        CoreConcreteLibfunc::Coupon(CouponConcreteLibfunc::Buy(_)) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::Coupon], 0)
+        }
+        CoreConcreteLibfunc::Coupon(CouponConcreteLibfunc::Refund(_)) => {
+            take_inputs!(let [CoreValue::Coupon] = inputs);
+            (vec![], 0)
+        }
+        CoreConcreteLibfunc::BoundedInt(libfunc) => {
+            simulate_bounded_int_libfunc(libfunc, inputs, get_type)?
+        }
+        CoreConcreteLibfunc::Circuit(libfunc) => {
+            simulate_circuit_libfunc(libfunc, inputs, get_type)?
+        }
+        CoreConcreteLibfunc::IntRange(libfunc) => match libfunc {
+            IntRangeConcreteLibfunc::TryNew(_) => {
+                take_inputs!(let [CoreValue::RangeCheck, start, end] = inputs);
+                if take_int(&start)? <= take_int(&end)? {
+                    (vec![CoreValue::RangeCheck, CoreValue::Struct(vec![start, end])], 0)
+                } else {
+                    (vec![CoreValue::RangeCheck, CoreValue::Struct(vec![end.clone(), end])], 1)
+                }
+            }
+            IntRangeConcreteLibfunc::PopFront(_) => {
+                take_inputs!(let [CoreValue::Struct(range)] = inputs);
+                let Ok::<[CoreValue; 2], _>([start, end]) = range.try_into() else {
+                    return Err(LibfuncSimulationError::WrongArgType);
+                };
+                let start_value = take_int(&start)?;
+                if start_value < take_int(&end)? {
+                    let next = int_from_type(get_type, output_ty(libfunc, 1, 1), start_value + 1)?;
+                    (vec![CoreValue::Struct(vec![next, end]), start], 1)
+                } else {
+                    (vec![], 0)
+                }
+            }
+        },
+        CoreConcreteLibfunc::Blake(libfunc) => {
+            take_inputs!(let [
+                CoreValue::Struct(state), CoreValue::Uint32(byte_count), CoreValue::Struct(message)
+            ] = inputs);
+            let finalize = match libfunc {
+                BlakeConcreteLibfunc::Blake2sCompress(_) => 0,
+                BlakeConcreteLibfunc::Blake2sFinalize(_) => u32::MAX,
+            };
+            let state =
+                blake2s_compress(u32_array(state)?, u32_array(message)?, byte_count, finalize);
+            (vec![u32_array_value(state)], 0)
+        }
+        CoreConcreteLibfunc::QM31(libfunc) => simulate_qm31_libfunc(libfunc, inputs)?,
+        CoreConcreteLibfunc::UnsafePanic(_) => return Err(LibfuncSimulationError::UnsafePanic),
     })
 }
 
-/// Simulate boolean library functions.
-fn simulate_bool_libfunc(
-    libfunc: &BoolConcreteLibfunc,
+/// Simulate gas library functions.
+fn simulate_gas_libfunc(
+    libfunc: &GasConcreteLibfunc,
     inputs: Vec<CoreValue>,
+    get_statement_gas_info: impl Fn() -> Option<i64>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let get_count =
+        || get_statement_gas_info().ok_or(LibfuncSimulationError::UnresolvedStatementGasInfo);
     Ok(match libfunc {
-        BoolConcreteLibfunc::And(_) => {
+        GasConcreteLibfunc::WithdrawGas(_) => {
+            let count = get_count()?;
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)] = inputs);
+            if gas_counter >= count {
+                // Have enough gas - return reduced counter and jump to success branch.
+                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0)
+            } else {
+                // Don't have enough gas - return the same counter and jump to failure branch.
+                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)], 1)
+            }
+        }
+        GasConcreteLibfunc::BuiltinWithdrawGas(_) => {
+            let count = get_count()?;
             take_inputs!(let [
-                CoreValue::Enum { index: a_index, .. },
-                CoreValue::Enum { index: b_index, .. },
+                CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter), CoreValue::BuiltinCosts
             ] = inputs);
-            (
-                vec![CoreValue::Enum {
-                    value: Box::new(CoreValue::Struct(vec![])),
-                    index: usize::from(a_index == 1 && b_index == 1),
-                }],
-                0,
-            )
+            if gas_counter >= count {
+                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter - count)], 0)
+            } else {
+                (vec![CoreValue::RangeCheck, CoreValue::GasBuiltin(gas_counter)], 1)
+            }
         }
-        BoolConcreteLibfunc::Not(_) => {
-            take_inputs!(let [CoreValue::Enum { index, .. }] = inputs);
-            (
-                vec![CoreValue::Enum {
-                    value: Box::new(CoreValue::Struct(vec![])),
-                    index: 1_usize - index,
-                }],
-                0,
-            )
+        GasConcreteLibfunc::RedepositGas(_) => {
+            let count = get_count()?;
+            take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
+            (vec![CoreValue::GasBuiltin(gas_counter + count)], 0)
         }
-        BoolConcreteLibfunc::Xor(_) => {
-            take_inputs!(let [
-                CoreValue::Enum { index: a_index, .. },
-                CoreValue::Enum { index: b_index, .. },
-            ] = inputs);
-            (
-                vec![CoreValue::Enum {
-                    value: Box::new(CoreValue::Struct(vec![])),
-                    index: usize::from(a_index != b_index),
-                }],
-                0,
-            )
+        GasConcreteLibfunc::GetAvailableGas(_) => {
+            take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
+            (vec![CoreValue::GasBuiltin(gas_counter), CoreValue::Uint128(gas_counter as u128)], 0)
         }
-        BoolConcreteLibfunc::Or(_) => {
-            take_inputs!(let [
-                CoreValue::Enum { index: a_index, .. },
-                CoreValue::Enum { index: b_index, .. },
-            ] = inputs);
+        GasConcreteLibfunc::GetUnspentGas(_) => {
+            // The gas already withdrawn for the rest of the current code path is unspent as well.
+            let count = get_count()?;
+            take_inputs!(let [CoreValue::GasBuiltin(gas_counter)] = inputs);
             (
-                vec![CoreValue::Enum {
-                    value: Box::new(CoreValue::Struct(vec![])),
-                    index: usize::from(a_index + b_index > 0),
-                }],
+                vec![
+                    CoreValue::GasBuiltin(gas_counter),
+                    CoreValue::Uint128((gas_counter + count) as u128),
+                ],
                 0,
             )
         }
-        BoolConcreteLibfunc::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::Enum { index, .. }] = inputs);
-            (vec![CoreValue::Felt252(Felt252::from(index))], 0)
+        GasConcreteLibfunc::GetBuiltinCosts(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::BuiltinCosts], 0)
         }
     })
 }
 
-/// Simulate u128 library functions.
-fn simulate_u128_libfunc(
-    libfunc: &Uint128Concrete,
+/// Simulate array library functions.
+fn simulate_array_libfunc(
+    libfunc: &ArrayConcreteLibfunc,
     inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
     Ok(match libfunc {
-        Uint128Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
+        ArrayConcreteLibfunc::New(_) => {
             let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Uint128(*c)], 0)
+            (vec![CoreValue::Array(vec![])], 0)
         }
-        Uint128Concrete::FromFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
-            match value.to_u128() {
-                Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
-            }
+        ArrayConcreteLibfunc::SpanFromTuple(_) => {
+            take_inputs!(let [CoreValue::Struct(members)] = inputs);
+            (vec![CoreValue::Array(members)], 0)
         }
-        Uint128Concrete::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::Uint128(value)] = inputs);
-            (vec![CoreValue::Felt252(Felt252::from(value))], 0)
+        ArrayConcreteLibfunc::TupleFromSpan(SignatureAndTypeConcreteLibfunc { ty, .. }) => {
+            take_inputs!(let [CoreValue::Array(arr)] = inputs);
+            if arr.len() == struct_members(get_type, ty)?.len() {
+                (vec![CoreValue::Struct(arr)], 0)
+            } else {
+                (vec![], 1)
+            }
         }
-        Uint128Concrete::Operation(libfunc) => {
-            take_inputs!(let [
-                CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)
-            ] = inputs);
-            let (value, overflow) = match libfunc.operator {
-                IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
-                IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
-            };
-            (vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], usize::from(overflow))
+        ArrayConcreteLibfunc::Append(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr), element] = inputs);
+            arr.push(element);
+            (vec![CoreValue::Array(arr)], 0)
         }
-        Uint128Concrete::Divmod(_) => {
-            take_inputs!(let [
-                CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)
-            ] = inputs);
-            (
-                vec![
-                    CoreValue::RangeCheck,
-                    CoreValue::Uint128(lhs / rhs),
-                    CoreValue::Uint128(lhs % rhs),
-                ],
-                0,
-            )
+        ArrayConcreteLibfunc::PopFront(_) | ArrayConcreteLibfunc::SnapshotPopFront(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
+            if arr.is_empty() {
+                (vec![CoreValue::Array(arr)], 1)
+            } else {
+                let front = arr.remove(0);
+                (vec![CoreValue::Array(arr), front], 0)
+            }
         }
-        Uint128Concrete::GuaranteeMul(_) => {
-            take_inputs!(let [CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)] = inputs);
-            let (limb1, limb0) = (BigInt::from(lhs) * rhs).div_rem(&BigInt::one().pow(128));
-            (
-                vec![
-                    CoreValue::Uint128(limb0.to_u128().unwrap()),
-                    CoreValue::Uint128(limb1.to_u128().unwrap()),
-                    CoreValue::U128MulGuarantee,
-                ],
-                0,
-            )
+        ArrayConcreteLibfunc::SnapshotPopBack(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
+            match arr.pop() {
+                Some(back) => (vec![CoreValue::Array(arr), back], 0),
+                None => (vec![CoreValue::Array(arr)], 1),
+            }
         }
-        Uint128Concrete::MulGuaranteeVerify(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::U128MulGuarantee] = inputs);
-            (vec![CoreValue::RangeCheck], 0)
+        ArrayConcreteLibfunc::PopFrontConsume(_) => {
+            take_inputs!(let [CoreValue::Array(mut arr)] = inputs);
+            if arr.is_empty() { (vec![CoreValue::Array(arr)], 1) } else { (vec![arr.remove(0)], 0) }
+        }
+        ArrayConcreteLibfunc::SnapshotMultiPopFront(ConcreteMultiPopLibfunc {
+            popped_ty, ..
+        }) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Array(mut arr)] = inputs);
+            let count = struct_members(get_type, popped_ty)?.len();
+            if arr.len() < count {
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr)], 1)
+            } else {
+                let popped = arr.drain(..count).collect();
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Struct(popped)], 0)
+            }
+        }
+        ArrayConcreteLibfunc::SnapshotMultiPopBack(ConcreteMultiPopLibfunc {
+            popped_ty, ..
+        }) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Array(mut arr)] = inputs);
+            let count = struct_members(get_type, popped_ty)?.len();
+            if arr.len() < count {
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr)], 1)
+            } else {
+                let popped = arr.split_off(arr.len() - count);
+                (vec![CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Struct(popped)], 0)
+            }
+        }
+        ArrayConcreteLibfunc::Get(_) => {
+            take_inputs!(
+                let [CoreValue::RangeCheck, CoreValue::Array(arr), CoreValue::Uint32(idx)] = inputs
+            );
+            match arr.get(idx as usize).cloned() {
+                Some(element) => (vec![CoreValue::RangeCheck, element], 0),
+                None => (vec![CoreValue::RangeCheck], 1),
+            }
+        }
+        ArrayConcreteLibfunc::Slice(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck,
+                CoreValue::Array(arr),
+                CoreValue::Uint32(start),
+                CoreValue::Uint32(length),
+            ] = inputs);
+            match arr.get(start as usize..start as usize + length as usize) {
+                Some(elements) => {
+                    (vec![CoreValue::RangeCheck, CoreValue::Array(elements.to_vec())], 0)
+                }
+                None => (vec![CoreValue::RangeCheck], 1),
+            }
+        }
+        ArrayConcreteLibfunc::Len(_) => {
+            take_inputs!(let [CoreValue::Array(arr)] = inputs);
+            (vec![CoreValue::Uint32(arr.len() as u32)], 0)
+        }
+    })
+}
+
+/// Simulate EC library functions.
+fn simulate_ec_libfunc(
+    libfunc: &EcConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    const BETA: Felt252 = Felt252::from_hex_unchecked(
+        "0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
+    );
+    Ok(match libfunc {
+        EcConcreteLibfunc::TryNew(_) => {
+            take_inputs!(let [CoreValue::Felt252(x), CoreValue::Felt252(y)] = inputs);
+            // If the point is on the curve use the fallthrough branch and return the point.
+            if y * y == x * x * x + x + BETA {
+                (vec![CoreValue::EcPoint(x, y)], 0)
+            } else {
+                (vec![], 1)
+            }
+        }
+        EcConcreteLibfunc::PointFromX(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(x)] = inputs);
+            match (x * x * x + x + BETA).sqrt() {
+                Some(y) => (vec![CoreValue::RangeCheck, CoreValue::EcPoint(x, y)], 0),
+                None => (vec![CoreValue::RangeCheck], 1),
+            }
+        }
+        EcConcreteLibfunc::UnwrapPoint(_) => {
+            take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
+            (vec![CoreValue::Felt252(x), CoreValue::Felt252(y)], 0)
+        }
+        EcConcreteLibfunc::Zero(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::EcPoint(Felt252::ZERO, Felt252::ZERO)], 0)
+        }
+        EcConcreteLibfunc::Neg(_) => {
+            take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
+            (vec![CoreValue::EcPoint(x, -y)], 0)
+        }
+        EcConcreteLibfunc::IsZero(_) => {
+            take_inputs!(let [CoreValue::EcPoint(x, y)] = inputs);
+            if x.is_zero() && y.is_zero() {
+                (vec![], 0)
+            } else {
+                (vec![CoreValue::EcPoint(x, y)], 1)
+            }
+        }
+        EcConcreteLibfunc::StateInit(_) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::EcState(Felt252::ZERO, Felt252::ZERO)], 0)
+        }
+        EcConcreteLibfunc::StateAdd(_) => {
+            take_inputs!(let [CoreValue::EcState(sx, sy), CoreValue::EcPoint(x, y)] = inputs);
+            let (x, y) = from_affine_point(to_affine_point(sx, sy) + to_affine_point(x, y));
+            (vec![CoreValue::EcState(x, y)], 0)
+        }
+        EcConcreteLibfunc::StateAddMul(_) => {
+            take_inputs!(let [
+                CoreValue::EcOp,
+                CoreValue::EcState(sx, sy),
+                CoreValue::Felt252(scalar),
+                CoreValue::EcPoint(x, y),
+            ] = inputs);
+            let (x, y) =
+                from_affine_point(to_affine_point(sx, sy) + &to_affine_point(x, y) * scalar);
+            (vec![CoreValue::EcOp, CoreValue::EcState(x, y)], 0)
+        }
+        EcConcreteLibfunc::StateFinalize(_) => {
+            take_inputs!(let [CoreValue::EcState(x, y)] = inputs);
+            if x.is_zero() && y.is_zero() {
+                (vec![], 1)
+            } else {
+                (vec![CoreValue::EcPoint(x, y)], 0)
+            }
+        }
+    })
+}
+
+/// Returns the curve point of the given coordinates, where `(0, 0)` is the point at infinity.
+fn to_affine_point(x: Felt252, y: Felt252) -> AffinePoint {
+    if x.is_zero() && y.is_zero() {
+        AffinePoint::identity()
+    } else {
+        AffinePoint::new_unchecked(x, y)
+    }
+}
+
+/// Returns the coordinates of the given curve point, where `(0, 0)` is the point at infinity.
+fn from_affine_point(point: AffinePoint) -> (Felt252, Felt252) {
+    if point.is_identity() { (Felt252::ZERO, Felt252::ZERO) } else { (point.x(), point.y()) }
+}
+
+/// Simulate boolean library functions.
+fn simulate_bool_libfunc(
+    libfunc: &BoolConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        BoolConcreteLibfunc::And(_) => {
+            take_inputs!(let [
+                CoreValue::Enum { index: a_index, .. },
+                CoreValue::Enum { index: b_index, .. },
+            ] = inputs);
+            (vec![bool_value(a_index == 1 && b_index == 1)], 0)
+        }
+        BoolConcreteLibfunc::Not(_) => {
+            take_inputs!(let [CoreValue::Enum { index, .. }] = inputs);
+            (vec![bool_value(index == 0)], 0)
+        }
+        BoolConcreteLibfunc::Xor(_) => {
+            take_inputs!(let [
+                CoreValue::Enum { index: a_index, .. },
+                CoreValue::Enum { index: b_index, .. },
+            ] = inputs);
+            (vec![bool_value(a_index != b_index)], 0)
+        }
+        BoolConcreteLibfunc::Or(_) => {
+            take_inputs!(let [
+                CoreValue::Enum { index: a_index, .. },
+                CoreValue::Enum { index: b_index, .. },
+            ] = inputs);
+            (vec![bool_value(a_index + b_index > 0)], 0)
+        }
+        BoolConcreteLibfunc::ToFelt252(_) => {
+            take_inputs!(let [CoreValue::Enum { index, .. }] = inputs);
+            (vec![CoreValue::Felt252(Felt252::from(index))], 0)
+        }
+    })
+}
+
+/// Simulate u128 library functions.
+fn simulate_u128_libfunc(
+    libfunc: &Uint128Concrete,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        Uint128Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::Uint128(*c)], 0)
+        }
+        Uint128Concrete::FromFelt252(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
+            match value.to_u128() {
+                Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], 0),
+                None => {
+                    let value = value.to_biguint();
+                    let (high, low) = (&value >> 128u32, value & BigUint::from(u128::MAX));
+                    (
+                        vec![
+                            CoreValue::RangeCheck,
+                            CoreValue::Uint128(high.to_u128().unwrap()),
+                            CoreValue::Uint128(low.to_u128().unwrap()),
+                        ],
+                        1,
+                    )
+                }
+            }
+        }
+        Uint128Concrete::ToFelt252(_) => {
+            take_inputs!(let [CoreValue::Uint128(value)] = inputs);
+            (vec![CoreValue::Felt252(Felt252::from(value))], 0)
+        }
+        Uint128Concrete::Operation(libfunc) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)
+            ] = inputs);
+            let (value, overflow) = match libfunc.operator {
+                IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
+                IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
+            };
+            (vec![CoreValue::RangeCheck, CoreValue::Uint128(value)], usize::from(overflow))
+        }
+        Uint128Concrete::Divmod(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    CoreValue::Uint128(lhs / rhs),
+                    CoreValue::Uint128(lhs % rhs),
+                ],
+                0,
+            )
+        }
+        Uint128Concrete::GuaranteeMul(_) => {
+            take_inputs!(let [CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)] = inputs);
+            let (limb1, limb0) = (BigInt::from(lhs) * rhs).div_rem(&(BigInt::one() << 128));
+            (
+                vec![
+                    CoreValue::Uint128(limb1.to_u128().unwrap()),
+                    CoreValue::Uint128(limb0.to_u128().unwrap()),
+                    CoreValue::U128MulGuarantee,
+                ],
+                0,
+            )
+        }
+        Uint128Concrete::MulGuaranteeVerify(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::U128MulGuarantee] = inputs);
+            (vec![CoreValue::RangeCheck], 0)
         }
         Uint128Concrete::IsZero(_) => {
             take_inputs!(let [CoreValue::Uint128(value)] = inputs);
@@ -444,7 +841,10 @@ fn simulate_u128_libfunc(
             // "True" branch (branch 1) is the case a == b.
             (vec![], usize::from(lhs == rhs))
         }
-        Uint128Concrete::ByteReverse(_) => todo!("ByteReverse"),
+        Uint128Concrete::ByteReverse(_) => {
+            take_inputs!(let [CoreValue::Bitwise, CoreValue::Uint128(value)] = inputs);
+            (vec![CoreValue::Bitwise, CoreValue::Uint128(value.swap_bytes())], 0)
+        }
         Uint128Concrete::Bitwise(_) => {
             take_inputs!(let [
                 CoreValue::Bitwise, CoreValue::Uint128(lhs), CoreValue::Uint128(rhs)
@@ -462,209 +862,999 @@ fn simulate_u128_libfunc(
     })
 }
 
-/// Simulate u8 library functions.
-fn simulate_u8_libfunc(
-    libfunc: &Uint8Concrete,
+/// Simulate u8, u16, u32 and u64 library functions.
+fn simulate_uint_libfunc<TUintTraits: UintTraits + IntMulTraits + IsZeroTraits>(
+    libfunc: &UintConcrete<TUintTraits>,
     inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
-    Ok(match libfunc {
-        Uint8Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
-            let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Uint8(*c)], 0)
+    match libfunc {
+        UintConcrete::Const(libfunc) => simulate_int_const(libfunc, inputs, get_type),
+        UintConcrete::Operation(libfunc) => simulate_int_operation(libfunc, inputs, get_type),
+        UintConcrete::SquareRoot(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let root = take_int(&value)?.sqrt();
+            Ok((
+                vec![
+                    CoreValue::RangeCheck,
+                    int_from_type(get_type, output_ty(libfunc, 0, 1), root)?,
+                ],
+                0,
+            ))
         }
-        Uint8Concrete::Operation(libfunc) => {
-            take_inputs!(
-                let [CoreValue::RangeCheck, CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)] = inputs
-            );
-            let (value, overflow) = match libfunc.operator {
-                IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
-                IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
-            };
-            (vec![CoreValue::RangeCheck, CoreValue::Uint8(value)], usize::from(overflow))
+        UintConcrete::Equal(_) => simulate_int_equal(inputs),
+        UintConcrete::ToFelt252(_) => simulate_int_to_felt252(inputs),
+        UintConcrete::FromFelt252(_) => simulate_int_from_felt252(libfunc, inputs, get_type),
+        UintConcrete::IsZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            Ok(if take_int(&value)?.is_zero() { (vec![], 0) } else { (vec![value], 1) })
         }
-        Uint8Concrete::SquareRoot(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint8(value)] = inputs);
-            let root = BigInt::from(value).sqrt();
-            (vec![CoreValue::RangeCheck, CoreValue::Uint8(root.to_u8().unwrap())], 0)
+        UintConcrete::Divmod(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+            let (quotient, remainder) = take_int(&lhs)?.div_rem(&take_int(&rhs)?);
+            Ok((
+                vec![
+                    CoreValue::RangeCheck,
+                    int_from_type(get_type, output_ty(libfunc, 0, 1), quotient)?,
+                    int_from_type(get_type, output_ty(libfunc, 0, 2), remainder)?,
+                ],
+                0,
+            ))
+        }
+        UintConcrete::WideMul(_) => simulate_int_wide_mul(libfunc, inputs, get_type),
+        UintConcrete::Bitwise(_) => {
+            take_inputs!(let [CoreValue::Bitwise, lhs, rhs] = inputs);
+            let (lhs, rhs) = (take_int(&lhs)?, take_int(&rhs)?);
+            let ty = output_ty(libfunc, 0, 1);
+            Ok((
+                vec![
+                    CoreValue::Bitwise,
+                    int_from_type(get_type, ty, &lhs & &rhs)?,
+                    int_from_type(get_type, ty, &lhs | &rhs)?,
+                    int_from_type(get_type, ty, &lhs ^ &rhs)?,
+                ],
+                0,
+            ))
         }
-        Uint8Concrete::Equal(_) => {
-            take_inputs!(let [CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)] = inputs);
-            // "False" branch (branch 0) is the case a != b.
-            // "True" branch (branch 1) is the case a == b.
-            (vec![], usize::from(lhs == rhs))
+    }
+}
+
+/// Simulate i8, i16, i32 and i64 library functions.
+fn simulate_sint_libfunc<TSintTraits: SintTraits + IntMulTraits>(
+    libfunc: &SintConcrete<TSintTraits>,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        SintConcrete::Const(libfunc) => simulate_int_const(libfunc, inputs, get_type),
+        SintConcrete::Equal(_) => simulate_int_equal(inputs),
+        SintConcrete::ToFelt252(_) => simulate_int_to_felt252(inputs),
+        SintConcrete::FromFelt252(_) => simulate_int_from_felt252(libfunc, inputs, get_type),
+        SintConcrete::Operation(libfunc) => simulate_int_operation(libfunc, inputs, get_type),
+        SintConcrete::Diff(_) => simulate_sint_diff(libfunc, inputs, get_type),
+        SintConcrete::WideMul(_) => simulate_int_wide_mul(libfunc, inputs, get_type),
+    }
+}
+
+/// Simulate i128 library functions.
+fn simulate_sint128_libfunc(
+    libfunc: &Sint128Concrete,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    match libfunc {
+        Sint128Concrete::Const(libfunc) => simulate_int_const(libfunc, inputs, get_type),
+        Sint128Concrete::Equal(_) => simulate_int_equal(inputs),
+        Sint128Concrete::ToFelt252(_) => simulate_int_to_felt252(inputs),
+        Sint128Concrete::FromFelt252(_) => simulate_int_from_felt252(libfunc, inputs, get_type),
+        Sint128Concrete::Operation(libfunc) => simulate_int_operation(libfunc, inputs, get_type),
+        Sint128Concrete::Diff(_) => simulate_sint_diff(libfunc, inputs, get_type),
+    }
+}
+
+/// Simulates the `*_const` libfuncs of the integer types.
+fn simulate_int_const<TIntTraits: IntTraits>(
+    libfunc: &IntConstConcreteLibfunc<TIntTraits>,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [] = take_inputs(inputs)?;
+    Ok((vec![int_from_type(get_type, output_ty(libfunc, 0, 0), libfunc.c.into())?], 0))
+}
+
+/// Simulates the `*_eq` libfuncs of the integer types.
+fn simulate_int_equal(
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [lhs, rhs] = take_inputs(inputs)?;
+    // "False" branch (branch 0) is the case a != b.
+    // "True" branch (branch 1) is the case a == b.
+    Ok((vec![], usize::from(take_int(&lhs)? == take_int(&rhs)?)))
+}
+
+/// Simulates the `*_to_felt252` libfuncs of the integer types.
+fn simulate_int_to_felt252(
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [value] = take_inputs(inputs)?;
+    Ok((vec![CoreValue::Felt252(Felt252::from(take_int(&value)?))], 0))
+}
+
+/// Simulates the `*_try_from_felt252` libfuncs of the integer types.
+fn simulate_int_from_felt252(
+    libfunc: &impl ConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, value @ CoreValue::Felt252(_)] = inputs);
+    let ty = output_ty(libfunc, 0, 1);
+    let value = take_int(&value)?;
+    let range = int_range(get_type, ty)?;
+    Ok(if range.lower <= value && value < range.upper {
+        (vec![CoreValue::RangeCheck, int_from_type(get_type, ty, value)?], 0)
+    } else {
+        (vec![CoreValue::RangeCheck], 1)
+    })
+}
+
+/// Simulates the overflowing add and sub libfuncs of the integer types.
+///
+/// The result is wrapped into the range of the type when out of range, jumping to the overflow
+/// branch for unsigned integers, and to the underflow or overflow branch for signed integers.
+fn simulate_int_operation(
+    libfunc: &IntOperationConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+    let (lhs, rhs) = (take_int(&lhs)?, take_int(&rhs)?);
+    let value = match libfunc.operator {
+        IntOperator::OverflowingAdd => lhs + rhs,
+        IntOperator::OverflowingSub => lhs - rhs,
+    };
+    let ty = output_ty(libfunc, 0, 1);
+    let range = int_range(get_type, ty)?;
+    let branch = if value < range.lower {
+        1
+    } else if value >= range.upper {
+        libfunc.branch_signatures().len() - 1
+    } else {
+        0
+    };
+    let wrapped = (value - &range.lower).mod_floor(&range.size()) + &range.lower;
+    Ok((vec![CoreValue::RangeCheck, int_from_type(get_type, ty, wrapped)?], branch))
+}
+
+/// Simulates the `*_diff` libfuncs of the signed integer types, returning the difference as the
+/// matching unsigned type, wrapped around if negative.
+fn simulate_sint_diff(
+    libfunc: &impl ConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+    let value = take_int(&lhs)? - take_int(&rhs)?;
+    let ty = output_ty(libfunc, 0, 1);
+    let branch = usize::from(value.is_negative());
+    let wrapped = value.mod_floor(&int_range(get_type, ty)?.size());
+    Ok((vec![CoreValue::RangeCheck, int_from_type(get_type, ty, wrapped)?], branch))
+}
+
+/// Simulates the `*_wide_mul` libfuncs of the integer types.
+fn simulate_int_wide_mul(
+    libfunc: &impl ConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let [lhs, rhs] = take_inputs(inputs)?;
+    let value = take_int(&lhs)? * take_int(&rhs)?;
+    Ok((vec![int_from_type(get_type, output_ty(libfunc, 0, 0), value)?], 0))
+}
+
+/// Simulate u256 library functions.
+fn simulate_u256_libfunc(
+    libfunc: &Uint256Concrete,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        Uint256Concrete::IsZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            if u256_from_value(&value)?.is_zero() { (vec![], 0) } else { (vec![value], 1) }
         }
-        Uint8Concrete::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::Uint8(value)] = inputs);
-            (vec![CoreValue::Felt252(Felt252::from(value))], 0)
+        Uint256Concrete::Divmod(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+            let (quotient, remainder) = u256_from_value(&lhs)?.div_rem(&u256_from_value(&rhs)?);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    u256_value(&quotient),
+                    u256_value(&remainder),
+                    CoreValue::U128MulGuarantee,
+                ],
+                0,
+            )
         }
-        Uint8Concrete::FromFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint8(value)] = inputs);
-            match value.to_u8() {
-                Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint8(value)], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
+        Uint256Concrete::SquareRoot(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let root = u256_from_value(&value)?.sqrt();
+            (vec![CoreValue::RangeCheck, CoreValue::Uint128(root.to_u128().unwrap())], 0)
+        }
+        Uint256Concrete::InvModN(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, value, modulus] = inputs);
+            let value = u256_from_value(&value)?.to_bigint().unwrap();
+            let modulus = u256_from_value(&modulus)?.to_bigint().unwrap();
+            let ExtendedGcd { gcd, x, .. } = value.extended_gcd(&modulus);
+            if gcd.is_one() && !modulus.is_one() {
+                let inverse = x.mod_floor(&modulus).to_biguint().unwrap();
+                let guarantees = libfunc.branch_signatures()[0].vars.len() - 2;
+                (
+                    chain!(
+                        [CoreValue::RangeCheck, u256_value(&inverse)],
+                        repeat_n(CoreValue::U128MulGuarantee, guarantees)
+                    )
+                    .collect(),
+                    0,
+                )
+            } else {
+                (
+                    vec![
+                        CoreValue::RangeCheck,
+                        CoreValue::U128MulGuarantee,
+                        CoreValue::U128MulGuarantee,
+                    ],
+                    1,
+                )
             }
         }
-        Uint8Concrete::IsZero(_) => unimplemented!(),
-        Uint8Concrete::Divmod(_) => unimplemented!(),
-        Uint8Concrete::Bitwise(_) => unimplemented!(),
-        Uint8Concrete::WideMul(_) => {
-            take_inputs!(let [CoreValue::Uint8(lhs), CoreValue::Uint8(rhs)] = inputs);
-            (vec![CoreValue::Uint16(u16::from(lhs) * u16::from(rhs))], 0)
-        }
     })
 }
 
-/// Simulate u16 library functions.
-fn simulate_u16_libfunc(
-    libfunc: &Uint16Concrete,
+/// Simulate u512 library functions.
+fn simulate_u512_libfunc(
+    libfunc: &Uint512Concrete,
     inputs: Vec<CoreValue>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
     Ok(match libfunc {
-        Uint16Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
-            let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Uint16(*c)], 0)
+        Uint512Concrete::DivModU256(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Struct(lhs), rhs] = inputs);
+            let lhs = u128_limbs_from_values(&lhs)?;
+            let (quotient, remainder) = lhs.div_rem(&u256_from_value(&rhs)?);
+            let guarantees = libfunc.branch_signatures()[0].vars.len() - 3;
+            (
+                chain!(
+                    [
+                        CoreValue::RangeCheck,
+                        CoreValue::Struct(u128_limbs_to_values(quotient, 4)),
+                        u256_value(&remainder),
+                    ],
+                    repeat_n(CoreValue::U128MulGuarantee, guarantees)
+                )
+                .collect(),
+                0,
+            )
         }
-        Uint16Concrete::Operation(libfunc) => {
-            take_inputs!(let [
-                CoreValue::RangeCheck, CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)
-            ] = inputs);
-            let (value, overflow) = match libfunc.operator {
-                IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
-                IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
+    })
+}
+
+/// Simulate bounded int library functions.
+fn simulate_bounded_int_libfunc(
+    libfunc: &BoundedIntConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        BoundedIntConcreteLibfunc::Add(_)
+        | BoundedIntConcreteLibfunc::Sub(_)
+        | BoundedIntConcreteLibfunc::Mul(_) => {
+            let [lhs, rhs] = take_inputs(inputs)?;
+            let (lhs, rhs) = (take_int(&lhs)?, take_int(&rhs)?);
+            let value = match libfunc {
+                BoundedIntConcreteLibfunc::Add(_) => lhs + rhs,
+                BoundedIntConcreteLibfunc::Sub(_) => lhs - rhs,
+                _ => lhs * rhs,
             };
-            (vec![CoreValue::RangeCheck, CoreValue::Uint16(value)], usize::from(overflow))
-        }
-        Uint16Concrete::SquareRoot(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint16(value)] = inputs);
-            let root = BigInt::from(value).sqrt();
-            (vec![CoreValue::RangeCheck, CoreValue::Uint8(root.to_u8().unwrap())], 0)
+            (vec![int_from_type(get_type, output_ty(libfunc, 0, 0), value)?], 0)
         }
-        Uint16Concrete::Equal(_) => {
-            take_inputs!(let [CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)] = inputs);
-            // "False" branch (branch 0) is the case a != b.
-            // "True" branch (branch 1) is the case a == b.
-            (vec![], usize::from(lhs == rhs))
+        BoundedIntConcreteLibfunc::DivRem(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, lhs, rhs] = inputs);
+            let (quotient, remainder) = take_int(&lhs)?.div_mod_floor(&take_int(&rhs)?);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    int_from_type(get_type, output_ty(libfunc, 0, 1), quotient)?,
+                    int_from_type(get_type, output_ty(libfunc, 0, 2), remainder)?,
+                ],
+                0,
+            )
         }
-        Uint16Concrete::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::Uint16(value)] = inputs);
-            (vec![CoreValue::Felt252(Felt252::from(value))], 0)
+        BoundedIntConcreteLibfunc::Constrain(BoundedIntConstrainConcreteLibfunc {
+            boundary,
+            ..
+        }) => {
+            take_inputs!(let [CoreValue::RangeCheck, value] = inputs);
+            let value = take_int(&value)?;
+            let branch = usize::from(&value >= boundary);
+            (
+                vec![
+                    CoreValue::RangeCheck,
+                    int_from_type(get_type, output_ty(libfunc, branch, 1), value)?,
+                ],
+                branch,
+            )
         }
-        Uint16Concrete::FromFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint16(value)] = inputs);
-            match value.to_u16() {
-                Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint16(value)], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
+        BoundedIntConcreteLibfunc::TrimMin(BoundedIntTrimConcreteLibfunc {
+            trimmed_value, ..
+        })
+        | BoundedIntConcreteLibfunc::TrimMax(BoundedIntTrimConcreteLibfunc {
+            trimmed_value, ..
+        }) => {
+            let [value] = take_inputs(inputs)?;
+            let value = take_int(&value)?;
+            if &value == trimmed_value {
+                (vec![], 0)
+            } else {
+                (vec![int_from_type(get_type, output_ty(libfunc, 1, 0), value)?], 1)
             }
         }
-        Uint16Concrete::IsZero(_) => unimplemented!(),
-        Uint16Concrete::Divmod(_) => unimplemented!(),
-        Uint16Concrete::Bitwise(_) => unimplemented!(),
-        Uint16Concrete::WideMul(_) => {
-            take_inputs!(let [CoreValue::Uint16(lhs), CoreValue::Uint16(rhs)] = inputs);
-            (vec![CoreValue::Uint32(u32::from(lhs) * u32::from(rhs))], 0)
+        BoundedIntConcreteLibfunc::IsZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            if take_int(&value)?.is_zero() { (vec![], 0) } else { (vec![value], 1) }
+        }
+        BoundedIntConcreteLibfunc::WrapNonZero(_) => {
+            let [value] = take_inputs(inputs)?;
+            (vec![value], 0)
         }
     })
 }
 
-/// Simulate u32 library functions.
-fn simulate_u32_libfunc(
-    libfunc: &Uint32Concrete,
+/// Simulate circuit library functions.
+fn simulate_circuit_libfunc(
+    libfunc: &CircuitConcreteLibfunc,
     inputs: Vec<CoreValue>,
+    get_type: &GetType<'_, '_>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
     Ok(match libfunc {
-        Uint32Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
+        CircuitConcreteLibfunc::InitCircuitData(_) => {
+            take_inputs!(let [CoreValue::RangeCheck96] = inputs);
+            (vec![CoreValue::RangeCheck96, CoreValue::CircuitInputs(vec![])], 0)
+        }
+        CircuitConcreteLibfunc::AddInput(SignatureAndTypeConcreteLibfunc { ty, .. }) => {
+            take_inputs!(let [CoreValue::CircuitInputs(mut values), CoreValue::Struct(limbs)] = inputs);
+            values.push(u96_limbs_from_values(&limbs)?);
+            let branch = usize::from(values.len() < circuit_info(get_type, ty)?.n_inputs);
+            (vec![CoreValue::CircuitInputs(values)], branch)
+        }
+        CircuitConcreteLibfunc::GetDescriptor(_) => {
             let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Uint32(*c)], 0)
+            (vec![CoreValue::CircuitDescriptor], 0)
+        }
+        CircuitConcreteLibfunc::TryIntoCircuitModulus(_) => {
+            take_inputs!(let [CoreValue::Struct(limbs)] = inputs);
+            if u96_limbs_from_values(&limbs)? > BigInt::one() {
+                (vec![CoreValue::Struct(limbs)], 0)
+            } else {
+                (vec![], 1)
+            }
         }
-        Uint32Concrete::Operation(libfunc) => {
+        CircuitConcreteLibfunc::Eval(SignatureAndTypeConcreteLibfunc { ty, .. }) => {
             take_inputs!(let [
-                CoreValue::RangeCheck, CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)
+                CoreValue::AddMod,
+                CoreValue::MulMod,
+                CoreValue::CircuitDescriptor,
+                CoreValue::CircuitInputs(circuit_inputs),
+                CoreValue::Struct(modulus),
+                _,
+                _,
             ] = inputs);
-            let (value, overflow) = match libfunc.operator {
-                IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
-                IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
-            };
-            (vec![CoreValue::RangeCheck, CoreValue::Uint32(value)], usize::from(overflow))
+            let modulus = u96_limbs_from_values(&modulus)?;
+            let (values, failure) =
+                eval_circuit(circuit_info(get_type, ty)?, &circuit_inputs, &modulus);
+            let outputs = CoreValue::CircuitOutputs { values, modulus: modulus.clone() };
+            match failure {
+                None => (vec![CoreValue::AddMod, CoreValue::MulMod, outputs], 0),
+                Some(nullifier) => (
+                    vec![
+                        CoreValue::AddMod,
+                        CoreValue::MulMod,
+                        outputs,
+                        CoreValue::CircuitFailureGuarantee { nullifier, modulus },
+                    ],
+                    1,
+                ),
+            }
         }
-        Uint32Concrete::SquareRoot(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint32(value)] = inputs);
-            let root = BigInt::from(value).sqrt();
-            (vec![CoreValue::RangeCheck, CoreValue::Uint16(root.to_u16().unwrap())], 0)
+        CircuitConcreteLibfunc::GetOutput(ConcreteGetOutputLibFunc {
+            circuit_ty,
+            output_ty,
+            ..
+        }) => {
+            take_inputs!(let [CoreValue::CircuitOutputs { values, modulus }] = inputs);
+            let offset = *circuit_info(get_type, circuit_ty)?
+                .values
+                .get(output_ty)
+                .ok_or(LibfuncSimulationError::WrongArgType)?;
+            let value = values.get(offset).ok_or(LibfuncSimulationError::WrongArgType)?;
+            (
+                vec![
+                    CoreValue::Struct(u96_limbs_to_values(value)),
+                    CoreValue::Struct(
+                        chain!(u96_limbs_to_values(value), u96_limbs_to_values(&modulus)).collect(),
+                    ),
+                ],
+                0,
+            )
         }
-        Uint32Concrete::Equal(_) => {
-            take_inputs!(let [CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)] = inputs);
-            // "False" branch (branch 0) is the case a != b.
-            // "True" branch (branch 1) is the case a == b.
-            (vec![], usize::from(lhs == rhs))
+        CircuitConcreteLibfunc::FailureGuaranteeVerify(_) => {
+            take_inputs!(let [
+                CoreValue::RangeCheck96,
+                CoreValue::MulMod,
+                CoreValue::CircuitFailureGuarantee { nullifier, modulus },
+                _,
+                _,
+            ] = inputs);
+            (
+                vec![
+                    CoreValue::RangeCheck96,
+                    CoreValue::MulMod,
+                    CoreValue::Struct(
+                        chain!(u96_limbs_to_values(&nullifier), u96_limbs_to_values(&modulus))
+                            .collect(),
+                    ),
+                ],
+                0,
+            )
         }
-        Uint32Concrete::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::Uint32(value)] = inputs);
-            (vec![CoreValue::Felt252(Felt252::from(value))], 0)
+        CircuitConcreteLibfunc::IntoU96Guarantee(_) => {
+            let [value] = take_inputs(inputs)?;
+            (vec![CoreValue::BoundedInt(take_int(&value)?)], 0)
         }
-        Uint32Concrete::FromFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
-            match value.to_u32() {
-                Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint32(value)], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
+        CircuitConcreteLibfunc::U96GuaranteeVerify(_) => {
+            take_inputs!(let [CoreValue::RangeCheck96, CoreValue::BoundedInt(_)] = inputs);
+            (vec![CoreValue::RangeCheck96], 0)
+        }
+        CircuitConcreteLibfunc::U96LimbsLessThanGuaranteeVerify(
+            ConcreteU96LimbsLessThanGuaranteeVerifyLibfunc { limb_count, .. },
+        ) => {
+            take_inputs!(let [CoreValue::Struct(mut limbs)] = inputs);
+            let limb_count = *limb_count;
+            if limbs.len() != 2 * limb_count {
+                return Err(LibfuncSimulationError::WrongArgType);
+            }
+            let rhs_high = limbs.pop().unwrap();
+            let lhs_high = limbs.remove(limb_count - 1);
+            let diff = take_int(&rhs_high)? - take_int(&lhs_high)?;
+            if diff.is_zero() {
+                (vec![CoreValue::Struct(limbs)], 0)
+            } else {
+                (vec![CoreValue::BoundedInt(diff)], 1)
             }
         }
-        Uint32Concrete::IsZero(_) => unimplemented!(),
-        Uint32Concrete::Divmod(_) => unimplemented!(),
-        Uint32Concrete::Bitwise(_) => unimplemented!(),
-        Uint32Concrete::WideMul(_) => {
-            take_inputs!(let [CoreValue::Uint32(lhs), CoreValue::Uint32(rhs)] = inputs);
-            (vec![CoreValue::Uint64(u64::from(lhs) * u64::from(rhs))], 0)
+        CircuitConcreteLibfunc::U96SingleLimbLessThanGuaranteeVerify(_) => {
+            take_inputs!(let [CoreValue::Struct(limbs)] = inputs);
+            let Ok::<[CoreValue; 2], _>([lhs, rhs]) = limbs.try_into() else {
+                return Err(LibfuncSimulationError::WrongArgType);
+            };
+            (vec![CoreValue::BoundedInt(take_int(&rhs)? - take_int(&lhs)?)], 0)
         }
     })
 }
 
-/// Simulate u64 library functions.
-fn simulate_u64_libfunc(
-    libfunc: &Uint64Concrete,
+/// Returns the info of the circuit of the given type.
+fn circuit_info<'a>(
+    get_type: &GetType<'a, '_>,
+    ty: &ConcreteTypeId,
+) -> Result<&'a CircuitInfo, LibfuncSimulationError> {
+    match get_type(ty).ok_or(LibfuncSimulationError::UnresolvedTypeInfo)? {
+        CoreTypeConcrete::Circuit(CircuitTypeConcrete::Circuit(ConcreteCircuit {
+            circuit_info,
+            ..
+        })) => Ok(circuit_info),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    }
+}
+
+/// Evaluates a circuit in the same order as the runner fills the values of the gates.
+///
+/// Returns the values of all the gates (unfilled values as zero), and the nullifier of the first
+/// failing inverse gate, if any.
+fn eval_circuit(
+    info: &CircuitInfo,
+    inputs: &[BigInt],
+    modulus: &BigInt,
+) -> (Vec<BigInt>, Option<BigInt>) {
+    let mut values: Vec<Option<BigInt>> = vec![None; 1 + info.n_inputs + info.values.len()];
+    values[0] = Some(BigInt::one());
+    for (value, input) in values[1..].iter_mut().zip(inputs) {
+        *value = Some(input.clone());
+    }
+    let mut failure = None;
+    let mut add_gates = info.add_offsets.iter().peekable();
+    let mut mul_gates = info.mul_offsets.iter();
+    loop {
+        while let Some(gate) = add_gates.peek() {
+            match (&values[gate.lhs], &values[gate.rhs], &values[gate.output]) {
+                (Some(lhs), Some(rhs), _) => {
+                    values[gate.output] = Some((lhs + rhs).mod_floor(modulus));
+                }
+                (None, Some(rhs), Some(output)) => {
+                    values[gate.lhs] = Some((output - rhs).mod_floor(modulus));
+                }
+                _ => break,
+            }
+            add_gates.next();
+        }
+        let Some(gate) = mul_gates.next() else {
+            break;
+        };
+        match (&values[gate.lhs], &values[gate.rhs]) {
+            (Some(lhs), Some(rhs)) => {
+                values[gate.output] = Some((lhs * rhs).mod_floor(modulus));
+            }
+            (None, Some(rhs)) => {
+                let ExtendedGcd { gcd, x, .. } = rhs.extended_gcd(modulus);
+                if gcd.is_one() {
+                    values[gate.lhs] = Some(x.mod_floor(modulus));
+                } else {
+                    let nullifier = modulus / gcd;
+                    values[gate.lhs] = Some(nullifier.clone());
+                    failure.get_or_insert(nullifier);
+                }
+            }
+            _ => {}
+        }
+    }
+    (values.into_iter().map(Option::unwrap_or_default).collect(), failure)
+}
+
+/// Simulate QM31 library functions.
+fn simulate_qm31_libfunc(
+    libfunc: &QM31Concrete,
     inputs: Vec<CoreValue>,
 ) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
     Ok(match libfunc {
-        Uint64Concrete::Const(IntConstConcreteLibfunc { c, .. }) => {
+        QM31Concrete::BinaryOperation(QM31BinaryOpConcreteLibfunc { operator, .. }) => {
+            match take_inputs(inputs)? {
+                [CoreValue::QM31(lhs), CoreValue::QM31(rhs)] => {
+                    (vec![CoreValue::QM31(qm31::binary_op(*operator, lhs, rhs))], 0)
+                }
+                [CoreValue::BoundedInt(lhs), CoreValue::BoundedInt(rhs)] => {
+                    let as_m31 = |v: BigInt| v.to_u32().ok_or(LibfuncSimulationError::WrongArgType);
+                    let [value, ..] = qm31::binary_op(
+                        *operator,
+                        [as_m31(lhs)?, 0, 0, 0],
+                        [as_m31(rhs)?, 0, 0, 0],
+                    );
+                    (vec![CoreValue::BoundedInt(value.into())], 0)
+                }
+                _ => return Err(LibfuncSimulationError::WrongArgType),
+            }
+        }
+        QM31Concrete::Const(libfunc) => {
             let [] = take_inputs(inputs)?;
-            (vec![CoreValue::Uint64(*c)], 0)
+            (vec![CoreValue::QM31([libfunc.w0, libfunc.w1, libfunc.w2, libfunc.w3])], 0)
+        }
+        QM31Concrete::IsZero(_) => {
+            take_inputs!(let [CoreValue::QM31(value)] = inputs);
+            if value == [0; 4] { (vec![], 0) } else { (vec![CoreValue::QM31(value)], 1) }
+        }
+        QM31Concrete::Pack(_) => {
+            let coordinates: [CoreValue; 4] = take_inputs(inputs)?;
+            let mut value = [0; 4];
+            for (coordinate, input) in value.iter_mut().zip(coordinates) {
+                *coordinate =
+                    take_int(&input)?.to_u32().ok_or(LibfuncSimulationError::WrongArgType)?;
+            }
+            (vec![CoreValue::QM31(value)], 0)
         }
-        Uint64Concrete::Operation(libfunc) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] = inputs);
-            let (value, overflow) = match libfunc.operator {
-                IntOperator::OverflowingAdd => lhs.overflowing_add(rhs),
-                IntOperator::OverflowingSub => lhs.overflowing_sub(rhs),
-            };
-            (vec![CoreValue::RangeCheck, CoreValue::Uint64(value)], usize::from(overflow))
+        QM31Concrete::Unpack(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::QM31(value)] = inputs);
+            (
+                chain!(
+                    [CoreValue::RangeCheck],
+                    value.map(|coordinate| CoreValue::BoundedInt(coordinate.into()))
+                )
+                .collect(),
+                0,
+            )
         }
-        Uint64Concrete::SquareRoot(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint64(value)] = inputs);
-            let root = BigInt::from(value).sqrt();
-            (vec![CoreValue::RangeCheck, CoreValue::Uint32(root.to_u32().unwrap())], 0)
+        QM31Concrete::FromM31(_) => {
+            let [value] = take_inputs(inputs)?;
+            let value = take_int(&value)?.to_u32().ok_or(LibfuncSimulationError::WrongArgType)?;
+            (vec![CoreValue::QM31([value, 0, 0, 0])], 0)
         }
-        Uint64Concrete::Equal(_) => {
-            take_inputs!(let [CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] = inputs);
-            // "False" branch (branch 0) is the case a != b.
-            // "True" branch (branch 1) is the case a == b.
-            (vec![], usize::from(lhs == rhs))
+    })
+}
+
+/// Arithmetic over the QM31 field, the degree 4 extension of the M31 field.
+///
+/// A QM31 value `[a, b, c, d]` represents `(a + b*i) + (c + d*i)*u` where `i^2 = -1` and
+/// `u^2 = 2 + i`.
+mod qm31 {
+    use super::QM31BinaryOperator;
+
+    /// The M31 prime.
+    const P: u64 = (1 << 31) - 1;
+
+    type CM31 = [u64; 2];
+
+    fn cm31_add(a: CM31, b: CM31) -> CM31 {
+        [(a[0] + b[0]) % P, (a[1] + b[1]) % P]
+    }
+
+    fn cm31_neg(a: CM31) -> CM31 {
+        [(P - a[0]) % P, (P - a[1]) % P]
+    }
+
+    fn cm31_mul(a: CM31, b: CM31) -> CM31 {
+        [(a[0] * b[0] + (P - a[1]) * b[1]) % P, (a[0] * b[1] + a[1] * b[0]) % P]
+    }
+
+    fn m31_inverse(a: u64) -> u64 {
+        let mut result = 1;
+        let mut base = a;
+        let mut exp = P - 2;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % P;
+            }
+            base = base * base % P;
+            exp >>= 1;
         }
-        Uint64Concrete::ToFelt252(_) => {
-            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Uint64(value)] = inputs);
-            (vec![CoreValue::Felt252(Felt252::from(value))], 0)
+        result
+    }
+
+    fn cm31_inverse(a: CM31) -> CM31 {
+        // (a + bi)^-1 = (a - bi) / (a^2 + b^2).
+        let norm_inverse = m31_inverse((a[0] * a[0] + a[1] * a[1]) % P);
+        [a[0] * norm_inverse % P, (P - a[1]) * norm_inverse % P]
+    }
+
+    fn to_cm31_pair(a: [u32; 4]) -> (CM31, CM31) {
+        let [a0, a1, a2, a3] = a.map(|coordinate| u64::from(coordinate) % P);
+        ([a0, a1], [a2, a3])
+    }
+
+    fn from_cm31_pair(x: CM31, y: CM31) -> [u32; 4] {
+        [x[0], x[1], y[0], y[1]].map(|coordinate| coordinate as u32)
+    }
+
+    fn mul(a: [u32; 4], b: [u32; 4]) -> [u32; 4] {
+        // (x1 + y1*u)(x2 + y2*u) = x1*x2 + R*y1*y2 + (x1*y2 + y1*x2)*u, where R = u^2 = 2 + i.
+        let (x1, y1) = to_cm31_pair(a);
+        let (x2, y2) = to_cm31_pair(b);
+        let r = [2, 1];
+        from_cm31_pair(
+            cm31_add(cm31_mul(x1, x2), cm31_mul(r, cm31_mul(y1, y2))),
+            cm31_add(cm31_mul(x1, y2), cm31_mul(y1, x2)),
+        )
+    }
+
+    fn inverse(a: [u32; 4]) -> [u32; 4] {
+        // (x + y*u)^-1 = (x - y*u) / (x^2 - R*y^2).
+        let (x, y) = to_cm31_pair(a);
+        let r = [2, 1];
+        let denominator_inverse =
+            cm31_inverse(cm31_add(cm31_mul(x, x), cm31_neg(cm31_mul(r, cm31_mul(y, y)))));
+        from_cm31_pair(cm31_mul(x, denominator_inverse), cm31_mul(cm31_neg(y), denominator_inverse))
+    }
+
+    /// Applies the binary operator on the given values.
+    pub fn binary_op(operator: QM31BinaryOperator, lhs: [u32; 4], rhs: [u32; 4]) -> [u32; 4] {
+        let (lhs_x, lhs_y) = to_cm31_pair(lhs);
+        let (rhs_x, rhs_y) = to_cm31_pair(rhs);
+        match operator {
+            QM31BinaryOperator::Add => {
+                from_cm31_pair(cm31_add(lhs_x, rhs_x), cm31_add(lhs_y, rhs_y))
+            }
+            QM31BinaryOperator::Sub => {
+                from_cm31_pair(cm31_add(lhs_x, cm31_neg(rhs_x)), cm31_add(lhs_y, cm31_neg(rhs_y)))
+            }
+            QM31BinaryOperator::Mul => mul(lhs, rhs),
+            QM31BinaryOperator::Div => mul(lhs, inverse(rhs)),
         }
-        Uint64Concrete::FromFelt252(_) => {
+    }
+}
+
+/// The initialization vector of blake2s.
+const BLAKE2S_IV: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+/// The message word permutations of the rounds of blake2s.
+const BLAKE2S_SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Applies the blake2s compression function on the given state and message, where `byte_count` is
+/// the number of bytes hashed so far and `finalize` is the finalization flag.
+fn blake2s_compress(h: [u32; 8], message: [u32; 16], byte_count: u32, finalize: u32) -> [u32; 8] {
+    let mut v = [0; 16];
+    v[..8].copy_from_slice(&h);
+    v[8..].copy_from_slice(&BLAKE2S_IV);
+    v[12] ^= byte_count;
+    v[14] ^= finalize;
+    let mut mix = |a: usize, b: usize, c: usize, d: usize, x: u32, y: u32| {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(12);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(8);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(7);
+    };
+    for sigma in BLAKE2S_SIGMA {
+        let m = |i: usize| message[sigma[i]];
+        mix(0, 4, 8, 12, m(0), m(1));
+        mix(1, 5, 9, 13, m(2), m(3));
+        mix(2, 6, 10, 14, m(4), m(5));
+        mix(3, 7, 11, 15, m(6), m(7));
+        mix(0, 5, 10, 15, m(8), m(9));
+        mix(1, 6, 11, 12, m(10), m(11));
+        mix(2, 7, 8, 13, m(12), m(13));
+        mix(3, 4, 9, 14, m(14), m(15));
+    }
+    std::array::from_fn(|i| h[i] ^ v[i] ^ v[i + 8])
+}
+
+/// Simulate Starknet library functions.
+fn simulate_starknet_libfunc(
+    libfunc: &StarknetConcreteLibfunc,
+    inputs: Vec<CoreValue>,
+    handler: &mut dyn StarknetSyscallHandler,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    Ok(match libfunc {
+        StarknetConcreteLibfunc::ClassHashConst(SignatureAndConstConcreteLibfunc { c, .. })
+        | StarknetConcreteLibfunc::ContractAddressConst(SignatureAndConstConcreteLibfunc {
+            c,
+            ..
+        })
+        | StarknetConcreteLibfunc::StorageBaseAddressConst(SignatureAndConstConcreteLibfunc {
+            c,
+            ..
+        }) => {
+            let [] = take_inputs(inputs)?;
+            (vec![CoreValue::Felt252(c.into())], 0)
+        }
+        StarknetConcreteLibfunc::ClassHashTryFromFelt252(_)
+        | StarknetConcreteLibfunc::ContractAddressTryFromFelt252(_)
+        | StarknetConcreteLibfunc::StorageAddressTryFromFelt252(_) => {
             take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
-            match value.to_u64() {
-                Some(value) => (vec![CoreValue::RangeCheck, CoreValue::Uint64(value)], 0),
-                None => (vec![CoreValue::RangeCheck], 1),
+            if value.to_biguint().bits() <= 251 {
+                (vec![CoreValue::RangeCheck, CoreValue::Felt252(value)], 0)
+            } else {
+                (vec![CoreValue::RangeCheck], 1)
             }
         }
-        Uint64Concrete::IsZero(_) => unimplemented!(),
-        Uint64Concrete::Divmod(_) => unimplemented!(),
-        Uint64Concrete::Bitwise(_) => unimplemented!(),
-        Uint64Concrete::WideMul(_) => {
-            take_inputs!(let [CoreValue::Uint64(lhs), CoreValue::Uint64(rhs)] = inputs);
-            (vec![CoreValue::Uint128(u128::from(lhs) * u128::from(rhs))], 0)
+        StarknetConcreteLibfunc::ClassHashToFelt252(_)
+        | StarknetConcreteLibfunc::ContractAddressToFelt252(_)
+        | StarknetConcreteLibfunc::StorageAddressToFelt252(_)
+        | StarknetConcreteLibfunc::StorageAddressFromBase(_) => {
+            take_inputs!(let [CoreValue::Felt252(value)] = inputs);
+            (vec![CoreValue::Felt252(value)], 0)
         }
+        StarknetConcreteLibfunc::StorageBaseAddressFromFelt252(_) => {
+            take_inputs!(let [CoreValue::RangeCheck, CoreValue::Felt252(value)] = inputs);
+            let bound = (BigUint::one() << 251) - 256u32;
+            let address = Felt252::from(value.to_biguint() % bound);
+            (vec![CoreValue::RangeCheck, CoreValue::Felt252(address)], 0)
+        }
+        StarknetConcreteLibfunc::StorageAddressFromBaseAndOffset(_) => {
+            take_inputs!(let [CoreValue::Felt252(base), CoreValue::Uint8(offset)] = inputs);
+            (vec![CoreValue::Felt252(base + Felt252::from(offset))], 0)
+        }
+        StarknetConcreteLibfunc::StorageRead(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [CoreValue::Uint32(domain), CoreValue::Felt252(address)] = inputs);
+            Ok(handler
+                .storage_read(domain, address, gas)
+                .map(|value| vec![CoreValue::Felt252(value)]))
+        })?,
+        StarknetConcreteLibfunc::StorageWrite(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [
+                CoreValue::Uint32(domain), CoreValue::Felt252(address), CoreValue::Felt252(value)
+            ] = inputs);
+            Ok(handler.storage_write(domain, address, value, gas).map(|()| vec![]))
+        })?,
+        StarknetConcreteLibfunc::GetBlockHash(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [CoreValue::Uint64(block_number)] = inputs);
+            Ok(handler.get_block_hash(block_number, gas).map(|hash| vec![CoreValue::Felt252(hash)]))
+        })?,
+        StarknetConcreteLibfunc::GetExecutionInfo(_) => simulate_syscall(inputs, |inputs, gas| {
+            let [] = take_inputs(inputs)?;
+            Ok(handler.get_execution_info(gas).map(|info| vec![info.to_core_value(false)]))
+        })?,
+        StarknetConcreteLibfunc::GetExecutionInfoV2(_) => {
+            simulate_syscall(inputs, |inputs, gas| {
+                let [] = take_inputs(inputs)?;
+                Ok(handler.get_execution_info(gas).map(|info| vec![info.to_core_value(true)]))
+            })?
+        }
+        StarknetConcreteLibfunc::EmitEvent(_) => simulate_syscall(inputs, |inputs, gas| {
+            let [keys, data] = take_inputs(inputs)?;
+            let (keys, data) = (felt252_span_values(keys)?, felt252_span_values(data)?);
+            Ok(handler.emit_event(keys, data, gas).map(|()| vec![]))
+        })?,
+        StarknetConcreteLibfunc::SendMessageToL1(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [CoreValue::Felt252(to_address), payload] = inputs);
+            let payload = felt252_span_values(payload)?;
+            Ok(handler.send_message_to_l1(to_address, payload, gas).map(|()| vec![]))
+        })?,
+        StarknetConcreteLibfunc::CallContract(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [
+                CoreValue::Felt252(address), CoreValue::Felt252(selector), calldata
+            ] = inputs);
+            let calldata = felt252_span_values(calldata)?;
+            Ok(handler
+                .call_contract(address, selector, calldata, gas)
+                .map(|result| vec![felt252_span(&result)]))
+        })?,
+        StarknetConcreteLibfunc::LibraryCall(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [
+                CoreValue::Felt252(class_hash), CoreValue::Felt252(selector), calldata
+            ] = inputs);
+            let calldata = felt252_span_values(calldata)?;
+            Ok(handler
+                .library_call(class_hash, selector, calldata, gas)
+                .map(|result| vec![felt252_span(&result)]))
+        })?,
+        StarknetConcreteLibfunc::MetaTxV0(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [
+                CoreValue::Felt252(address), CoreValue::Felt252(selector), calldata, signature
+            ] = inputs);
+            let (calldata, signature) =
+                (felt252_span_values(calldata)?, felt252_span_values(signature)?);
+            Ok(handler
+                .meta_tx_v0(address, selector, calldata, signature, gas)
+                .map(|result| vec![felt252_span(&result)]))
+        })?,
+        StarknetConcreteLibfunc::Deploy(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [
+                CoreValue::Felt252(class_hash),
+                CoreValue::Felt252(salt),
+                calldata,
+                CoreValue::Enum { index: deploy_from_zero, .. },
+            ] = inputs);
+            let calldata = felt252_span_values(calldata)?;
+            Ok(handler
+                .deploy(class_hash, salt, calldata, deploy_from_zero == 1, gas)
+                .map(|(address, result)| vec![CoreValue::Felt252(address), felt252_span(&result)]))
+        })?,
+        StarknetConcreteLibfunc::ReplaceClass(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [CoreValue::Felt252(class_hash)] = inputs);
+            Ok(handler.replace_class(class_hash, gas).map(|()| vec![]))
+        })?,
+        StarknetConcreteLibfunc::GetClassHashAt(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [CoreValue::Felt252(address)] = inputs);
+            Ok(handler
+                .get_class_hash_at(address, gas)
+                .map(|class_hash| vec![CoreValue::Felt252(class_hash)]))
+        })?,
+        StarknetConcreteLibfunc::Keccak(_) => simulate_syscall(inputs, |inputs, gas| {
+            take_inputs!(let [CoreValue::Struct(span)] = inputs);
+            let Ok::<[CoreValue; 1], _>([CoreValue::Array(words)]) = span.try_into() else {
+                return Err(LibfuncSimulationError::WrongArgType);
+            };
+            let words = words
+                .into_iter()
+                .map(|word| match word {
+                    CoreValue::Uint64(word) => Ok(word),
+                    _ => Err(LibfuncSimulationError::WrongArgType),
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(handler.keccak(words, gas).map(|hash| vec![u256_value(&hash)]))
+        })?,
+        StarknetConcreteLibfunc::Sha256ProcessBlock(_) => {
+            simulate_syscall(inputs, |inputs, gas| {
+                take_inputs!(let [CoreValue::Struct(state), CoreValue::Struct(block)] = inputs);
+                Ok(handler
+                    .sha256_process_block(u32_array(state)?, u32_array(block)?, gas)
+                    .map(|state| vec![u32_array_value(state)]))
+            })?
+        }
+        StarknetConcreteLibfunc::Sha256StateHandleInit(_)
+        | StarknetConcreteLibfunc::Sha256StateHandleDigest(_) => {
+            take_inputs!(let [value @ CoreValue::Struct(_)] = inputs);
+            (vec![value], 0)
+        }
+        StarknetConcreteLibfunc::Testing(TestingConcreteLibfunc::Cheatcode(
+            CheatcodeConcreteLibfunc { selector, .. },
+        )) => {
+            let [input] = take_inputs(inputs)?;
+            let output = handler
+                .cheatcode(selector, felt252_span_values(input)?)
+                .ok_or_else(|| LibfuncSimulationError::UnknownCheatcode(selector.clone()))?;
+            (vec![felt252_span(&output)], 0)
+        }
+        StarknetConcreteLibfunc::Secp256(Secp256ConcreteLibfunc::K1(libfunc)) => {
+            simulate_secp256_libfunc(Secp256Curve::K1, libfunc, inputs, handler)?
+        }
+        StarknetConcreteLibfunc::Secp256(Secp256ConcreteLibfunc::R1(libfunc)) => {
+            simulate_secp256_libfunc(Secp256Curve::R1, libfunc, inputs, handler)?
+        }
+    })
+}
+
+/// Simulate secp256 library functions of the given curve.
+fn simulate_secp256_libfunc<T: Secp256Trait>(
+    curve: Secp256Curve,
+    libfunc: &Secp256OpConcreteLibfunc<T>,
+    inputs: Vec<CoreValue>,
+    handler: &mut dyn StarknetSyscallHandler,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    simulate_syscall(inputs, |inputs, gas| {
+        Ok(match libfunc {
+            Secp256OpConcreteLibfunc::New(_) => {
+                let [x, y] = take_inputs(inputs)?;
+                handler
+                    .secp256_new(curve, u256_from_value(&x)?, u256_from_value(&y)?, gas)
+                    .map(|point| vec![option_value(point.map(CoreValue::Secp256Point))])
+            }
+            Secp256OpConcreteLibfunc::Add(_) => {
+                take_inputs!(let [CoreValue::Secp256Point(p0), CoreValue::Secp256Point(p1)] = inputs);
+                handler
+                    .secp256_add(curve, p0, p1, gas)
+                    .map(|point| vec![CoreValue::Secp256Point(point)])
+            }
+            Secp256OpConcreteLibfunc::Mul(_) => {
+                take_inputs!(let [CoreValue::Secp256Point(p), scalar] = inputs);
+                handler
+                    .secp256_mul(curve, p, u256_from_value(&scalar)?, gas)
+                    .map(|point| vec![CoreValue::Secp256Point(point)])
+            }
+            Secp256OpConcreteLibfunc::GetPointFromX(_) => {
+                take_inputs!(let [x, CoreValue::Enum { index: y_parity, .. }] = inputs);
+                handler
+                    .secp256_get_point_from_x(curve, u256_from_value(&x)?, y_parity == 1, gas)
+                    .map(|point| vec![option_value(point.map(CoreValue::Secp256Point))])
+            }
+            Secp256OpConcreteLibfunc::GetXy(_) => {
+                take_inputs!(let [CoreValue::Secp256Point(p)] = inputs);
+                handler
+                    .secp256_get_xy(curve, p, gas)
+                    .map(|(x, y)| vec![u256_value(&x), u256_value(&y)])
+            }
+        })
+    })
+}
+
+/// Simulates a syscall libfunc, given the syscall inputs without the gas builtin and the system.
+///
+/// On success, jumps to the success branch with the outputs of the syscall. On failure, jumps to
+/// the failure branch with the revert reason.
+fn simulate_syscall(
+    inputs: Vec<CoreValue>,
+    syscall: impl FnOnce(
+        Vec<CoreValue>,
+        &mut i64,
+    ) -> Result<SyscallResult<Vec<CoreValue>>, LibfuncSimulationError>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    if inputs.len() < 2 {
+        return Err(LibfuncSimulationError::WrongNumberOfArgs);
+    }
+    let mut inputs = inputs;
+    let syscall_inputs = inputs.split_off(2);
+    take_inputs!(let [CoreValue::GasBuiltin(mut gas_counter), CoreValue::System] = inputs);
+    Ok(match syscall(syscall_inputs, &mut gas_counter)? {
+        Ok(outputs) => {
+            (chain!([CoreValue::GasBuiltin(gas_counter), CoreValue::System], outputs).collect(), 0)
+        }
+        Err(revert_reason) => (
+            vec![
+                CoreValue::GasBuiltin(gas_counter),
+                CoreValue::System,
+                CoreValue::Array(revert_reason.into_iter().map(CoreValue::Felt252).collect()),
+            ],
+            1,
+        ),
     })
 }
 
@@ -731,3 +1921,232 @@ fn take_inputs<const COUNT: usize>(
 ) -> Result<[CoreValue; COUNT], LibfuncSimulationError> {
     TryFrom::try_from(inputs).map_err(|_| LibfuncSimulationError::WrongNumberOfArgs)
 }
+
+/// Returns the type of an output of the libfunc.
+fn output_ty(libfunc: &impl ConcreteLibfunc, branch: usize, idx: usize) -> &ConcreteTypeId {
+    &libfunc.branch_signatures()[branch].vars[idx].ty
+}
+
+/// Returns the integer value of a numeric value, or an error if the value is not numeric.
+///
+/// Felt252 values are considered signed, i.e. in the range `(-P/2, P/2)`.
+fn take_int(value: &CoreValue) -> Result<BigInt, LibfuncSimulationError> {
+    Ok(match value {
+        CoreValue::Felt252(value) => {
+            let value = value.to_bigint();
+            if value > &*CAIRO_PRIME_BIGINT / 2 { value - &*CAIRO_PRIME_BIGINT } else { value }
+        }
+        CoreValue::Uint8(value) => (*value).into(),
+        CoreValue::Uint16(value) => (*value).into(),
+        CoreValue::Uint32(value) => (*value).into(),
+        CoreValue::Uint64(value) => (*value).into(),
+        CoreValue::Uint128(value) => (*value).into(),
+        CoreValue::Sint8(value) => (*value).into(),
+        CoreValue::Sint16(value) => (*value).into(),
+        CoreValue::Sint32(value) => (*value).into(),
+        CoreValue::Sint64(value) => (*value).into(),
+        CoreValue::Sint128(value) => (*value).into(),
+        CoreValue::BoundedInt(value) => value.clone(),
+        _ => return Err(LibfuncSimulationError::WrongArgType),
+    })
+}
+
+/// Returns the concrete type behind wrapping types with no additional runtime information.
+fn unwrap_type<'a>(
+    get_type: &GetType<'a, '_>,
+    ty: &ConcreteTypeId,
+) -> Result<&'a CoreTypeConcrete, LibfuncSimulationError> {
+    let concrete = get_type(ty).ok_or(LibfuncSimulationError::UnresolvedTypeInfo)?;
+    match concrete {
+        CoreTypeConcrete::NonZero(inner)
+        | CoreTypeConcrete::Box(inner)
+        | CoreTypeConcrete::Snapshot(inner) => unwrap_type(get_type, &inner.ty),
+        _ => Ok(concrete),
+    }
+}
+
+/// Returns the value of the numeric type `ty` with the given integer value.
+fn int_from_type(
+    get_type: &GetType<'_, '_>,
+    ty: &ConcreteTypeId,
+    value: BigInt,
+) -> Result<CoreValue, LibfuncSimulationError> {
+    let out_of_range = || LibfuncSimulationError::WrongArgType;
+    Ok(match unwrap_type(get_type, ty)? {
+        CoreTypeConcrete::Felt252(_)
+        | CoreTypeConcrete::Bytes31(_)
+        | CoreTypeConcrete::Starknet(
+            StarknetTypeConcrete::ClassHash(_)
+            | StarknetTypeConcrete::ContractAddress(_)
+            | StarknetTypeConcrete::StorageBaseAddress(_)
+            | StarknetTypeConcrete::StorageAddress(_),
+        ) => CoreValue::Felt252(value.into()),
+        CoreTypeConcrete::Uint8(_) => CoreValue::Uint8(value.to_u8().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Uint16(_) => CoreValue::Uint16(value.to_u16().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Uint32(_) => CoreValue::Uint32(value.to_u32().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Uint64(_) => CoreValue::Uint64(value.to_u64().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Uint128(_) => {
+            CoreValue::Uint128(value.to_u128().ok_or_else(out_of_range)?)
+        }
+        CoreTypeConcrete::Sint8(_) => CoreValue::Sint8(value.to_i8().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Sint16(_) => CoreValue::Sint16(value.to_i16().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Sint32(_) => CoreValue::Sint32(value.to_i32().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Sint64(_) => CoreValue::Sint64(value.to_i64().ok_or_else(out_of_range)?),
+        CoreTypeConcrete::Sint128(_) => {
+            CoreValue::Sint128(value.to_i128().ok_or_else(out_of_range)?)
+        }
+        CoreTypeConcrete::BoundedInt(_)
+        | CoreTypeConcrete::Circuit(CircuitTypeConcrete::U96Guarantee(_)) => {
+            CoreValue::BoundedInt(value)
+        }
+        _ => return Err(LibfuncSimulationError::WrongArgType),
+    })
+}
+
+/// Returns the range of values of the numeric type `ty`.
+fn int_range(
+    get_type: &GetType<'_, '_>,
+    ty: &ConcreteTypeId,
+) -> Result<Range, LibfuncSimulationError> {
+    Range::from_type_info(unwrap_type(get_type, ty)?.info())
+        .map_err(|_| LibfuncSimulationError::WrongArgType)
+}
+
+/// Returns the member types of the struct type `ty`.
+fn struct_members<'a>(
+    get_type: &GetType<'a, '_>,
+    ty: &ConcreteTypeId,
+) -> Result<&'a [ConcreteTypeId], LibfuncSimulationError> {
+    match unwrap_type(get_type, ty)? {
+        CoreTypeConcrete::Struct(StructConcreteType { members, .. }) => Ok(members),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    }
+}
+
+/// Returns the default value of a dict value of type `ty`.
+fn default_value(
+    get_type: &GetType<'_, '_>,
+    ty: &ConcreteTypeId,
+) -> Result<CoreValue, LibfuncSimulationError> {
+    match unwrap_type(get_type, ty)? {
+        CoreTypeConcrete::Nullable(_) => Ok(CoreValue::Nullable(None)),
+        _ => int_from_type(get_type, ty, BigInt::zero()),
+    }
+}
+
+/// Returns the value of the const type `const_ty`.
+fn const_value(
+    get_type: &GetType<'_, '_>,
+    const_ty: &ConcreteTypeId,
+) -> Result<CoreValue, LibfuncSimulationError> {
+    let CoreTypeConcrete::Const(ConstConcreteType { inner_ty, inner_data, .. }) =
+        get_type(const_ty).ok_or(LibfuncSimulationError::UnresolvedTypeInfo)?
+    else {
+        return Err(LibfuncSimulationError::WrongArgType);
+    };
+    let inner_const_value = |arg: &GenericArg| match arg {
+        GenericArg::Type(ty) => const_value(get_type, ty),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    };
+    match (get_type(inner_ty), inner_data.as_slice()) {
+        (Some(CoreTypeConcrete::Struct(_)), _) => Ok(CoreValue::Struct(
+            inner_data.iter().map(inner_const_value).collect::<Result<_, _>>()?,
+        )),
+        (Some(CoreTypeConcrete::Enum(_)), [GenericArg::Value(index), variant]) => {
+            Ok(CoreValue::Enum {
+                value: Box::new(inner_const_value(variant)?),
+                index: index.to_usize().ok_or(LibfuncSimulationError::WrongArgType)?,
+            })
+        }
+        (Some(CoreTypeConcrete::NonZero(_)), [inner]) => inner_const_value(inner),
+        (_, [GenericArg::Value(value)]) => int_from_type(get_type, inner_ty, value.clone()),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    }
+}
+
+/// Returns the value representation of a bool.
+fn bool_value(value: bool) -> CoreValue {
+    CoreValue::Enum { value: Box::new(CoreValue::Struct(vec![])), index: usize::from(value) }
+}
+
+/// Returns the value representation of an `Option`.
+fn option_value(value: Option<CoreValue>) -> CoreValue {
+    match value {
+        Some(value) => CoreValue::Enum { value: Box::new(value), index: 0 },
+        None => CoreValue::Enum { value: Box::new(CoreValue::Struct(vec![])), index: 1 },
+    }
+}
+
+/// Returns the felts of a `Span<felt252>` value.
+fn felt252_span_values(span: CoreValue) -> Result<Vec<Felt252>, LibfuncSimulationError> {
+    let CoreValue::Struct(span) = span else {
+        return Err(LibfuncSimulationError::WrongArgType);
+    };
+    let Ok::<[CoreValue; 1], _>([CoreValue::Array(values)]) = span.try_into() else {
+        return Err(LibfuncSimulationError::WrongArgType);
+    };
+    values
+        .into_iter()
+        .map(|value| match value {
+            CoreValue::Felt252(value) => Ok(value),
+            _ => Err(LibfuncSimulationError::WrongArgType),
+        })
+        .collect()
+}
+
+/// Returns the number represented by a `u256` value.
+fn u256_from_value(value: &CoreValue) -> Result<BigUint, LibfuncSimulationError> {
+    match value {
+        CoreValue::Struct(limbs) if limbs.len() == 2 => u128_limbs_from_values(limbs),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    }
+}
+
+/// Returns the number represented by little-endian `u128` limbs.
+fn u128_limbs_from_values(limbs: &[CoreValue]) -> Result<BigUint, LibfuncSimulationError> {
+    limbs.iter().rev().try_fold(BigUint::zero(), |acc, limb| match limb {
+        CoreValue::Uint128(limb) => Ok((acc << 128) + *limb),
+        _ => Err(LibfuncSimulationError::WrongArgType),
+    })
+}
+
+/// Returns the little-endian `u128` limbs representing the number.
+fn u128_limbs_to_values(mut value: BigUint, count: usize) -> Vec<CoreValue> {
+    (0..count)
+        .map(|_| {
+            let limb = (&value % (BigUint::one() << 128u32)).to_u128().unwrap();
+            value >>= 128;
+            CoreValue::Uint128(limb)
+        })
+        .collect()
+}
+
+/// Returns the number represented by little-endian `u96` limbs.
+fn u96_limbs_from_values(limbs: &[CoreValue]) -> Result<BigInt, LibfuncSimulationError> {
+    limbs.iter().rev().try_fold(BigInt::zero(), |acc, limb| Ok((acc << 96) + take_int(limb)?))
+}
+
+/// Returns the 4 little-endian `u96` limbs representing the number.
+fn u96_limbs_to_values(value: &BigInt) -> Vec<CoreValue> {
+    (0..4).map(|i| CoreValue::BoundedInt((value >> (96 * i)) % (BigInt::one() << 96))).collect()
+}
+
+/// Returns the `u32` values of a fixed size array value.
+fn u32_array<const COUNT: usize>(
+    values: Vec<CoreValue>,
+) -> Result<[u32; COUNT], LibfuncSimulationError> {
+    let values: [CoreValue; COUNT] = take_inputs(values)?;
+    let mut result = [0; COUNT];
+    for (result, value) in result.iter_mut().zip(values) {
+        let CoreValue::Uint32(value) = value else {
+            return Err(LibfuncSimulationError::WrongArgType);
+        };
+        *result = value;
+    }
+    Ok(result)
+}
+
+/// Returns the value representation of a fixed size array of `u32` values.
+fn u32_array_value<const COUNT: usize>(values: [u32; COUNT]) -> CoreValue {
+    CoreValue::Struct(values.into_iter().map(CoreValue::Uint32).collect())
+}
@@ -4,6 +4,7 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::izip;
 use thiserror::Error;
 
+use self::starknet::{StarknetState, StarknetSyscallHandler};
 use self::value::CoreValue;
 use crate::edit_state::{EditStateError, put_results, take_args};
 use crate::extensions::core::{CoreConcreteLibfunc, CoreLibfunc, CoreType};
@@ -12,6 +13,7 @@ use crate::program::{Program, Statement, StatementIdx};
 use crate::program_registry::{ProgramRegistry, ProgramRegistryError};
 
 pub mod core;
+pub mod starknet;
 #[cfg(test)]
 mod test;
 pub mod value;
@@ -25,6 +27,12 @@ pub enum LibfuncSimulationError {
     WrongArgType,
     #[error("Could not resolve requested symbol value")]
     UnresolvedStatementGasInfo,
+    #[error("Could not resolve requested type info")]
+    UnresolvedTypeInfo,
+    #[error("Panicked unsafely")]
+    UnsafePanic,
+    #[error("Cheatcode is not supported by the syscall handler")]
+    UnknownCheatcode(num_bigint::BigInt),
     #[error("Error occurred during user function call")]
     FunctionSimulationError(FunctionId, Box<SimulationError>),
 }
@@ -47,18 +55,38 @@ pub enum SimulationError {
 }
 
 /// Runs a function from the program with the given inputs.
+///
+/// Syscalls are handled by a default [StarknetState].
 pub fn run(
     program: &Program,
     statement_gas_info: &HashMap<StatementIdx, i64>,
     function_id: &FunctionId,
     inputs: Vec<CoreValue>,
+) -> Result<Vec<CoreValue>, SimulationError> {
+    run_with_syscall_handler(
+        program,
+        statement_gas_info,
+        function_id,
+        inputs,
+        &mut StarknetState::default(),
+    )
+}
+
+/// Runs a function from the program with the given inputs, handling syscalls with the given
+/// handler.
+pub fn run_with_syscall_handler(
+    program: &Program,
+    statement_gas_info: &HashMap<StatementIdx, i64>,
+    function_id: &FunctionId,
+    inputs: Vec<CoreValue>,
+    syscall_handler: &mut dyn StarknetSyscallHandler,
 ) -> Result<Vec<CoreValue>, SimulationError> {
     let context = SimulationContext {
         program,
         statement_gas_info,
         registry: &ProgramRegistry::new(program)?,
     };
-    context.simulate_function(function_id, inputs)
+    context.simulate_function(function_id, inputs, syscall_handler)
 }
 
 /// Helper class for running the simulation.
@@ -73,6 +101,7 @@ impl SimulationContext<'_> {
         &self,
         function_id: &FunctionId,
         inputs: Vec<CoreValue>,
+        syscall_handler: &mut dyn StarknetSyscallHandler,
     ) -> Result<Vec<CoreValue>, SimulationError> {
         let func = self.registry.get_function(function_id)?;
         let mut current_statement_id = func.entry_point;
@@ -116,6 +145,7 @@ impl SimulationContext<'_> {
                         libfunc,
                         inputs,
                         current_statement_id,
+                        syscall_handler,
                     )?;
                     let branch_info = &invocation.branches[chosen_branch];
                     state = put_results(remaining, izip!(branch_info.results.iter(), outputs))
@@ -135,13 +165,16 @@ impl SimulationContext<'_> {
         libfunc: &CoreConcreteLibfunc,
         inputs: Vec<CoreValue>,
         current_statement_id: StatementIdx,
+        syscall_handler: &mut dyn StarknetSyscallHandler,
     ) -> Result<(Vec<CoreValue>, usize), SimulationError> {
         core::simulate(
             libfunc,
             inputs,
             || self.statement_gas_info.get(idx).copied(),
-            |function_id, inputs| {
-                self.simulate_function(function_id, inputs).map_err(|error| {
+            |id| self.registry.get_type(id).ok(),
+            syscall_handler,
+            |function_id, inputs, syscall_handler| {
+                self.simulate_function(function_id, inputs, syscall_handler).map_err(|error| {
                     LibfuncSimulationError::FunctionSimulationError(
                         function_id.clone(),
                         Box::new(error),
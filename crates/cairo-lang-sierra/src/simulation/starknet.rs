@@ -0,0 +1,643 @@
+use std::collections::{HashMap, VecDeque};
+
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+use starknet_types_core::felt::Felt as Felt252;
+
+use super::value::CoreValue;
+
+/// The result of a simulated syscall - either the outputs of the syscall, or the revert reason.
+pub type SyscallResult<T> = Result<T, Vec<Felt252>>;
+
+/// Returns a revert reason made of a single short string.
+pub fn revert_reason(reason: &str) -> Vec<Felt252> {
+    vec![Felt252::from_bytes_be_slice(reason.as_bytes())]
+}
+
+/// Handler for the Starknet syscalls and testing cheatcodes invoked during simulation.
+///
+/// Each syscall receives the gas counter available at the time of the call, which the handler may
+/// reduce by the cost of the syscall. Returning a revert reason makes the simulated libfunc jump to
+/// its failure branch, in the same manner a failed syscall behaves in an actual run.
+///
+/// All the syscalls that depend on the state of the network fail by default, while the pure
+/// syscalls (hashes and secp256 curve operations) are computed by default.
+pub trait StarknetSyscallHandler {
+    /// Handles the `storage_read` syscall.
+    fn storage_read(
+        &mut self,
+        _address_domain: u32,
+        _address: Felt252,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Felt252> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `storage_write` syscall.
+    fn storage_write(
+        &mut self,
+        _address_domain: u32,
+        _address: Felt252,
+        _value: Felt252,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<()> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `get_block_hash` syscall.
+    fn get_block_hash(
+        &mut self,
+        _block_number: u64,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Felt252> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles both the `get_execution_info` and the `get_execution_info_v2` syscalls.
+    fn get_execution_info(&mut self, _gas_counter: &mut i64) -> SyscallResult<ExecutionInfo> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `emit_event` syscall.
+    fn emit_event(
+        &mut self,
+        _keys: Vec<Felt252>,
+        _data: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<()> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `send_message_to_l1` syscall.
+    fn send_message_to_l1(
+        &mut self,
+        _to_address: Felt252,
+        _payload: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<()> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `call_contract` syscall.
+    fn call_contract(
+        &mut self,
+        _address: Felt252,
+        _selector: Felt252,
+        _calldata: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Vec<Felt252>> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `library_call` syscall.
+    fn library_call(
+        &mut self,
+        _class_hash: Felt252,
+        _selector: Felt252,
+        _calldata: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Vec<Felt252>> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `deploy` syscall. Returns the address of the deployed contract and the result
+    /// of its constructor.
+    fn deploy(
+        &mut self,
+        _class_hash: Felt252,
+        _contract_address_salt: Felt252,
+        _calldata: Vec<Felt252>,
+        _deploy_from_zero: bool,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<(Felt252, Vec<Felt252>)> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `replace_class` syscall.
+    fn replace_class(&mut self, _class_hash: Felt252, _gas_counter: &mut i64) -> SyscallResult<()> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `get_class_hash_at` syscall.
+    fn get_class_hash_at(
+        &mut self,
+        _contract_address: Felt252,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Felt252> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `meta_tx_v0` syscall.
+    fn meta_tx_v0(
+        &mut self,
+        _address: Felt252,
+        _selector: Felt252,
+        _calldata: Vec<Felt252>,
+        _signature: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Vec<Felt252>> {
+        Err(revert_reason("Unsupported syscall"))
+    }
+
+    /// Handles the `keccak` syscall. Returns the resulting u256 hash.
+    fn keccak(&mut self, input: Vec<u64>, _gas_counter: &mut i64) -> SyscallResult<BigUint> {
+        keccak(&input)
+    }
+
+    /// Handles the `sha256_process_block` syscall.
+    fn sha256_process_block(
+        &mut self,
+        state: [u32; 8],
+        block: [u32; 16],
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<[u32; 8]> {
+        Ok(sha256_process_block(state, block))
+    }
+
+    /// Handles the `secp256k1_new` and `secp256r1_new` syscalls.
+    fn secp256_new(
+        &mut self,
+        curve: Secp256Curve,
+        x: BigUint,
+        y: BigUint,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Option<Secp256Point>> {
+        curve.new_point(x, y)
+    }
+
+    /// Handles the `secp256k1_add` and `secp256r1_add` syscalls.
+    fn secp256_add(
+        &mut self,
+        curve: Secp256Curve,
+        p0: Secp256Point,
+        p1: Secp256Point,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Secp256Point> {
+        Ok(curve.add(&p0, &p1))
+    }
+
+    /// Handles the `secp256k1_mul` and `secp256r1_mul` syscalls.
+    fn secp256_mul(
+        &mut self,
+        curve: Secp256Curve,
+        p: Secp256Point,
+        scalar: BigUint,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Secp256Point> {
+        Ok(curve.mul(&p, &scalar))
+    }
+
+    /// Handles the `secp256k1_get_point_from_x` and `secp256r1_get_point_from_x` syscalls.
+    fn secp256_get_point_from_x(
+        &mut self,
+        curve: Secp256Curve,
+        x: BigUint,
+        y_parity: bool,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Option<Secp256Point>> {
+        curve.point_from_x(x, y_parity)
+    }
+
+    /// Handles the `secp256k1_get_xy` and `secp256r1_get_xy` syscalls.
+    fn secp256_get_xy(
+        &mut self,
+        _curve: Secp256Curve,
+        p: Secp256Point,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<(BigUint, BigUint)> {
+        Ok((p.x, p.y))
+    }
+
+    /// Handles a testing cheatcode. Returns `None` if the cheatcode is not supported by the
+    /// handler.
+    fn cheatcode(&mut self, _selector: &BigInt, _input: Vec<Felt252>) -> Option<Vec<Felt252>> {
+        None
+    }
+}
+
+/// Copy of the cairo `ExecutionInfo` struct.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExecutionInfo {
+    pub block_info: BlockInfo,
+    pub tx_info: TxInfo,
+    pub caller_address: Felt252,
+    pub contract_address: Felt252,
+    pub entry_point_selector: Felt252,
+}
+impl ExecutionInfo {
+    /// Returns the value representation of the `ExecutionInfo` struct, or of the
+    /// `v2::ExecutionInfo` struct if `v2` is set.
+    pub fn to_core_value(&self, v2: bool) -> CoreValue {
+        CoreValue::Struct(vec![
+            self.block_info.to_core_value(),
+            self.tx_info.to_core_value(v2),
+            CoreValue::Felt252(self.caller_address),
+            CoreValue::Felt252(self.contract_address),
+            CoreValue::Felt252(self.entry_point_selector),
+        ])
+    }
+}
+
+/// Copy of the cairo `BlockInfo` struct.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BlockInfo {
+    pub block_number: u64,
+    pub block_timestamp: u64,
+    pub sequencer_address: Felt252,
+}
+impl BlockInfo {
+    /// Returns the value representation of the struct.
+    fn to_core_value(&self) -> CoreValue {
+        CoreValue::Struct(vec![
+            CoreValue::Uint64(self.block_number),
+            CoreValue::Uint64(self.block_timestamp),
+            CoreValue::Felt252(self.sequencer_address),
+        ])
+    }
+}
+
+/// Copy of the cairo `TxInfo` struct, including the fields of `v2::TxInfo`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TxInfo {
+    pub version: Felt252,
+    pub account_contract_address: Felt252,
+    pub max_fee: u128,
+    pub signature: Vec<Felt252>,
+    pub transaction_hash: Felt252,
+    pub chain_id: Felt252,
+    pub nonce: Felt252,
+    pub resource_bounds: Vec<ResourceBounds>,
+    pub tip: u128,
+    pub paymaster_data: Vec<Felt252>,
+    pub nonce_data_availability_mode: u32,
+    pub fee_data_availability_mode: u32,
+    pub account_deployment_data: Vec<Felt252>,
+}
+impl TxInfo {
+    /// Returns the value representation of the struct, with the `v2` fields if `v2` is set.
+    fn to_core_value(&self, v2: bool) -> CoreValue {
+        let mut members = vec![
+            CoreValue::Felt252(self.version),
+            CoreValue::Felt252(self.account_contract_address),
+            CoreValue::Uint128(self.max_fee),
+            felt252_span(&self.signature),
+            CoreValue::Felt252(self.transaction_hash),
+            CoreValue::Felt252(self.chain_id),
+            CoreValue::Felt252(self.nonce),
+        ];
+        if v2 {
+            members.extend([
+                CoreValue::Struct(vec![CoreValue::Array(
+                    self.resource_bounds.iter().map(ResourceBounds::to_core_value).collect(),
+                )]),
+                CoreValue::Uint128(self.tip),
+                felt252_span(&self.paymaster_data),
+                CoreValue::Uint32(self.nonce_data_availability_mode),
+                CoreValue::Uint32(self.fee_data_availability_mode),
+                felt252_span(&self.account_deployment_data),
+            ]);
+        }
+        CoreValue::Struct(members)
+    }
+}
+
+/// Copy of the cairo `ResourceBounds` struct.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResourceBounds {
+    pub resource: Felt252,
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+impl ResourceBounds {
+    /// Returns the value representation of the struct.
+    fn to_core_value(&self) -> CoreValue {
+        CoreValue::Struct(vec![
+            CoreValue::Felt252(self.resource),
+            CoreValue::Uint64(self.max_amount),
+            CoreValue::Uint128(self.max_price_per_unit),
+        ])
+    }
+}
+
+/// Returns the value representation of a `Span<felt252>`.
+pub fn felt252_span(values: &[Felt252]) -> CoreValue {
+    CoreValue::Struct(vec![CoreValue::Array(
+        values.iter().copied().map(CoreValue::Felt252).collect(),
+    )])
+}
+
+/// Object storing the logs of a contract.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContractLogs {
+    /// Events, as pairs of keys and data.
+    pub events: VecDeque<(Vec<Felt252>, Vec<Felt252>)>,
+    /// Messages sent to L1, as pairs of the destination address and the payload.
+    pub l2_to_l1_messages: VecDeque<(Felt252, Vec<Felt252>)>,
+}
+
+/// An in-memory simulation of the Starknet state, handling the syscalls of a single contract
+/// context. Contract interactions (calls, deployments, etc.) are not supported.
+///
+/// Supports the same testing cheatcodes as the runner (`set_block_number`, `pop_log`, etc.).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StarknetState {
+    /// The values of addresses in the simulated storage per contract.
+    pub storage: HashMap<Felt252, HashMap<Felt252, Felt252>>,
+    /// A mapping from contract address to logs.
+    pub logs: HashMap<Felt252, ContractLogs>,
+    /// The simulated execution info.
+    pub exec_info: ExecutionInfo,
+    /// A mock history, mapping block number to the block hash.
+    pub block_hash: HashMap<u64, Felt252>,
+}
+impl StarknetSyscallHandler for StarknetState {
+    fn storage_read(
+        &mut self,
+        address_domain: u32,
+        address: Felt252,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Felt252> {
+        if address_domain != 0 {
+            // Only address_domain 0 is currently supported.
+            return Err(revert_reason("Unsupported address domain"));
+        }
+        Ok(self
+            .storage
+            .get(&self.exec_info.contract_address)
+            .and_then(|contract_storage| contract_storage.get(&address))
+            .copied()
+            .unwrap_or_default())
+    }
+
+    fn storage_write(
+        &mut self,
+        address_domain: u32,
+        address: Felt252,
+        value: Felt252,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<()> {
+        if address_domain != 0 {
+            // Only address_domain 0 is currently supported.
+            return Err(revert_reason("Unsupported address domain"));
+        }
+        self.storage.entry(self.exec_info.contract_address).or_default().insert(address, value);
+        Ok(())
+    }
+
+    fn get_block_hash(
+        &mut self,
+        block_number: u64,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<Felt252> {
+        self.block_hash
+            .get(&block_number)
+            .copied()
+            .ok_or_else(|| revert_reason("GET_BLOCK_HASH_NOT_SET"))
+    }
+
+    fn get_execution_info(&mut self, _gas_counter: &mut i64) -> SyscallResult<ExecutionInfo> {
+        Ok(self.exec_info.clone())
+    }
+
+    fn emit_event(
+        &mut self,
+        keys: Vec<Felt252>,
+        data: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<()> {
+        let contract = self.exec_info.contract_address;
+        self.logs.entry(contract).or_default().events.push_back((keys, data));
+        Ok(())
+    }
+
+    fn send_message_to_l1(
+        &mut self,
+        to_address: Felt252,
+        payload: Vec<Felt252>,
+        _gas_counter: &mut i64,
+    ) -> SyscallResult<()> {
+        let contract = self.exec_info.contract_address;
+        self.logs.entry(contract).or_default().l2_to_l1_messages.push_back((to_address, payload));
+        Ok(())
+    }
+
+    fn cheatcode(&mut self, selector: &BigInt, input: Vec<Felt252>) -> Option<Vec<Felt252>> {
+        let selector = String::from_utf8(selector.to_bytes_be().1).ok()?;
+        let single_input = || match input.as_slice() {
+            [value] => Some(*value),
+            _ => None,
+        };
+        let exec_info = &mut self.exec_info;
+        match selector.as_str() {
+            "set_sequencer_address" => exec_info.block_info.sequencer_address = single_input()?,
+            "set_block_number" => exec_info.block_info.block_number = single_input()?.to_u64()?,
+            "set_block_timestamp" => {
+                exec_info.block_info.block_timestamp = single_input()?.to_u64()?
+            }
+            "set_caller_address" => exec_info.caller_address = single_input()?,
+            "set_contract_address" => exec_info.contract_address = single_input()?,
+            "set_version" => exec_info.tx_info.version = single_input()?,
+            "set_account_contract_address" => {
+                exec_info.tx_info.account_contract_address = single_input()?
+            }
+            "set_max_fee" => exec_info.tx_info.max_fee = single_input()?.to_u128()?,
+            "set_transaction_hash" => exec_info.tx_info.transaction_hash = single_input()?,
+            "set_chain_id" => exec_info.tx_info.chain_id = single_input()?,
+            "set_nonce" => exec_info.tx_info.nonce = single_input()?,
+            "set_signature" => exec_info.tx_info.signature = input,
+            "set_block_hash" => {
+                let [block_number, block_hash] = input.as_slice() else {
+                    return None;
+                };
+                self.block_hash.insert(block_number.to_u64()?, *block_hash);
+            }
+            "pop_log" => {
+                let contract_logs = self.logs.get_mut(&single_input()?);
+                let mut res = vec![];
+                if let Some((keys, data)) =
+                    contract_logs.and_then(|contract_logs| contract_logs.events.pop_front())
+                {
+                    res.push(keys.len().into());
+                    res.extend(keys);
+                    res.push(data.len().into());
+                    res.extend(data);
+                }
+                return Some(res);
+            }
+            "pop_l2_to_l1_message" => {
+                let contract_logs = self.logs.get_mut(&single_input()?);
+                let mut res = vec![];
+                if let Some((to_address, payload)) = contract_logs
+                    .and_then(|contract_logs| contract_logs.l2_to_l1_messages.pop_front())
+                {
+                    res.push(to_address);
+                    res.push(payload.len().into());
+                    res.extend(payload);
+                }
+                return Some(res);
+            }
+            _ => return None,
+        }
+        Some(vec![])
+    }
+}
+
+/// Computes the keccak hash of the given input, made of 64-bit words in chunks of 17 words.
+/// Returns the resulting hash as a u256.
+pub fn keccak(input: &[u64]) -> SyscallResult<BigUint> {
+    if !input.len().is_multiple_of(17) {
+        return Err(revert_reason("Invalid keccak input size"));
+    }
+    let mut state = [0u64; 25];
+    for chunk in input.chunks(17) {
+        for (i, val) in chunk.iter().enumerate() {
+            state[i] ^= val;
+        }
+        keccak::f1600(&mut state)
+    }
+    Ok(state[..4].iter().rev().fold(BigUint::zero(), |acc, word| (acc << 64) + word))
+}
+
+/// Applies the sha256 compression function on the given state and block.
+pub fn sha256_process_block(mut state: [u32; 8], block: [u32; 16]) -> [u32; 8] {
+    let block = sha2::digest::generic_array::GenericArray::from_exact_iter(
+        block.iter().flat_map(|word| word.to_be_bytes()),
+    )
+    .unwrap();
+    sha2::compress256(&mut state, &[block]);
+    state
+}
+
+/// The secp256 curves supported by the secp256 syscalls.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Secp256Curve {
+    K1,
+    R1,
+}
+
+/// A point on a secp256 curve, where the point at infinity is represented by `(0, 0)`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Secp256Point {
+    pub x: BigUint,
+    pub y: BigUint,
+}
+impl Secp256Point {
+    /// Returns whether the point is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+impl Secp256Curve {
+    /// The prime of the field the curve is defined over.
+    pub fn prime(self) -> BigUint {
+        let hex = match self {
+            Secp256Curve::K1 => "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            Secp256Curve::R1 => "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        };
+        BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    /// The `a` coefficient of the curve equation `y^2 = x^3 + a*x + b`.
+    fn a(self) -> BigUint {
+        match self {
+            Secp256Curve::K1 => BigUint::zero(),
+            Secp256Curve::R1 => self.prime() - 3u32,
+        }
+    }
+
+    /// The `b` coefficient of the curve equation `y^2 = x^3 + a*x + b`.
+    fn b(self) -> BigUint {
+        match self {
+            Secp256Curve::K1 => BigUint::from(7u32),
+            Secp256Curve::R1 => BigUint::parse_bytes(
+                b"5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+                16,
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Returns `x^3 + a*x + b` modulo the prime.
+    fn curve_rhs(self, x: &BigUint) -> BigUint {
+        (x * x * x + self.a() * x + self.b()) % self.prime()
+    }
+
+    /// Returns the inverse of a non-zero field element.
+    fn inverse(self, value: &BigUint) -> BigUint {
+        let p = self.prime();
+        value.modpow(&(&p - 2u32), &p)
+    }
+
+    /// Creates a point from its coordinates. Returns `None` if the point is not on the curve.
+    pub fn new_point(self, x: BigUint, y: BigUint) -> SyscallResult<Option<Secp256Point>> {
+        let p = self.prime();
+        if x >= p || y >= p {
+            return Err(revert_reason("Coordinates out of range"));
+        }
+        let point = Secp256Point { x, y };
+        // Both curves have a cofactor of 1, so any point on the curve is in the correct subgroup.
+        Ok((point.is_infinity() || (&point.y * &point.y) % &p == self.curve_rhs(&point.x))
+            .then_some(point))
+    }
+
+    /// Returns the point with the given `x` coordinate and `y` parity, if there is one.
+    pub fn point_from_x(self, x: BigUint, y_parity: bool) -> SyscallResult<Option<Secp256Point>> {
+        let p = self.prime();
+        if x >= p {
+            return Err(revert_reason("Coordinates out of range"));
+        }
+        let rhs = self.curve_rhs(&x);
+        // Both primes are `3 (mod 4)`, so a square root is `rhs^((p + 1) / 4)`.
+        let y = rhs.modpow(&((&p + 1u32) >> 2), &p);
+        if (&y * &y) % &p != rhs {
+            return Ok(None);
+        }
+        let y = if y.is_odd() == y_parity { y } else { (&p - y) % &p };
+        Ok(Some(Secp256Point { x, y }))
+    }
+
+    /// Adds two points on the curve.
+    pub fn add(self, p0: &Secp256Point, p1: &Secp256Point) -> Secp256Point {
+        if p0.is_infinity() {
+            return p1.clone();
+        }
+        if p1.is_infinity() {
+            return p0.clone();
+        }
+        let p = self.prime();
+        let slope = if p0.x == p1.x {
+            if (&p0.y + &p1.y) % &p == BigUint::zero() {
+                return Secp256Point::default();
+            }
+            (3u32 * &p0.x * &p0.x + self.a()) * self.inverse(&(2u32 * &p0.y)) % &p
+        } else {
+            (&p + &p1.y - &p0.y) * self.inverse(&((&p + &p1.x - &p0.x) % &p)) % &p
+        };
+        let x = (&slope * &slope + 2u32 * &p - &p0.x - &p1.x) % &p;
+        let y = (slope * ((&p + &p0.x - &x) % &p) + &p - &p0.y) % &p;
+        Secp256Point { x, y }
+    }
+
+    /// Multiplies a point on the curve by a scalar.
+    pub fn mul(self, point: &Secp256Point, scalar: &BigUint) -> Secp256Point {
+        let mut result = Secp256Point::default();
+        for i in (0..scalar.bits()).rev() {
+            result = self.add(&result, &result);
+            if scalar.bit(i) {
+                result = self.add(&result, point);
+            }
+        }
+        result
+    }
+}
+
+/// Returns the value representation of a u256 number.
+pub fn u256_value(value: &BigUint) -> CoreValue {
+    let (high, low) = value.div_rem(&(BigUint::one() << 128));
+    CoreValue::Struct(vec![
+        CoreValue::Uint128(low.to_u128().unwrap()),
+        CoreValue::Uint128(high.to_u128().unwrap()),
+    ])
+}
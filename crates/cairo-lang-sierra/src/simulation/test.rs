@@ -1,21 +1,31 @@
 use bimap::BiMap;
+use indoc::{formatdoc, indoc};
+use itertools::chain;
 use num_bigint::BigInt;
+use starknet_types_core::felt::Felt;
+use starknet_types_core::hash::Poseidon as PoseidonHash;
 use test_case::test_case;
 
 use super::LibfuncSimulationError::{
     self, FunctionSimulationError, WrongArgType, WrongNumberOfArgs,
 };
+use super::starknet::{StarknetState, revert_reason};
 use super::value::CoreValue::{
-    self, Array, GasBuiltin, RangeCheck, Uint32, Uint128, Uninitialized,
+    self, AddMod, Array, Bitwise, BoundedInt, Coupon, EcOp, EcPoint, Enum, Felt252, GasBuiltin,
+    GasReserve, MulMod, Nullable, Pedersen, Poseidon, QM31, RangeCheck, RangeCheck96, SegmentArena,
+    Sint8, Sint16, Sint128, Struct, System, U128MulGuarantee, Uint8, Uint16, Uint32, Uint64,
+    Uint128, Uninitialized,
 };
 use super::{SimulationError, core};
+use crate::ProgramParser;
 use crate::extensions::GenericLibfunc;
-use crate::extensions::core::CoreLibfunc;
+use crate::extensions::core::{CoreLibfunc, CoreType};
 use crate::extensions::lib_func::{SignatureSpecializationContext, SpecializationContext};
 use crate::extensions::type_specialization_context::TypeSpecializationContext;
 use crate::extensions::types::TypeInfo;
 use crate::ids::{ConcreteTypeId, FunctionId, GenericTypeId};
 use crate::program::{ConcreteTypeLongId, Function, FunctionSignature, GenericArg, StatementIdx};
+use crate::program_registry::ProgramRegistry;
 use crate::test_utils::build_bijective_mapping;
 
 fn type_arg(name: &str) -> GenericArg {
@@ -111,7 +121,9 @@ fn simulate(
             .unwrap(),
         inputs,
         || Some(4),
-        |id, inputs| {
+        |_| None,
+        &mut StarknetState::default(),
+        |id, inputs, _| {
             if id == &"drop_all_inputs".into() {
                 Ok(vec![])
             } else if id == &"identity".into() {
@@ -227,3 +239,872 @@ fn simulate_error(
 ) -> LibfuncSimulationError {
     simulate(id, generic_args, inputs).err().unwrap()
 }
+
+/// Simulates the libfunc `id` declared in the given Sierra type and libfunc declarations, with
+/// syscalls handled by `state`.
+fn simulate_declared_with_state(
+    declarations: &str,
+    id: &str,
+    inputs: Vec<CoreValue>,
+    state: &mut StarknetState,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let program = ProgramParser::new().parse(declarations).unwrap();
+    let registry = ProgramRegistry::<CoreType, CoreLibfunc>::new(&program).unwrap();
+    core::simulate(
+        registry.get_libfunc(&id.into()).unwrap(),
+        inputs,
+        || Some(4),
+        |id| registry.get_type(id).ok(),
+        state,
+        |_, inputs, _| Ok(inputs),
+    )
+}
+
+/// Simulates the libfunc `id` declared in the given Sierra type and libfunc declarations.
+fn simulate_declared(
+    declarations: &str,
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    simulate_declared_with_state(declarations, id, inputs, &mut StarknetState::default())
+}
+
+/// Common type declarations for the simulation tests of declared libfuncs.
+const COMMON_TYPES: &str = indoc! {"
+    type felt252 = felt252;
+    type u8 = u8;
+    type u16 = u16;
+    type u32 = u32;
+    type u64 = u64;
+    type u128 = u128;
+    type i8 = i8;
+    type i16 = i16;
+    type i128 = i128;
+    type RangeCheck = RangeCheck;
+    type Bitwise = Bitwise;
+    type GasBuiltin = GasBuiltin;
+    type Unit = Struct<ut@Tuple>;
+    type bool = Enum<ut@core::bool, Unit, Unit>;
+    type u256 = Struct<ut@core::integer::u256, u128, u128>;
+    type NonZeroU256 = NonZero<u256>;
+    type u512 = Struct<ut@core::integer::u512, u128, u128, u128, u128>;
+    type U128MulGuarantee = U128MulGuarantee;
+    type NonZeroU8 = NonZero<u8>;
+    type NonZeroFelt252 = NonZero<felt252>;
+    type BoxFelt252 = Box<felt252>;
+    type ArrayFelt252 = Array<felt252>;
+    type SnapshotArrayFelt252 = Snapshot<ArrayFelt252>;
+    type SpanFelt252 = Struct<ut@core::array::Span::<core::felt252>, SnapshotArrayFelt252>;
+    type Tuple2Felt252 = Struct<ut@Tuple, felt252, felt252>;
+    type BoxTuple2Felt252 = Box<Tuple2Felt252>;
+"};
+
+fn felt(value: i128) -> CoreValue {
+    Felt252(value.into())
+}
+
+fn u256(value: u128) -> CoreValue {
+    Struct(vec![Uint128(value), Uint128(0)])
+}
+
+fn unit() -> CoreValue {
+    Struct(vec![])
+}
+
+fn bool_value(value: bool) -> CoreValue {
+    Enum { value: Box::new(unit()), index: value.into() }
+}
+
+#[test_case("felt252_add", vec![felt(2), felt(3)] => Ok((vec![felt(5)], 0)); "felt252_add")]
+#[test_case("felt252_div", vec![felt(6), felt(3)] => Ok((vec![felt(2)], 0)); "felt252_div")]
+#[test_case("felt252_mul_const_3", vec![felt(2)] => Ok((vec![felt(6)], 0)); "felt252_mul_const")]
+#[test_case("felt252_is_zero", vec![felt(0)] => Ok((vec![], 0)); "felt252_is_zero(0)")]
+#[test_case("felt252_is_zero", vec![felt(1)] => Ok((vec![felt(1)], 1)); "felt252_is_zero(1)")]
+#[test_case("bool_and_impl", vec![bool_value(true), bool_value(false)]
+             => Ok((vec![bool_value(false)], 0)); "bool_and")]
+#[test_case("bool_or_impl", vec![bool_value(true), bool_value(false)]
+             => Ok((vec![bool_value(true)], 0)); "bool_or")]
+#[test_case("bool_xor_impl", vec![bool_value(true), bool_value(true)]
+             => Ok((vec![bool_value(false)], 0)); "bool_xor")]
+#[test_case("bool_not_impl", vec![bool_value(false)] => Ok((vec![bool_value(true)], 0)); "bool_not")]
+#[test_case("bool_to_felt252", vec![bool_value(true)] => Ok((vec![felt(1)], 0)); "bool_to_felt252")]
+fn simulate_felt252_and_bool(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        libfunc felt252_add = felt252_add;
+        libfunc felt252_div = felt252_div;
+        libfunc felt252_mul_const_3 = felt252_mul_const<3>;
+        libfunc felt252_is_zero = felt252_is_zero;
+        libfunc bool_and_impl = bool_and_impl;
+        libfunc bool_or_impl = bool_or_impl;
+        libfunc bool_xor_impl = bool_xor_impl;
+        libfunc bool_not_impl = bool_not_impl;
+        libfunc bool_to_felt252 = bool_to_felt252;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test_case("u8_overflowing_add", vec![RangeCheck, Uint8(250), Uint8(10)]
+             => Ok((vec![RangeCheck, Uint8(4)], 1)); "u8_overflowing_add(250, 10)")]
+#[test_case("u8_overflowing_sub", vec![RangeCheck, Uint8(10), Uint8(3)]
+             => Ok((vec![RangeCheck, Uint8(7)], 0)); "u8_overflowing_sub(10, 3)")]
+#[test_case("u8_safe_divmod", vec![RangeCheck, Uint8(7), Uint8(2)]
+             => Ok((vec![RangeCheck, Uint8(3), Uint8(1)], 0)); "u8_safe_divmod(7, 2)")]
+#[test_case("u8_try_from_felt252", vec![RangeCheck, felt(300)]
+             => Ok((vec![RangeCheck], 1)); "u8_try_from_felt252(300)")]
+#[test_case("u8_try_from_felt252", vec![RangeCheck, felt(200)]
+             => Ok((vec![RangeCheck, Uint8(200)], 0)); "u8_try_from_felt252(200)")]
+#[test_case("u8_to_felt252", vec![Uint8(200)] => Ok((vec![felt(200)], 0)); "u8_to_felt252")]
+#[test_case("u8_wide_mul", vec![Uint8(200), Uint8(200)]
+             => Ok((vec![Uint16(40000)], 0)); "u8_wide_mul")]
+#[test_case("u8_sqrt", vec![RangeCheck, Uint8(17)] => Ok((vec![RangeCheck, Uint8(4)], 0)); "u8_sqrt")]
+#[test_case("u8_bitwise", vec![Bitwise, Uint8(12), Uint8(10)]
+             => Ok((vec![Bitwise, Uint8(8), Uint8(14), Uint8(6)], 0)); "u8_bitwise")]
+#[test_case("u8_is_zero", vec![Uint8(3)] => Ok((vec![Uint8(3)], 1)); "u8_is_zero")]
+#[test_case("u8_eq", vec![Uint8(3), Uint8(3)] => Ok((vec![], 1)); "u8_eq")]
+#[test_case("u16_wide_mul", vec![Uint16(60000), Uint16(2)]
+             => Ok((vec![Uint32(120000)], 0)); "u16_wide_mul")]
+#[test_case("u32_overflowing_sub", vec![RangeCheck, Uint32(0), Uint32(1)]
+             => Ok((vec![RangeCheck, Uint32(u32::MAX)], 1)); "u32_overflowing_sub(0, 1)")]
+#[test_case("u64_wide_mul", vec![Uint64(u64::MAX), Uint64(2)]
+             => Ok((vec![Uint128(u128::from(u64::MAX) * 2)], 0)); "u64_wide_mul")]
+#[test_case("u128_guarantee_mul", vec![Uint128(u128::MAX), Uint128(2)]
+             => Ok((vec![Uint128(1), Uint128(u128::MAX - 1), U128MulGuarantee], 0));
+            "u128_guarantee_mul")]
+#[test_case("u128_byte_reverse", vec![Bitwise, Uint128(0x0102)]
+             => Ok((vec![Bitwise, Uint128(0x0201 << 112)], 0)); "u128_byte_reverse")]
+#[test_case("u128s_from_felt252", vec![RangeCheck, felt(5)]
+             => Ok((vec![RangeCheck, Uint128(5)], 0)); "u128s_from_felt252")]
+#[test_case("u128s_from_felt252", vec![RangeCheck, felt(-1)]
+             => Ok((vec![RangeCheck, Uint128(0x8000000000000110000000000000000), Uint128(0)], 1));
+            "u128s_from_felt252(-1)")]
+fn simulate_unsigned_int(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        libfunc u8_overflowing_add = u8_overflowing_add;
+        libfunc u8_overflowing_sub = u8_overflowing_sub;
+        libfunc u8_safe_divmod = u8_safe_divmod;
+        libfunc u8_try_from_felt252 = u8_try_from_felt252;
+        libfunc u8_to_felt252 = u8_to_felt252;
+        libfunc u8_wide_mul = u8_wide_mul;
+        libfunc u8_sqrt = u8_sqrt;
+        libfunc u8_bitwise = u8_bitwise;
+        libfunc u8_is_zero = u8_is_zero;
+        libfunc u8_eq = u8_eq;
+        libfunc u16_wide_mul = u16_wide_mul;
+        libfunc u32_overflowing_sub = u32_overflowing_sub;
+        libfunc u64_wide_mul = u64_wide_mul;
+        libfunc u128_guarantee_mul = u128_guarantee_mul;
+        libfunc u128_byte_reverse = u128_byte_reverse;
+        libfunc u128s_from_felt252 = u128s_from_felt252;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test_case("i8_overflowing_add_impl", vec![RangeCheck, Sint8(100), Sint8(100)]
+             => Ok((vec![RangeCheck, Sint8(-56)], 2)); "i8_overflowing_add(100, 100)")]
+#[test_case("i8_overflowing_sub_impl", vec![RangeCheck, Sint8(-100), Sint8(100)]
+             => Ok((vec![RangeCheck, Sint8(56)], 1)); "i8_overflowing_sub(-100, 100)")]
+#[test_case("i8_overflowing_add_impl", vec![RangeCheck, Sint8(-100), Sint8(100)]
+             => Ok((vec![RangeCheck, Sint8(0)], 0)); "i8_overflowing_add(neg 100, 100)")]
+#[test_case("i8_diff", vec![RangeCheck, Sint8(-1), Sint8(1)]
+             => Ok((vec![RangeCheck, Uint8(254)], 1)); "i8_diff(neg 1, 1)")]
+#[test_case("i8_diff", vec![RangeCheck, Sint8(1), Sint8(-1)]
+             => Ok((vec![RangeCheck, Uint8(2)], 0)); "i8_diff(1, -1)")]
+#[test_case("i8_wide_mul", vec![Sint8(-100), Sint8(100)]
+             => Ok((vec![Sint16(-10000)], 0)); "i8_wide_mul")]
+#[test_case("i8_try_from_felt252", vec![RangeCheck, felt(-5)]
+             => Ok((vec![RangeCheck, Sint8(-5)], 0)); "i8_try_from_felt252(-5)")]
+#[test_case("i8_to_felt252", vec![Sint8(-5)] => Ok((vec![felt(-5)], 0)); "i8_to_felt252")]
+#[test_case("i128_try_from_felt252", vec![RangeCheck, Felt252(Felt::from(i128::MAX) + Felt::ONE)]
+             => Ok((vec![RangeCheck], 1)); "i128_try_from_felt252(i128::MAX + 1)")]
+#[test_case("i128_overflowing_sub_impl", vec![RangeCheck, Sint128(i128::MIN), Sint128(1)]
+             => Ok((vec![RangeCheck, Sint128(i128::MAX)], 1)); "i128_overflowing_sub(MIN, 1)")]
+#[test_case("i128_diff", vec![RangeCheck, Sint128(0), Sint128(1)]
+             => Ok((vec![RangeCheck, Uint128(u128::MAX)], 1)); "i128_diff(0, 1)")]
+#[test_case("i128_const", vec![] => Ok((vec![Sint128(-7)], 0)); "i128_const")]
+fn simulate_signed_int(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        libfunc i8_overflowing_add_impl = i8_overflowing_add_impl;
+        libfunc i8_overflowing_sub_impl = i8_overflowing_sub_impl;
+        libfunc i8_diff = i8_diff;
+        libfunc i8_wide_mul = i8_wide_mul;
+        libfunc i8_try_from_felt252 = i8_try_from_felt252;
+        libfunc i8_to_felt252 = i8_to_felt252;
+        libfunc i128_try_from_felt252 = i128_try_from_felt252;
+        libfunc i128_overflowing_sub_impl = i128_overflowing_sub_impl;
+        libfunc i128_diff = i128_diff;
+        libfunc i128_const = i128_const<-7>;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test_case("u256_safe_divmod", vec![RangeCheck, u256(10), u256(3)]
+             => Ok((vec![RangeCheck, u256(3), u256(1), U128MulGuarantee], 0));
+            "u256_safe_divmod(10, 3)")]
+#[test_case("u256_sqrt", vec![RangeCheck, Struct(vec![Uint128(0), Uint128(1)])]
+             => Ok((vec![RangeCheck, Uint128(1 << 64)], 0)); "u256_sqrt(2^128)")]
+#[test_case("u256_is_zero", vec![u256(0)] => Ok((vec![], 0)); "u256_is_zero(0)")]
+#[test_case("u256_guarantee_inv_mod_n", vec![RangeCheck, u256(3), u256(7)]
+             => Ok((
+                 [vec![RangeCheck, u256(5)], vec![U128MulGuarantee; 8]].concat(), 0
+             )); "u256_inv_mod_n(3, 7)")]
+#[test_case("u256_guarantee_inv_mod_n", vec![RangeCheck, u256(2), u256(4)]
+             => Ok((vec![RangeCheck, U128MulGuarantee, U128MulGuarantee], 1));
+            "u256_inv_mod_n(2, 4)")]
+#[test_case("u512_safe_divmod_by_u256",
+            vec![RangeCheck, Struct(vec![Uint128(1), Uint128(0), Uint128(0), Uint128(1)]), u256(2)]
+             => Ok((
+                 [
+                     vec![
+                         RangeCheck,
+                         Struct(vec![Uint128(0), Uint128(0), Uint128(1 << 127), Uint128(0)]),
+                         u256(1),
+                     ],
+                     vec![U128MulGuarantee; 5],
+                 ].concat(),
+                 0,
+             )); "u512_safe_divmod_by_u256(2^384 + 1, 2)")]
+fn simulate_wide_int(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        libfunc u256_safe_divmod = u256_safe_divmod;
+        libfunc u256_sqrt = u256_sqrt;
+        libfunc u256_is_zero = u256_is_zero;
+        libfunc u256_guarantee_inv_mod_n = u256_guarantee_inv_mod_n;
+        libfunc u512_safe_divmod_by_u256 = u512_safe_divmod_by_u256;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test_case("span_from_tuple", vec![Struct(vec![felt(1), felt(2)])]
+             => Ok((vec![Array(vec![felt(1), felt(2)])], 0)); "span_from_tuple")]
+#[test_case("tuple_from_span", vec![Array(vec![felt(1), felt(2)])]
+             => Ok((vec![Struct(vec![felt(1), felt(2)])], 0)); "tuple_from_span")]
+#[test_case("tuple_from_span", vec![Array(vec![felt(1)])] => Ok((vec![], 1));
+            "tuple_from_span(wrong size)")]
+#[test_case("array_snapshot_multi_pop_front", vec![RangeCheck, Array(vec![felt(1), felt(2), felt(3)])]
+             => Ok((vec![RangeCheck, Array(vec![felt(3)]), Struct(vec![felt(1), felt(2)])], 0));
+            "array_snapshot_multi_pop_front")]
+#[test_case("array_snapshot_multi_pop_back", vec![RangeCheck, Array(vec![felt(1), felt(2), felt(3)])]
+             => Ok((vec![RangeCheck, Array(vec![felt(1)]), Struct(vec![felt(2), felt(3)])], 0));
+            "array_snapshot_multi_pop_back")]
+#[test_case("array_snapshot_multi_pop_back", vec![RangeCheck, Array(vec![felt(1)])]
+             => Ok((vec![RangeCheck, Array(vec![felt(1)])], 1));
+            "array_snapshot_multi_pop_back(too short)")]
+#[test_case("array_snapshot_pop_back", vec![Array(vec![felt(1), felt(2)])]
+             => Ok((vec![Array(vec![felt(1)]), felt(2)], 0)); "array_snapshot_pop_back")]
+#[test_case("array_slice", vec![RangeCheck, Array(vec![felt(1), felt(2), felt(3)]), Uint32(1), Uint32(2)]
+             => Ok((vec![RangeCheck, Array(vec![felt(2), felt(3)])], 0)); "array_slice")]
+#[test_case("array_slice", vec![RangeCheck, Array(vec![felt(1)]), Uint32(1), Uint32(2)]
+             => Ok((vec![RangeCheck], 1)); "array_slice(out of bounds)")]
+#[test_case("array_pop_front_consume", vec![Array(vec![felt(1), felt(2)])]
+             => Ok((vec![felt(1)], 0)); "array_pop_front_consume")]
+fn simulate_array(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        libfunc span_from_tuple = span_from_tuple<Tuple2Felt252>;
+        libfunc tuple_from_span = tuple_from_span<Tuple2Felt252>;
+        libfunc array_snapshot_multi_pop_front = array_snapshot_multi_pop_front<Tuple2Felt252>;
+        libfunc array_snapshot_multi_pop_back = array_snapshot_multi_pop_back<Tuple2Felt252>;
+        libfunc array_snapshot_pop_back = array_snapshot_pop_back<felt252>;
+        libfunc array_slice = array_slice<felt252>;
+        libfunc array_pop_front_consume = array_pop_front_consume<felt252>;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test]
+fn simulate_felt252_dict() {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type SegmentArena = SegmentArena;
+        type Dict = Felt252Dict<u8>;
+        type Entry = Felt252DictEntry<u8>;
+        type SquashedDict = SquashedFelt252Dict<u8>;
+        type DictEntryTuple = Struct<ut@Tuple, felt252, u8, u8>;
+        type DictEntries = Array<DictEntryTuple>;
+        libfunc dict_new = felt252_dict_new<u8>;
+        libfunc entry_get = felt252_dict_entry_get<u8>;
+        libfunc entry_finalize = felt252_dict_entry_finalize<u8>;
+        libfunc dict_squash = felt252_dict_squash<u8>;
+        libfunc into_entries = squashed_felt252_dict_entries<u8>;
+    "};
+    let simulate = |id, inputs| simulate_declared(&declarations, id, inputs).unwrap();
+    let (outputs, _) = simulate("dict_new", vec![SegmentArena]);
+    let Ok::<[CoreValue; 2], _>([SegmentArena, dict]) = outputs.try_into() else { panic!() };
+    let (outputs, _) = simulate("entry_get", vec![dict, felt(3)]);
+    let [entry, value] = outputs.try_into().unwrap();
+    assert_eq!(value, Uint8(0));
+    let (outputs, _) = simulate("entry_finalize", vec![entry, Uint8(7)]);
+    let [dict] = outputs.try_into().unwrap();
+    let (outputs, _) = simulate("entry_get", vec![dict, felt(3)]);
+    let [entry, value] = outputs.try_into().unwrap();
+    assert_eq!(value, Uint8(7));
+    let (outputs, _) = simulate("entry_finalize", vec![entry, Uint8(9)]);
+    let [dict] = outputs.try_into().unwrap();
+    let (outputs, _) =
+        simulate("dict_squash", vec![RangeCheck, GasBuiltin(10), SegmentArena, dict]);
+    let Ok::<[CoreValue; 4], _>([RangeCheck, GasBuiltin(10), SegmentArena, squashed]) =
+        outputs.try_into()
+    else {
+        panic!()
+    };
+    assert_eq!(
+        simulate("into_entries", vec![squashed]),
+        (vec![Array(vec![Struct(vec![felt(3), Uint8(0), Uint8(9)])])], 0)
+    );
+}
+
+#[test]
+fn simulate_ec() {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type EcOp = EcOp;
+        type EcPoint = EcPoint;
+        type EcState = EcState;
+        type NonZeroEcPoint = NonZero<EcPoint>;
+        libfunc ec_point_try_new_nz = ec_point_try_new_nz;
+        libfunc ec_point_from_x_nz = ec_point_from_x_nz;
+        libfunc ec_neg = ec_neg;
+        libfunc ec_point_is_zero = ec_point_is_zero;
+        libfunc ec_state_init = ec_state_init;
+        libfunc ec_state_add = ec_state_add;
+        libfunc ec_state_add_mul = ec_state_add_mul;
+        libfunc ec_state_try_finalize_nz = ec_state_try_finalize_nz;
+    "};
+    let simulate = |id, inputs| simulate_declared(&declarations, id, inputs).unwrap();
+    let x = Felt::from_hex_unchecked(
+        "0x1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+    );
+    let y = Felt::from_hex_unchecked(
+        "0x5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+    );
+    let generator = EcPoint(x, y);
+    assert_eq!(
+        simulate("ec_point_try_new_nz", vec![Felt252(x), Felt252(y)]),
+        (vec![generator.clone()], 0)
+    );
+    assert_eq!(simulate("ec_point_try_new_nz", vec![Felt252(x), Felt252(x)]), (vec![], 1));
+    let (outputs, _) = simulate("ec_point_from_x_nz", vec![RangeCheck, Felt252(x)]);
+    let Ok::<[CoreValue; 2], _>([RangeCheck, EcPoint(from_x, from_x_y)]) = outputs.try_into()
+    else {
+        panic!()
+    };
+    assert_eq!(from_x, x);
+    assert!(from_x_y == y || from_x_y == -y);
+    let (outputs, _) = simulate("ec_neg", vec![generator.clone()]);
+    let [negated] = outputs.try_into().unwrap();
+    assert_eq!(negated, EcPoint(x, -y));
+    assert_eq!(simulate("ec_point_is_zero", vec![generator.clone()]), (vec![generator.clone()], 1));
+
+    // `G + G - G` through the state libfuncs.
+    let (outputs, _) = simulate("ec_state_init", vec![]);
+    let [state] = outputs.try_into().unwrap();
+    let (outputs, _) = simulate("ec_state_add", vec![state.clone(), generator.clone()]);
+    let [doubled] = outputs.try_into().unwrap();
+    let (outputs, _) = simulate("ec_state_add", vec![doubled, generator.clone()]);
+    let [doubled] = outputs.try_into().unwrap();
+    let (outputs, _) =
+        simulate("ec_state_add_mul", vec![EcOp, state.clone(), felt(2), generator.clone()]);
+    assert_eq!(outputs, vec![EcOp, doubled.clone()]);
+    let (outputs, _) = simulate("ec_state_add", vec![doubled, negated]);
+    let [state_g] = outputs.try_into().unwrap();
+    assert_eq!(simulate("ec_state_try_finalize_nz", vec![state_g]), (vec![generator], 0));
+    assert_eq!(simulate("ec_state_try_finalize_nz", vec![state]), (vec![], 1));
+}
+
+#[test]
+fn simulate_hashes() {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type Pedersen = Pedersen;
+        type Poseidon = Poseidon;
+        type Blake2sState = Struct<ut@Tuple, u32, u32, u32, u32, u32, u32, u32, u32>;
+        type BoxBlake2sState = Box<Blake2sState>;
+        type Blake2sInput = Struct<ut@Tuple, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32>;
+        type BoxBlake2sInput = Box<Blake2sInput>;
+        libfunc pedersen = pedersen;
+        libfunc hades_permutation = hades_permutation;
+        libfunc blake2s_finalize = blake2s_finalize;
+    "};
+    let simulate = |id, inputs| simulate_declared(&declarations, id, inputs).unwrap();
+    assert_eq!(
+        simulate("pedersen", vec![Pedersen, felt(1), felt(2)]),
+        (
+            vec![
+                Pedersen,
+                Felt252(Felt::from_hex_unchecked(
+                    "0x5bb9440e27889a364bcb678b1f679ecd1347acdedcbf36e83494f857cc58026"
+                ))
+            ],
+            0
+        )
+    );
+    let (outputs, _) = simulate("hades_permutation", vec![Poseidon, felt(1), felt(2), felt(3)]);
+    let mut expected = [1, 2, 3].map(Felt::from);
+    PoseidonHash::hades_permutation(&mut expected);
+    assert_eq!(outputs, chain!([Poseidon], expected.map(Felt252)).collect::<Vec<_>>());
+
+    let u32s = |values: &[u32]| Struct(values.iter().copied().map(Uint32).collect());
+    let state = u32s(&[
+        1795745351, 3144134277, 1013904242, 2773480762, 1359893119, 2600822924, 528734635,
+        1541459225,
+    ]);
+    assert_eq!(
+        simulate("blake2s_finalize", vec![state, Uint32(2), u32s(&[0; 16])]),
+        (
+            vec![u32s(&[
+                412110711, 3234706100, 3894970767, 982912411, 937789635, 742982576, 3942558313,
+                1407547065,
+            ])],
+            0
+        )
+    );
+}
+
+#[test_case("downcast_u128_u8", vec![RangeCheck, Uint128(300)] => Ok((vec![RangeCheck], 1));
+            "downcast(300)")]
+#[test_case("downcast_u128_u8", vec![RangeCheck, Uint128(30)]
+             => Ok((vec![RangeCheck, Uint8(30)], 0)); "downcast(30)")]
+#[test_case("downcast_felt252_i8", vec![RangeCheck, felt(-3)]
+             => Ok((vec![RangeCheck, Sint8(-3)], 0)); "downcast(-3)")]
+#[test_case("upcast_u8_u64", vec![Uint8(30)] => Ok((vec![Uint64(30)], 0)); "upcast")]
+#[test_case("bytes31_try_from_felt252", vec![RangeCheck, Felt252(Felt::TWO.pow(248u32))]
+             => Ok((vec![RangeCheck], 1)); "bytes31_try_from_felt252(2^248)")]
+#[test_case("bytes31_try_from_felt252", vec![RangeCheck, felt(5)]
+             => Ok((vec![RangeCheck, felt(5)], 0)); "bytes31_try_from_felt252(5)")]
+#[test_case("const_u8", vec![] => Ok((vec![Uint8(5)], 0)); "const_as_immediate<u8>")]
+#[test_case("const_tuple", vec![] => Ok((vec![Struct(vec![felt(1), felt(-2)])], 0));
+            "const_as_box<Tuple>")]
+#[test_case("const_option", vec![] => Ok((vec![Enum { value: Box::new(unit()), index: 1 }], 0));
+            "const_as_immediate<Option>")]
+#[test_case("const_non_zero", vec![] => Ok((vec![Uint8(5)], 0)); "const_as_immediate<NonZero>")]
+fn simulate_casts_and_consts(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type bytes31 = bytes31;
+        type OptionU8 = Enum<ut@core::option::Option::<u8>, u8, Unit>;
+        type ConstU8 = Const<u8, 5>;
+        type ConstFelt1 = Const<felt252, 1>;
+        type ConstFeltMinus2 = Const<felt252, -2>;
+        type ConstTuple = Const<Tuple2Felt252, ConstFelt1, ConstFeltMinus2>;
+        type ConstUnit = Const<Unit>;
+        type ConstNone = Const<OptionU8, 1, ConstUnit>;
+        type ConstNonZero = Const<NonZeroU8, ConstU8>;
+        libfunc downcast_u128_u8 = downcast<u128, u8>;
+        libfunc downcast_felt252_i8 = downcast<felt252, i8>;
+        libfunc upcast_u8_u64 = upcast<u8, u64>;
+        libfunc bytes31_try_from_felt252 = bytes31_try_from_felt252;
+        libfunc const_u8 = const_as_immediate<ConstU8>;
+        libfunc const_tuple = const_as_box<ConstTuple, 0>;
+        libfunc const_option = const_as_immediate<ConstNone>;
+        libfunc const_non_zero = const_as_immediate<ConstNonZero>;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test_case("bounded_int_add", vec![BoundedInt(7.into()), BoundedInt(9.into())]
+             => Ok((vec![BoundedInt(16.into())], 0)); "bounded_int_add")]
+#[test_case("bounded_int_sub", vec![BoundedInt(7.into()), BoundedInt(9.into())]
+             => Ok((vec![BoundedInt((-2).into())], 0)); "bounded_int_sub")]
+#[test_case("bounded_int_mul", vec![BoundedInt(7.into()), BoundedInt(9.into())]
+             => Ok((vec![BoundedInt(63.into())], 0)); "bounded_int_mul")]
+#[test_case("bounded_int_div_rem", vec![RangeCheck, BoundedInt(7.into()), BoundedInt(3.into())]
+             => Ok((vec![RangeCheck, BoundedInt(2.into()), BoundedInt(1.into())], 0));
+            "bounded_int_div_rem")]
+#[test_case("bounded_int_constrain", vec![RangeCheck, BoundedInt(4.into())]
+             => Ok((vec![RangeCheck, BoundedInt(4.into())], 0)); "bounded_int_constrain(4)")]
+#[test_case("bounded_int_constrain", vec![RangeCheck, BoundedInt(5.into())]
+             => Ok((vec![RangeCheck, BoundedInt(5.into())], 1)); "bounded_int_constrain(5)")]
+#[test_case("bounded_int_trim_min", vec![BoundedInt(0.into())] => Ok((vec![], 0));
+            "bounded_int_trim_min(0)")]
+#[test_case("bounded_int_trim_min", vec![BoundedInt(3.into())]
+             => Ok((vec![BoundedInt(3.into())], 1)); "bounded_int_trim_min(3)")]
+#[test_case("enum_from_bounded_int", vec![BoundedInt(1.into())]
+             => Ok((vec![bool_value(true)], 0)); "enum_from_bounded_int")]
+#[test_case("int_range_try_new", vec![RangeCheck, Uint32(3), Uint32(1)]
+             => Ok((vec![RangeCheck, Struct(vec![Uint32(1), Uint32(1)])], 1));
+            "int_range_try_new(3, 1)")]
+#[test_case("int_range_pop_front", vec![Struct(vec![Uint32(1), Uint32(3)])]
+             => Ok((vec![Struct(vec![Uint32(2), Uint32(3)]), Uint32(1)], 1));
+            "int_range_pop_front(1..3)")]
+#[test_case("int_range_pop_front", vec![Struct(vec![Uint32(3), Uint32(3)])]
+             => Ok((vec![], 0)); "int_range_pop_front(3..3)")]
+fn simulate_bounded_int_and_range(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type BI_0_9 = BoundedInt<0, 9>;
+        type BI_1_9 = BoundedInt<1, 9>;
+        type BI_0_18 = BoundedInt<0, 18>;
+        type BI_m9_9 = BoundedInt<-9, 9>;
+        type BI_0_81 = BoundedInt<0, 81>;
+        type BI_0_4 = BoundedInt<0, 4>;
+        type BI_5_9 = BoundedInt<5, 9>;
+        type BI_0_8 = BoundedInt<0, 8>;
+        type BI_0_1 = BoundedInt<0, 1>;
+        type NonZeroBI_1_9 = NonZero<BI_1_9>;
+        type IntRangeU32 = IntRange<u32>;
+        libfunc bounded_int_add = bounded_int_add<BI_0_9, BI_0_9>;
+        libfunc bounded_int_sub = bounded_int_sub<BI_0_9, BI_0_9>;
+        libfunc bounded_int_mul = bounded_int_mul<BI_0_9, BI_0_9>;
+        libfunc bounded_int_div_rem = bounded_int_div_rem<BI_0_9, BI_1_9>;
+        libfunc bounded_int_constrain = bounded_int_constrain<BI_0_9, 5>;
+        libfunc bounded_int_trim_min = bounded_int_trim_min<BI_0_9>;
+        libfunc enum_from_bounded_int = enum_from_bounded_int<bool>;
+        libfunc int_range_try_new = int_range_try_new<u32>;
+        libfunc int_range_pop_front = int_range_pop_front<u32>;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+/// Returns the `u96` limbs value of a `u384`.
+fn u384(value: u64) -> CoreValue {
+    Struct([value.into(), 0.into(), 0.into(), 0.into()].into_iter().map(BoundedInt).collect())
+}
+
+#[test_case(7, 3, 5 => (vec![1, 5], 0); "invertible")]
+#[test_case(6, 3, 5 => (vec![3, 2], 1); "non invertible")]
+fn simulate_circuit(modulus: u64, in0: u64, in1: u64) -> (Vec<u64>, usize) {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type RangeCheck96 = RangeCheck96;
+        type AddMod = AddMod;
+        type MulMod = MulMod;
+        type In0 = CircuitInput<0>;
+        type In1 = CircuitInput<1>;
+        type Mul = MulModGate<In0, In1>;
+        type Inv = InverseGate<In0>;
+        type Outputs = Struct<ut@Tuple, Mul, Inv>;
+        type C = Circuit<Outputs>;
+        type U96 = BoundedInt<0, 79228162514264337593543950335>;
+        type u384 = Struct<ut@core::circuit::u384, U96, U96, U96, U96>;
+        type U96s = Struct<ut@Tuple, U96, U96, U96, U96>;
+        type Accumulator = CircuitInputAccumulator<C>;
+        type Data = CircuitData<C>;
+        type Descriptor = CircuitDescriptor<C>;
+        type Modulus = CircuitModulus;
+        type CircuitOutputs = CircuitOutputs<C>;
+        type PartialOutputs = CircuitPartialOutputs<C>;
+        type FailureGuarantee = CircuitFailureGuarantee;
+        type Zero = BoundedInt<0, 0>;
+        type One = BoundedInt<1, 1>;
+        type U96Guarantee = U96Guarantee;
+        type U96Guarantees = Struct<ut@Tuple, U96Guarantee, U96Guarantee, U96Guarantee, U96Guarantee>;
+        type LessThanGuarantee = U96LimbsLtGuarantee<4>;
+        libfunc init = init_circuit_data<C>;
+        libfunc add_input = add_circuit_input<C>;
+        libfunc get_descriptor = get_circuit_descriptor<C>;
+        libfunc try_into_modulus = try_into_circuit_modulus;
+        libfunc eval = eval_circuit<C>;
+        libfunc get_mul = get_circuit_output<C, Mul>;
+        libfunc get_inv = get_circuit_output<C, Inv>;
+    "};
+    let simulate = |id, inputs| simulate_declared(&declarations, id, inputs).unwrap();
+    let (outputs, _) = simulate("init", vec![RangeCheck96]);
+    let Ok::<[CoreValue; 2], _>([RangeCheck96, accumulator]) = outputs.try_into() else { panic!() };
+    let (outputs, branch) = simulate("add_input", vec![accumulator, u384(in0)]);
+    assert_eq!(branch, 1);
+    let [accumulator] = outputs.try_into().unwrap();
+    let (outputs, branch) = simulate("add_input", vec![accumulator, u384(in1)]);
+    assert_eq!(branch, 0);
+    let [data] = outputs.try_into().unwrap();
+    let (outputs, _) = simulate("get_descriptor", vec![]);
+    let [descriptor] = outputs.try_into().unwrap();
+    assert_eq!(simulate("try_into_modulus", vec![u384(1)]), (vec![], 1));
+    let (outputs, _) = simulate("try_into_modulus", vec![u384(modulus)]);
+    let [modulus] = outputs.try_into().unwrap();
+    let (mut outputs, branch) = simulate(
+        "eval",
+        vec![AddMod, MulMod, descriptor, data, modulus, BoundedInt(0.into()), BoundedInt(1.into())],
+    );
+    let circuit_outputs = outputs.remove(2);
+    let values = ["get_mul", "get_inv"]
+        .map(|id| {
+            let (outputs, _) = simulate(id, vec![circuit_outputs.clone()]);
+            let Ok::<[CoreValue; 2], _>([Struct(limbs), _]) = outputs.try_into() else { panic!() };
+            let Ok::<[CoreValue; 4], _>([BoundedInt(value), ..]) = limbs.try_into() else {
+                panic!()
+            };
+            value.try_into().unwrap()
+        })
+        .to_vec();
+    (values, branch)
+}
+
+#[test_case("qm31_add", vec![QM31([1, 2, 3, 4]), QM31([(1 << 31) - 2, 0, 0, 5])]
+             => Ok((vec![QM31([0, 2, 3, 9])], 0)); "qm31_add")]
+#[test_case("qm31_sub", vec![QM31([1, 2, 3, 4]), QM31([2, 0, 0, 0])]
+             => Ok((vec![QM31([(1 << 31) - 2, 2, 3, 4])], 0)); "qm31_sub")]
+#[test_case("qm31_mul", vec![QM31([0, 0, 1, 0]), QM31([0, 0, 1, 0])]
+             => Ok((vec![QM31([2, 1, 0, 0])], 0)); "qm31_mul(u, u)")]
+#[test_case("qm31_div", vec![QM31([1, 2, 3, 4]), QM31([1, 2, 3, 4])]
+             => Ok((vec![QM31([1, 0, 0, 0])], 0)); "qm31_div(x, x)")]
+#[test_case("qm31_div", vec![QM31([2, 1, 0, 0]), QM31([0, 0, 1, 0])]
+             => Ok((vec![QM31([0, 0, 1, 0])], 0)); "qm31_div(u^2, u)")]
+#[test_case("m31_mul", vec![BoundedInt(((1 << 30) + 1).into()), BoundedInt(2.into())]
+             => Ok((vec![BoundedInt(3.into())], 0)); "m31_mul")]
+#[test_case("qm31_is_zero", vec![QM31([0; 4])] => Ok((vec![], 0)); "qm31_is_zero")]
+#[test_case("qm31_unpack", vec![RangeCheck, QM31([1, 2, 3, 4])]
+             => Ok((
+                 vec![
+                     RangeCheck,
+                     BoundedInt(1.into()),
+                     BoundedInt(2.into()),
+                     BoundedInt(3.into()),
+                     BoundedInt(4.into()),
+                 ],
+                 0,
+             )); "qm31_unpack")]
+#[test_case("qm31_const", vec![] => Ok((vec![QM31([1, 2, 3, 4])], 0)); "qm31_const")]
+fn simulate_qm31(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type qm31 = qm31;
+        type NonZeroQM31 = NonZero<qm31>;
+        type m31 = BoundedInt<0, 2147483646>;
+        type NonZeroM31 = NonZero<m31>;
+        libfunc qm31_add = qm31_add;
+        libfunc qm31_sub = qm31_sub;
+        libfunc qm31_mul = qm31_mul;
+        libfunc qm31_div = qm31_div;
+        libfunc m31_mul = m31_mul;
+        libfunc qm31_is_zero = qm31_is_zero;
+        libfunc qm31_unpack = qm31_unpack;
+        libfunc qm31_const = qm31_const<1, 2, 3, 4>;
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test_case("null", vec![] => Ok((vec![Nullable(None)], 0)); "null")]
+#[test_case("match_nullable", vec![Nullable(Some(Box::new(Uint8(3))))]
+             => Ok((vec![Uint8(3)], 1)); "match_nullable(3)")]
+#[test_case("match_nullable", vec![Nullable(None)] => Ok((vec![], 0)); "match_nullable(null)")]
+#[test_case("gas_reserve_create", vec![RangeCheck, GasBuiltin(10), Uint128(4)]
+             => Ok((vec![RangeCheck, GasBuiltin(6), GasReserve(4)], 0));
+            "gas_reserve_create(4)")]
+#[test_case("gas_reserve_create", vec![RangeCheck, GasBuiltin(10), Uint128(40)]
+             => Ok((vec![RangeCheck, GasBuiltin(10)], 1)); "gas_reserve_create(40)")]
+#[test_case("gas_reserve_utilize", vec![GasBuiltin(10), GasReserve(4)]
+             => Ok((vec![GasBuiltin(14)], 0)); "gas_reserve_utilize")]
+#[test_case("get_unspent_gas", vec![GasBuiltin(10)]
+             => Ok((vec![GasBuiltin(10), Uint128(14)], 0)); "get_unspent_gas")]
+#[test_case("unsafe_panic", vec![] => Err(LibfuncSimulationError::UnsafePanic); "unsafe_panic")]
+#[test_case("coupon_buy", vec![] => Ok((vec![Coupon], 0)); "coupon_buy")]
+#[test_case("coupon_refund", vec![Coupon] => Ok((vec![], 0)); "coupon_refund")]
+#[test_case("coupon_call", vec![Coupon] => Ok((vec![], 0)); "coupon_call")]
+fn simulate_misc(
+    id: &str,
+    inputs: Vec<CoreValue>,
+) -> Result<(Vec<CoreValue>, usize), LibfuncSimulationError> {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type BoxU8 = Box<u8>;
+        type NullableU8 = Nullable<u8>;
+        type GasReserve = GasReserve;
+        type Coupon = Coupon<user@foo>;
+        libfunc null = null<u8>;
+        libfunc match_nullable = match_nullable<u8>;
+        libfunc gas_reserve_create = gas_reserve_create;
+        libfunc gas_reserve_utilize = gas_reserve_utilize;
+        libfunc get_unspent_gas = get_unspent_gas;
+        libfunc unsafe_panic = unsafe_panic;
+        libfunc coupon_buy = coupon_buy<Coupon>;
+        libfunc coupon_refund = coupon_refund<Coupon>;
+        libfunc coupon_call = coupon_call<user@foo>;
+
+        return();
+
+        foo@0() -> ();
+    "};
+    simulate_declared(&declarations, id, inputs)
+}
+
+#[test]
+fn simulate_starknet() {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type System = System;
+        type StorageAddress = StorageAddress;
+        type StorageBaseAddress = StorageBaseAddress;
+        type ContractAddress = ContractAddress;
+        type ArrayU64 = Array<u64>;
+        type SnapshotArrayU64 = Snapshot<ArrayU64>;
+        type SpanU64 = Struct<ut@core::array::Span::<core::integer::u64>, SnapshotArrayU64>;
+        libfunc storage_write = storage_write_syscall;
+        libfunc storage_read = storage_read_syscall;
+        libfunc call_contract = call_contract_syscall;
+        libfunc emit_event = emit_event_syscall;
+        libfunc keccak = keccak_syscall;
+        libfunc contract_address_try_from_felt252 = contract_address_try_from_felt252;
+        libfunc storage_address_from_base_and_offset = storage_address_from_base_and_offset;
+        libfunc set_block_number = cheatcode<153388001814627426390955123978547651954>;
+        libfunc unknown_cheatcode = cheatcode<33053979968501614>;
+    "};
+    let mut state = StarknetState::default();
+    let mut simulate =
+        |id, inputs| simulate_declared_with_state(&declarations, id, inputs, &mut state);
+    let span = |values: Vec<CoreValue>| Struct(vec![Array(values)]);
+
+    assert_eq!(
+        simulate("storage_write", vec![GasBuiltin(100), System, Uint32(0), felt(5), felt(7)]),
+        Ok((vec![GasBuiltin(100), System], 0))
+    );
+    assert_eq!(
+        simulate("storage_read", vec![GasBuiltin(100), System, Uint32(0), felt(5)]),
+        Ok((vec![GasBuiltin(100), System, felt(7)], 0))
+    );
+    assert_eq!(
+        simulate("storage_read", vec![GasBuiltin(100), System, Uint32(0), felt(6)]),
+        Ok((vec![GasBuiltin(100), System, felt(0)], 0))
+    );
+    let (outputs, branch) =
+        simulate("call_contract", vec![GasBuiltin(100), System, felt(1), felt(2), span(vec![])])
+            .unwrap();
+    assert_eq!(branch, 1);
+    assert!(matches!(outputs.as_slice(), [GasBuiltin(100), System, Array(_)]));
+    assert_eq!(
+        simulate("emit_event", vec![GasBuiltin(100), System, span(vec![felt(1)]), span(vec![])]),
+        Ok((vec![GasBuiltin(100), System], 0))
+    );
+    assert_eq!(
+        simulate("keccak", vec![GasBuiltin(100), System, span(vec![Uint64(1)])]),
+        Ok((
+            vec![
+                GasBuiltin(100),
+                System,
+                Array(
+                    revert_reason("Invalid keccak input size").into_iter().map(Felt252).collect()
+                )
+            ],
+            1
+        ))
+    );
+    // The padded empty message, hashed to the little-endian form of `keccak256("")`.
+    let mut padded_empty = vec![Uint64(0); 17];
+    padded_empty[0] = Uint64(1);
+    padded_empty[16] = Uint64(0x8000000000000000);
+    assert_eq!(
+        simulate("keccak", vec![GasBuiltin(100), System, span(padded_empty)]),
+        Ok((
+            vec![
+                GasBuiltin(100),
+                System,
+                Struct(vec![
+                    Uint128(0xc003c7dcb27d7e923c23f7860146d2c5),
+                    Uint128(0x70a4855d04d8fa7b3b2782ca53b600e5),
+                ]),
+            ],
+            0
+        ))
+    );
+    assert_eq!(
+        simulate("contract_address_try_from_felt252", vec![RangeCheck, felt(-1)]),
+        Ok((vec![RangeCheck], 1))
+    );
+    assert_eq!(
+        simulate("storage_address_from_base_and_offset", vec![felt(5), Uint8(2)]),
+        Ok((vec![felt(7)], 0))
+    );
+    assert_eq!(
+        simulate("set_block_number", vec![span(vec![felt(12)])]),
+        Ok((vec![span(vec![])], 0))
+    );
+    assert_eq!(
+        simulate("unknown_cheatcode", vec![span(vec![])]),
+        Err(LibfuncSimulationError::UnknownCheatcode(BigInt::from_bytes_be(
+            num_bigint::Sign::Plus,
+            b"unknown"
+        )))
+    );
+    assert_eq!(state.exec_info.block_info.block_number, 12);
+}
+
+#[test]
+fn simulate_starknet_pure_syscalls() {
+    let declarations = formatdoc! {"
+        {COMMON_TYPES}
+        type System = System;
+        type Secp256k1Point = Secp256k1Point;
+        type OptionSecp256k1Point = Enum<ut@core::option::Option::<core::starknet::secp256k1::Secp256k1Point>, Secp256k1Point, Unit>;
+        type U32x8 = Struct<ut@Tuple, u32, u32, u32, u32, u32, u32, u32, u32>;
+        type BoxU32x8 = Box<U32x8>;
+        type U32x16 = Struct<ut@Tuple, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32, u32>;
+        type BoxU32x16 = Box<U32x16>;
+        type Sha256StateHandle = Sha256StateHandle;
+        libfunc secp256k1_new = secp256k1_new_syscall;
+        libfunc secp256k1_add = secp256k1_add_syscall;
+        libfunc secp256k1_mul = secp256k1_mul_syscall;
+        libfunc secp256k1_get_xy = secp256k1_get_xy_syscall;
+        libfunc sha256_process_block = sha256_process_block_syscall;
+    "};
+    let simulate = |id, inputs: Vec<CoreValue>| {
+        let inputs = chain!([GasBuiltin(100), System], inputs).collect();
+        let (outputs, branch) = simulate_declared(&declarations, id, inputs).unwrap();
+        assert_eq!(branch, 0);
+        let [GasBuiltin(100), System, outputs @ ..] = outputs.as_slice() else { panic!() };
+        outputs.to_vec()
+    };
+    let u256_from_hex = |hex: &str| {
+        let (high, low) = hex.split_at(hex.len() - 32);
+        Struct(vec![
+            Uint128(u128::from_str_radix(low, 16).unwrap()),
+            Uint128(u128::from_str_radix(high, 16).unwrap()),
+        ])
+    };
+    let x = u256_from_hex("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+    let y = u256_from_hex("483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+
+    let outputs = simulate("secp256k1_new", vec![x.clone(), x.clone()]);
+    assert_eq!(outputs, vec![Enum { value: Box::new(unit()), index: 1 }]);
+    let outputs = simulate("secp256k1_new", vec![x.clone(), y.clone()]);
+    let [Enum { value: generator, index: 0 }] = outputs.as_slice() else { panic!() };
+    let generator = *generator.clone();
+    assert_eq!(simulate("secp256k1_get_xy", vec![generator.clone()]), vec![x, y]);
+    assert_eq!(
+        simulate("secp256k1_add", vec![generator.clone(), generator.clone()]),
+        simulate("secp256k1_mul", vec![generator, u256(2)])
+    );
+
+    let u32s = |values: &[u32]| Struct(values.iter().copied().map(Uint32).collect());
+    let state = u32s(&[
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ]);
+    // The padded block of the message "abc".
+    let mut block = [0; 16];
+    block[0] = 0x61626380;
+    block[15] = 0x18;
+    assert_eq!(
+        simulate("sha256_process_block", vec![state, u32s(&block)]),
+        vec![u32s(&[
+            0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+            0xf20015ad,
+        ])]
+    );
+}
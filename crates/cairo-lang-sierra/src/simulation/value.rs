@@ -1,29 +1,81 @@
 use std::collections::HashMap;
 
+use num_bigint::BigInt;
 use starknet_types_core::felt::Felt as Felt252;
 
+use super::starknet::Secp256Point;
+
 /// The logical value of a variable for Sierra simulation.
+///
+/// Types with no additional runtime information are represented by the value of their inner type:
+/// `Box<T>`, `NonZero<T>` and `Snapshot<T>` are represented as `T`, and short-string-like types
+/// (`bytes31`, `ContractAddress`, `ClassHash`, storage addresses) are represented as `Felt252`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CoreValue {
     EcPoint(Felt252, Felt252),
+    /// The accumulated point of an EC state, where the point at infinity is `(0, 0)`.
+    EcState(Felt252, Felt252),
     Felt252(Felt252),
     GasBuiltin(i64),
+    /// A gas reserve created from the gas counter.
+    GasReserve(u128),
     Uint8(u8),
     Uint16(u16),
     Uint32(u32),
     Uint64(u64),
     Uint128(u128),
+    Sint8(i8),
+    Sint16(i16),
+    Sint32(i32),
+    Sint64(i64),
+    Sint128(i128),
+    /// A value of a `BoundedInt` type. Also used for the circuit `u96` based types.
+    BoundedInt(BigInt),
+    QM31([u32; 4]),
     Array(Vec<CoreValue>),
     Dict(HashMap<Felt252, CoreValue>),
+    /// An entry of a dict, owning the dict until finalized.
+    Felt252DictEntry {
+        dict: HashMap<Felt252, CoreValue>,
+        key: Felt252,
+    },
     Enum {
         value: Box<CoreValue>,
         /// The index of the relevant variant.
         index: usize,
     },
     Struct(Vec<CoreValue>),
+    /// A nullable value, `None` is the null value.
+    Nullable(Option<Box<CoreValue>>),
+    Secp256Point(Secp256Point),
+    /// The inputs added to a circuit so far - used for both the input accumulator and the
+    /// circuit data.
+    CircuitInputs(Vec<BigInt>),
+    /// The values of all the gates of an evaluated circuit, and the modulus it was evaluated with.
+    CircuitOutputs {
+        values: Vec<BigInt>,
+        modulus: BigInt,
+    },
+    /// The guarantee of a failed circuit evaluation - a non-zero value nullifying the input of
+    /// the failing inverse gate.
+    CircuitFailureGuarantee {
+        nullifier: BigInt,
+        modulus: BigInt,
+    },
     // The untracked types - do not carry any value.
     Uninitialized,
     RangeCheck,
+    RangeCheck96,
     Bitwise,
+    Pedersen,
+    Poseidon,
+    EcOp,
+    SegmentArena,
+    AddMod,
+    MulMod,
+    System,
+    BuiltinCosts,
+    Coupon,
+    CircuitDescriptor,
     U128MulGuarantee,
 }
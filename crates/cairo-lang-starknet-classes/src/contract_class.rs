@@ -1,9 +1,12 @@
 use cairo_lang_sierra as sierra;
 use cairo_lang_utils::bigint::{BigUintAsHex, deserialize_big_uint, serialize_big_uint};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use itertools::Itertools;
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use starknet_types_core::felt::Felt as Felt252;
+use starknet_types_core::hash::{Poseidon, StarkHash};
 use thiserror::Error;
 
 use crate::abi::Contract;
@@ -12,6 +15,7 @@ use crate::compiler_version::{VersionId, current_compiler_version_id, current_si
 use crate::felt252_serde::{
     Felt252SerdeError, sierra_from_felt252s, sierra_to_felt252s, version_id_from_felt252s,
 };
+use crate::keccak::starknet_keccak;
 
 #[cfg(test)]
 #[path = "contract_class_test.rs"]
@@ -68,6 +72,41 @@ impl ContractClass {
         Ok(sierra_program)
     }
 
+    /// Returns the hash of the contract class, as computed by Starknet when declaring it with the
+    /// ABI string of [Self::abi_string], as done by common Starknet tooling.
+    pub fn class_hash(&self) -> Felt252 {
+        self.class_hash_with_abi(&self.abi_string())
+    }
+
+    /// Returns the hash of the contract class, as computed by Starknet when declaring it with the
+    /// given ABI string, the `abi` field of the declare transaction.
+    pub fn class_hash_with_abi(&self, abi: &str) -> Felt252 {
+        let ContractEntryPoints { external, l1_handler, constructor } = &self.entry_points_by_type;
+        let sierra_program =
+            self.sierra_program.iter().map(|felt| Felt252::from(&felt.value)).collect_vec();
+        Poseidon::hash_array(&[
+            Felt252::from_bytes_be_slice(
+                format!("CONTRACT_CLASS_V{}", self.contract_class_version).as_bytes(),
+            ),
+            entry_points_hash(external),
+            entry_points_hash(l1_handler),
+            entry_points_hash(constructor),
+            Felt252::from(&starknet_keccak(abi.as_bytes())),
+            Poseidon::hash_array(&sierra_program),
+        ])
+    }
+
+    /// Returns the ABI string of the contract class: the compact JSON form of its ABI, without
+    /// whitespace, with the items in their order in the class, and the fields of each item in the
+    /// order of the compiler output (`type` first).
+    /// Returns an empty string if the class has no ABI.
+    pub fn abi_string(&self) -> String {
+        self.abi
+            .as_ref()
+            .map(|abi| serde_json::to_string(abi).expect("Failed to serialize the ABI."))
+            .unwrap_or_default()
+    }
+
     /// Sanity checks the contract class.
     /// Currently only checks that if ABI exists, its counts match the entry points counts.
     pub fn sanity_check(&self) {
@@ -104,6 +143,18 @@ impl ContractClass {
 
 const DEFAULT_CONTRACT_CLASS_VERSION: &str = "0.1.0";
 
+/// Returns the hash of a set of entry points, as part of the class hash.
+fn entry_points_hash(entry_points: &[ContractEntryPoint]) -> Felt252 {
+    Poseidon::hash_array(
+        &entry_points
+            .iter()
+            .flat_map(|entry_point| {
+                [Felt252::from(&entry_point.selector), Felt252::from(entry_point.function_idx)]
+            })
+            .collect_vec(),
+    )
+}
+
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ContractEntryPoints {
     #[serde(rename = "EXTERNAL")]
@@ -1,5 +1,6 @@
 use std::io::BufReader;
 
+use cairo_lang_utils::bigint::BigUintAsHex;
 use indoc::indoc;
 use num_bigint::BigUint;
 use pretty_assertions::assert_eq;
@@ -73,3 +74,66 @@ fn test_full_contract_deserialization_from_contracts_crate(name: &str) {
     let serialized = serde_json::to_value(&contract).unwrap();
     assert_eq!(serialized, deserialized);
 }
+
+// The hash depends on the Sierra program and the entry points only through the Poseidon and Keccak
+// hashes of the class, so this value guards against changes in the layout of the hashed data.
+#[test]
+fn test_class_hash() {
+    let contract = ContractClass {
+        sierra_program: vec![BigUintAsHex { value: BigUint::from(1u8) }],
+        sierra_program_debug_info: None,
+        contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
+        entry_points_by_type: ContractEntryPoints {
+            external: vec![ContractEntryPoint { selector: BigUint::from(5u8), function_idx: 0 }],
+            l1_handler: vec![],
+            constructor: vec![],
+        },
+        abi: None,
+    };
+    assert_eq!(
+        format!("{:#x}", contract.class_hash()),
+        "0x7cae769c2e87fc84736c3941955fd74fe1dfbe5cccbf7dc1f046615b6ad55fc"
+    );
+}
+
+#[test]
+fn test_abi_string() {
+    let abi = indoc! {r#"
+        [
+          {
+            "type": "function",
+            "name": "get",
+            "inputs": [
+              {
+                "name": "key",
+                "type": "core::felt252"
+              }
+            ],
+            "outputs": [
+              {
+                "type": "core::felt252"
+              }
+            ],
+            "state_mutability": "view"
+          }
+        ]"#};
+    let contract = ContractClass {
+        sierra_program: vec![BigUintAsHex { value: BigUint::from(1u8) }],
+        sierra_program_debug_info: None,
+        contract_class_version: DEFAULT_CONTRACT_CLASS_VERSION.to_string(),
+        entry_points_by_type: ContractEntryPoints {
+            external: vec![ContractEntryPoint { selector: BigUint::from(5u8), function_idx: 0 }],
+            l1_handler: vec![],
+            constructor: vec![],
+        },
+        abi: Some(serde_json::from_str(abi).unwrap()),
+    };
+    let abi_string = contract.abi_string();
+    assert_eq!(
+        abi_string,
+        r#"[{"type":"function","name":"get","inputs":[{"name":"key","type":"core::felt252"}],"outputs":[{"type":"core::felt252"}],"state_mutability":"view"}]"#
+    );
+    assert_eq!(contract.class_hash(), contract.class_hash_with_abi(&abi_string));
+    // The ABI is hashed as a string, so its formatting changes the class hash.
+    assert_ne!(contract.class_hash(), contract.class_hash_with_abi(abi));
+}
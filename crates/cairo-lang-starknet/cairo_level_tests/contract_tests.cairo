@@ -262,6 +262,26 @@ fn test_get_block_hash() {
     );
 }
 
+#[test]
+fn test_advance_blocks() {
+    starknet::testing::set_block_number(5);
+    starknet::testing::set_block_timestamp(1000);
+    starknet::testing::advance_blocks(3, 30);
+    let block_info = starknet::get_block_info().unbox();
+    assert_eq!(block_info.block_number, 8);
+    assert_eq!(block_info.block_timestamp, 1090);
+    assert!(
+        starknet::syscalls::get_block_hash_syscall(5) == Err(array!['Block number out of range']),
+    );
+    starknet::testing::advance_blocks(7, 30);
+    let block_hash = starknet::syscalls::get_block_hash_syscall(5).unwrap();
+    assert_ne!(block_hash, 0);
+    assert_ne!(starknet::syscalls::get_block_hash_syscall(4), Ok(block_hash));
+    assert!(
+        starknet::syscalls::get_block_hash_syscall(6) == Err(array!['Block number out of range']),
+    );
+}
+
 #[test]
 #[should_panic]
 fn test_pop_log_empty_logs() {
@@ -1,5 +1,6 @@
 use starknet::deployment::DeploymentParams;
 use starknet::syscalls::deploy_syscall;
+use starknet::testing::{precalculate_address, set_contract_address};
 
 #[starknet::interface]
 trait IValue<TContractState> {
@@ -33,6 +34,33 @@ mod self_caller {
     }
 }
 
+#[starknet::interface]
+trait IPeer<TContractState> {
+    fn get_peer(self: @TContractState) -> starknet::ContractAddress;
+}
+
+#[starknet::contract]
+mod peer {
+    use starknet::ContractAddress;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+    #[storage]
+    struct Storage {
+        peer: ContractAddress,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, peer: ContractAddress) {
+        self.peer.write(peer);
+    }
+
+    #[abi(embed_v0)]
+    impl PeerImpl of super::IPeer<ContractState> {
+        fn get_peer(self: @ContractState) -> ContractAddress {
+            self.peer.read()
+        }
+    }
+}
+
 #[starknet::contract]
 pub mod advanced {
     #[storage]
@@ -122,3 +150,33 @@ fn test_typed_deploy_default_with_complex_args() {
         ) == Err(array!['CONTRACT_ALREADY_DEPLOYED']),
     );
 }
+
+#[test]
+fn test_precalculate_address() {
+    set_contract_address(0x1234.try_into().unwrap());
+    let expected = precalculate_address(self_caller::TEST_CLASS_HASH, 7, [].span(), false);
+    let (contract_address, _) = deploy_syscall(self_caller::TEST_CLASS_HASH, 7, [].span(), false)
+        .unwrap();
+    assert_eq!(contract_address, expected);
+
+    let expected = precalculate_address(self_caller::TEST_CLASS_HASH, 7, [].span(), true);
+    assert_ne!(expected, contract_address);
+    let (contract_address, _) = deploy_syscall(self_caller::TEST_CLASS_HASH, 7, [].span(), true)
+        .unwrap();
+    assert_eq!(contract_address, expected);
+}
+
+#[test]
+fn test_precalculate_address_before_deploy() {
+    // The peer refers to a contract that is only deployed afterwards.
+    let value_address = precalculate_address(self_caller::TEST_CLASS_HASH, 0, [].span(), false);
+    let (peer_address, _) = deploy_syscall(
+        peer::TEST_CLASS_HASH, 0, [value_address.into()].span(), false,
+    )
+        .unwrap();
+    let (contract_address, _) = deploy_syscall(self_caller::TEST_CLASS_HASH, 0, [].span(), false)
+        .unwrap();
+    let peer = IPeerDispatcher { contract_address: peer_address }.get_peer();
+    assert_eq!(peer, contract_address);
+    assert!(IValueDispatcher { contract_address: peer }.get_value() == 1);
+}
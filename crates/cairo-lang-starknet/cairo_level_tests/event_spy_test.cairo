@@ -0,0 +1,167 @@
+use starknet::syscalls::deploy_syscall;
+use starknet::testing::{EventSpyTrait, SpiedEvent, SpiedEventsTrait, spy_events};
+
+#[starknet::interface]
+trait IToken<T> {
+    fn transfer(ref self: T, to: felt252, amount: u128);
+    fn approve(ref self: T, spender: felt252, amount: u128);
+    fn transfer_and_fail(ref self: T, to: felt252, amount: u128);
+}
+
+#[starknet::contract]
+mod token {
+    #[storage]
+    struct Storage {}
+
+    #[event]
+    #[derive(Drop, Debug, PartialEq, starknet::Event)]
+    pub enum Event {
+        Transfer: Transfer,
+        Approval: Approval,
+    }
+
+    #[derive(Drop, Debug, PartialEq, starknet::Event)]
+    pub struct Transfer {
+        #[key]
+        pub to: felt252,
+        pub amount: u128,
+    }
+
+    #[derive(Drop, Debug, PartialEq, starknet::Event)]
+    pub struct Approval {
+        #[key]
+        pub spender: felt252,
+        pub amount: u128,
+    }
+
+    #[abi(embed_v0)]
+    impl TokenImpl of super::IToken<ContractState> {
+        fn transfer(ref self: ContractState, to: felt252, amount: u128) {
+            self.emit(Transfer { to, amount });
+        }
+
+        fn approve(ref self: ContractState, spender: felt252, amount: u128) {
+            self.emit(Approval { spender, amount });
+        }
+
+        fn transfer_and_fail(ref self: ContractState, to: felt252, amount: u128) {
+            self.emit(Transfer { to, amount });
+            panic!("Transfer failed.");
+        }
+    }
+}
+
+fn deploy_token(salt: felt252) -> ITokenDispatcher {
+    let (contract_address, _) = deploy_syscall(token::TEST_CLASS_HASH, salt, [].span(), false)
+        .unwrap();
+    ITokenDispatcher { contract_address }
+}
+
+fn transfer(to: felt252, amount: u128) -> token::Event {
+    token::Event::Transfer(token::Transfer { to, amount })
+}
+
+fn approval(spender: felt252, amount: u128) -> token::Event {
+    token::Event::Approval(token::Approval { spender, amount })
+}
+
+#[test]
+fn test_spy_events_across_contracts() {
+    let token_a = deploy_token(0);
+    let token_b = deploy_token(1);
+    token_a.transfer(1, 10);
+    let spy = spy_events();
+    token_b.transfer(2, 20);
+    token_a.approve(3, 30);
+    let events = spy.get_events();
+    assert_eq!(
+        events.events,
+        array![
+            SpiedEvent {
+                from: token_b.contract_address,
+                keys: [selector!("Transfer"), 2].span(),
+                data: [20].span(),
+            },
+            SpiedEvent {
+                from: token_a.contract_address,
+                keys: [selector!("Approval"), 3].span(),
+                data: [30].span(),
+            },
+        ],
+    );
+    // Fetching the events does not consume them.
+    assert_eq!(spy.get_events(), events);
+}
+
+#[test]
+fn test_spy_events_filters() {
+    let token_a = deploy_token(0);
+    let token_b = deploy_token(1);
+    let spy = spy_events();
+    token_a.transfer(1, 10);
+    token_b.transfer(2, 20);
+    token_a.approve(3, 30);
+    let events = spy.get_events();
+    assert_eq!(
+        events.emitted_by(token_a.contract_address).decode::<token::Event>(),
+        array![transfer(1, 10), approval(3, 30)],
+    );
+    assert_eq!(
+        events.with_selector(selector!("Transfer")).decode::<token::Event>(),
+        array![transfer(1, 10), transfer(2, 20)],
+    );
+    assert_eq!(
+        events
+            .emitted_by(token_b.contract_address)
+            .with_selector(selector!("Approval"))
+            .events
+            .len(),
+        0,
+    );
+}
+
+#[test]
+fn test_spy_events_assertions() {
+    let token = deploy_token(0);
+    let spy = spy_events();
+    token.transfer(1, 10);
+    token.approve(3, 30);
+    let events = spy.get_events();
+    events.assert_emitted(token.contract_address, @approval(3, 30));
+    events.assert_not_emitted(token.contract_address, @approval(3, 31));
+    events.assert_events(token.contract_address, [transfer(1, 10), approval(3, 30)].span());
+}
+
+#[test]
+#[should_panic(
+    expected: "Event `Event::Approval(Approval { spender: 3, amount: 31 })` was not emitted by `2693290226644732020809713546801154424340673497030889761942745151784753369817`.\nEmitted events: [Event::Approval(Approval { spender: 3, amount: 30 })]",
+)]
+fn test_spy_events_assert_emitted_failure() {
+    let token = deploy_token(0);
+    let spy = spy_events();
+    token.approve(3, 30);
+    spy.get_events().assert_emitted(token.contract_address, @approval(3, 31));
+}
+
+#[test]
+#[should_panic(
+    expected: "Unexpected events emitted by `2693290226644732020809713546801154424340673497030889761942745151784753369817`.\nExpected: [Event::Transfer(Transfer { to: 1, amount: 10 })]\nActual: [Event::Transfer(Transfer { to: 1, amount: 10 }), Event::Approval(Approval { spender: 3, amount: 30 })]",
+)]
+fn test_spy_events_assert_events_failure() {
+    let token = deploy_token(0);
+    let spy = spy_events();
+    token.transfer(1, 10);
+    token.approve(3, 30);
+    spy.get_events().assert_events(token.contract_address, [transfer(1, 10)].span());
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_spy_events_of_reverted_calls() {
+    let token = deploy_token(0);
+    let safe_token = ITokenSafeDispatcher { contract_address: token.contract_address };
+    let spy = spy_events();
+    token.transfer(1, 10);
+    assert!(safe_token.transfer_and_fail(2, 20).is_err());
+    spy.get_events().assert_events(token.contract_address, [transfer(1, 10)].span());
+}
@@ -0,0 +1,104 @@
+use starknet::syscalls::deploy_syscall;
+use starknet::testing::{call_l1_handler, pop_l2_to_l1_message};
+
+const L1_BRIDGE: felt252 = 0x1b;
+
+#[starknet::interface]
+trait IBridge<T> {
+    fn balance_of(self: @T, account: felt252) -> u128;
+    fn withdraw(ref self: T, account: felt252, amount: u128);
+}
+
+#[starknet::contract]
+mod bridge {
+    use core::num::traits::Zero;
+    use starknet::storage::{
+        Map, StorageMapReadAccess, StorageMapWriteAccess, StoragePointerReadAccess,
+        StoragePointerWriteAccess,
+    };
+    use starknet::{SyscallResultTrait, get_caller_address};
+
+    #[storage]
+    struct Storage {
+        l1_bridge: felt252,
+        balances: Map<felt252, u128>,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, l1_bridge: felt252) {
+        self.l1_bridge.write(l1_bridge);
+    }
+
+    #[l1_handler]
+    fn deposit(ref self: ContractState, from_address: felt252, account: felt252, amount: u128) {
+        assert(from_address == self.l1_bridge.read(), 'Unknown L1 bridge');
+        assert(get_caller_address().is_zero(), 'Unexpected caller');
+        self.balances.write(account, self.balances.read(account) + amount);
+    }
+
+    #[abi(embed_v0)]
+    impl BridgeImpl of super::IBridge<ContractState> {
+        fn balance_of(self: @ContractState, account: felt252) -> u128 {
+            self.balances.read(account)
+        }
+
+        fn withdraw(ref self: ContractState, account: felt252, amount: u128) {
+            self.balances.write(account, self.balances.read(account) - amount);
+            starknet::syscalls::send_message_to_l1_syscall(
+                self.l1_bridge.read(), [account, amount.into()].span(),
+            )
+                .unwrap_syscall();
+        }
+    }
+}
+
+fn deploy_bridge() -> IBridgeDispatcher {
+    let (contract_address, _) = deploy_syscall(
+        bridge::TEST_CLASS_HASH, 0, [L1_BRIDGE].span(), false,
+    )
+        .unwrap();
+    IBridgeDispatcher { contract_address }
+}
+
+#[test]
+fn test_l1_handler() {
+    let bridge = deploy_bridge();
+    call_l1_handler(bridge.contract_address, selector!("deposit"), L1_BRIDGE, [7, 100].span())
+        .unwrap();
+    call_l1_handler(bridge.contract_address, selector!("deposit"), L1_BRIDGE, [7, 50].span())
+        .unwrap();
+    assert_eq!(bridge.balance_of(7), 150);
+}
+
+#[test]
+fn test_l1_handler_roundtrip() {
+    let bridge = deploy_bridge();
+    call_l1_handler(bridge.contract_address, selector!("deposit"), L1_BRIDGE, [7, 100].span())
+        .unwrap();
+    bridge.withdraw(7, 30);
+    assert_eq!(bridge.balance_of(7), 70);
+    assert_eq!(pop_l2_to_l1_message(bridge.contract_address), Some((L1_BRIDGE, [7, 30].span())));
+}
+
+#[test]
+fn test_l1_handler_failure_reverts() {
+    let bridge = deploy_bridge();
+    assert_eq!(
+        call_l1_handler(bridge.contract_address, selector!("deposit"), 0x1234, [7, 100].span()),
+        Err(array!['Unknown L1 bridge']),
+    );
+    assert_eq!(bridge.balance_of(7), 0);
+}
+
+#[test]
+fn test_l1_handler_not_found() {
+    let bridge = deploy_bridge();
+    assert_eq!(
+        call_l1_handler(bridge.contract_address, selector!("balance_of"), L1_BRIDGE, [7].span()),
+        Err(array!['ENTRYPOINT_NOT_FOUND']),
+    );
+    assert_eq!(
+        call_l1_handler(0x1234.try_into().unwrap(), selector!("deposit"), L1_BRIDGE, [].span()),
+        Err(array!['CONTRACT_NOT_DEPLOYED']),
+    );
+}
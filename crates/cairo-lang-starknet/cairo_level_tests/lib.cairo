@@ -15,6 +15,8 @@ mod deployment;
 #[cfg(test)]
 mod erc20_test;
 #[cfg(test)]
+mod event_spy_test;
+#[cfg(test)]
 mod events;
 #[cfg(test)]
 mod flat_storage_test;
@@ -23,8 +25,14 @@ mod interoperability;
 #[cfg(test)]
 mod keccak;
 #[cfg(test)]
+mod l1_handler_test;
+#[cfg(test)]
 mod l2_to_l1_messages;
 #[cfg(test)]
+mod meta_tx_test;
+#[cfg(test)]
+mod mock_call_test;
+#[cfg(test)]
 mod multi_component_test;
 #[cfg(test)]
 mod renamed_storage_test;
@@ -32,4 +40,6 @@ mod renamed_storage_test;
 mod replace_class_test;
 #[cfg(test)]
 mod storage_access;
+#[cfg(test)]
+mod storage_cheatcodes_test;
 mod utils;
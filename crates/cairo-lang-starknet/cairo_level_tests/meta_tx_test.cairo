@@ -0,0 +1,111 @@
+use core::hash::HashStateTrait;
+use core::pedersen::PedersenTrait;
+use starknet::syscalls::{deploy_syscall, meta_tx_v0_syscall};
+use starknet::testing::{mock_call, set_chain_id, set_signature, set_version};
+use starknet::{ContractAddress, get_tx_info};
+
+#[starknet::contract(account)]
+mod account {
+    use starknet::{get_caller_address, get_tx_info};
+
+    #[storage]
+    struct Storage {}
+
+    /// Returns the version, caller, account, transaction hash and signature seen by the account.
+    #[external(v0)]
+    fn __execute__(self: @ContractState, should_fail: bool) -> Array<felt252> {
+        assert(!should_fail, 'Execution failed');
+        let tx_info = get_tx_info().unbox();
+        let mut result = array![
+            tx_info.version, get_caller_address().into(), tx_info.account_contract_address.into(),
+            tx_info.transaction_hash,
+        ];
+        result.append_span(tx_info.signature);
+        result
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, should_fail: bool) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn get_version(self: @ContractState) -> felt252 {
+        get_tx_info().unbox().version
+    }
+}
+
+fn deploy_account() -> ContractAddress {
+    let (contract_address, _) = deploy_syscall(account::TEST_CLASS_HASH, 0, [].span(), false)
+        .unwrap();
+    contract_address
+}
+
+/// Hashes the given values as a chain of Pedersen hashes, followed by their count.
+fn pedersen_hash_array(values: Span<felt252>) -> felt252 {
+    let mut state = PedersenTrait::new(0);
+    for value in values {
+        state = state.update(*value);
+    }
+    state.update(values.len().into()).finalize()
+}
+
+#[test]
+fn test_meta_tx_v0() {
+    let account = deploy_account();
+    set_version(3);
+    set_chain_id('SN_SEPOLIA');
+    set_signature([7].span());
+    let result = meta_tx_v0_syscall(account, selector!("__execute__"), [0].span(), [1, 2].span())
+        .unwrap();
+    let tx_hash = pedersen_hash_array(
+        [
+            'invoke', 0, account.into(), selector!("__execute__"), pedersen_hash_array([0].span()),
+            0, 'SN_SEPOLIA',
+        ]
+            .span(),
+    );
+    assert_eq!(result, [6, 0, 0, account.into(), tx_hash, 1, 2].span());
+    // The transaction info is restored after the meta transaction.
+    let tx_info = get_tx_info().unbox();
+    assert_eq!(tx_info.version, 3);
+    assert_eq!(tx_info.signature, [7].span());
+}
+
+#[test]
+fn test_meta_tx_v0_revert() {
+    let account = deploy_account();
+    assert_eq!(
+        meta_tx_v0_syscall(account, selector!("__execute__"), [1].span(), [].span()),
+        Err(array!['Execution failed', 'ENTRYPOINT_FAILED']),
+    );
+}
+
+#[test]
+fn test_meta_tx_v0_invalid_selector() {
+    let account = deploy_account();
+    assert_eq!(
+        meta_tx_v0_syscall(account, selector!("get_version"), [].span(), [].span()),
+        Err(array!['Invalid argument']),
+    );
+}
+
+#[test]
+fn test_meta_tx_v0_not_deployed() {
+    assert_eq!(
+        meta_tx_v0_syscall(
+            0x1234.try_into().unwrap(), selector!("__execute__"), [0].span(), [].span(),
+        ),
+        Err(array!['CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED']),
+    );
+}
+
+#[test]
+fn test_meta_tx_v0_mocked() {
+    let account = deploy_account();
+    mock_call(account, selector!("__execute__"), Ok([5].span()), None);
+    assert_eq!(
+        meta_tx_v0_syscall(account, selector!("__execute__"), [0].span(), [].span()),
+        Ok([5].span()),
+    );
+}
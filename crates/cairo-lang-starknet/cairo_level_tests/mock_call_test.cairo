@@ -0,0 +1,91 @@
+use starknet::testing::{clear_mock_call, clear_mock_library_call, mock_call, mock_library_call};
+
+#[starknet::interface]
+trait IToken<T> {
+    fn balance_of(self: @T, account: felt252) -> u256;
+}
+
+#[starknet::contract]
+mod token {
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl TokenImpl of super::IToken<ContractState> {
+        fn balance_of(self: @ContractState, account: felt252) -> u256 {
+            7
+        }
+    }
+}
+
+fn token_address() -> starknet::ContractAddress {
+    0x1234.try_into().unwrap()
+}
+
+#[test]
+fn test_mock_call() {
+    let token = ITokenDispatcher { contract_address: token_address() };
+    mock_call(token_address(), selector!("balance_of"), Ok([100, 0].span()), None);
+    assert_eq!(token.balance_of(1), 100);
+    assert_eq!(token.balance_of(2), 100);
+    mock_call(token_address(), selector!("balance_of"), Ok([200, 0].span()), None);
+    assert_eq!(token.balance_of(1), 200);
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_mock_call_revert() {
+    let token = ITokenSafeDispatcher { contract_address: token_address() };
+    mock_call(token_address(), selector!("balance_of"), Err(['Mocked'].span()), None);
+    assert_eq!(token.balance_of(1), Err(array!['Mocked', 'ENTRYPOINT_FAILED']));
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_mock_call_count() {
+    let token = ITokenSafeDispatcher { contract_address: token_address() };
+    mock_call(token_address(), selector!("balance_of"), Ok([100, 0].span()), Some(2));
+    assert_eq!(token.balance_of(1), Ok(100));
+    assert_eq!(token.balance_of(1), Ok(100));
+    assert_eq!(token.balance_of(1), Err(array!['CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED']));
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_clear_mock_call() {
+    let token = ITokenSafeDispatcher { contract_address: token_address() };
+    mock_call(token_address(), selector!("balance_of"), Ok([100, 0].span()), None);
+    clear_mock_call(token_address(), selector!("balance_of"));
+    assert_eq!(token.balance_of(1), Err(array!['CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED']));
+}
+
+#[test]
+fn test_mock_call_of_deployed_contract() {
+    let (contract_address, _) = starknet::syscalls::deploy_syscall(
+        token::TEST_CLASS_HASH, 0, [].span(), false,
+    )
+        .unwrap();
+    let token = ITokenDispatcher { contract_address };
+    assert_eq!(token.balance_of(1), 7);
+    mock_call(contract_address, selector!("balance_of"), Ok([100, 0].span()), Some(1));
+    assert_eq!(token.balance_of(1), 100);
+    assert_eq!(token.balance_of(1), 7);
+}
+
+#[test]
+#[feature("safe_dispatcher")]
+fn test_mock_library_call() {
+    let class_hash = 0x5678.try_into().unwrap();
+    let library = ITokenSafeLibraryDispatcher { class_hash };
+    mock_library_call(class_hash, selector!("balance_of"), Ok([100, 0].span()), None);
+    assert_eq!(library.balance_of(1), Ok(100));
+    // Library calls are mocked separately from contract calls.
+    let token = ITokenSafeDispatcher { contract_address: 0x5678.try_into().unwrap() };
+    assert_eq!(token.balance_of(1), Err(array!['CONTRACT_NOT_DEPLOYED', 'ENTRYPOINT_FAILED']));
+    mock_library_call(class_hash, selector!("balance_of"), Err(['Mocked'].span()), Some(1));
+    assert_eq!(library.balance_of(1), Err(array!['Mocked', 'ENTRYPOINT_FAILED']));
+    assert_eq!(library.balance_of(1), Err(array!['CLASS_HASH_NOT_DECLARED']));
+    mock_library_call(class_hash, selector!("balance_of"), Ok([100, 0].span()), None);
+    clear_mock_library_call(class_hash, selector!("balance_of"));
+    assert_eq!(library.balance_of(1), Err(array!['CLASS_HASH_NOT_DECLARED']));
+}
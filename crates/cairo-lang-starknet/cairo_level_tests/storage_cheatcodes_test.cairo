@@ -0,0 +1,90 @@
+use starknet::ContractAddress;
+use starknet::storage::StoragePathEntry;
+use starknet::testing::{load, storage_address, store};
+
+#[derive(Copy, Drop, Debug, Serde, PartialEq, starknet::Store)]
+struct Position {
+    x: u32,
+    y: u32,
+}
+
+#[starknet::interface]
+trait IGame<T> {
+    fn get_owner(self: @T) -> ContractAddress;
+    fn get_balance(self: @T, account: ContractAddress) -> u256;
+    fn get_position(self: @T, id: u32) -> Position;
+    fn set_balance(ref self: T, account: ContractAddress, balance: u256);
+}
+
+#[starknet::contract]
+mod game {
+    use starknet::ContractAddress;
+    use starknet::storage::{
+        Map, StorageMapReadAccess, StorageMapWriteAccess, StoragePointerReadAccess,
+    };
+    use super::Position;
+
+    #[storage]
+    pub struct Storage {
+        pub owner: ContractAddress,
+        pub balances: Map<ContractAddress, u256>,
+        pub positions: Map<u32, Position>,
+    }
+
+    #[abi(embed_v0)]
+    impl GameImpl of super::IGame<ContractState> {
+        fn get_owner(self: @ContractState) -> ContractAddress {
+            self.owner.read()
+        }
+        fn get_balance(self: @ContractState, account: ContractAddress) -> u256 {
+            self.balances.read(account)
+        }
+        fn get_position(self: @ContractState, id: u32) -> Position {
+            self.positions.read(id)
+        }
+        fn set_balance(ref self: ContractState, account: ContractAddress, balance: u256) {
+            self.balances.write(account, balance);
+        }
+    }
+}
+
+fn deploy_game() -> IGameDispatcher {
+    let (contract_address, _) = starknet::syscalls::deploy_syscall(
+        game::TEST_CLASS_HASH, 0, [].span(), false,
+    )
+        .unwrap();
+    IGameDispatcher { contract_address }
+}
+
+#[test]
+fn test_store() {
+    let game = deploy_game();
+    let state = @game::contract_state_for_testing();
+    let account = 0x123.try_into().unwrap();
+    store(game.contract_address, storage_address(@state.owner), [account.into()].span());
+    store(game.contract_address, storage_address(@state.balances.entry(account)), [100, 1].span());
+    store(game.contract_address, storage_address(@state.positions.entry(7)), [3, 4].span());
+    assert_eq!(game.get_owner(), account);
+    assert_eq!(game.get_balance(account), u256 { low: 100, high: 1 });
+    assert_eq!(game.get_position(7), Position { x: 3, y: 4 });
+    assert_eq!(game.get_position(8), Position { x: 0, y: 0 });
+}
+
+#[test]
+fn test_load() {
+    let game = deploy_game();
+    let state = @game::contract_state_for_testing();
+    let account = 0x123.try_into().unwrap();
+    let balance_address = storage_address(@state.balances.entry(account));
+    assert_eq!(load(game.contract_address, balance_address, 2), [0, 0].span());
+    game.set_balance(account, 500);
+    assert_eq!(load(game.contract_address, balance_address, 2), [500, 0].span());
+    assert_eq!(load(game.contract_address, balance_address, 0), [].span());
+}
+
+#[test]
+fn test_store_undeployed_contract() {
+    let contract_address = 0x456.try_into().unwrap();
+    store(contract_address, 10, [1, 2, 3].span());
+    assert_eq!(load(contract_address, 9, 5), [0, 1, 2, 3, 0].span());
+}
@@ -1,5 +1,6 @@
 use cairo_lang_defs::ids::{EnumId, LanguageElementId, ModuleId, ModuleItemId, StructId};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::ids::{StrRef, db_str};
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::helper::ModuleHelper;
@@ -63,11 +64,15 @@ fn add_non_starknet_interface_embeddable_diagnostics<'db>(
         }
         let Ok(impl_trait) = db.impl_def_trait(*id) else { continue };
         if !impl_trait.has_attr(db, INTERFACE_ATTR).unwrap_or(true) {
-            diagnostics.push(PluginDiagnostic::warning(
-                item.stable_ptr(db).untyped(),
-                "Impls with the embeddable attribute must implement a starknet interface trait."
-                    .to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    item.stable_ptr(db).untyped(),
+                    "Impls with the embeddable attribute must implement a starknet interface \
+                     trait."
+                        .to_string(),
+                )
+                .with_error_code(error_code!(E3271)),
+            );
         }
     }
 }
@@ -98,10 +103,10 @@ fn add_abi_diagnostics<'db>(
                 }
             });
 
-            diagnostics.push(PluginDiagnostic::warning(
-                location,
-                format!("Failed to generate ABI: {err}"),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(location, format!("Failed to generate ABI: {err}"))
+                    .with_error_code(error_code!(E3272)),
+            );
         }
     }
 }
@@ -194,17 +199,20 @@ fn analyze_storage_struct<'db>(
 
             if let Err(inference_error) = inference_result {
                 let type_pointer = member_ast.type_clause(db).ty(db);
-                diagnostics.push(PluginDiagnostic::warning(
-                    type_pointer.stable_ptr(db),
-                    format!(
-                        "Missing `ValidStorageTypeTrait` for member type. Inference failed with: \
-                         `{}`. Possible solutions: implement `Store`, mark type with \
-                         `#[storage_node]`, or use valid args for `Vec` or `Map` library types. \
-                         To suppress this warning, use \
-                         `#[allow(starknet::invalid_storage_member_types)]`.",
-                        inference_error.format(db.elongate())
-                    ),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::warning(
+                        type_pointer.stable_ptr(db),
+                        format!(
+                            "Missing `ValidStorageTypeTrait` for member type. Inference failed \
+                             with: `{}`. Possible solutions: implement `Store`, mark type with \
+                             `#[storage_node]`, or use valid args for `Vec` or `Map` library \
+                             types. To suppress this warning, use \
+                             `#[allow(starknet::invalid_storage_member_types)]`.",
+                            inference_error.format(db.elongate())
+                        ),
+                    )
+                    .with_error_code(error_code!(E3273)),
+                );
             }
         }
 
@@ -242,15 +250,18 @@ impl<'db> StorageStructMembers<'db> {
         diagnostics: &mut Vec<PluginDiagnostic<'db>>,
     ) {
         if let Some(existing_path) = self.name_to_paths.get(&member_name) {
-            diagnostics.push(PluginDiagnostic::warning(
-                pointer_to_code,
-                format!(
-                    "The path `{}` collides with existing path `{}`. Fix or add \
-                     `#[allow({ALLOW_COLLIDING_PATHS_ATTR})]` if intentional.",
-                    path_to_member.join("."),
-                    existing_path.join(".")
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    pointer_to_code,
+                    format!(
+                        "The path `{}` collides with existing path `{}`. Fix or add \
+                         `#[allow({ALLOW_COLLIDING_PATHS_ATTR})]` if intentional.",
+                        path_to_member.join("."),
+                        existing_path.join(".")
+                    ),
+                )
+                .with_error_code(error_code!(E3274)),
+            );
         } else {
             self.name_to_paths.insert(member_name, path_to_member);
         }
@@ -319,13 +330,16 @@ fn add_derive_store_enum_diags<'db>(
         .iter()
         .any(|(_, variant_id)| variant_id.stable_ptr(db).lookup(db).has_attr(db, "default"))
     {
-        diagnostics.push(PluginDiagnostic::warning(
-            id.stable_ptr(db).untyped(),
-            format!(
-                "Enum with `#[derive({STORE_TRAIT})] has no default variant. Either add one, or \
-                 add `#[allow({ALLOW_NO_DEFAULT_VARIANT_ATTR})]`"
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::warning(
+                id.stable_ptr(db).untyped(),
+                format!(
+                    "Enum with `#[derive({STORE_TRAIT})] has no default variant. Either add one, \
+                     or add `#[allow({ALLOW_NO_DEFAULT_VARIANT_ATTR})]`"
+                ),
+            )
+            .with_error_code(error_code!(E3275)),
+        );
     }
 }
 
@@ -54,6 +54,25 @@ pub fn compile_path(
     compile_contract_in_prepared_db(&db, contract_path, main_crate_ids, compiler_config)
 }
 
+/// Compile all the contracts in the crates given by path.
+pub fn compile_path_contracts(
+    path: &Path,
+    mut compiler_config: CompilerConfig<'_>,
+) -> Result<Vec<ContractClass>> {
+    let mut db = RootDatabase::builder()
+        .with_inlining_strategy(compiler_config.inlining_strategy)
+        .detect_corelib()
+        .with_default_plugin_suite(starknet_plugin_suite())
+        .build()?;
+
+    let main_crate_inputs = setup_project(&mut db, Path::new(&path))?;
+    compiler_config.diagnostics_reporter =
+        compiler_config.diagnostics_reporter.with_crates(&main_crate_inputs);
+    let main_crate_ids = CrateInput::into_crate_ids(&db, main_crate_inputs);
+    let contracts = find_contracts(&db, &main_crate_ids);
+    compile_prepared_db(&db, &contracts.iter().collect_vec(), compiler_config)
+}
+
 /// Runs Starknet contract compiler on the specified contract.
 /// If no contract was specified, verify that there is only one.
 /// Otherwise, return an error.
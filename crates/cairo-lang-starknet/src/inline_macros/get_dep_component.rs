@@ -5,6 +5,7 @@ use cairo_lang_defs::plugin::{
     PluginGeneratedFile,
 };
 use cairo_lang_defs::plugin_utils::{PluginResultTrait, not_legacy_macro_diagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
 use cairo_lang_utils::extract_matches;
@@ -83,15 +84,18 @@ fn get_dep_component_generate_code_helper<'db>(
         if !matches!(contract_arg_modifiers, Some([ast::Modifier::Ref(_)])) {
             // TODO(Gil): The generated diagnostics points to the whole inline macro, it should
             // point to the arg.
-            let diagnostics = vec![PluginDiagnostic::error_with_inner_span(
-                db,
-                syntax.stable_ptr(db),
-                contract_arg.as_syntax_node(),
-                format!(
-                    "The first argument of `{}` macro must have only a `ref` modifier.",
-                    GetDepComponentMutMacro::NAME
-                ),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error_with_inner_span(
+                    db,
+                    syntax.stable_ptr(db),
+                    contract_arg.as_syntax_node(),
+                    format!(
+                        "The first argument of `{}` macro must have only a `ref` modifier.",
+                        GetDepComponentMutMacro::NAME
+                    ),
+                )
+                .with_error_code(error_code!(E3270)),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
     }
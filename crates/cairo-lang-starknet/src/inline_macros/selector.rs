@@ -4,6 +4,7 @@ use cairo_lang_defs::plugin::{
     PluginGeneratedFile,
 };
 use cairo_lang_defs::plugin_utils::{PluginResultTrait, not_legacy_macro_diagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
@@ -35,10 +36,13 @@ impl InlineMacroExprPlugin for SelectorMacro {
         );
 
         let ast::Expr::String(input_string) = arg else {
-            let diagnostics = vec![PluginDiagnostic::error(
-                syntax.stable_ptr(db).untyped(),
-                format!("`{}` macro argument must be a string", SelectorMacro::NAME),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error(
+                    syntax.stable_ptr(db).untyped(),
+                    format!("`{}` macro argument must be a string", SelectorMacro::NAME),
+                )
+                .with_error_code(error_code!(E3269)),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
         let selector_string = input_string.string_value(db).unwrap();
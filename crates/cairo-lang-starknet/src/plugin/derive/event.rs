@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::{ModifiedNode, RewriteNode};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::abi::EventFieldKind;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{Terminal, TypedStablePtr, TypedSyntaxNode, ast};
@@ -35,10 +36,13 @@ fn handle_struct<'db>(
     // TODO(spapini): Support generics.
     let generic_params = struct_ast.generic_params(db);
     let ast::OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr(db).untyped(),
-            format!("{EVENT_TYPE_NAME} structs with generic arguments are unsupported"),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr(db).untyped(),
+                format!("{EVENT_TYPE_NAME} structs with generic arguments are unsupported"),
+            )
+            .with_error_code(error_code!(E3245)),
+        );
         return None;
     };
 
@@ -118,17 +122,23 @@ fn get_field_kind_for_member<'db>(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic::error(
-            member.stable_ptr(db).untyped(),
-            "Nested event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                member.stable_ptr(db).untyped(),
+                "Nested event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3246)),
+        );
     }
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic::error(
-            member.stable_ptr(db).untyped(),
-            "Serde event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                member.stable_ptr(db).untyped(),
+                "Serde event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3247)),
+        );
     }
 
     if is_key {
@@ -153,10 +163,13 @@ fn get_field_kind_for_variant<'db>(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic::error(
-            variant.stable_ptr(db),
-            "Nested event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                variant.stable_ptr(db),
+                "Nested event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3246)),
+        );
     }
 
     if is_flat {
@@ -165,10 +178,13 @@ fn get_field_kind_for_variant<'db>(
 
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic::error(
-            variant.stable_ptr(db),
-            "Serde event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                variant.stable_ptr(db),
+                "Serde event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!(E3247)),
+        );
     }
 
     if is_key {
@@ -189,10 +205,13 @@ fn handle_enum<'db>(
     // TODO(spapini): Support generics.
     let generic_params = enum_ast.generic_params(db);
     let ast::OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr(db),
-            format!("{EVENT_TYPE_NAME} enums with generic arguments are unsupported"),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr(db),
+                format!("{EVENT_TYPE_NAME} enums with generic arguments are unsupported"),
+            )
+            .with_error_code(error_code!(E3248)),
+        );
         return None;
     };
 
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_plugins::plugins::utils::{PluginTypeInfo, TypeVariant};
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
@@ -229,10 +230,13 @@ fn handle_enum<'db>(
     for (i, variant) in info.members_info.iter().enumerate() {
         let indicator = if variant.attributes.has_attr(db, "default") {
             if default_index.is_some() {
-                diagnostics.push(PluginDiagnostic::error(
-                    variant.attributes.stable_ptr(db),
-                    "Multiple variants annotated with `#[default]`".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        variant.attributes.stable_ptr(db),
+                        "Multiple variants annotated with `#[default]`".to_string(),
+                    )
+                    .with_error_code(error_code!(E3249)),
+                );
                 return None;
             }
             default_index = Some(i);
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{PluginDiagnostic, PluginGeneratedFile, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::keyword::SELF_PARAM_KW;
 use cairo_lang_syntax::node::ast::{
     self, MaybeTraitBody, OptionReturnTypeClause, OptionTypeClause,
@@ -26,13 +27,16 @@ pub fn handle_trait<'db>(
     if trait_ast.has_attr(db, DEPRECATED_ABI_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                trait_ast.stable_ptr(db).untyped(),
-                format!(
-                    "The '{DEPRECATED_ABI_ATTR}' attribute for traits was deprecated, please use \
-                     `{INTERFACE_ATTR}` instead.",
-                ),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    trait_ast.stable_ptr(db).untyped(),
+                    format!(
+                        "The '{DEPRECATED_ABI_ATTR}' attribute for traits was deprecated, please \
+                         use `{INTERFACE_ATTR}` instead.",
+                    ),
+                )
+                .with_error_code(error_code!(E3250)),
+            ],
             remove_original_item: false,
         };
     }
@@ -44,10 +48,13 @@ pub fn handle_trait<'db>(
         MaybeTraitBody::None(empty_body) => {
             return PluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    empty_body.stable_ptr(db).untyped(),
-                    "Starknet interfaces without body are not supported.".to_string(),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        empty_body.stable_ptr(db).untyped(),
+                        "Starknet interfaces without body are not supported.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3251)),
+                ],
                 remove_original_item: false,
             };
         }
@@ -67,11 +74,14 @@ pub fn handle_trait<'db>(
     }) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                generic_params.stable_ptr(db).untyped(),
-                "Starknet interfaces must have exactly one generic parameter, which is a type."
-                    .to_string(),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    generic_params.stable_ptr(db).untyped(),
+                    "Starknet interfaces must have exactly one generic parameter, which is a type."
+                        .to_string(),
+                )
+                .with_error_code(error_code!(E3252)),
+            ],
             remove_original_item: false,
         };
     };
@@ -94,10 +104,13 @@ pub fn handle_trait<'db>(
         match item_ast {
             ast::TraitItem::Function(func) => {
                 if let ast::MaybeTraitFunctionBody::Some(body) = func.body(db) {
-                    diagnostics.push(PluginDiagnostic::error(
-                        body.stable_ptr(db),
-                        format!("`{INTERFACE_ATTR}` functions don't support bodies."),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            body.stable_ptr(db),
+                            format!("`{INTERFACE_ATTR}` functions don't support bodies."),
+                        )
+                        .with_error_code(error_code!(E3253)),
+                    );
                 }
                 let declaration = func.declaration(db);
 
@@ -108,17 +121,23 @@ pub fn handle_trait<'db>(
                 let mut params = sig_params.elements(db);
                 // The first parameter is the `self` parameter.
                 let Some(self_param) = params.next() else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        declaration.stable_ptr(db),
-                        format!("`{INTERFACE_ATTR}` functions must have a `self` parameter."),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            declaration.stable_ptr(db),
+                            format!("`{INTERFACE_ATTR}` functions must have a `self` parameter."),
+                        )
+                        .with_error_code(error_code!(E3254)),
+                    );
                     continue;
                 };
                 if self_param.name(db).text(db) != SELF_PARAM_KW {
-                    diagnostics.push(PluginDiagnostic::error(
-                        self_param.stable_ptr(db),
-                        "The first parameter must be named `self`.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            self_param.stable_ptr(db),
+                            "The first parameter must be named `self`.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3255)),
+                    );
                     skip_generation = true;
                 }
                 let self_param_type_ok = if self_param.is_ref_param(db) {
@@ -131,13 +150,16 @@ pub fn handle_trait<'db>(
                     false
                 };
                 if !self_param_type_ok {
-                    diagnostics.push(PluginDiagnostic::error(
-                        self_param.stable_ptr(db),
-                        format!(
-                            "`{INTERFACE_ATTR}` function first parameter must be a reference to \
-                             the trait's generic parameter or a snapshot of it."
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            self_param.stable_ptr(db),
+                            format!(
+                                "`{INTERFACE_ATTR}` function first parameter must be a reference \
+                                 to the trait's generic parameter or a snapshot of it."
+                            ),
+                        )
+                        .with_error_code(error_code!(E3256)),
+                    );
                     skip_generation = true;
                 }
 
@@ -145,13 +167,16 @@ pub fn handle_trait<'db>(
                     if param.is_ref_param(db) {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic::error(
-                            param.modifiers(db).stable_ptr(db),
-                            format!(
-                                "`{INTERFACE_ATTR}` functions don't support `ref` parameters \
-                                 other than the first one."
-                            ),
-                        ))
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                param.modifiers(db).stable_ptr(db),
+                                format!(
+                                    "`{INTERFACE_ATTR}` functions don't support `ref` parameters \
+                                     other than the first one."
+                                ),
+                            )
+                            .with_error_code(error_code!(E3257)),
+                        )
                     }
                     if extract_matches!(param.type_clause(db), OptionTypeClause::TypeClause)
                         .ty(db)
@@ -159,24 +184,33 @@ pub fn handle_trait<'db>(
                     {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic::error(
-                            extract_matches!(param.type_clause(db), OptionTypeClause::TypeClause)
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                extract_matches!(
+                                    param.type_clause(db),
+                                    OptionTypeClause::TypeClause
+                                )
                                 .ty(db)
                                 .stable_ptr(db),
-                            format!(
-                                "`{INTERFACE_ATTR}` functions don't support parameters that \
-                                 depend on the trait's generic param type."
-                            ),
-                        ))
+                                format!(
+                                    "`{INTERFACE_ATTR}` functions don't support parameters that \
+                                     depend on the trait's generic param type."
+                                ),
+                            )
+                            .with_error_code(error_code!(E3258)),
+                        )
                     }
 
                     if param.name(db).text(db) == CALLDATA_PARAM_NAME {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic::error(
-                            param.name(db).stable_ptr(db),
-                            "Parameter name `__calldata__` cannot be used.".to_string(),
-                        ))
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                param.name(db).stable_ptr(db),
+                                "Parameter name `__calldata__` cannot be used.".to_string(),
+                            )
+                            .with_error_code(error_code!(E3259)),
+                        )
                     }
 
                     let param_type =
@@ -268,24 +302,33 @@ pub fn handle_trait<'db>(
             // Ignore the missing item.
             ast::TraitItem::Missing(_) => {}
             ast::TraitItem::Type(ty) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    ty.type_kw(db).stable_ptr(db),
-                    format!("`{INTERFACE_ATTR}` does not yet support type items."),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        ty.type_kw(db).stable_ptr(db),
+                        format!("`{INTERFACE_ATTR}` does not yet support type items."),
+                    )
+                    .with_error_code(error_code!(E3260)),
+                );
                 continue;
             }
             ast::TraitItem::Constant(constant) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    constant.const_kw(db).stable_ptr(db),
-                    format!("`{INTERFACE_ATTR}` does not yet support constant items."),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        constant.const_kw(db).stable_ptr(db),
+                        format!("`{INTERFACE_ATTR}` does not yet support constant items."),
+                    )
+                    .with_error_code(error_code!(E3261)),
+                );
                 continue;
             }
             ast::TraitItem::Impl(imp) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    imp.impl_kw(db).stable_ptr(db),
-                    format!("`{INTERFACE_ATTR}` does not yet support impl items."),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        imp.impl_kw(db).stable_ptr(db),
+                        format!("`{INTERFACE_ATTR}` does not yet support impl items."),
+                    )
+                    .with_error_code(error_code!(E3262)),
+                );
                 continue;
             }
         }
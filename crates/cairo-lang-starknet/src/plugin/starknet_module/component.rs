@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_plugins::plugins::HasItemsInCfgEx;
 use cairo_lang_semantic::keyword::SELF_PARAM_KW;
 use cairo_lang_syntax::attribute::structured::{AttributeArg, AttributeArgVariant};
@@ -133,6 +134,7 @@ fn get_embeddable_as_impl_generic_params<'db>(
                  `TContractState`."
             ),
         )
+        .with_error_code(error_code!(E3216))
     };
 
     let ast::OptionWrappedGenericParamList::WrappedGenericParamList(params) = generic_params else {
@@ -161,7 +163,8 @@ fn get_embeddable_as_impl_generic_params<'db>(
                 "An impl with #[{EMBEDDABLE_AS_ATTR}] should have a generic parameter which is an \
                  impl of `{HAS_COMPONENT_TRAIT}<{GENERIC_CONTRACT_STATE_NAME}>`."
             ),
-        ));
+        )
+        .with_error_code(error_code!(E3217)));
     }
 
     Ok(generic_params_node)
@@ -187,13 +190,16 @@ impl<'db> EmbeddableAsImplParams<'db> {
         attr: ast::Attribute<'db>,
     ) -> Option<EmbeddableAsImplParams<'db>> {
         let Some(attr_arg_value) = get_embeddable_as_attr_value(db, &attr) else {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                format!(
-                    "`{EMBEDDABLE_AS_ATTR}` attribute must have a single unnamed argument for the \
-                     generated impl name, e.g.: #[{EMBEDDABLE_AS_ATTR}(MyImpl)]."
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr(db),
+                    format!(
+                        "`{EMBEDDABLE_AS_ATTR}` attribute must have a single unnamed argument for \
+                         the generated impl name, e.g.: #[{EMBEDDABLE_AS_ATTR}(MyImpl)]."
+                    ),
+                )
+                .with_error_code(error_code!(E3218)),
+            );
             return None;
         };
 
@@ -210,10 +216,15 @@ impl<'db> EmbeddableAsImplParams<'db> {
         let impl_body = match item_impl.body(db) {
             ast::MaybeImplBody::Some(impl_body) => impl_body,
             ast::MaybeImplBody::None(semicolon) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    semicolon.stable_ptr(db),
-                    format!("`{EMBEDDABLE_AS_ATTR}` attribute is not supported for empty impls."),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        semicolon.stable_ptr(db),
+                        format!(
+                            "`{EMBEDDABLE_AS_ATTR}` attribute is not supported for empty impls."
+                        ),
+                    )
+                    .with_error_code(error_code!(E3219)),
+                );
                 return None;
             }
         };
@@ -242,14 +253,17 @@ fn handle_component_impl<'db>(
         if param.is_impl_of(db, "Destruct", GENERIC_CONTRACT_STATE_NAME)
             || param.is_impl_of(db, "PanicDestruct", GENERIC_CONTRACT_STATE_NAME)
         {
-            diagnostics.push(PluginDiagnostic::error(
-                param.stable_ptr(db),
-                format!(
-                    "`embeddable_as` impls can't have impl generic parameters of \
-                     `Destruct<{GENERIC_CONTRACT_STATE_NAME}>` or \
-                     `PanicDestruct<{GENERIC_CONTRACT_STATE_NAME}>`."
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    param.stable_ptr(db),
+                    format!(
+                        "`embeddable_as` impls can't have impl generic parameters of \
+                         `Destruct<{GENERIC_CONTRACT_STATE_NAME}>` or \
+                         `PanicDestruct<{GENERIC_CONTRACT_STATE_NAME}>`."
+                    ),
+                )
+                .with_error_code(error_code!(E3220)),
+            );
             return;
         }
     }
@@ -344,27 +358,33 @@ fn handle_component_embeddable_as_impl_item<'db>(
     let function_name = RewriteNode::from_ast_trimmed(&declaration.name(db));
     let parameters_elements = parameters.elements_vec(db);
     let Some((first_param, rest_params)) = parameters_elements.split_first() else {
-        diagnostics.push(PluginDiagnostic::error(
-            parameters.stable_ptr(db),
-            format!(
-                "A function in an #[{EMBEDDABLE_AS_ATTR}] impl in a component must have a first \
-                 `self` parameter."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                parameters.stable_ptr(db),
+                format!(
+                    "A function in an #[{EMBEDDABLE_AS_ATTR}] impl in a component must have a \
+                     first `self` parameter."
+                ),
+            )
+            .with_error_code(error_code!(E3221)),
+        );
         return None;
     };
     let Some((self_param, get_component_call, callsite_modifier)) =
         handle_first_param_for_embeddable_as(db, first_param)
     else {
-        diagnostics.push(PluginDiagnostic::error(
-            parameters.stable_ptr(db),
-            format!(
-                "The first parameter of a function in an #[{EMBEDDABLE_AS_ATTR}] impl in a \
-                 component must be either `self: @{GENERIC_COMPONENT_STATE_NAME}` (for view \
-                 functions) or `ref self: {GENERIC_COMPONENT_STATE_NAME}` (for external \
-                 functions)."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                parameters.stable_ptr(db),
+                format!(
+                    "The first parameter of a function in an #[{EMBEDDABLE_AS_ATTR}] impl in a \
+                     component must be either `self: @{GENERIC_COMPONENT_STATE_NAME}` (for view \
+                     functions) or `ref self: {GENERIC_COMPONENT_STATE_NAME}` (for external \
+                     functions)."
+                ),
+            )
+            .with_error_code(error_code!(E3222)),
+        );
         return None;
     };
     let rest_params_node = RewriteNode::new_modified(
@@ -1,6 +1,7 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic, PluginResult};
 use cairo_lang_defs::plugin_utils::not_legacy_macro_diagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_plugins::plugins::HasItemsInCfgEx;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
@@ -73,12 +74,15 @@ impl<'db> ComponentsGenerationData<'db> {
             // Currently it doesn't work as the name of the impl is the same.
             let Some(component_segment) = component_path.segments(db).elements(db).next_back()
             else {
-                diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                    db,
-                    node.stable_ptr(db),
-                    component_path.as_syntax_node(),
-                    "Component path must contain at least one segment.".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error_with_inner_span(
+                        db,
+                        node.stable_ptr(db),
+                        component_path.as_syntax_node(),
+                        "Component path must contain at least one segment.".into(),
+                    )
+                    .with_error_code(error_code!(E3205)),
+                );
                 continue;
             };
 
@@ -143,36 +147,43 @@ impl<'db> ComponentsGenerationData<'db> {
         let storage_name_syntax_node = storage_name.as_syntax_node();
         let storage_node_text = storage_name_syntax_node.get_text_without_trivia(db);
         if !self.substorage_members.contains(&storage_node_text) {
-            diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                db,
-                component_macro.stable_ptr(db).untyped(),
-                storage_name_syntax_node,
-                format!(
-                    "`{storage_node_text}` is not a substorage member in the contract's \
-                     `{STORAGE_STRUCT_NAME}`.\nConsider adding to \
-                     `{STORAGE_STRUCT_NAME}`:\n```\n#[{SUBSTORAGE_ATTR}(v0)]\n{storage_node_text}: \
-                     path::to::component::{STORAGE_STRUCT_NAME},\n````"
-                ),
-            ));
+            let member =
+                format!("{storage_node_text}: path::to::component::{STORAGE_STRUCT_NAME},");
+            diagnostics.push(
+                PluginDiagnostic::error_with_inner_span(
+                    db,
+                    component_macro.stable_ptr(db).untyped(),
+                    storage_name_syntax_node,
+                    format!(
+                        "`{storage_node_text}` is not a substorage member in the contract's \
+                         `{STORAGE_STRUCT_NAME}`.\nConsider adding to \
+                         `{STORAGE_STRUCT_NAME}`:\n```\n#[{SUBSTORAGE_ATTR}(v0)]\n{member}\n````"
+                    ),
+                )
+                .with_error_code(error_code!(E3206)),
+            );
             is_valid = false;
         }
 
         let event_name_syntax_node = event_name.as_syntax_node();
         let event_name_str = event_name_syntax_node.get_text_without_trivia(db);
         if !self.nested_event_variants.contains(&event_name_str) {
-            diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                db,
-                component_macro.stable_ptr(db).untyped(),
-                event_name_syntax_node,
-                format!(
-                    "`{event_name_str}` is not a nested event in the contract's \
-                     `{EVENT_TYPE_NAME}` enum.\nConsider adding to the `{EVENT_TYPE_NAME}` \
-                     enum:\n```\n{event_name_str}: \
-                     path::to::component::{EVENT_TYPE_NAME},\n```\nNote: currently with \
-                     components, only an enum {EVENT_TYPE_NAME} directly in the contract is \
-                     supported.",
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error_with_inner_span(
+                    db,
+                    component_macro.stable_ptr(db).untyped(),
+                    event_name_syntax_node,
+                    format!(
+                        "`{event_name_str}` is not a nested event in the contract's \
+                         `{EVENT_TYPE_NAME}` enum.\nConsider adding to the `{EVENT_TYPE_NAME}` \
+                         enum:\n```\n{event_name_str}: \
+                         path::to::component::{EVENT_TYPE_NAME},\n```\nNote: currently with \
+                         components, only an enum {EVENT_TYPE_NAME} directly in the contract is \
+                         supported.",
+                    ),
+                )
+                .with_error_code(error_code!(E3207)),
+            );
             is_valid = false;
         }
 
@@ -293,13 +304,16 @@ fn handle_contract_item<'db, 'a>(
                     &mut data.specific.entry_points_code,
                 );
             } else {
-                diagnostics.push(PluginDiagnostic::error(
-                    alias_ast.stable_ptr(db),
-                    format!(
-                        "The '{ABI_ATTR}' attribute for impl aliases only supports the \
-                         '{ABI_ATTR_EMBED_V0_ARG}' argument.",
-                    ),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        alias_ast.stable_ptr(db),
+                        format!(
+                            "The '{ABI_ATTR}' attribute for impl aliases only supports the \
+                             '{ABI_ATTR_EMBED_V0_ARG}' argument.",
+                        ),
+                    )
+                    .with_error_code(error_code!(E3208)),
+                );
             }
         }
         ast::ModuleItem::InlineMacro(inline_macro_ast)
@@ -567,13 +581,16 @@ fn impl_abi_config<'db>(
         } else if is_single_arg_attr(db, &abi_attr, ABI_ATTR_EMBED_V0_ARG) {
             ImplAbiConfig::Embed
         } else {
-            diagnostics.push(PluginDiagnostic::error(
-                abi_attr.stable_ptr(db),
-                format!(
-                    "The '{ABI_ATTR}' attribute for impls only supports the \
-                     '{ABI_ATTR_PER_ITEM_ARG}' or '{ABI_ATTR_EMBED_V0_ARG}' argument.",
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    abi_attr.stable_ptr(db),
+                    format!(
+                        "The '{ABI_ATTR}' attribute for impls only supports the \
+                         '{ABI_ATTR_PER_ITEM_ARG}' or '{ABI_ATTR_EMBED_V0_ARG}' argument.",
+                    ),
+                )
+                .with_error_code(error_code!(E3209)),
+            );
             ImplAbiConfig::None
         }
     } else if has_v0_attribute_ex(db, diagnostics, imp, EXTERNAL_ATTR, || {
@@ -602,13 +619,16 @@ fn handle_embed_impl_alias<'db>(
         }
     };
     if has_generic_params {
-        diagnostics.push(PluginDiagnostic::error(
-            alias_ast.stable_ptr(db),
-            format!(
-                "Generic parameters are not supported in impl aliases with \
-                 `#[{ABI_ATTR}({ABI_ATTR_EMBED_V0_ARG})]`."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                alias_ast.stable_ptr(db),
+                format!(
+                    "Generic parameters are not supported in impl aliases with \
+                     `#[{ABI_ATTR}({ABI_ATTR_EMBED_V0_ARG})]`."
+                ),
+            )
+            .with_error_code(error_code!(E3210)),
+        );
         return;
     }
     let segments = alias_ast.impl_path(db).segments(db);
@@ -618,13 +638,16 @@ fn handle_embed_impl_alias<'db>(
     };
 
     if !is_first_generic_arg_contract_state(db, &impl_final_part) {
-        diagnostics.push(PluginDiagnostic::error(
-            alias_ast.stable_ptr(db),
-            format!(
-                "First generic argument of impl alias with \
-                 `#[{ABI_ATTR}({ABI_ATTR_EMBED_V0_ARG})]` must be `{CONTRACT_STATE_NAME}`."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                alias_ast.stable_ptr(db),
+                format!(
+                    "First generic argument of impl alias with \
+                     `#[{ABI_ATTR}({ABI_ATTR_EMBED_V0_ARG})]` must be `{CONTRACT_STATE_NAME}`."
+                ),
+            )
+            .with_error_code(error_code!(E3211)),
+        );
         return;
     }
     let impl_name = impl_final_part.identifier_ast(db);
@@ -730,6 +753,7 @@ fn invalid_macro_diagnostic<'db>(
              \"<component_name>\", storage: \"<storage_name>\", event: \"<event_name>\");`"
         ),
     )
+    .with_error_code(error_code!(E3212))
 }
 
 /// Remove a `component!` inline macro from the original code if it's inside a starknet::contract.
@@ -790,39 +814,48 @@ fn try_extract_named_macro_argument<'db>(
                     if elements.len() != 1
                         || !matches!(elements.next_back().unwrap(), ast::PathSegment::Simple(_))
                     {
-                        diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                            db,
-                            component_macro_stable_ptr,
-                            path.as_syntax_node(),
-                            format!(
-                                "Component macro argument `{arg_name}` must be a simple \
-                                 identifier.",
-                            ),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error_with_inner_span(
+                                db,
+                                component_macro_stable_ptr,
+                                path.as_syntax_node(),
+                                format!(
+                                    "Component macro argument `{arg_name}` must be a simple \
+                                     identifier.",
+                                ),
+                            )
+                            .with_error_code(error_code!(E3213)),
+                        );
                         return None;
                     }
                     Some(path)
                 }
                 value => {
-                    diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                        db,
-                        component_macro_stable_ptr,
-                        value.as_syntax_node(),
-                        format!(
-                            "Component macro argument `{arg_name}` must be a path expression.",
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error_with_inner_span(
+                            db,
+                            component_macro_stable_ptr,
+                            value.as_syntax_node(),
+                            format!(
+                                "Component macro argument `{arg_name}` must be a path expression.",
+                            ),
+                        )
+                        .with_error_code(error_code!(E3214)),
+                    );
                     None
                 }
             }
         }
         _ => {
-            diagnostics.push(PluginDiagnostic::error_with_inner_span(
-                db,
-                component_macro_stable_ptr,
-                arg_ast.as_syntax_node(),
-                format!("Invalid component macro argument. Expected `{arg_name}: <value>`"),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error_with_inner_span(
+                    db,
+                    component_macro_stable_ptr,
+                    arg_ast.as_syntax_node(),
+                    format!("Invalid component macro argument. Expected `{arg_name}: <value>`"),
+                )
+                .with_error_code(error_code!(E3215)),
+            );
             None
         }
     }
@@ -5,6 +5,7 @@ use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile,
     PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::db::Edition;
 use cairo_lang_plugins::plugins::HasItemsInCfgEx;
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
@@ -106,13 +107,16 @@ pub(super) fn handle_module<'db>(
     if module_ast.has_attr(db, DEPRECATED_CONTRACT_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                module_ast.stable_ptr(db),
-                format!(
-                    "The '{DEPRECATED_CONTRACT_ATTR}' attribute was deprecated, please use \
-                     `{CONTRACT_ATTR}` instead.",
-                ),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    module_ast.stable_ptr(db),
+                    format!(
+                        "The '{DEPRECATED_CONTRACT_ATTR}' attribute was deprecated, please use \
+                         `{CONTRACT_ATTR}` instead.",
+                    ),
+                )
+                .with_error_code(error_code!(E3201)),
+            ],
             remove_original_item: false,
         };
     }
@@ -132,10 +136,13 @@ fn validate_module<'db>(
     let MaybeModuleBody::Some(body) = module_ast.body(db) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                module_ast.stable_ptr(db),
-                format!("{module_kind_str}s without body are not supported."),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    module_ast.stable_ptr(db),
+                    format!("{module_kind_str}s without body are not supported."),
+                )
+                .with_error_code(error_code!(E3202)),
+            ],
             remove_original_item: false,
         };
     };
@@ -147,7 +154,7 @@ fn validate_module<'db>(
             diagnostics: vec![PluginDiagnostic::error(
                  module_ast.stable_ptr(db),
                  format!("{module_kind_str}s must define a '{STORAGE_STRUCT_NAME}' struct."),
-            )],
+            ).with_error_code(error_code!(E3203))],
             remove_original_item: false,
         };
     };
@@ -155,10 +162,15 @@ fn validate_module<'db>(
     if !storage_struct_ast.has_attr(db, STORAGE_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                storage_struct_ast.stable_ptr(db),
-                format!("'{STORAGE_STRUCT_NAME}' struct must be annotated with #[{STORAGE_ATTR}]."),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    storage_struct_ast.stable_ptr(db),
+                    format!(
+                        "'{STORAGE_STRUCT_NAME}' struct must be annotated with #[{STORAGE_ATTR}]."
+                    ),
+                )
+                .with_error_code(error_code!(E3204)),
+            ],
             remove_original_item: false,
         };
     }
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::helpers::{GetIdentifier, QueryAttrs};
 use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
 use indoc::formatdoc;
@@ -44,13 +45,16 @@ pub fn handle_storage_struct<'db, 'a>(
                 substorage_members_struct_code.push(struct_code);
                 substorage_members_init_code.push(init_code);
             } else {
-                diagnostics.push(PluginDiagnostic::error(
-                    member.stable_ptr(db),
-                    format!(
-                        "`{SUBSTORAGE_ATTR}` attribute is only allowed for members of type \
-                         [some_path::]{STORAGE_STRUCT_NAME}`"
-                    ),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        member.stable_ptr(db),
+                        format!(
+                            "`{SUBSTORAGE_ATTR}` attribute is only allowed for members of type \
+                             [some_path::]{STORAGE_STRUCT_NAME}`"
+                        ),
+                    )
+                    .with_error_code(error_code!(E3231)),
+                );
             }
         }
         let SimpleMemberGeneratedCode { struct_code, struct_code_mut, init_code, storage_member } =
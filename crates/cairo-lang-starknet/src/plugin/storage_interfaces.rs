@@ -3,6 +3,7 @@ use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
 use cairo_lang_defs::plugin_utils::extract_single_unnamed_arg;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::ids::CodeMapping;
 use cairo_lang_plugins::plugins::utils::GenericParamsInfo;
 use cairo_lang_syntax::attribute::structured::{
@@ -70,36 +71,49 @@ impl MacroPlugin for StorageInterfacesPlugin {
         let storage_node_attrs = item_ast.query_attr(db, STORAGE_NODE_ATTR).collect_vec();
         if !matches!(item_ast, ast::ModuleItem::Struct(_)) && !storage_node_attrs.is_empty() {
             for attr in &storage_node_attrs {
-                diagnostics.push(PluginDiagnostic::error(
-                    attr.stable_ptr(db),
-                    "Can only be applied to structs.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        attr.stable_ptr(db),
+                        "Can only be applied to structs.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3232)),
+                );
             }
         }
         let sub_pointers_attrs = item_ast.query_attr(db, STORAGE_SUB_POINTERS_ATTR).collect_vec();
         if !matches!(item_ast, ast::ModuleItem::Enum(_)) && !sub_pointers_attrs.is_empty() {
             for attr in &sub_pointers_attrs {
-                diagnostics.push(PluginDiagnostic::error(
-                    attr.stable_ptr(db),
-                    "Can only be applied to enums.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        attr.stable_ptr(db),
+                        "Can only be applied to enums.".to_string(),
+                    )
+                    .with_error_code(error_code!(E3233)),
+                );
             }
         }
         match item_ast {
             ast::ModuleItem::Struct(struct_ast) if !storage_node_attrs.is_empty() => {
                 for attr in &storage_node_attrs[1..] {
-                    diagnostics.push(PluginDiagnostic::error(
-                        attr.stable_ptr(db),
-                        "Multiple storage node attributes are not allowed.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            attr.stable_ptr(db),
+                            "Multiple storage node attributes are not allowed.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3234)),
+                    );
                 }
                 if has_derive(&struct_ast, db, STORE_TRAIT).is_some() {
-                    diagnostics.push(PluginDiagnostic::error(
-                        struct_ast.stable_ptr(db),
-                        format!(
-                            "Storage node attribute cannot be used with derive of `{STORE_TRAIT}`."
-                        ),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            struct_ast.stable_ptr(db),
+                            format!(
+                                "Storage node attribute cannot be used with derive of \
+                                 `{STORE_TRAIT}`."
+                            ),
+                        )
+                        .with_error_code(error_code!(E3235)),
+                    );
                 }
                 if !diagnostics.is_empty() {
                     return PluginResult { code: None, diagnostics, remove_original_item: false };
@@ -122,20 +136,26 @@ impl MacroPlugin for StorageInterfacesPlugin {
             }
             ast::ModuleItem::Enum(enum_ast) if !sub_pointers_attrs.is_empty() => {
                 for attr in &sub_pointers_attrs[1..] {
-                    diagnostics.push(PluginDiagnostic::error(
-                        attr.stable_ptr(db),
-                        "Multiple sub pointers attributes are not allowed.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            attr.stable_ptr(db),
+                            "Multiple sub pointers attributes are not allowed.".to_string(),
+                        )
+                        .with_error_code(error_code!(E3236)),
+                    );
                 }
                 let arguments = sub_pointers_attrs[0].arguments(db);
                 if !matches!(&arguments, OptionArgListParenthesized::ArgListParenthesized(args)
                             if extract_single_unnamed_arg(db, args.arguments(db)).is_some())
                 {
-                    diagnostics.push(PluginDiagnostic::error(
-                        arguments.stable_ptr(db),
-                        "Sub pointers attribute must have exactly one unnamed argument."
-                            .to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            arguments.stable_ptr(db),
+                            "Sub pointers attribute must have exactly one unnamed argument."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!(E3237)),
+                    );
                 }
                 if !diagnostics.is_empty() {
                     return PluginResult { code: None, diagnostics, remove_original_item: false };
@@ -850,41 +870,54 @@ pub fn get_member_storage_config<'db>(
     for attr in member.query_attr(db, FLAT_ATTR) {
         let attr = attr.structurize(db);
         if result.kind != StorageMemberKind::Basic {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr,
-                "Multiple storage attributes are not allowed.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr,
+                    "Multiple storage attributes are not allowed.".to_string(),
+                )
+                .with_error_code(error_code!(E3238)),
+            );
         }
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.args_stable_ptr,
-                "Unexpected arguments.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(attr.args_stable_ptr, "Unexpected arguments.".to_string())
+                    .with_error_code(error_code!(E3239)),
+            );
         }
         result.kind = StorageMemberKind::Flat;
     }
     for attr in member.query_attr(db, SUBSTORAGE_ATTR) {
         if result.kind != StorageMemberKind::Basic {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                "Multiple storage attributes are not allowed.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr(db),
+                    "Multiple storage attributes are not allowed.".to_string(),
+                )
+                .with_error_code(error_code!(E3238)),
+            );
         }
         validate_v0(db, diagnostics, &attr, SUBSTORAGE_ATTR);
         result.kind = StorageMemberKind::SubStorage;
     }
     for attr in member.query_attr(db, RENAME_ATTR) {
         if result.kind != StorageMemberKind::Basic {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                "The `rename` attribute cannot be used with other storage attributes.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr(db),
+                    "The `rename` attribute cannot be used with other storage attributes."
+                        .to_string(),
+                )
+                .with_error_code(error_code!(E3240)),
+            );
         }
         if result.rename.is_some() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr(db),
-                "Multiple `rename` attributes are not allowed.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr(db),
+                    "Multiple `rename` attributes are not allowed.".to_string(),
+                )
+                .with_error_code(error_code!(E3241)),
+            );
         }
         let attr = attr.structurize(db);
         let [
@@ -893,10 +926,13 @@ pub fn get_member_storage_config<'db>(
             },
         ] = &attr.args[..]
         else {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.args_stable_ptr,
-                "Unexpected arguments, expected single string argument.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.args_stable_ptr,
+                    "Unexpected arguments, expected single string argument.".to_string(),
+                )
+                .with_error_code(error_code!(E3242)),
+            );
             continue;
         };
         result.rename = value.string_value(db);
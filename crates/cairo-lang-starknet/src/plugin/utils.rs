@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
@@ -178,7 +179,10 @@ pub fn has_v0_attribute_ex<'db>(
     };
     validate_v0(db, diagnostics, &attr, attr_name);
     if let Some(deprecated) = deprecated() {
-        diagnostics.push(PluginDiagnostic::warning(attr.stable_ptr(db), deprecated));
+        diagnostics.push(
+            PluginDiagnostic::warning(attr.stable_ptr(db), deprecated)
+                .with_error_code(error_code!(E3266)),
+        );
     }
     true
 }
@@ -191,10 +195,13 @@ pub fn validate_v0<'db>(
     name: &str,
 ) {
     if !is_single_arg_attr(db, attr, "v0") {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr(db),
-            format!("Only #[{name}(v0)] is supported."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr(db),
+                format!("Only #[{name}(v0)] is supported."),
+            )
+            .with_error_code(error_code!(E3267)),
+        );
     }
 }
 
@@ -219,13 +226,16 @@ pub fn forbid_attribute_in_impl<'db>(
     embedded_impl_attr: &str,
 ) {
     if let Some(attr) = impl_item.find_attr(db, attr_name) {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr(db),
-            format!(
-                "The `{attr_name}` attribute is not allowed inside an impl marked as \
-                 `{embedded_impl_attr}`."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr(db),
+                format!(
+                    "The `{attr_name}` attribute is not allowed inside an impl marked as \
+                     `{embedded_impl_attr}`."
+                ),
+            )
+            .with_error_code(error_code!(E3268)),
+        );
     }
 }
 
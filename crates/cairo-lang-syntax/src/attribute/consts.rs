@@ -39,6 +39,64 @@ pub const UNUSED_VARIABLES: &str = "unused_variables";
 /// Also included in the [`UNUSED`] lint group.
 pub const UNUSED_IMPORTS: &str = "unused_imports";
 
+/// An argument to the `allow` attribute that suppresses warnings for unused constants.
+/// Also included in the [`UNUSED`] lint group.
+pub const UNUSED_CONSTANTS: &str = "unused_constants";
+
+/// An attribute to report the warnings of a lint as warnings, even if allowed by an outer item.
+pub const WARN_ATTR: &str = "warn";
+
+/// An attribute to report the warnings of a lint as errors.
+pub const DENY_ATTR: &str = "deny";
+
+/// An attribute to suppress the warnings of a lint, while warning if there are none to suppress.
+pub const EXPECT_ATTR: &str = "expect";
+
+/// A lint for unused results of `#[must_use]` types and functions.
+pub const UNUSED_MUST_USE: &str = "unused_must_use";
+
+/// A lint for usages of unstable features without a `#[feature]` attribute.
+pub const UNSTABLE_FEATURES: &str = "unstable_features";
+
+/// A lint for paths to the current trait or impl inside it, instead of using `Self`.
+pub const IMPLICIT_SELF_PATHS: &str = "implicit_self_paths";
+
+/// A lint for calls of functions shadowed by a local variable.
+pub const SHADOWED_FUNCTION_CALLS: &str = "shadowed_function_calls";
+
+/// A lint for enum variant patterns missing the pattern for their payload.
+pub const MISSING_PAYLOAD_PATTERNS: &str = "missing_payload_patterns";
+
+/// A lint for unsupported arguments of lint attributes.
+pub const UNKNOWN_LINTS: &str = "unknown_lints";
+
+/// A lint for `#[expect]` attributes with no warning to suppress.
+pub const UNFULFILLED_LINT_EXPECTATIONS: &str = "unfulfilled_lint_expectations";
+
+/// A lint for unreachable code.
+pub const UNREACHABLE_CODE: &str = "unreachable_code";
+
+/// A lint for unreachable match arms.
+pub const UNREACHABLE_PATTERNS: &str = "unreachable_patterns";
+
+/// A lint for clones of values that are not used afterwards.
+pub const REDUNDANT_CLONES: &str = "redundant_clones";
+
+/// A lint for comparisons of bounded integers that are always true or always false.
+pub const ABSURD_EXTREME_COMPARISONS: &str = "absurd_extreme_comparisons";
+
+/// A lint for field divisions of `felt252` values.
+pub const FELT252_DIVISION: &str = "felt252_division";
+
+/// A lint for discarded results of syscalls.
+pub const UNUSED_SYSCALL_RESULTS: &str = "unused_syscall_results";
+
+/// A lint for `ref` parameters that are never modified.
+pub const NEEDLESS_REF_PARAMS: &str = "needless_ref_params";
+
+/// A lint for loops that never exit, when gas is not withdrawn.
+pub const UNBOUNDED_LOOPS: &str = "unbounded_loops";
+
 /// An attribute to allow additional attributes on an item.
 pub const ALLOW_ATTR_ATTR: &str = "allow_attr";
 
@@ -0,0 +1,390 @@
+use cairo_lang_defs::ids::{FreeFunctionId, FunctionWithBodyId, ModuleId, NamedLanguageElementId};
+use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
+use cairo_lang_defs::plugin::{PluginDiagnostic, PluginGeneratedFile, PluginResult};
+use cairo_lang_diagnostics::error_code;
+use cairo_lang_filesystem::ids::FileLongId;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::plugin::AnalyzerPlugin;
+use cairo_lang_syntax::attribute::structured::{
+    Attribute, AttributeArg, AttributeArgVariant, AttributeListStructurize,
+};
+use cairo_lang_syntax::node::helpers::OptionWrappedGenericParamListHelper;
+use cairo_lang_syntax::node::{Terminal, TypedStablePtr, TypedSyntaxNode, ast};
+use indoc::formatdoc;
+use salsa::Database;
+
+use super::{
+    FIXTURE_ATTR, FIXTURE_PARAMS_ATTR, FIXTURE_PREFIX, FIXTURE_SCOPE_ARG, FIXTURE_VALUE_PREFIX,
+    TEST_ATTR, TEST_CASE_ATTR,
+};
+
+/// The scope of a fixture, setting how many times it runs.
+///
+/// Fixtures only provide values and have no teardown - the Starknet state a test ran in is dropped
+/// once the test ends, whatever the scope of its fixtures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixtureScope {
+    /// The fixture runs as a part of each test taking it, in the Starknet state of the test.
+    Test,
+    /// The fixture runs once, before the tests. Each test taking it gets its value, and starts
+    /// from a clone of the Starknet state it left.
+    /// A test can take at most one module-scoped fixture, as their Starknet states cannot be
+    /// combined.
+    Module,
+}
+
+/// Extracts the scope of a fixture from the `fixture` attribute, of the form
+/// `#[fixture(scope: test|module)]`, where the scope is optional and defaults to `test`.
+/// Returns `None` if the function is not a fixture.
+pub fn try_extract_fixture_scope<'db>(
+    db: &'db dyn Database,
+    attrs: &[Attribute<'db>],
+) -> Result<Option<FixtureScope>, PluginDiagnostic<'db>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.id == FIXTURE_ATTR) else {
+        return Ok(None);
+    };
+    match &attr.args[..] {
+        [] => return Ok(Some(FixtureScope::Test)),
+        [
+            AttributeArg {
+                variant: AttributeArgVariant::Named { name, value: ast::Expr::Path(path), .. },
+                ..
+            },
+        ] if name.text == FIXTURE_SCOPE_ARG => {
+            match path.as_syntax_node().get_text_without_trivia(db) {
+                "test" => return Ok(Some(FixtureScope::Test)),
+                "module" => return Ok(Some(FixtureScope::Module)),
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+    Err(PluginDiagnostic::error(
+        attr.args_stable_ptr.untyped(),
+        format!(
+            "Attribute should be of the form `{FIXTURE_ATTR}({FIXTURE_SCOPE_ARG}: test)` or \
+             `{FIXTURE_ATTR}({FIXTURE_SCOPE_ARG}: module)`, where the scope is optional."
+        ),
+    )
+    .with_error_code(error_code!(E3113)))
+}
+
+/// Generates the functions providing the value of a fixture:
+/// * `__fixture__<name>`, called by the tests taking the fixture. For test-scoped fixtures it calls
+///   the fixture, and for module-scoped fixtures it deserializes the value computed by the runner
+///   from the arguments of the test.
+/// * `__fixture_value__<name>`, only for module-scoped fixtures, run by the runner to compute the
+///   serialized value of the fixture.
+pub fn generate_fixture<'db>(
+    db: &'db dyn Database,
+    func: &ast::FunctionWithBody<'db>,
+    scope: FixtureScope,
+) -> PluginResult<'db> {
+    let mut diagnostics = vec![];
+    for attr in func.attributes(db).structurize(db) {
+        if attr.id == TEST_ATTR || attr.id == TEST_CASE_ATTR {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    format!("Attribute should not be used together with `{FIXTURE_ATTR}`."),
+                )
+                .with_error_code(error_code!(E3114)),
+            );
+        }
+    }
+    let declaration = func.declaration(db);
+    let generics = declaration.generic_params(db);
+    if !generics.is_empty(db) {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generics.stable_ptr(db),
+                "Fixtures cannot have generic params.".into(),
+            )
+            .with_error_code(error_code!(E3115)),
+        );
+    }
+    let signature = declaration.signature(db);
+    let params = signature.parameters(db);
+    if params.elements(db).next().is_some() {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                params.stable_ptr(db),
+                "Fixtures cannot have parameters.".into(),
+            )
+            .with_error_code(error_code!(E3116)),
+        );
+    }
+    let ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty) = signature.ret_ty(db) else {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                declaration.name(db).stable_ptr(db),
+                "Fixtures must return a value.".into(),
+            )
+            .with_error_code(error_code!(E3117)),
+        );
+        return PluginResult { code: None, diagnostics, remove_original_item: false };
+    };
+    if !diagnostics.is_empty() {
+        return PluginResult { code: None, diagnostics, remove_original_item: false };
+    }
+
+    let value = match scope {
+        FixtureScope::Test => formatdoc! {"
+            $visibility$fn {FIXTURE_PREFIX}$name$(ref _fixture_args: core::array::Span<felt252>) -> $ty$ {{
+                $name$()
+            }}
+        "},
+        FixtureScope::Module => formatdoc! {"
+            $visibility$fn {FIXTURE_PREFIX}$name$(ref fixture_args: core::array::Span<felt252>) -> $ty$ {{
+                core::option::OptionTrait::expect(
+                    core::serde::Serde::<$ty$>::deserialize(ref fixture_args),
+                    'Invalid fixture arguments.',
+                )
+            }}
+            $visibility$fn {FIXTURE_VALUE_PREFIX}$name$() -> core::array::Span<felt252> {{
+                let mut output = core::array::ArrayTrait::new();
+                core::serde::Serde::<$ty$>::serialize(@$name$(), ref output);
+                core::array::ArrayTrait::span(@output)
+            }}
+        "},
+    };
+    let mut builder = PatchBuilder::new(db, func);
+    builder.add_modified(RewriteNode::interpolate_patched(
+        &value,
+        &[
+            ("visibility".to_string(), RewriteNode::from_ast(&func.visibility(db))),
+            ("name".to_string(), RewriteNode::from_ast_trimmed(&declaration.name(db))),
+            ("ty".to_string(), RewriteNode::from_ast_trimmed(&ret_ty.ty(db))),
+        ]
+        .into(),
+    ));
+    let (content, code_mappings) = builder.build();
+    PluginResult {
+        code: Some(PluginGeneratedFile {
+            name: FIXTURE_ATTR.into(),
+            content,
+            code_mappings,
+            aux_data: None,
+            diagnostics_note: Default::default(),
+            is_unhygienic: false,
+        }),
+        diagnostics,
+        remove_original_item: false,
+    }
+}
+
+/// Rewrites a test with parameters, that are not fuzzed, to take the values of its parameters from
+/// the fixtures of the same names, ignoring leading underscores.
+///
+/// The rewritten test takes a single `Span<felt252>` argument, with the serialized value of its
+/// module-scoped fixture, if any, and is marked with the names of its fixtures, for the runner to
+/// know which fixture to run before it.
+pub fn inject_fixtures<'db>(
+    db: &'db dyn Database,
+    func: &ast::FunctionWithBody<'db>,
+) -> PluginResult<'db> {
+    let declaration = func.declaration(db);
+    let signature = declaration.signature(db);
+    let params = signature.parameters(db).elements_vec(db);
+    let mut diagnostics = vec![];
+    let mut builder = PatchBuilder::new(db, func);
+    builder.add_modified(RewriteNode::from_ast(&func.attributes(db)));
+    builder.add_str(&format!("#[{FIXTURE_PARAMS_ATTR}("));
+    builder.add_modified(RewriteNode::interspersed(
+        params
+            .iter()
+            .map(|param| RewriteNode::text(&fixture_name(db, param)).mapped(db, &param.name(db))),
+        RewriteNode::text(", "),
+    ));
+    builder.add_str(")]\n");
+    builder.add_modified(RewriteNode::interpolate_patched(
+        "$visibility$fn $name$(mut __fixture_args: core::array::Span<felt252>) $ret_ty$ {\n",
+        &[
+            ("visibility".to_string(), RewriteNode::from_ast(&func.visibility(db))),
+            ("name".to_string(), RewriteNode::from_ast_trimmed(&declaration.name(db))),
+            ("ret_ty".to_string(), RewriteNode::from_ast_trimmed(&signature.ret_ty(db))),
+        ]
+        .into(),
+    ));
+    for param in &params {
+        let mut is_mut = false;
+        for modifier in param.modifiers(db).elements(db) {
+            match modifier {
+                ast::Modifier::Mut(_) => is_mut = true,
+                ast::Modifier::Ref(terminal_ref) => diagnostics.push(
+                    PluginDiagnostic::error(
+                        terminal_ref.stable_ptr(db),
+                        "Parameters of a test taking fixtures can't be `ref`.".into(),
+                    )
+                    .with_error_code(error_code!(E3118)),
+                ),
+            }
+        }
+        let ast::OptionTypeClause::TypeClause(type_clause) = param.type_clause(db) else {
+            continue;
+        };
+        let call = format!("{FIXTURE_PREFIX}{}(ref __fixture_args)", fixture_name(db, param));
+        builder.add_modified(RewriteNode::interpolate_patched(
+            &format!("    let {}$name$: $ty$ = $call$;\n", if is_mut { "mut " } else { "" }),
+            &[
+                ("name".to_string(), RewriteNode::from_ast_trimmed(&param.name(db))),
+                ("ty".to_string(), RewriteNode::from_ast_trimmed(&type_clause.ty(db))),
+                ("call".to_string(), RewriteNode::text(&call).mapped(db, param)),
+            ]
+            .into(),
+        ));
+    }
+    builder.add_str("    ");
+    builder.add_modified(RewriteNode::from_ast_trimmed(&func.body(db)));
+    builder.add_str("\n}\n");
+    if !diagnostics.is_empty() {
+        return PluginResult { code: None, diagnostics, remove_original_item: false };
+    }
+    let (content, code_mappings) = builder.build();
+    PluginResult {
+        code: Some(PluginGeneratedFile {
+            name: FIXTURE_PARAMS_ATTR.into(),
+            content,
+            code_mappings,
+            aux_data: None,
+            diagnostics_note: Default::default(),
+            is_unhygienic: false,
+        }),
+        diagnostics,
+        remove_original_item: true,
+    }
+}
+
+/// Checks that the attribute added by [inject_fixtures] is only on the tests it generated, as a
+/// hand-written one would make the runner run fixtures the test does not take.
+pub fn check_fixture_params_attr<'db>(
+    db: &'db dyn Database,
+    func: &ast::FunctionWithBody<'db>,
+    attrs: &[Attribute<'db>],
+) -> Option<PluginDiagnostic<'db>> {
+    let attr = attrs.iter().find(|attr| attr.id == FIXTURE_PARAMS_ATTR)?;
+    let file_id = func.stable_ptr(db).untyped().file_id(db);
+    let is_injected = !matches!(file_id.long(db), FileLongId::OnDisk(_))
+        && file_id.file_name(db) == FIXTURE_PARAMS_ATTR;
+    (!is_injected).then(|| {
+        PluginDiagnostic::error(
+            attr.id_stable_ptr.untyped(),
+            "Attribute is internal to tests taking fixtures. Fixtures are taken as parameters of \
+             the test, named as the fixtures."
+                .into(),
+        )
+        .with_error_code(error_code!(E3127))
+    })
+}
+
+/// Returns the name of the fixture providing the value of a parameter, which is the name of the
+/// parameter without leading underscores, allowing to take fixtures that are not used by the test.
+fn fixture_name<'db>(db: &'db dyn Database, param: &ast::Param<'db>) -> String {
+    param.name(db).text(db).trim_start_matches('_').to_string()
+}
+
+/// Returns the names of the fixtures of a test, from the attribute added by [inject_fixtures].
+pub fn fixture_param_names<'db>(
+    db: &'db dyn Database,
+    attr: &Attribute<'db>,
+) -> Vec<(String, ast::Expr<'db>)> {
+    attr.args
+        .iter()
+        .filter_map(|arg| match &arg.variant {
+            AttributeArgVariant::Unnamed(value @ ast::Expr::Path(path)) => {
+                Some((path.as_syntax_node().get_text_without_trivia(db).to_string(), value.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the scope of the fixture with the given name in a module, or `None` if there is no such
+/// fixture.
+pub fn find_fixture<'db>(
+    db: &'db dyn SemanticGroup,
+    module_id: ModuleId<'db>,
+    name: &str,
+) -> Option<(FreeFunctionId<'db>, FixtureScope)> {
+    let func_id = find_free_function(db, module_id, name)?;
+    let attrs = db.function_with_body_attributes(FunctionWithBodyId::Free(func_id)).ok()?;
+    Some((func_id, try_extract_fixture_scope(db, &attrs).ok()??))
+}
+
+/// Returns the function computing the value of the module-scoped fixture of a test with the given
+/// fixtures, if any.
+pub fn find_module_fixture_value<'db>(
+    db: &'db dyn SemanticGroup,
+    module_id: ModuleId<'db>,
+    fixtures: &[(String, ast::Expr<'db>)],
+) -> Option<FreeFunctionId<'db>> {
+    let name = fixtures.iter().find_map(|(name, _)| {
+        matches!(find_fixture(db, module_id, name)?.1, FixtureScope::Module).then_some(name)
+    })?;
+    find_free_function(db, module_id, &format!("{FIXTURE_VALUE_PREFIX}{name}"))
+}
+
+/// Returns the free function with the given name in a module, if any.
+fn find_free_function<'db>(
+    db: &'db dyn SemanticGroup,
+    module_id: ModuleId<'db>,
+    name: &str,
+) -> Option<FreeFunctionId<'db>> {
+    let free_functions = module_id.module_data(db).ok()?.free_functions(db);
+    free_functions.keys().find(|func_id| func_id.name(db) == name).copied()
+}
+
+/// Plugin to add diagnostics on the fixtures taken by tests.
+#[derive(Default, Debug)]
+pub struct FixtureAnalyzer;
+
+impl AnalyzerPlugin for FixtureAnalyzer {
+    fn diagnostics<'db>(
+        &self,
+        db: &'db dyn SemanticGroup,
+        module_id: ModuleId<'db>,
+    ) -> Vec<PluginDiagnostic<'db>> {
+        let mut diagnostics = vec![];
+        let Ok(free_functions) = module_id.module_data(db).map(|data| data.free_functions(db))
+        else {
+            return diagnostics;
+        };
+        for id in free_functions.keys() {
+            let Ok(attrs) = db.function_with_body_attributes(FunctionWithBodyId::Free(*id)) else {
+                continue;
+            };
+            let Some(attr) = attrs.iter().find(|attr| attr.id == FIXTURE_PARAMS_ATTR) else {
+                continue;
+            };
+            let mut module_fixtures = vec![];
+            for (name, value) in fixture_param_names(db, attr) {
+                match find_fixture(db, module_id, &name) {
+                    Some((_, FixtureScope::Module)) => module_fixtures.push(value),
+                    Some((_, FixtureScope::Test)) => {}
+                    None => diagnostics.push(
+                        PluginDiagnostic::error(
+                            value.stable_ptr(db),
+                            format!(
+                                "No fixture named `{name}` in the module of the test. Fixtures \
+                                 are functions with the `{FIXTURE_ATTR}` attribute."
+                            ),
+                        )
+                        .with_error_code(error_code!(E3119)),
+                    ),
+                }
+            }
+            for value in module_fixtures.iter().skip(1) {
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        value.stable_ptr(db),
+                        "A test can take at most one module-scoped fixture, as their Starknet \
+                         states cannot be combined."
+                            .into(),
+                    )
+                    .with_error_code(error_code!(E3120)),
+                );
+            }
+        }
+        diagnostics
+    }
+}
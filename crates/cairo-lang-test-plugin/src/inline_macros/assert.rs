@@ -7,6 +7,7 @@ use cairo_lang_defs::plugin_utils::{
     PluginResultTrait, escape_node, not_legacy_macro_diagnostic, try_extract_unnamed_arg,
     unsupported_bracket_diagnostic,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::cfg::Cfg;
 use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
 use cairo_lang_syntax::node::ast::WrappedArgList;
@@ -51,10 +52,13 @@ trait CompareAssertionPlugin: NamedPlugin {
         if arguments.len() < 2 {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    arguments_syntax.lparen(db).stable_ptr(db),
-                    format!("Macro `{}` requires at least 2 arguments.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        arguments_syntax.lparen(db).stable_ptr(db),
+                        format!("Macro `{}` requires at least 2 arguments.", Self::NAME),
+                    )
+                    .with_error_code(error_code!(E3121)),
+                ],
             };
         }
         let (lhs, rest) = arguments.split_first().unwrap();
@@ -62,23 +66,35 @@ trait CompareAssertionPlugin: NamedPlugin {
         let Some(lhs) = try_extract_unnamed_arg(db, lhs) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error_with_inner_span(
-                    db,
-                    syntax.stable_ptr(db),
-                    lhs.as_syntax_node(),
-                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error_with_inner_span(
+                        db,
+                        syntax.stable_ptr(db),
+                        lhs.as_syntax_node(),
+                        format!(
+                            "Macro `{}` requires the first argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!(E3122)),
+                ],
             };
         };
         let Some(rhs) = try_extract_unnamed_arg(db, rhs) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error_with_inner_span(
-                    db,
-                    syntax.stable_ptr(db),
-                    rhs.as_syntax_node(),
-                    format!("Macro `{}` requires the second argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error_with_inner_span(
+                        db,
+                        syntax.stable_ptr(db),
+                        rhs.as_syntax_node(),
+                        format!(
+                            "Macro `{}` requires the second argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!(E3123)),
+                ],
             };
         };
         let f = format!("__formatter_for_{}_macro_", Self::NAME);
@@ -189,10 +205,13 @@ trait CompareAssertionPlugin: NamedPlugin {
         if !metadata.cfg_set.contains(&Cfg::kv("target", "test"))
             && !metadata.cfg_set.contains(&Cfg::name("test"))
         {
-            diagnostics.push(PluginDiagnostic::error(
-                syntax.stable_ptr(db),
-                format!("`{}` macro is only available in test mode.", Self::NAME),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    syntax.stable_ptr(db),
+                    format!("`{}` macro is only available in test mode.", Self::NAME),
+                )
+                .with_error_code(error_code!(E3124)),
+            );
         }
         InlinePluginResult {
             code: Some(PluginGeneratedFile {
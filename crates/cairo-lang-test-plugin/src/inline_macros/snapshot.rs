@@ -0,0 +1,149 @@
+use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
+use cairo_lang_defs::plugin::{
+    InlineMacroExprPlugin, InlinePluginResult, MacroPluginMetadata, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
+};
+use cairo_lang_defs::plugin_utils::{
+    PluginResultTrait, not_legacy_macro_diagnostic, try_extract_unnamed_arg,
+    unsupported_bracket_diagnostic,
+};
+use cairo_lang_diagnostics::error_code;
+use cairo_lang_filesystem::cfg::Cfg;
+use cairo_lang_parser::macro_helpers::AsLegacyInlineMacro;
+use cairo_lang_syntax::node::ast::WrappedArgList;
+use cairo_lang_syntax::node::{TypedSyntaxNode, ast};
+use indoc::formatdoc;
+use salsa::Database;
+
+/// Macro for asserting that the `Debug` formatting of a value matches a stored snapshot.
+///
+/// `assert_snapshot!(value)` and `assert_snapshot!(value, "name")` format the value and pass it to
+/// the test runner, which compares it with the snapshot file of the test, and returns a
+/// description of the mismatch, if any, for the macro to panic with.
+#[derive(Default, Debug)]
+pub struct AssertSnapshotMacro;
+impl NamedPlugin for AssertSnapshotMacro {
+    const NAME: &'static str = "assert_snapshot";
+}
+impl InlineMacroExprPlugin for AssertSnapshotMacro {
+    fn generate_code<'db>(
+        &self,
+        db: &'db dyn Database,
+        syntax: &ast::ExprInlineMacro<'db>,
+        metadata: &MacroPluginMetadata<'_>,
+    ) -> InlinePluginResult<'db> {
+        let Some(legacy_inline_macro) = syntax.as_legacy_inline_macro(db) else {
+            return InlinePluginResult::diagnostic_only(not_legacy_macro_diagnostic(
+                syntax.as_syntax_node().stable_ptr(db),
+            ));
+        };
+        let WrappedArgList::ParenthesizedArgList(arguments_syntax) =
+            legacy_inline_macro.arguments(db)
+        else {
+            return unsupported_bracket_diagnostic(db, &legacy_inline_macro, syntax.stable_ptr(db));
+        };
+        let arguments = arguments_syntax.arguments(db).elements_vec(db);
+        let (value, name) = match arguments.as_slice() {
+            [value] => (value, None),
+            [value, name] => (value, Some(name)),
+            _ => {
+                return InlinePluginResult::diagnostic_only(
+                    PluginDiagnostic::error(
+                        arguments_syntax.lparen(db).stable_ptr(db),
+                        format!("Macro `{}` requires 1 or 2 arguments.", Self::NAME),
+                    )
+                    .with_error_code(error_code!(E3125)),
+                );
+            }
+        };
+        let Some(value) = try_extract_unnamed_arg(db, value) else {
+            return InlinePluginResult::diagnostic_only(
+                PluginDiagnostic::error(
+                    value.stable_ptr(db),
+                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
+                )
+                .with_error_code(error_code!(E3122)),
+            );
+        };
+        let name = match name {
+            None => String::new(),
+            Some(name_arg) => {
+                let name = match try_extract_unnamed_arg(db, name_arg) {
+                    Some(ast::Expr::String(literal)) => literal.string_value(db),
+                    _ => None,
+                };
+                match name.filter(|name| is_valid_snapshot_name(name)) {
+                    Some(name) => name,
+                    None => {
+                        return InlinePluginResult::diagnostic_only(
+                            PluginDiagnostic::error(
+                                name_arg.stable_ptr(db),
+                                "Snapshot name must be a string literal of letters, digits, \
+                                 underscores and dashes."
+                                    .into(),
+                            )
+                            .with_error_code(error_code!(E3126)),
+                        );
+                    }
+                }
+            }
+        };
+        let f = format!("__formatter_for_{}_macro_", Self::NAME);
+        let input = format!("__input_for_{}_macro_", Self::NAME);
+        let output = format!("__output_for_{}_macro_", Self::NAME);
+        let mut builder = PatchBuilder::new(db, syntax);
+        builder.add_modified(RewriteNode::interpolate_patched(
+            &formatdoc! {
+                r#"
+                {{
+                    let mut {f}: core::fmt::Formatter = core::traits::Default::default();
+                    core::result::ResultTrait::<(), core::fmt::Error>::unwrap(
+                        core::fmt::Debug::fmt(@($value$), ref {f})
+                    );
+                    let mut {input}: core::array::Array<felt252> = core::array::ArrayTrait::new();
+                    core::serde::Serde::<core::byte_array::ByteArray>::serialize(@"{name}", ref {input});
+                    core::serde::Serde::<core::byte_array::ByteArray>::serialize(@{f}.buffer, ref {input});
+                    let mut {output} = starknet::testing::cheatcode::<'{snapshot}'>(
+                        core::array::ArrayTrait::span(@{input})
+                    );
+                    if let core::option::Option::Some(message) =
+                        core::serde::Serde::<core::byte_array::ByteArray>::deserialize(ref {output}) {{
+                        core::panics::panic_with_byte_array(@message)
+                    }}
+                }}
+                "#,
+                snapshot = Self::NAME,
+            },
+            &[("value".to_string(), RewriteNode::from_ast_trimmed(&value))].into(),
+        ));
+        let (content, code_mappings) = builder.build();
+        let mut diagnostics = vec![];
+        if !metadata.cfg_set.contains(&Cfg::kv("target", "test"))
+            && !metadata.cfg_set.contains(&Cfg::name("test"))
+        {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    syntax.stable_ptr(db),
+                    format!("`{}` macro is only available in test mode.", Self::NAME),
+                )
+                .with_error_code(error_code!(E3124)),
+            );
+        }
+        InlinePluginResult {
+            code: Some(PluginGeneratedFile {
+                name: format!("{}_macro", Self::NAME),
+                content,
+                code_mappings,
+                aux_data: None,
+                diagnostics_note: Default::default(),
+                is_unhygienic: false,
+            }),
+            diagnostics,
+        }
+    }
+}
+
+/// Returns whether a snapshot name can be used as a part of its file name.
+fn is_valid_snapshot_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
@@ -33,24 +33,46 @@ use cairo_lang_starknet_classes::casm_contract_class::ENTRY_POINT_COST;
 use cairo_lang_utils::ordered_hash_map::{
     OrderedHashMap, deserialize_ordered_hashmap_vec, serialize_ordered_hashmap_vec,
 };
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use fixture::{find_module_fixture_value, fixture_param_names};
 use itertools::{Itertools, chain};
 pub use plugin::TestPlugin;
 use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt as Felt252;
-pub use test_config::{TestConfig, try_extract_test_config};
+pub use test_config::{FuzzConfig, TestConfig, try_extract_test_config};
 
+mod fixture;
 mod inline_macros;
 pub mod plugin;
+mod test_case;
 pub mod test_config;
 
+#[cfg(test)]
+mod test;
+
 const TEST_ATTR: &str = "test";
 const SHOULD_PANIC_ATTR: &str = "should_panic";
 const IGNORE_ATTR: &str = "ignore";
 const AVAILABLE_GAS_ATTR: &str = "available_gas";
 const STATIC_GAS_ARG: &str = "static";
+const TEST_CASE_ATTR: &str = "test_case";
+const TEST_CASE_NAME_ARG: &str = "name";
+const FUZZ_ATTR: &str = "fuzz";
+const FUZZ_RUNS_ARG: &str = "runs";
+const FUZZ_SEED_ARG: &str = "seed";
+/// The number of runs of a fuzz test, if not specified.
+const DEFAULT_FUZZ_RUNS: usize = 256;
+const FIXTURE_ATTR: &str = "fixture";
+const FIXTURE_SCOPE_ARG: &str = "scope";
+/// The attribute added to tests taking fixtures, with the names of their fixtures.
+const FIXTURE_PARAMS_ATTR: &str = "fixture_params";
+/// The prefix of the generated function providing the value of a fixture to the tests taking it.
+const FIXTURE_PREFIX: &str = "__fixture__";
+/// The prefix of the generated function serializing the value of a module-scoped fixture.
+const FIXTURE_VALUE_PREFIX: &str = "__fixture_value__";
 
 /// Configuration for test compilation.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct TestsCompilationConfig<'db> {
     /// Adds the starknet contracts to the compiled tests.
     pub starknet: bool,
@@ -135,7 +157,7 @@ pub fn compile_test_prepared_db<'db>(
         db,
         tests_compilation_config.executable_crate_ids.unwrap_or_else(|| test_crate_ids.clone()),
     );
-    let all_tests = find_all_tests(db, test_crate_ids);
+    let (all_tests, module_fixtures) = find_all_tests(db, test_crate_ids);
 
     let func_ids = chain!(
         executable_functions.clone().into_keys(),
@@ -143,7 +165,10 @@ pub fn compile_test_prepared_db<'db>(
         // TODO(maciektr): Remove test entrypoints after migration to executable attr.
         all_tests.iter().flat_map(|(func_id, _cfg)| {
             ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id)
-        })
+        }),
+        module_fixtures
+            .iter()
+            .flat_map(|func_id| ConcreteFunctionWithBodyId::from_no_generics_free(db, *func_id))
     )
     .collect();
 
@@ -178,21 +203,7 @@ pub fn compile_test_prepared_db<'db>(
     let executables = collect_executables(db, executable_functions, &sierra_program);
     let named_tests = all_tests
         .into_iter()
-        .map(|(func_id, test)| {
-            (
-                format!(
-                    "{:?}",
-                    FunctionLongId {
-                        function: ConcreteFunction {
-                            generic_function: GenericFunctionId::Free(func_id),
-                            generic_args: vec![]
-                        }
-                    }
-                    .debug(db)
-                ),
-                test,
-            )
-        })
+        .map(|(func_id, test)| (function_name(db, func_id), test))
         .collect_vec();
     let contracts_info = get_contracts_info(db, contracts, &replacer)?;
     let sierra_program = ProgramArtifact::stripped(sierra_program).with_debug_info(DebugInfo {
@@ -248,12 +259,28 @@ pub struct TestCompilationMetadata<'db> {
     pub statements_locations: Option<StatementsLocations<'db>>,
 }
 
-/// Finds the tests in the requested crates.
+/// Returns the full path of a free function, as used by the runner to find it.
+fn function_name<'db>(db: &'db dyn SemanticGroup, func_id: FreeFunctionId<'db>) -> String {
+    format!(
+        "{:?}",
+        FunctionLongId {
+            function: ConcreteFunction {
+                generic_function: GenericFunctionId::Free(func_id),
+                generic_args: vec![]
+            }
+        }
+        .debug(db)
+    )
+}
+
+/// Finds the tests in the requested crates, and the functions computing the values of the
+/// module-scoped fixtures they take.
 fn find_all_tests<'db>(
     db: &'db dyn SemanticGroup,
     main_crates: Vec<CrateId<'db>>,
-) -> Vec<(FreeFunctionId<'db>, TestConfig)> {
+) -> (Vec<(FreeFunctionId<'db>, TestConfig)>, OrderedHashSet<FreeFunctionId<'db>>) {
     let mut tests = vec![];
+    let mut module_fixtures = OrderedHashSet::default();
     for crate_id in main_crates {
         let modules = db.crate_modules(crate_id);
         for module_id in modules.iter() {
@@ -267,14 +294,23 @@ fn find_all_tests<'db>(
                 else {
                     return None;
                 };
-                Some((*func_id, try_extract_test_config(db, attrs).ok()??))
+                let mut config = try_extract_test_config(db, attrs.clone()).ok()??;
+                if let Some(attr) = attrs.iter().find(|attr| attr.id == FIXTURE_PARAMS_ATTR) {
+                    let fixtures = fixture_param_names(db, attr);
+                    let module_fixture = find_module_fixture_value(db, *module_id, &fixtures);
+                    if let Some(fixture_id) = module_fixture {
+                        module_fixtures.insert(fixture_id);
+                        config.module_fixture = Some(function_name(db, fixture_id));
+                    }
+                }
+                Some((*func_id, config))
             }));
         }
     }
-    tests
+    (tests, module_fixtures)
 }
 
-/// The suite of plugins that implements assert macros for tests.
+/// The suite of plugins that implements assert and snapshot macros for tests.
 pub fn test_assert_suite() -> PluginSuite {
     let mut suite = PluginSuite::default();
     suite
@@ -283,13 +319,17 @@ pub fn test_assert_suite() -> PluginSuite {
         .add_inline_macro_plugin::<inline_macros::assert::AssertLtMacro>()
         .add_inline_macro_plugin::<inline_macros::assert::AssertLeMacro>()
         .add_inline_macro_plugin::<inline_macros::assert::AssertGtMacro>()
-        .add_inline_macro_plugin::<inline_macros::assert::AssertGeMacro>();
+        .add_inline_macro_plugin::<inline_macros::assert::AssertGeMacro>()
+        .add_inline_macro_plugin::<inline_macros::snapshot::AssertSnapshotMacro>();
     suite
 }
 
 /// The suite of plugins for compilation for testing.
 pub fn test_plugin_suite() -> PluginSuite {
     let mut suite = PluginSuite::default();
-    suite.add_plugin::<TestPlugin>().add(test_assert_suite());
+    suite
+        .add_plugin::<TestPlugin>()
+        .add_analyzer_plugin::<fixture::FixtureAnalyzer>()
+        .add(test_assert_suite());
     suite
 }
@@ -1,12 +1,21 @@
-use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginResult};
+use cairo_lang_defs::plugin::{MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
-use cairo_lang_syntax::node::ast;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
 use salsa::Database;
 
-use super::{AVAILABLE_GAS_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR, TEST_ATTR};
-use crate::test_config::try_extract_test_config;
+use super::{
+    AVAILABLE_GAS_ATTR, FIXTURE_ATTR, FIXTURE_PARAMS_ATTR, FUZZ_ATTR, IGNORE_ATTR,
+    SHOULD_PANIC_ATTR, TEST_ATTR, TEST_CASE_ATTR,
+};
+use crate::fixture::{
+    check_fixture_params_attr, generate_fixture, inject_fixtures, try_extract_fixture_scope,
+};
+use crate::test_case::generate_test_cases;
+use crate::test_config::{TestConfig, try_extract_test_config};
 
-/// Plugin to create diagnostics for tests attributes.
+/// Plugin to create diagnostics for tests attributes, to generate the tests of the cases of
+/// `#[test_case]` functions, and to inject the values of fixtures into the tests taking them.
 #[derive(Debug, Default)]
 #[non_exhaustive]
 pub struct TestPlugin;
@@ -18,15 +27,44 @@ impl MacroPlugin for TestPlugin {
         item_ast: ast::ModuleItem<'db>,
         _metadata: &MacroPluginMetadata<'_>,
     ) -> PluginResult<'db> {
-        PluginResult {
-            code: None,
-            diagnostics: if let ast::ModuleItem::FreeFunction(free_func_ast) = item_ast {
-                try_extract_test_config(db, free_func_ast.attributes(db).structurize(db)).err()
-            } else {
-                None
+        let ast::ModuleItem::FreeFunction(free_func_ast) = item_ast else {
+            return PluginResult::default();
+        };
+        let attrs = free_func_ast.attributes(db).structurize(db);
+        if let Some(diagnostic) = check_fixture_params_attr(db, &free_func_ast, &attrs) {
+            return PluginResult {
+                code: None,
+                diagnostics: vec![diagnostic],
+                remove_original_item: false,
+            };
+        }
+        match try_extract_fixture_scope(db, &attrs) {
+            Ok(Some(scope)) => return generate_fixture(db, &free_func_ast, scope),
+            Ok(None) => {}
+            Err(diagnostic) => {
+                return PluginResult {
+                    code: None,
+                    diagnostics: vec![diagnostic],
+                    remove_original_item: false,
+                };
+            }
+        }
+        match try_extract_test_config(db, attrs) {
+            Ok(Some(config)) if takes_fixtures(db, &free_func_ast, &config) => {
+                inject_fixtures(db, &free_func_ast)
+            }
+            Ok(Some(config)) => PluginResult {
+                code: None,
+                diagnostics: check_test_params(db, &free_func_ast, &config).into_iter().collect(),
+                remove_original_item: false,
+            },
+            Ok(None) => {
+                let (code, diagnostics) = generate_test_cases(db, &free_func_ast);
+                PluginResult { code, diagnostics, remove_original_item: false }
+            }
+            Err(diagnostics) => {
+                PluginResult { code: None, diagnostics, remove_original_item: false }
             }
-            .unwrap_or_default(),
-            remove_original_item: false,
         }
     }
 
@@ -36,6 +74,41 @@ impl MacroPlugin for TestPlugin {
             AVAILABLE_GAS_ATTR.to_string(),
             SHOULD_PANIC_ATTR.to_string(),
             IGNORE_ATTR.to_string(),
+            FUZZ_ATTR.to_string(),
+            TEST_CASE_ATTR.to_string(),
+            FIXTURE_ATTR.to_string(),
+            FIXTURE_PARAMS_ATTR.to_string(),
         ]
     }
 }
+
+/// Returns whether a test takes its parameters from fixtures, and should be rewritten to take
+/// their values. That is, whether it has parameters and is not a fuzz test, and was not already
+/// rewritten.
+fn takes_fixtures<'db>(
+    db: &'db dyn Database,
+    func: &ast::FunctionWithBody<'db>,
+    config: &TestConfig,
+) -> bool {
+    let params = func.declaration(db).signature(db).parameters(db);
+    config.fuzz.is_none() && !config.uses_fixtures && params.elements(db).next().is_some()
+}
+
+/// Checks that fuzz tests have parameters, as their arguments are generated.
+fn check_test_params<'db>(
+    db: &'db dyn Database,
+    func: &ast::FunctionWithBody<'db>,
+    config: &TestConfig,
+) -> Option<PluginDiagnostic<'db>> {
+    let params = func.declaration(db).signature(db).parameters(db);
+    if config.fuzz.is_none() || params.elements(db).next().is_some() {
+        return None;
+    }
+    Some(
+        PluginDiagnostic::error(
+            params.stable_ptr(db).untyped(),
+            "Fuzz tests must have parameters.".into(),
+        )
+        .with_error_code(error_code!(E3101)),
+    )
+}
@@ -0,0 +1,50 @@
+use std::sync::{LazyLock, Mutex};
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_compiler::diagnostics::get_diagnostics_as_string;
+use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
+use cairo_lang_test_utils::{
+    get_direct_or_file_content, test_lock, verify_diagnostics_expectation,
+};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+
+use crate::test_plugin_suite;
+
+/// Salsa database configured to find the corelib, with the test plugin suite.
+static SHARED_DB: LazyLock<Mutex<RootDatabase>> = LazyLock::new(|| {
+    Mutex::new(
+        RootDatabase::builder()
+            .detect_corelib()
+            .with_default_plugin_suite(test_plugin_suite())
+            .build()
+            .unwrap(),
+    )
+});
+
+cairo_lang_test_utils::test_file_test!(
+    test_plugin_diagnostics,
+    "src/plugin_test_data",
+    {
+        fixture: "fixture",
+        fuzz: "fuzz",
+        test_case: "test_case",
+    },
+    test_plugin_diagnostics
+);
+
+/// Returns the diagnostics of the given code, compiled with the test plugin suite.
+fn test_plugin_diagnostics(
+    inputs: &OrderedHashMap<String, String>,
+    args: &OrderedHashMap<String, String>,
+) -> TestRunnerResult {
+    let db = test_lock(&SHARED_DB).snapshot();
+    let (_, cairo_code) = get_direct_or_file_content(&inputs["cairo_code"]);
+    let (test_module, _) = setup_test_module(&db, &cairo_code).split();
+    let diagnostics = get_diagnostics_as_string(&db, Some(vec![test_module.crate_id]));
+    let error = verify_diagnostics_expectation(args, &diagnostics);
+    TestRunnerResult {
+        outputs: OrderedHashMap::from([("expected_diagnostics".into(), diagnostics)]),
+        error,
+    }
+}
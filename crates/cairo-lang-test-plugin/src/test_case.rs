@@ -0,0 +1,172 @@
+use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
+use cairo_lang_defs::plugin::{PluginDiagnostic, PluginGeneratedFile};
+use cairo_lang_diagnostics::error_code;
+use cairo_lang_syntax::attribute::structured::{
+    Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
+};
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::{Terminal, TypedStablePtr, TypedSyntaxNode, ast};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use itertools::Itertools;
+use salsa::Database;
+
+use super::{
+    AVAILABLE_GAS_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR, TEST_ATTR, TEST_CASE_ATTR,
+    TEST_CASE_NAME_ARG,
+};
+
+/// Generates a test for each `#[test_case(<args>, name: "<name>")]` attribute of a function, that
+/// calls the function with the arguments of the case.
+///
+/// The generated test of a case is named `<function name>_<case name>`, where the case name is
+/// `case_<index>` if not given. The `should_panic`, `available_gas` and `ignore` attributes of the
+/// function apply to all its cases. The arguments are checked against the parameter types by the
+/// semantic model, as in any other call.
+pub fn generate_test_cases<'db>(
+    db: &'db dyn Database,
+    func: &ast::FunctionWithBody<'db>,
+) -> (Option<PluginGeneratedFile>, Vec<PluginDiagnostic<'db>>) {
+    let attributes = func.attributes(db);
+    let case_attrs = attributes.query_attr(db, TEST_CASE_ATTR).collect_vec();
+    if case_attrs.is_empty() {
+        return (None, vec![]);
+    }
+    let declaration = func.declaration(db);
+    let func_name = declaration.name(db).text(db);
+    let params = declaration.signature(db).parameters(db).elements(db).collect_vec();
+    let shared_attrs = attributes
+        .elements(db)
+        .filter(|attr| {
+            let name = attr.attr(db).as_syntax_node().get_text_without_trivia(db);
+            [SHOULD_PANIC_ATTR, AVAILABLE_GAS_ATTR, IGNORE_ATTR].contains(&name)
+        })
+        .collect_vec();
+
+    let mut diagnostics = vec![];
+    let mut names = OrderedHashSet::<String>::default();
+    let mut builder = PatchBuilder::new(db, &declaration.name(db));
+    for (index, attr_ast) in case_attrs.iter().enumerate() {
+        let attr = attr_ast.clone().structurize(db);
+        let Some(case) = parse_test_case(db, &attr, &params, &mut diagnostics) else {
+            continue;
+        };
+        let name = case.name.unwrap_or_else(|| format!("case_{}", index + 1));
+        if !names.insert(name.clone()) {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.args_stable_ptr.untyped(),
+                    format!("Duplicate test case name `{name}`."),
+                )
+                .with_error_code(error_code!(E3108)),
+            );
+            continue;
+        }
+        let mut case_builder = PatchBuilder::new(db, attr_ast);
+        case_builder.add_str(&format!("#[{TEST_ATTR}]\n"));
+        for shared_attr in &shared_attrs {
+            case_builder.add_modified(RewriteNode::from_ast_trimmed(shared_attr));
+            case_builder.add_char('\n');
+        }
+        case_builder.add_str(&format!("fn {func_name}_{name}() {{\n    {func_name}("));
+        case_builder.add_modified(RewriteNode::interspersed(
+            case.args.iter().map(RewriteNode::from_ast_trimmed),
+            RewriteNode::text(", "),
+        ));
+        case_builder.add_str(");\n}\n");
+        builder.add_modified(case_builder.into_rewrite_node());
+    }
+    if names.is_empty() {
+        return (None, diagnostics);
+    }
+    let (content, code_mappings) = builder.build();
+    let file = PluginGeneratedFile {
+        name: "test_case".into(),
+        content,
+        code_mappings,
+        aux_data: None,
+        diagnostics_note: Default::default(),
+        is_unhygienic: false,
+    };
+    (Some(file), diagnostics)
+}
+
+/// A parsed `#[test_case]` attribute.
+struct TestCase<'db> {
+    /// The arguments of the case, in the order of the function parameters.
+    args: Vec<ast::Expr<'db>>,
+    /// The name of the case, if given.
+    name: Option<String>,
+}
+
+/// Parses a `#[test_case]` attribute of a function with the given parameters.
+/// Adds diagnostics and returns `None` if the attribute is invalid.
+fn parse_test_case<'db>(
+    db: &'db dyn Database,
+    attr: &Attribute<'db>,
+    params: &[ast::Param<'db>],
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) -> Option<TestCase<'db>> {
+    let mut args = vec![];
+    let mut name = None;
+    let mut valid = true;
+    for arg in &attr.args {
+        match &arg.variant {
+            AttributeArgVariant::Unnamed(value) if name.is_none() => args.push(value.clone()),
+            AttributeArgVariant::Named { name: arg_name, value, .. }
+                if arg_name.text == TEST_CASE_NAME_ARG && name.is_none() =>
+            {
+                name = Some(extract_case_name(db, arg, value, diagnostics)?);
+            }
+            _ => {
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        arg.arg.stable_ptr(db).untyped(),
+                        format!(
+                            "Unexpected argument. Expected the arguments of the test, optionally \
+                             followed by `{TEST_CASE_NAME_ARG}: \"<name>\"`."
+                        ),
+                    )
+                    .with_error_code(error_code!(E3109)),
+                );
+                valid = false;
+            }
+        }
+    }
+    if args.len() != params.len() {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                format!("Expected {} arguments, found {}.", params.len(), args.len()),
+            )
+            .with_error_code(error_code!(E3110)),
+        );
+        return None;
+    }
+    valid.then_some(TestCase { args, name })
+}
+
+/// Extracts the name of a test case from the `name` argument, which must be a string literal
+/// that can be used as a part of an identifier.
+fn extract_case_name<'db>(
+    db: &'db dyn Database,
+    arg: &AttributeArg<'db>,
+    value: &ast::Expr<'db>,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) -> Option<String> {
+    let name = match value {
+        ast::Expr::String(literal) => literal.string_value(db),
+        _ => None,
+    }
+    .filter(|name| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    if name.is_none() {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                arg.arg.stable_ptr(db).untyped(),
+                "Test case name must be a string literal of letters, digits and underscores."
+                    .into(),
+            )
+            .with_error_code(error_code!(E3111)),
+        );
+    }
+    name
+}
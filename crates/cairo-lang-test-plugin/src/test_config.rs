@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeArg, AttributeArgVariant};
 use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode, ast};
 use cairo_lang_utils::byte_array::{BYTE_ARRAY_MAGIC, BYTES_IN_WORD};
@@ -10,7 +11,10 @@ use salsa::Database;
 use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt as Felt252;
 
-use super::{AVAILABLE_GAS_ATTR, IGNORE_ATTR, SHOULD_PANIC_ATTR, STATIC_GAS_ARG, TEST_ATTR};
+use super::{
+    AVAILABLE_GAS_ATTR, DEFAULT_FUZZ_RUNS, FIXTURE_PARAMS_ATTR, FUZZ_ATTR, FUZZ_RUNS_ARG,
+    FUZZ_SEED_ARG, IGNORE_ATTR, SHOULD_PANIC_ATTR, STATIC_GAS_ARG, TEST_ATTR, TEST_CASE_ATTR,
+};
 
 /// Expectation for a panic case.
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
@@ -30,6 +34,16 @@ pub enum TestExpectation {
     Panics(PanicExpectation),
 }
 
+/// The configuration for fuzzing a test, running it with generated arguments.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FuzzConfig {
+    /// The number of runs, each with different arguments.
+    pub runs: usize,
+    /// The seed of the arguments generation. If not set, a random seed is used for each run of
+    /// the test.
+    pub seed: Option<u64>,
+}
+
 /// The configuration for running a single test.
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct TestConfig {
@@ -39,10 +53,25 @@ pub struct TestConfig {
     pub expectation: TestExpectation,
     /// Should the test be ignored.
     pub ignored: bool,
+    /// The fuzzing configuration, if the test takes generated arguments.
+    #[serde(default)]
+    pub fuzz: Option<FuzzConfig>,
+    /// Whether the parameters of the test are provided by fixtures. Such a test takes the
+    /// serialized value of its module-scoped fixture, if any, as a single `Span<felt252>`
+    /// argument.
+    #[serde(default)]
+    pub uses_fixtures: bool,
+    /// The function computing the serialized value of the module-scoped fixture of the test, if
+    /// any. The test starts from the Starknet state left by this function.
+    #[serde(default)]
+    pub module_fixture: Option<String>,
 }
 
 /// Extracts the configuration of a tests from attributes, or returns the diagnostics if the
 /// attributes are set illegally.
+///
+/// Returns `None` for functions with `#[test_case]` attributes, as they are not tests by
+/// themselves. A test is generated for each of their cases instead.
 pub fn try_extract_test_config<'db>(
     db: &'db dyn Database,
     attrs: Vec<Attribute<'db>>,
@@ -51,34 +80,61 @@ pub fn try_extract_test_config<'db>(
     let ignore_attr = attrs.iter().find(|attr| attr.id == IGNORE_ATTR);
     let available_gas_attr = attrs.iter().find(|attr| attr.id == AVAILABLE_GAS_ATTR);
     let should_panic_attr = attrs.iter().find(|attr| attr.id == SHOULD_PANIC_ATTR);
+    let fuzz_attr = attrs.iter().find(|attr| attr.id == FUZZ_ATTR);
+    let test_case_attr = attrs.iter().find(|attr| attr.id == TEST_CASE_ATTR);
+    let fixture_params_attr = attrs.iter().find(|attr| attr.id == FIXTURE_PARAMS_ATTR);
     let mut diagnostics = vec![];
-    if let Some(attr) = test_attr {
+    if test_case_attr.is_some() {
+        for other in [test_attr, fuzz_attr].into_iter().flatten() {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    other.id_stable_ptr.untyped(),
+                    format!("Attribute should not be used together with `{TEST_CASE_ATTR}`."),
+                )
+                .with_error_code(error_code!(E3102)),
+            );
+        }
+    } else if let Some(attr) = test_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should not have arguments.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should not have arguments.".into(),
+                )
+                .with_error_code(error_code!(E3103)),
+            );
         }
     } else {
-        for attr in [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should only appear on tests.".into(),
-            ));
+        for attr in
+            [ignore_attr, available_gas_attr, should_panic_attr, fuzz_attr, fixture_params_attr]
+                .into_iter()
+                .flatten()
+        {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should only appear on tests.".into(),
+                )
+                .with_error_code(error_code!(E3104)),
+            );
         }
     }
     let ignored = if let Some(attr) = ignore_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should not have arguments.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should not have arguments.".into(),
+                )
+                .with_error_code(error_code!(E3103)),
+            );
         }
         true
     } else {
         false
     };
     let available_gas = extract_available_gas(available_gas_attr, db, &mut diagnostics);
+    let fuzz = fuzz_attr.and_then(|attr| extract_fuzz_config(attr, db, &mut diagnostics));
     let (should_panic, expected_panic_felts) = if let Some(attr) = should_panic_attr {
         if attr.args.is_empty() {
             (true, None)
@@ -86,12 +142,16 @@ pub fn try_extract_test_config<'db>(
             (
                 true,
                 extract_panic_bytes(db, attr).on_none(|| {
-                    diagnostics.push(PluginDiagnostic::error(
-                        attr.args_stable_ptr.untyped(),
-                        "Expected panic must be of the form `expected: <tuple of felt252s and \
-                         strings>` or `expected: \"some string\"` or `expected: <some felt252>`."
-                            .into(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            attr.args_stable_ptr.untyped(),
+                            "Expected panic must be of the form `expected: <tuple of felt252s and \
+                             strings>` or `expected: \"some string\"` or `expected: <some \
+                             felt252>`."
+                                .into(),
+                        )
+                        .with_error_code(error_code!(E3105)),
+                    );
                 }),
             )
         }
@@ -101,7 +161,7 @@ pub fn try_extract_test_config<'db>(
     if !diagnostics.is_empty() {
         return Err(diagnostics);
     }
-    Ok(if test_attr.is_none() {
+    Ok(if test_attr.is_none() || test_case_attr.is_some() {
         None
     } else {
         Some(TestConfig {
@@ -116,6 +176,9 @@ pub fn try_extract_test_config<'db>(
                 TestExpectation::Success
             },
             ignored,
+            fuzz,
+            uses_fixtures: fixture_params_attr.is_some(),
+            module_fixture: None,
         })
     })
 }
@@ -148,16 +211,68 @@ fn extract_available_gas<'db>(
         _ => None,
     }
     .on_none(|| {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.args_stable_ptr.untyped(),
-            format!(
-                "Attribute should have a single non-negative literal in `i64` range or \
-                 `{STATIC_GAS_ARG}`."
-            ),
-        ))
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                format!(
+                    "Attribute should have a single non-negative literal in `i64` range or \
+                     `{STATIC_GAS_ARG}`."
+                ),
+            )
+            .with_error_code(error_code!(E3106)),
+        )
     })
 }
 
+/// Extracts the fuzzing configuration from the `fuzz` attribute, of the form
+/// `#[fuzz(runs: <number>, seed: <number>)]` where both arguments are optional.
+/// Adds a diagnostic and returns `None` if the attribute is malformed.
+fn extract_fuzz_config<'db>(
+    attr: &Attribute<'db>,
+    db: &'db dyn Database,
+    diagnostics: &mut Vec<PluginDiagnostic<'db>>,
+) -> Option<FuzzConfig> {
+    let mut runs = None;
+    let mut seed = None;
+    let mut valid = true;
+    for arg in &attr.args {
+        let AttributeArg { variant: AttributeArgVariant::Named { name, value, .. }, .. } = arg
+        else {
+            valid = false;
+            continue;
+        };
+        let value = match value {
+            ast::Expr::Literal(literal) => literal.numeric_value(db),
+            _ => None,
+        };
+        match &*name.text {
+            FUZZ_RUNS_ARG if runs.is_none() => {
+                runs = value.and_then(|v| v.to_usize()).filter(|runs| *runs > 0);
+                valid &= runs.is_some();
+            }
+            FUZZ_SEED_ARG if seed.is_none() => {
+                seed = value.and_then(|v| v.to_u64());
+                valid &= seed.is_some();
+            }
+            _ => valid = false,
+        }
+    }
+    if !valid {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                format!(
+                    "Attribute should be of the form `{FUZZ_ATTR}({FUZZ_RUNS_ARG}: <positive \
+                     number>, {FUZZ_SEED_ARG}: <u64>)`, where both arguments are optional."
+                ),
+            )
+            .with_error_code(error_code!(E3107)),
+        );
+        return None;
+    }
+    Some(FuzzConfig { runs: runs.unwrap_or(DEFAULT_FUZZ_RUNS), seed })
+}
+
 /// Tries to extract the expected panic bytes out of the given `should_panic` attribute.
 /// Assumes the attribute is `should_panic`.
 fn extract_panic_bytes(db: &dyn Database, attr: &Attribute<'_>) -> Option<Vec<Felt252>> {
@@ -0,0 +1,83 @@
+//! Contract classes compiled separately from the tested crates, registered with the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_starknet::compile::compile_path_contracts;
+use cairo_lang_starknet_classes::contract_class::ContractClass;
+use serde_json::Value;
+use starknet_types_core::felt::Felt as Felt252;
+
+/// A source of contract classes compiled separately from the tested crates.
+///
+/// Each class runs in its own program, and is deployed by its class hash, as on Starknet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContractClassSource {
+    /// A `ContractClass` JSON file, such as the output of `starknet-compile`, or a class returned
+    /// by a Starknet node, whose ABI is a string.
+    File(PathBuf),
+    /// A crate, or a project of crates, all of whose contracts are compiled.
+    Crate(PathBuf),
+}
+impl ContractClassSource {
+    /// Returns the path of the source.
+    pub fn path(&self) -> &Path {
+        match self {
+            ContractClassSource::File(path) | ContractClassSource::Crate(path) => path,
+        }
+    }
+
+    /// Loads the contract classes of the source, along with their class hashes.
+    pub fn load(&self) -> Result<Vec<(ContractClass, Felt252)>> {
+        match self {
+            ContractClassSource::File(path) => {
+                let contents = fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read `{}`.", path.display()))?;
+                let contract_class = parse_contract_class(&contents).with_context(|| {
+                    format!("Failed to parse the contract class in `{}`.", path.display())
+                })?;
+                Ok(vec![contract_class])
+            }
+            ContractClassSource::Crate(path) => Ok(compile_path_contracts(
+                path,
+                CompilerConfig { replace_ids: true, ..CompilerConfig::default() },
+            )
+            .with_context(|| format!("Failed to compile the contracts in `{}`.", path.display()))?
+            .into_iter()
+            .map(|contract_class| {
+                let class_hash = contract_class.class_hash();
+                (contract_class, class_hash)
+            })
+            .collect()),
+        }
+    }
+}
+
+/// Parses a `ContractClass` JSON, and returns the class along with its class hash.
+///
+/// The ABI is either a JSON array, as in the output of `starknet-compile`, or a string, as in
+/// classes returned by Starknet nodes. A string ABI is the one the class was declared with, so it
+/// is hashed as is, instead of being reserialized.
+fn parse_contract_class(contents: &str) -> Result<(ContractClass, Felt252)> {
+    let mut value: Value = serde_json::from_str(contents)?;
+    let declared_abi = match value.get_mut("abi") {
+        Some(abi) if abi.is_string() => {
+            let declared_abi = abi.as_str().unwrap_or_default().to_string();
+            *abi = if declared_abi.is_empty() {
+                Value::Null
+            } else {
+                serde_json::from_str(&declared_abi)?
+            };
+            Some(declared_abi)
+        }
+        _ => None,
+    };
+    let contract_class: ContractClass = serde_json::from_value(value)?;
+    let class_hash = match &declared_abi {
+        Some(abi) => contract_class.class_hash_with_abi(abi),
+        None => contract_class.class_hash(),
+    };
+    Ok((contract_class, class_hash))
+}
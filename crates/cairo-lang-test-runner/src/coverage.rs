@@ -0,0 +1,249 @@
+//! Code coverage of test runs, mapped from the executed Sierra statements to the Cairo source
+//! lines that generated them.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use cairo_lang_sierra::program::{Program, StatementIdx};
+use cairo_lang_sierra_generator::statements_code_locations::StatementsSourceCodeLocations;
+use cairo_lang_sierra_to_casm::compiler::CairoProgram;
+use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "coverage_test.rs"]
+mod test;
+
+/// The name of the LCOV report file in the coverage output directory.
+pub const LCOV_FILE_NAME: &str = "lcov.info";
+/// The name of the JSON report file in the coverage output directory.
+pub const JSON_FILE_NAME: &str = "coverage.json";
+
+/// A source line, as a file path and a 1 based line number.
+type SourceLine = (String, usize);
+
+/// A Sierra function of the program, for function coverage.
+struct FunctionInfo {
+    /// The name of the function.
+    name: String,
+    /// The first line of the code of the function.
+    line: SourceLine,
+    /// The entry point of the function.
+    entry_point: StatementIdx,
+}
+
+/// Collects the coverage of runs of a Sierra program.
+pub struct CoverageCollector {
+    /// The source lines of each statement, including the lines of the functions it was inlined
+    /// into.
+    statement_lines: Vec<Vec<SourceLine>>,
+    /// The statement whose hits count as the hits of each statement.
+    ///
+    /// A statement with no CASM instructions is never entered by itself, so it is considered
+    /// entered whenever the statement sharing its first CASM instruction is. This is exact unless
+    /// the execution jumps directly to the later statement.
+    hits_source: Vec<StatementIdx>,
+    /// The functions of the program.
+    functions: Vec<FunctionInfo>,
+    /// The number of times each statement was entered, over all the collected runs.
+    hits: Vec<usize>,
+}
+
+impl CoverageCollector {
+    /// Creates a collector for a program, given the locations of the Cairo code that generated
+    /// each statement.
+    pub fn new(
+        program: &Program,
+        casm_program: &CairoProgram,
+        code_locations: &StatementsSourceCodeLocations,
+    ) -> Self {
+        let statement_lines: Vec<Vec<SourceLine>> = (0..program.statements.len())
+            .map(|idx| {
+                let locations =
+                    code_locations.statements_to_code_location_map.get(&StatementIdx(idx));
+                locations
+                    .into_iter()
+                    .flatten()
+                    .map(|(path, span, _)| (path.0.clone(), span.start.line + 1))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .collect();
+        let hits_source = casm_program
+            .debug_info
+            .sierra_statement_info
+            .iter()
+            .map(|info| casm_program.sierra_statement_index_by_pc(info.start_offset))
+            .collect();
+        let functions = program
+            .funcs
+            .iter()
+            .filter_map(|func| {
+                let end = program
+                    .funcs
+                    .iter()
+                    .map(|other| other.entry_point.0)
+                    .filter(|idx| *idx > func.entry_point.0)
+                    .min()
+                    .unwrap_or(program.statements.len());
+                // The outermost locations of the statements are in the function itself, while
+                // the rest are in functions inlined into it.
+                let outermost_lines = (func.entry_point.0..end).filter_map(|idx| {
+                    let locations =
+                        code_locations.statements_to_code_location_map.get(&StatementIdx(idx))?;
+                    let (path, span, _) = locations.last()?;
+                    Some((path.0.clone(), span.start.line + 1))
+                });
+                let path = outermost_lines.clone().next()?.0;
+                let line = outermost_lines.filter(|(p, _)| *p == path).min()?;
+                Some(FunctionInfo {
+                    name: func.id.to_string(),
+                    line,
+                    entry_point: func.entry_point,
+                })
+            })
+            .collect();
+        Self { statement_lines, hits_source, functions, hits: vec![0; program.statements.len()] }
+    }
+
+    /// Adds the statement hits of a single run.
+    pub fn add_run(&mut self, statement_hits: &UnorderedHashMap<StatementIdx, usize>) {
+        for (idx, count) in statement_hits.iter_sorted() {
+            if let Some(hits) = self.hits.get_mut(idx.0) {
+                *hits += count;
+            }
+        }
+    }
+
+    /// Returns the coverage report of the runs collected so far.
+    /// The hits of a line are the most hits of any of the statements generated by it.
+    pub fn report(&self) -> CoverageReport {
+        let mut report = CoverageReport::default();
+        for (idx, lines) in self.statement_lines.iter().enumerate() {
+            let hits = self.statement_hits(StatementIdx(idx));
+            for (path, line) in lines {
+                let file = report.files.entry(path.clone()).or_default();
+                let line_hits = file.lines.entry(*line).or_default();
+                *line_hits = (*line_hits).max(hits);
+            }
+        }
+        for function in &self.functions {
+            let (path, line) = &function.line;
+            let file = report.files.entry(path.clone()).or_default();
+            let hits = self.statement_hits(function.entry_point);
+            let coverage = file
+                .functions
+                .entry(function.name.clone())
+                .or_insert(FunctionCoverage { line: *line, hits: 0 });
+            coverage.hits += hits;
+        }
+        report
+    }
+
+    /// Returns the number of times a statement was entered.
+    fn statement_hits(&self, idx: StatementIdx) -> usize {
+        self.hits_source.get(idx.0).map_or(0, |source| self.hits[source.0])
+    }
+}
+
+/// A coverage report, mergeable with reports of other runs.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// The coverage of each source file, by path.
+    pub files: BTreeMap<String, FileCoverage>,
+}
+
+/// The coverage of a single source file.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FileCoverage {
+    /// The number of hits of each line with code, by 1 based line number.
+    pub lines: BTreeMap<usize, usize>,
+    /// The coverage of each function starting in the file, by name.
+    pub functions: BTreeMap<String, FunctionCoverage>,
+}
+
+/// The coverage of a single function.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FunctionCoverage {
+    /// The 1 based line number of the start of the function.
+    pub line: usize,
+    /// The number of calls of the function.
+    pub hits: usize,
+}
+
+impl CoverageReport {
+    /// Adds the coverage of another report into this report.
+    pub fn merge(&mut self, other: &CoverageReport) {
+        for (path, other_file) in &other.files {
+            let file = self.files.entry(path.clone()).or_default();
+            for (line, hits) in &other_file.lines {
+                *file.lines.entry(*line).or_default() += hits;
+            }
+            for (name, other_function) in &other_file.functions {
+                let function = file
+                    .functions
+                    .entry(name.clone())
+                    .or_insert(FunctionCoverage { line: other_function.line, hits: 0 });
+                function.hits += other_function.hits;
+            }
+        }
+    }
+
+    /// Returns the report in the LCOV tracefile format.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, file) in &self.files {
+            writeln!(lcov, "TN:").unwrap();
+            writeln!(lcov, "SF:{path}").unwrap();
+            for (name, function) in &file.functions {
+                writeln!(lcov, "FN:{},{name}", function.line).unwrap();
+            }
+            for (name, function) in &file.functions {
+                writeln!(lcov, "FNDA:{},{name}", function.hits).unwrap();
+            }
+            let functions_hit = file.functions.values().filter(|f| f.hits > 0).count();
+            writeln!(lcov, "FNF:{}", file.functions.len()).unwrap();
+            writeln!(lcov, "FNH:{functions_hit}").unwrap();
+            for (line, hits) in &file.lines {
+                writeln!(lcov, "DA:{line},{hits}").unwrap();
+            }
+            let lines_hit = file.lines.values().filter(|hits| **hits > 0).count();
+            writeln!(lcov, "LF:{}", file.lines.len()).unwrap();
+            writeln!(lcov, "LH:{lines_hit}").unwrap();
+            writeln!(lcov, "end_of_record").unwrap();
+        }
+        lcov
+    }
+
+    /// Writes the LCOV and JSON reports into the given directory.
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed creating directory `{}`.", dir.display()))?;
+        let lcov_path = dir.join(LCOV_FILE_NAME);
+        fs::write(&lcov_path, self.to_lcov())
+            .with_context(|| format!("Failed writing `{}`.", lcov_path.display()))?;
+        let json_path = dir.join(JSON_FILE_NAME);
+        fs::write(&json_path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed writing `{}`.", json_path.display()))?;
+        Ok(())
+    }
+
+    /// Removes the coverage of the files under the given directory.
+    pub fn exclude_dir(&mut self, dir: &Path) {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        self.files.retain(|path, _| {
+            let path = Path::new(path);
+            !path.canonicalize().unwrap_or_else(|_| path.to_path_buf()).starts_with(&dir)
+        });
+    }
+
+    /// Returns the number of lines with code and the number of them that were hit.
+    pub fn line_counts(&self) -> (usize, usize) {
+        let lines = self.files.values().flat_map(|file| file.lines.values());
+        lines.fold((0, 0), |(total, hit), hits| (total + 1, hit + usize::from(*hits > 0)))
+    }
+}
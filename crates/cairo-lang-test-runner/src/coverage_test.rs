@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+use cairo_lang_test_plugin::TestsCompilationConfig;
+use indoc::indoc;
+
+use super::{CoverageReport, FileCoverage, FunctionCoverage};
+use crate::{TestCompiler, TestRunConfig, run_tests};
+
+/// Returns a report of a single file with the given line hits and function hits.
+fn file_report(lines: &[(usize, usize)], functions: &[(&str, usize, usize)]) -> CoverageReport {
+    let file = FileCoverage {
+        lines: lines.iter().copied().collect(),
+        functions: functions
+            .iter()
+            .map(|(name, line, hits)| {
+                (name.to_string(), FunctionCoverage { line: *line, hits: *hits })
+            })
+            .collect(),
+    };
+    CoverageReport { files: [("lib.cairo".to_string(), file)].into_iter().collect() }
+}
+
+#[test]
+fn test_merge_and_lcov() {
+    let mut report = file_report(&[(2, 1), (3, 0)], &[("foo", 1, 1), ("bar", 5, 0)]);
+    report.merge(&file_report(&[(3, 2), (6, 0)], &[("bar", 5, 0)]));
+    assert_eq!(report, file_report(&[(2, 1), (3, 2), (6, 0)], &[("foo", 1, 1), ("bar", 5, 0)]));
+    assert_eq!(report.line_counts(), (3, 2));
+    assert_eq!(
+        report.to_lcov(),
+        indoc! {"
+            TN:
+            SF:lib.cairo
+            FN:5,bar
+            FN:1,foo
+            FNDA:0,bar
+            FNDA:1,foo
+            FNF:2
+            FNH:1
+            DA:2,1
+            DA:3,2
+            DA:6,0
+            LF:3
+            LH:2
+            end_of_record
+        "}
+    );
+}
+
+#[test]
+fn test_run_coverage() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/coverage");
+    let compiler = TestCompiler::try_new(
+        &path,
+        false,
+        true,
+        TestsCompilationConfig { add_statements_code_locations: true, ..Default::default() },
+    )
+    .unwrap();
+    let config =
+        TestRunConfig { coverage_dir: Some(PathBuf::from("coverage")), ..Default::default() };
+    let summary = run_tests(None, compiler.build().unwrap(), &config, None).unwrap();
+    let report = summary.coverage.unwrap();
+    let file = &report.files[path.join("lib.cairo").to_str().unwrap()];
+    // The inlined `double` is hit, as is the taken branch of `classify`, but not the other one.
+    assert_eq!(file.lines.get(&3), Some(&1));
+    assert_eq!(file.lines.get(&8), Some(&1));
+    assert_eq!(file.lines.get(&10), Some(&0));
+    assert!(file.lines.range(15..=18).all(|(_, hits)| *hits == 1));
+    assert_eq!(file.functions["coverage::test_double"], FunctionCoverage { line: 15, hits: 1 });
+}
@@ -0,0 +1,102 @@
+//! Module-scoped fixtures, run once before the tests taking them.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, anyhow, bail};
+use cairo_lang_runner::casm_run::format_for_panic;
+use cairo_lang_runner::{
+    Arg, DEFAULT_AVAILABLE_GAS, RunResultValue, SierraCasmRunner, StarknetState,
+};
+use cairo_lang_test_plugin::TestConfig;
+use num_traits::ToPrimitive;
+use starknet_types_core::felt::Felt as Felt252;
+
+use crate::ArcCustomHintProcessorFactory;
+
+/// The result of a run of a module-scoped fixture, shared by the tests taking it.
+pub struct FixtureValue {
+    /// The serialized value of the fixture.
+    value: Vec<Felt252>,
+    /// The Starknet state left by the fixture.
+    starknet_state: StarknetState,
+}
+
+/// The results of the module-scoped fixtures of a run, by the names of their functions. A failed
+/// fixture holds the description of its failure, reported by each test taking it.
+pub type ModuleFixtures = HashMap<String, Result<FixtureValue, String>>;
+
+/// Runs the module-scoped fixtures taken by the given tests, once each, starting from the given
+/// Starknet state.
+pub fn run_module_fixtures(
+    tests: &[(String, TestConfig)],
+    runner: &SierraCasmRunner,
+    custom_hint_processor_factory: &Option<ArcCustomHintProcessorFactory>,
+    initial_state: &StarknetState,
+) -> ModuleFixtures {
+    let mut fixtures = ModuleFixtures::new();
+    for (_, test) in tests.iter().filter(|(_, test)| !test.ignored) {
+        let Some(name) = &test.module_fixture else { continue };
+        if !fixtures.contains_key(name) {
+            let result = run_fixture(name, runner, custom_hint_processor_factory, initial_state)
+                .map_err(|err| format!("Fixture `{name}` failed: {err:#}"));
+            fixtures.insert(name.clone(), result);
+        }
+    }
+    fixtures
+}
+
+/// Runs the function computing the serialized value of a fixture.
+fn run_fixture(
+    name: &str,
+    runner: &SierraCasmRunner,
+    custom_hint_processor_factory: &Option<ArcCustomHintProcessorFactory>,
+    initial_state: &StarknetState,
+) -> Result<FixtureValue> {
+    let func = runner.find_function(name)?;
+    let (hint_processor, ctx) = runner.prepare_starknet_context(
+        func,
+        vec![],
+        Some(DEFAULT_AVAILABLE_GAS),
+        initial_state.clone(),
+    )?;
+    let mut hint_processor = match custom_hint_processor_factory {
+        Some(f) => f(hint_processor),
+        None => Box::new(hint_processor),
+    };
+    let result =
+        runner.run_function_with_prepared_starknet_context(func, &mut *hint_processor, ctx)?;
+    let span = match result.value {
+        RunResultValue::Success(span) => span,
+        RunResultValue::Panic(values) => bail!("{}", format_for_panic(values.into_iter())),
+    };
+    // The value is returned as a `Span<felt252>`, given by its start and end addresses.
+    let [start, end] = span.as_slice() else {
+        bail!("Unexpected fixture return value: {span:?}.");
+    };
+    let (start, end) = start.to_usize().zip(end.to_usize()).context("Invalid fixture span.")?;
+    let value = result
+        .memory
+        .get(start..end)
+        .and_then(|cells| cells.iter().cloned().collect::<Option<Vec<_>>>())
+        .context("Invalid fixture span.")?;
+    Ok(FixtureValue { value, starknet_state: result.starknet_state })
+}
+
+/// Returns the arguments and the initial Starknet state of a test taking fixtures. Tests without a
+/// module-scoped fixture start from the given state.
+pub fn fixture_context(
+    test: &TestConfig,
+    fixtures: &ModuleFixtures,
+    initial_state: &StarknetState,
+) -> Result<(Vec<Arg>, StarknetState)> {
+    let Some(name) = &test.module_fixture else {
+        return Ok((vec![Arg::Array(vec![])], initial_state.clone()));
+    };
+    let fixture = fixtures
+        .get(name)
+        .with_context(|| format!("Fixture `{name}` was not run."))?
+        .as_ref()
+        .map_err(|err| anyhow!("{err}"))?;
+    let args = fixture.value.iter().cloned().map(Arg::Value).collect();
+    Ok((vec![Arg::Array(args)], fixture.starknet_state.clone()))
+}
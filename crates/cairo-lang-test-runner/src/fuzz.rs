@@ -0,0 +1,536 @@
+//! Fuzzing of tests, by running them with generated arguments, and shrinking the arguments of a
+//! failing run to a minimal failing input.
+
+use std::borrow::Borrow;
+use std::fmt::{self, Write};
+
+use anyhow::{Context, Result, bail};
+use cairo_lang_runnable_utils::builder::RunnableBuilder;
+use cairo_lang_runner::Arg;
+use cairo_lang_runner::arg_values::{
+    numeric_range, numeric_value, random_int, simplest_int, value_to_args,
+};
+use cairo_lang_runner::profiling::ProfilingInfo;
+use cairo_lang_sierra::extensions::core::CoreTypeConcrete;
+use cairo_lang_sierra::extensions::enm::EnumConcreteType;
+use cairo_lang_sierra::extensions::structure::StructConcreteType;
+use cairo_lang_sierra::extensions::types::InfoAndTypeConcreteType;
+use cairo_lang_sierra::extensions::utils::Range;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{Function, GenericArg};
+use cairo_lang_sierra::simulation::value::CoreValue;
+use cairo_lang_test_plugin::FuzzConfig;
+use cairo_lang_utils::byte_array::BYTES_IN_WORD;
+use itertools::{Itertools, chain};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use starknet_types_core::felt::Felt as Felt252;
+
+use crate::{TestResult, TestStatus};
+
+#[cfg(test)]
+#[path = "fuzz_test.rs"]
+mod test;
+
+/// The maximal length of a generated array.
+const MAX_ARRAY_LEN: usize = 8;
+/// The maximal length of a generated `ByteArray`.
+const MAX_BYTE_ARRAY_LEN: usize = 64;
+/// The maximal number of runs done while shrinking the arguments of a failing run.
+const MAX_SHRINK_RUNS: usize = 1024;
+/// The debug name of the `ByteArray` type.
+const BYTE_ARRAY_TYPE: &str = "core::byte_array::ByteArray";
+/// The debug name of the `bool` type.
+const BOOL_TYPE: &str = "core::bool";
+/// The debug name of the `u256` type.
+const U256_TYPE: &str = "core::integer::u256";
+
+/// The fuzzing summary of a test.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FuzzingResult {
+    /// The seed of the arguments generation.
+    pub seed: u64,
+    /// The number of runs done, including the failing run if any, but not the shrinking runs.
+    pub runs: usize,
+    /// The shrunk arguments of the failing run, formatted, if any run failed.
+    pub failing_args: Option<String>,
+}
+
+/// Runs a fuzz test according to `config`, where `run` runs the test with the given arguments.
+///
+/// Stops at the first failing run, and shrinks its arguments. The returned result is of the
+/// shrunk failing run if any, and otherwise of the last run, with the highest gas usage of all
+/// runs and the statement hits of all runs.
+pub fn run_fuzz_test(
+    builder: &RunnableBuilder,
+    func: &Function,
+    config: &FuzzConfig,
+    mut run: impl FnMut(Vec<Arg>) -> Result<TestResult>,
+) -> Result<TestResult> {
+    let generator = ArgsGenerator::new(builder, func)?;
+    let seed = config.seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut run_with = |values: &[Value]| {
+        run(generator.to_args(values)).with_context(|| {
+            format!("Failed running with arguments {} (seed: {seed}).", generator.format(values))
+        })
+    };
+    let mut gas_usage = None;
+    let mut profiling_info: Option<ProfilingInfo> = None;
+    let mut last_result = None;
+    for runs in 1..=config.runs {
+        let values = generator.random_values(&mut rng);
+        let result = run_with(&values)?;
+        if matches!(result.status, TestStatus::Fail(_)) {
+            let (values, result) = shrink(&generator, values, result, run_with)?;
+            let failing_args = Some(generator.format(&values));
+            return Ok(TestResult {
+                fuzzing: Some(FuzzingResult { seed, runs, failing_args }),
+                ..result
+            });
+        }
+        gas_usage = gas_usage.max(result.gas_usage);
+        if let Some(info) = &result.profiling_info {
+            match &mut profiling_info {
+                // The profile is of the first run, while the statement hits, used for coverage,
+                // are of all the runs.
+                Some(first) => {
+                    for (idx, hits) in info.sierra_statement_hits.iter_sorted() {
+                        *first.sierra_statement_hits.entry(*idx).or_default() += hits;
+                    }
+                }
+                None => profiling_info = Some(info.clone()),
+            }
+        }
+        last_result = Some(result);
+    }
+    let last_result = last_result.context("Fuzz tests must have at least one run.")?;
+    Ok(TestResult {
+        gas_usage,
+        profiling_info,
+        fuzzing: Some(FuzzingResult { seed, runs: config.runs, failing_args: None }),
+        ..last_result
+    })
+}
+
+/// Shrinks the arguments of a failing run, by repeatedly replacing them with simpler arguments
+/// for which the run still fails. Returns the shrunk arguments and the result of their run.
+///
+/// The arguments are shrunk one at a time, in rounds, until a round shrinks none of them.
+fn shrink(
+    generator: &ArgsGenerator<'_>,
+    mut values: Vec<Value>,
+    mut result: TestResult,
+    mut run_with: impl FnMut(&[Value]) -> Result<TestResult>,
+) -> Result<(Vec<Value>, TestResult)> {
+    let mut runs_left = MAX_SHRINK_RUNS;
+    let mut shrunk = true;
+    while shrunk {
+        shrunk = false;
+        for param_idx in 0..values.len() {
+            'param: loop {
+                for candidate in generator.shrink_arg(&values, param_idx) {
+                    if runs_left == 0 {
+                        return Ok((values, result));
+                    }
+                    runs_left -= 1;
+                    let candidate_result = run_with(&candidate)?;
+                    if matches!(candidate_result.status, TestStatus::Fail(_)) {
+                        values = candidate;
+                        result = candidate_result;
+                        shrunk = true;
+                        continue 'param;
+                    }
+                }
+                break;
+            }
+        }
+    }
+    Ok((values, result))
+}
+
+/// A generated value of a function argument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value {
+    /// A value of a numeric type, such as `felt252`, integers and `ContractAddress`.
+    Int(BigInt),
+    /// A `ByteArray`.
+    ByteArray(Vec<u8>),
+    /// An array, by its elements.
+    Array(Vec<Value>),
+    /// A struct or tuple, by its members.
+    Struct(Vec<Value>),
+    /// An enum, by its variant index and the value of the variant.
+    Enum { index: usize, value: Box<Value> },
+}
+
+/// The kind of an argument type, determining how its values are generated.
+enum TypeKind<'a> {
+    /// A numeric type, with its range of values.
+    Int(Range),
+    /// A `NonZero` of a numeric type, with the range of values of the numeric type.
+    NonZeroInt(Range),
+    ByteArray,
+    Array(&'a ConcreteTypeId),
+    Struct(&'a [ConcreteTypeId]),
+    Enum(&'a [ConcreteTypeId]),
+}
+
+/// Generates, shrinks and formats the arguments of a function.
+struct ArgsGenerator<'a> {
+    builder: &'a RunnableBuilder,
+    /// The types of the non-implicit parameters of the function.
+    param_types: Vec<&'a ConcreteTypeId>,
+}
+
+impl<'a> ArgsGenerator<'a> {
+    /// Creates a generator for the arguments of `func`, failing if some parameter type is not
+    /// supported.
+    fn new(builder: &'a RunnableBuilder, func: &'a Function) -> Result<Self> {
+        let param_types = func
+            .signature
+            .param_types
+            .iter()
+            .filter(|ty| builder.is_user_arg_type(&builder.type_long_id(ty).generic_id))
+            .collect_vec();
+        let generator = Self { builder, param_types };
+        for ty in &generator.param_types {
+            generator.check_supported(ty)?;
+        }
+        Ok(generator)
+    }
+
+    /// Fails if values of `ty` can not be generated.
+    fn check_supported(&self, ty: &ConcreteTypeId) -> Result<()> {
+        match self.kind(ty) {
+            Some(TypeKind::Int(_) | TypeKind::NonZeroInt(_) | TypeKind::ByteArray) => Ok(()),
+            Some(TypeKind::Array(ty)) => self.check_supported(ty),
+            Some(TypeKind::Struct(tys) | TypeKind::Enum(tys)) => {
+                tys.iter().try_for_each(|ty| self.check_supported(ty))
+            }
+            None => bail!("Type `{}` is not supported as a fuzz test parameter.", self.name(ty)),
+        }
+    }
+
+    /// Returns the kind of `ty`, or `None` if it is not supported.
+    fn kind(&self, ty: &ConcreteTypeId) -> Option<TypeKind<'a>> {
+        Some(match self.builder.registry().get_type(ty).ok()? {
+            CoreTypeConcrete::Snapshot(InfoAndTypeConcreteType { ty, .. }) => self.kind(ty)?,
+            CoreTypeConcrete::NonZero(InfoAndTypeConcreteType { ty, .. }) => {
+                match self.kind(ty)? {
+                    TypeKind::Int(range) => TypeKind::NonZeroInt(range),
+                    _ => return None,
+                }
+            }
+            CoreTypeConcrete::Array(InfoAndTypeConcreteType { ty, .. }) => TypeKind::Array(ty),
+            CoreTypeConcrete::Struct(_) if self.name(ty) == BYTE_ARRAY_TYPE => TypeKind::ByteArray,
+            CoreTypeConcrete::Struct(StructConcreteType { members, .. }) => {
+                TypeKind::Struct(members)
+            }
+            CoreTypeConcrete::Enum(EnumConcreteType { variants, .. }) if !variants.is_empty() => {
+                TypeKind::Enum(variants)
+            }
+            _ => TypeKind::Int(numeric_range(self.builder, ty)?),
+        })
+    }
+
+    /// Returns the name of `ty`, which for structs and enums is the name of the Cairo type.
+    fn name(&self, ty: &ConcreteTypeId) -> String {
+        let long_id = self.builder.type_long_id(ty);
+        match long_id.generic_args.first() {
+            Some(GenericArg::UserType(user_type)) => user_type.to_string(),
+            _ => long_id.to_string(),
+        }
+    }
+
+    /// Generates random values for all the parameters.
+    fn random_values(&self, rng: &mut impl Rng) -> Vec<Value> {
+        self.param_types.iter().map(|ty| self.random_value(ty, rng)).collect()
+    }
+
+    /// Generates a random value of `ty`, biased toward the edges of its range.
+    fn random_value(&self, ty: &ConcreteTypeId, rng: &mut impl Rng) -> Value {
+        match self.kind(ty).unwrap() {
+            TypeKind::Int(range) => Value::Int(random_int(&range, rng)),
+            TypeKind::NonZeroInt(range) => loop {
+                let value = random_int(&range, rng);
+                if !value.is_zero() {
+                    break Value::Int(value);
+                }
+            },
+            TypeKind::ByteArray => Value::ByteArray(
+                (0..rng.random_range(0..=MAX_BYTE_ARRAY_LEN))
+                    .map(|_| rng.random_range(b' '..=b'~'))
+                    .collect(),
+            ),
+            TypeKind::Array(ty) => Value::Array(
+                (0..rng.random_range(0..=MAX_ARRAY_LEN))
+                    .map(|_| self.random_value(ty, rng))
+                    .collect(),
+            ),
+            TypeKind::Struct(members) => {
+                Value::Struct(members.iter().map(|ty| self.random_value(ty, rng)).collect())
+            }
+            TypeKind::Enum(variants) => {
+                let index = rng.random_range(0..variants.len());
+                Value::Enum { index, value: Box::new(self.random_value(&variants[index], rng)) }
+            }
+        }
+    }
+
+    /// Returns the simplest value of `ty`.
+    fn minimal_value(&self, ty: &ConcreteTypeId) -> Value {
+        match self.kind(ty).unwrap() {
+            TypeKind::Int(range) => Value::Int(simplest_int(&range)),
+            TypeKind::NonZeroInt(range) => {
+                let target = simplest_int(&range);
+                Value::Int(if target.is_zero() { BigInt::one() } else { target })
+            }
+            TypeKind::ByteArray => Value::ByteArray(vec![]),
+            TypeKind::Array(_) => Value::Array(vec![]),
+            TypeKind::Struct(members) => {
+                Value::Struct(members.iter().map(|ty| self.minimal_value(ty)).collect())
+            }
+            TypeKind::Enum(variants) => {
+                Value::Enum { index: 0, value: Box::new(self.minimal_value(&variants[0])) }
+            }
+        }
+    }
+
+    /// Returns the candidates for simpler arguments, simplest first, each replacing the value of
+    /// the parameter at `param_idx`.
+    fn shrink_arg(&self, values: &[Value], param_idx: usize) -> Vec<Vec<Value>> {
+        self.shrink_value(self.param_types[param_idx], &values[param_idx])
+            .into_iter()
+            .map(|candidate| {
+                let mut values = values.to_vec();
+                values[param_idx] = candidate;
+                values
+            })
+            .collect()
+    }
+
+    /// Returns the candidates for simpler values of a sequence of values of the given types,
+    /// each replacing a single value.
+    fn shrink_members(
+        &self,
+        tys: &[impl Borrow<ConcreteTypeId>],
+        values: &[Value],
+    ) -> Vec<Vec<Value>> {
+        let mut candidates = vec![];
+        for (i, (ty, value)) in tys.iter().zip(values).enumerate() {
+            for candidate in self.shrink_value(ty.borrow(), value) {
+                let mut values = values.to_vec();
+                values[i] = candidate;
+                candidates.push(values);
+            }
+        }
+        candidates
+    }
+
+    /// Returns the candidates for simpler values of `ty` than `value`, simplest first.
+    fn shrink_value(&self, ty: &ConcreteTypeId, value: &Value) -> Vec<Value> {
+        match (self.kind(ty).unwrap(), value) {
+            (TypeKind::Int(range), Value::Int(value)) => {
+                shrink_int(value, &simplest_int(&range)).into_iter().map(Value::Int).collect()
+            }
+            (TypeKind::NonZeroInt(range), Value::Int(value)) => {
+                shrink_int(value, &simplest_int(&range))
+                    .into_iter()
+                    .filter(|value| !value.is_zero())
+                    .map(Value::Int)
+                    .collect()
+            }
+            (TypeKind::ByteArray, Value::ByteArray(bytes)) => {
+                let mut candidates = shrink_sequence(bytes);
+                // Simplify all the characters at once, and then one at a time.
+                if bytes.iter().filter(|byte| **byte != b'a').count() > 1 {
+                    candidates.push(vec![b'a'; bytes.len()]);
+                }
+                for (i, byte) in bytes.iter().enumerate() {
+                    if *byte != b'a' {
+                        let mut bytes = bytes.clone();
+                        bytes[i] = b'a';
+                        candidates.push(bytes);
+                    }
+                }
+                candidates.into_iter().map(Value::ByteArray).collect()
+            }
+            (TypeKind::Array(ty), Value::Array(items)) => {
+                let shorter = shrink_sequence(items).into_iter();
+                // Simplify all the elements at once, and then one at a time.
+                let minimal = vec![self.minimal_value(ty); items.len()];
+                let all_minimal = (items.iter().filter(|item| **item != minimal[0]).count() > 1)
+                    .then_some(minimal);
+                let tys = vec![ty; items.len()];
+                let simpler = self.shrink_members(&tys, items).into_iter();
+                chain!(shorter, all_minimal, simpler).map(Value::Array).collect()
+            }
+            (TypeKind::Struct(members), Value::Struct(values)) => {
+                self.shrink_members(members, values).into_iter().map(Value::Struct).collect()
+            }
+            (TypeKind::Enum(variants), Value::Enum { index, value }) => {
+                let earlier_variants = (0..*index).map(|index| Value::Enum {
+                    index,
+                    value: Box::new(self.minimal_value(&variants[index])),
+                });
+                let simpler = self
+                    .shrink_value(&variants[*index], value)
+                    .into_iter()
+                    .map(|value| Value::Enum { index: *index, value: Box::new(value) });
+                chain!(earlier_variants, simpler).collect()
+            }
+            _ => unreachable!("Value does not match its type."),
+        }
+    }
+
+    /// Returns the arguments of a run with the given values of the parameters.
+    fn to_args(&self, values: &[Value]) -> Vec<Arg> {
+        self.param_types
+            .iter()
+            .zip(values)
+            .flat_map(|(ty, value)| {
+                value_to_args(self.builder, ty, &self.to_core_value(ty, value)).unwrap()
+            })
+            .collect()
+    }
+
+    /// Returns the simulation value representing `value` of `ty`.
+    fn to_core_value(&self, ty: &ConcreteTypeId, value: &Value) -> CoreValue {
+        match (self.kind(ty).unwrap(), value) {
+            (TypeKind::Int(_) | TypeKind::NonZeroInt(_), Value::Int(value)) => {
+                numeric_value(self.builder, ty, value.clone())
+            }
+            (TypeKind::ByteArray, Value::ByteArray(bytes)) => {
+                let chunks = bytes.chunks_exact(BYTES_IN_WORD);
+                let pending_word = chunks.remainder();
+                let words =
+                    chunks.map(|word| CoreValue::Felt252(Felt252::from_bytes_be_slice(word)));
+                CoreValue::Struct(vec![
+                    CoreValue::Array(words.collect()),
+                    CoreValue::Felt252(Felt252::from_bytes_be_slice(pending_word)),
+                    CoreValue::Uint32(pending_word.len() as u32),
+                ])
+            }
+            (TypeKind::Array(ty), Value::Array(items)) => {
+                CoreValue::Array(items.iter().map(|item| self.to_core_value(ty, item)).collect())
+            }
+            (TypeKind::Struct(members), Value::Struct(values)) => CoreValue::Struct(
+                members
+                    .iter()
+                    .zip(values)
+                    .map(|(ty, value)| self.to_core_value(ty, value))
+                    .collect(),
+            ),
+            (TypeKind::Enum(variants), Value::Enum { index, value }) => CoreValue::Enum {
+                value: Box::new(self.to_core_value(&variants[*index], value)),
+                index: *index,
+            },
+            _ => unreachable!("Value does not match its type."),
+        }
+    }
+
+    /// Formats the values of all the parameters.
+    fn format(&self, values: &[Value]) -> String {
+        let mut formatted = String::new();
+        self.format_members(&mut formatted, &self.param_types, values).unwrap();
+        format!("({formatted})")
+    }
+
+    /// Formats a sequence of values of the given types, separated by commas.
+    fn format_members(
+        &self,
+        f: &mut String,
+        tys: &[impl Borrow<ConcreteTypeId>],
+        values: &[Value],
+    ) -> fmt::Result {
+        for (i, (ty, value)) in tys.iter().zip(values).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            self.format_value(f, ty.borrow(), value)?;
+        }
+        Ok(())
+    }
+
+    /// Formats `value` of `ty`, similarly to its Cairo representation.
+    fn format_value(&self, f: &mut String, ty: &ConcreteTypeId, value: &Value) -> fmt::Result {
+        match (self.kind(ty).unwrap(), value) {
+            (_, Value::Int(value)) if value.bits() > 128 => {
+                write!(f, "{:#x}", Felt252::from(value))
+            }
+            (_, Value::Int(value)) => write!(f, "{value}"),
+            (_, Value::ByteArray(bytes)) => write!(f, "{:?}", String::from_utf8_lossy(bytes)),
+            (TypeKind::Array(ty), Value::Array(items)) => {
+                write!(f, "array![")?;
+                self.format_members(f, &vec![ty; items.len()], items)?;
+                write!(f, "]")
+            }
+            (TypeKind::Struct(_), Value::Struct(values)) if self.name(ty) == U256_TYPE => {
+                let [Value::Int(low), Value::Int(high)] = values.as_slice() else {
+                    unreachable!("Value does not match its type.");
+                };
+                write!(f, "{}", (high << 128) + low)
+            }
+            (TypeKind::Struct(members), Value::Struct(values)) => {
+                let name = self.name(ty);
+                if name != "Tuple" {
+                    write!(f, "{name}")?;
+                }
+                write!(f, "(")?;
+                self.format_members(f, members, values)?;
+                if members.len() == 1 && name == "Tuple" {
+                    write!(f, ",")?;
+                }
+                write!(f, ")")
+            }
+            (TypeKind::Enum(_), Value::Enum { index, .. }) if self.name(ty) == BOOL_TYPE => {
+                write!(f, "{}", *index == 1)
+            }
+            (TypeKind::Enum(variants), Value::Enum { index, value }) => {
+                write!(f, "{}::<variant {index}>", self.name(ty))?;
+                if *value.as_ref() != Value::Struct(vec![]) {
+                    write!(f, "(")?;
+                    self.format_value(f, &variants[*index], value)?;
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            _ => unreachable!("Value does not match its type."),
+        }
+    }
+}
+
+/// Returns the candidates for integers closer to `target` than `value`, closest first.
+fn shrink_int(value: &BigInt, target: &BigInt) -> Vec<BigInt> {
+    let mut candidates = vec![];
+    let mut distance = value - target;
+    while !distance.is_zero() {
+        candidates.push(value - &distance);
+        distance /= 2;
+    }
+    candidates
+}
+
+/// Returns the candidates for shorter versions of `items`, shortest first.
+fn shrink_sequence<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut candidates = vec![];
+    if items.is_empty() {
+        return candidates;
+    }
+    candidates.push(vec![]);
+    let half = items.len() / 2;
+    if half > 0 {
+        candidates.push(items[..half].to_vec());
+        candidates.push(items[half..].to_vec());
+    }
+    if items.len() > 2 {
+        for i in 0..items.len() {
+            candidates.push(chain!(&items[..i], &items[i + 1..]).cloned().collect());
+        }
+    }
+    candidates
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use cairo_lang_test_plugin::TestsCompilationConfig;
+use num_bigint::BigInt;
+
+use super::{shrink_int, shrink_sequence};
+use crate::{TestCompiler, TestRunConfig, run_tests};
+
+#[test]
+fn test_shrink_int() {
+    let shrink = |value: i64, target: i64| {
+        shrink_int(&value.into(), &target.into())
+            .into_iter()
+            .map(|v| v.try_into().unwrap())
+            .collect::<Vec<i64>>()
+    };
+    assert_eq!(shrink(100, 0), [0, 50, 75, 88, 94, 97, 99]);
+    assert_eq!(shrink(-8, 0), [0, -4, -6, -7]);
+    assert_eq!(shrink(5, 3), [3, 4]);
+    assert_eq!(shrink(0, 0), [] as [i64; 0]);
+    assert_eq!(shrink_int(&BigInt::from(1), &BigInt::from(0)), [BigInt::from(0)]);
+}
+
+#[test]
+fn test_shrink_sequence() {
+    assert_eq!(shrink_sequence::<u8>(&[]), [] as [Vec<u8>; 0]);
+    assert_eq!(shrink_sequence(&[1]), [Vec::<i32>::new()]);
+    assert_eq!(shrink_sequence(&[1, 2]), [vec![], vec![1], vec![2]]);
+    assert_eq!(
+        shrink_sequence(&[1, 2, 3]),
+        [vec![], vec![1], vec![2, 3], vec![2, 3], vec![1, 3], vec![1, 2]]
+    );
+}
+
+#[test]
+fn test_run_fuzz_tests() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/fuzz");
+    let compiler =
+        TestCompiler::try_new(&path, false, true, TestsCompilationConfig::default()).unwrap();
+    let config = TestRunConfig::default();
+    let summary = run_tests(None, compiler.build().unwrap(), &config, None).unwrap();
+    assert_eq!(summary.passed, ["fuzz::test_passing"]);
+    assert_eq!(summary.failed, ["fuzz::test_failing"]);
+    let [(_, Some(fuzzing))] = summary.failed_run_results.as_slice() else {
+        panic!("Expected a fuzzing result of the failed test.");
+    };
+    assert_eq!(fuzzing.seed, 1);
+    assert_eq!(
+        fuzzing.failing_args.as_deref(),
+        Some(r#"(array![0, 0, 0], fuzz::Point(101, 0), false, "aaaa")"#)
+    );
+}
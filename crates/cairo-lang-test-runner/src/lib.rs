@@ -1,23 +1,27 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::mpsc::channel;
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::message_format::MessageFormat;
 use cairo_lang_compiler::project::setup_project;
 use cairo_lang_debug::debug::DebugWithDb;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::ids::CrateInput;
 use cairo_lang_runner::casm_run::{StarknetHintProcessor, format_for_panic};
 use cairo_lang_runner::profiling::{
     ProfilerConfig, ProfilingInfo, ProfilingInfoProcessor, ProfilingInfoProcessorParams,
 };
 use cairo_lang_runner::{
-    CairoHintProcessor, ProfilingInfoCollectionConfig, RunResultValue, RunnerError,
-    SierraCasmRunner, StarknetExecutionResources,
+    Arg, CairoHintProcessor, ProfilingInfoCollectionConfig, RunResultValue, RunnerError,
+    SierraCasmRunner, StarknetExecutionResources, StarknetState,
 };
+use cairo_lang_sierra::program::Function;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
+use cairo_lang_sierra_generator::statements_code_locations::StatementsSourceCodeLocations;
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
 use cairo_lang_starknet::starknet_plugin_suite;
 use cairo_lang_test_plugin::test_config::{PanicExpectation, TestExpectation};
@@ -26,13 +30,32 @@ use cairo_lang_test_plugin::{
     compile_test_prepared_db, test_plugin_suite,
 };
 use cairo_lang_utils::casts::IntoOrPanic;
-use colored::Colorize;
+use colored::{ColoredString, Colorize};
+use contract_classes::ContractClassSource;
+use coverage::{CoverageCollector, CoverageReport};
+use fixture::{ModuleFixtures, fixture_context, run_module_fixtures};
+pub use fuzz::FuzzingResult;
+use fuzz::run_fuzz_test;
 use itertools::Itertools;
 use num_traits::ToPrimitive;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use report::{OutputFormat, TestEvent, TestReport, TestReportStatus, junit_report};
+use resource_snapshot::{ResourceCheck, ResourceSnapshot, ResourceSnapshotConfig};
+use serde::Serialize;
+use snapshot::{SnapshotConfig, SnapshotHintProcessor};
+
+pub mod contract_classes;
+pub mod coverage;
+mod fixture;
+mod fuzz;
+pub mod report;
+pub mod resource_snapshot;
+pub mod snapshot;
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod test_utils;
 
 type ArcCustomHintProcessorFactory = Arc<
     dyn (for<'a> Fn(CairoHintProcessor<'a>) -> Box<dyn StarknetHintProcessor + 'a>) + Send + Sync,
@@ -61,7 +84,7 @@ impl<'db> TestRunner<'db> {
         allow_warnings: bool,
         config: TestRunConfig,
     ) -> Result<Self> {
-        let compiler = TestCompiler::try_new(
+        let mut compiler = TestCompiler::try_new(
             path,
             allow_warnings,
             config.gas_enabled,
@@ -71,12 +94,11 @@ impl<'db> TestRunner<'db> {
                     .profiler_config
                     .as_ref()
                     .is_some_and(|c| c.requires_cairo_debug_info()),
-                add_statements_code_locations: false,
-                contract_declarations: None,
-                contract_crate_ids: None,
-                executable_crate_ids: None,
+                add_statements_code_locations: config.coverage_dir.is_some(),
+                ..Default::default()
             },
         )?;
+        compiler.message_format = config.message_format;
         Ok(Self { compiler, config, custom_hint_processor_factory: None })
     }
 
@@ -141,13 +163,66 @@ impl<'db> CompiledTestRunner<'db> {
             &self.config.filter,
         );
 
-        let TestsSummary { passed, failed, ignored, failed_run_results } = run_tests(
+        let TestsSummary {
+            passed,
+            failed,
+            ignored,
+            failed_run_results,
+            reports,
+            coverage,
+            resource_checks,
+        } = run_tests(
             opt_db.map(|db| db as &dyn SierraGenGroup),
             compiled,
             &self.config,
             self.custom_hint_processor_factory,
         )?;
 
+        if let (Some(dir), Some(mut coverage)) = (&self.config.coverage_dir, coverage) {
+            // Only the tested code is reported. Corelib code inlined into it is still counted by
+            // the lines it was inlined into.
+            if let Some(corelib) = detect_corelib() {
+                coverage.exclude_dir(&corelib);
+            }
+            coverage.write(dir)?;
+            if self.config.output_format == OutputFormat::Pretty {
+                let (lines, lines_hit) = coverage.line_counts();
+                println!(
+                    "coverage: {lines_hit}/{lines} lines hit; report written to `{}`.",
+                    dir.display()
+                );
+            }
+        }
+
+        if let Some(snapshots) = &self.config.resource_snapshots
+            && self.config.output_format == OutputFormat::Pretty
+        {
+            print_resource_checks_summary(&resource_checks, snapshots);
+        }
+
+        match self.config.output_format {
+            OutputFormat::Pretty => {}
+            OutputFormat::Json => TestEvent::SuiteFinished {
+                passed: passed.len(),
+                failed: failed.len(),
+                ignored: ignored.len(),
+                filtered_out,
+            }
+            .print(),
+            OutputFormat::Junit => print!("{}", junit_report(&reports)),
+        }
+        if self.config.output_format != OutputFormat::Pretty {
+            // The results are already reported, only the run failure is left to report.
+            if !failed.is_empty() {
+                bail!(
+                    "test result: FAILED. {} passed; {} failed; {} ignored",
+                    passed.len(),
+                    failed.len(),
+                    ignored.len()
+                );
+            }
+            return Ok(None);
+        }
         if failed.is_empty() {
             println!(
                 "test result: {}. {} passed; {} failed; {} ignored; {filtered_out} filtered out;",
@@ -159,7 +234,7 @@ impl<'db> CompiledTestRunner<'db> {
             Ok(None)
         } else {
             println!("failures:");
-            for (failure, run_result) in failed.iter().zip_eq(failed_run_results) {
+            for (failure, (run_result, fuzzing)) in failed.iter().zip_eq(failed_run_results) {
                 print!("   {failure} - ");
                 match run_result {
                     Ok(RunResultValue::Success(_)) => {
@@ -172,6 +247,11 @@ impl<'db> CompiledTestRunner<'db> {
                         println!("{err}");
                     }
                 }
+                if let Some(FuzzingResult { seed, runs, failing_args: Some(args) }) = fuzzing {
+                    println!(
+                        "      failing arguments: {args} (seed: {seed}, found in run {runs})."
+                    );
+                }
             }
             println!();
             bail!(
@@ -197,6 +277,51 @@ pub struct TestRunConfig {
     pub gas_enabled: bool,
     /// Whether to print used resources after each test.
     pub print_resource_usage: bool,
+    /// The directory to write the coverage reports to, if collecting coverage.
+    pub coverage_dir: Option<PathBuf>,
+    /// The format of the output. Resource usage and profiling info are only printed in the
+    /// [OutputFormat::Pretty] format.
+    pub output_format: OutputFormat,
+    /// The number of tests to run in parallel. If not set, the number of CPUs is used.
+    pub jobs: Option<usize>,
+    /// The configuration of the snapshot assertions. If not set, snapshot assertions fail to run.
+    pub snapshots: Option<SnapshotConfig>,
+    /// The configuration of the resource snapshots, if checking the resource usage of the tests.
+    /// Fuzz tests are not checked, as their resource usage depends on the generated arguments.
+    pub resource_snapshots: Option<ResourceSnapshotConfig>,
+    /// Contract classes compiled separately from the tested crates, deployable by the tests.
+    pub contract_classes: Vec<ContractClassSource>,
+    /// A file of a dumped Starknet state to start the tests and fixtures from, instead of the
+    /// default state.
+    pub state_in: Option<PathBuf>,
+    /// A directory to dump the Starknet states the passing tests end in into, as
+    /// `<test name>.json` files. Fuzz tests are not dumped, as they run many times.
+    pub state_out_dir: Option<PathBuf>,
+    /// The format of the diagnostics of the compilation of the tested crates.
+    pub message_format: MessageFormat,
+}
+
+impl Default for TestRunConfig {
+    /// Runs all non-ignored tests with gas enabled, printing in the default formats.
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            include_ignored: false,
+            ignored: false,
+            profiler_config: None,
+            gas_enabled: true,
+            print_resource_usage: false,
+            coverage_dir: None,
+            output_format: OutputFormat::default(),
+            jobs: None,
+            snapshots: None,
+            resource_snapshots: None,
+            contract_classes: vec![],
+            state_in: None,
+            state_out_dir: None,
+            message_format: MessageFormat::default(),
+        }
+    }
 }
 
 /// The test cases compiler.
@@ -205,6 +330,8 @@ pub struct TestCompiler<'db> {
     pub main_crate_ids: Vec<CrateInput>,
     pub test_crate_ids: Vec<CrateInput>,
     pub allow_warnings: bool,
+    /// The format of the reported diagnostics.
+    pub message_format: MessageFormat,
     pub config: TestsCompilationConfig<'db>,
 }
 
@@ -244,13 +371,15 @@ impl<'db> TestCompiler<'db> {
             test_crate_ids: main_crate_inputs.clone(),
             main_crate_ids: main_crate_inputs,
             allow_warnings,
+            message_format: MessageFormat::Human,
             config,
         })
     }
 
     /// Build the tests and collect metadata.
     pub fn build(&self) -> Result<TestCompilation<'_>> {
-        let mut diag_reporter = DiagnosticsReporter::stderr().with_crates(&self.main_crate_ids);
+        let mut diag_reporter = DiagnosticsReporter::stderr_with_format(self.message_format)
+            .with_crates(&self.main_crate_ids);
         if self.allow_warnings {
             diag_reporter = diag_reporter.allow_warnings();
         }
@@ -319,14 +448,27 @@ struct TestResult {
     used_resources: StarknetExecutionResources,
     /// The profiling info of the run, if requested.
     profiling_info: Option<ProfilingInfo>,
+    /// The fuzzing summary, if the test is a fuzz test.
+    fuzzing: Option<FuzzingResult>,
 }
 
 /// Summary data of the ran tests.
+#[derive(Serialize)]
 pub struct TestsSummary {
     passed: Vec<String>,
     failed: Vec<String>,
     ignored: Vec<String>,
-    failed_run_results: Vec<Result<RunResultValue>>,
+    /// The results of the failed tests, with their fuzzing summaries for fuzz tests.
+    #[serde(skip)]
+    failed_run_results: Vec<(Result<RunResultValue>, Option<FuzzingResult>)>,
+    /// The reports of the tests, in the order they finished.
+    reports: Vec<TestReport>,
+    /// The coverage of the ran tests, if collected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coverage: Option<CoverageReport>,
+    /// The results of checking the resource usage of the tests against their snapshots.
+    #[serde(skip)]
+    resource_checks: Vec<ResourceCheck>,
 }
 
 /// Runs the tests and process the results for a summary.
@@ -346,8 +488,24 @@ pub fn run_tests(
                 statements_locations,
             },
     } = compiled;
+    let coverage_locations = if config.coverage_dir.is_some() {
+        let annotations =
+            sierra_program_with_debug_info.debug_info.as_ref().map(|d| &d.annotations);
+        Some(
+            annotations
+                .and_then(StatementsSourceCodeLocations::from_annotations)
+                .with_context(|| "Coverage requires the statements code locations annotations.")?,
+        )
+    } else {
+        None
+    };
     let sierra_program = sierra_program_with_debug_info.program;
-    let runner = SierraCasmRunner::new(
+    // Coverage is computed from the statement hits collected by the profiler.
+    let profiling_collection_config =
+        config.profiler_config.as_ref().map(ProfilingInfoCollectionConfig::from_profiler_config);
+    let profiling_collection_config = profiling_collection_config
+        .or_else(|| coverage_locations.as_ref().map(|_| ProfilingInfoCollectionConfig::default()));
+    let mut runner = SierraCasmRunner::new(
         sierra_program.clone(),
         if config.gas_enabled {
             Some(MetadataComputationConfig {
@@ -361,7 +519,7 @@ pub fn run_tests(
             None
         },
         contracts_info,
-        config.profiler_config.as_ref().map(ProfilingInfoCollectionConfig::from_profiler_config),
+        profiling_collection_config,
     )
     .map_err(|err| {
         let (RunnerError::BuildError(err), Some(db), Some(statements_locations)) =
@@ -378,15 +536,63 @@ pub fn run_tests(
         anyhow::anyhow!("{err}\n{}", locs.join("\n"))
     })
     .with_context(|| "Failed setting up runner.")?;
-    let suffix = if named_tests.len() != 1 { "s" } else { "" };
-    println!("running {} test{}", named_tests.len(), suffix);
+    for source in &config.contract_classes {
+        for (contract_class, class_hash) in source.load()? {
+            runner.add_contract_class(&contract_class, class_hash).with_context(|| {
+                format!("Failed loading a contract class from `{}`.", source.path().display())
+            })?;
+            if config.output_format == OutputFormat::Pretty {
+                println!("loaded contract class {class_hash:#x} from {}", source.path().display());
+            }
+        }
+    }
+    let mut coverage_collector = coverage_locations.map(|locations| {
+        CoverageCollector::new(&sierra_program, runner.builder().casm_program(), &locations)
+    });
+    match config.output_format {
+        OutputFormat::Pretty => {
+            let suffix = if named_tests.len() != 1 { "s" } else { "" };
+            println!("running {} test{}", named_tests.len(), suffix);
+        }
+        OutputFormat::Json => TestEvent::SuiteStarted { test_count: named_tests.len() }.print(),
+        OutputFormat::Junit => {}
+    }
 
+    // A zero number of threads makes rayon use the number of CPUs.
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(config.jobs.unwrap_or(0))
+        .build()
+        .with_context(|| "Failed creating the test threads.")?;
+    let initial_state = match &config.state_in {
+        Some(path) => StarknetState::load(path)
+            .with_context(|| format!("Failed loading the state from `{}`.", path.display()))?,
+        None => StarknetState::default(),
+    };
+    if let Some(dir) = &config.state_out_dir {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed creating `{}`.", dir.display()))?;
+    }
+    let fixtures =
+        run_module_fixtures(&named_tests, &runner, &custom_hint_processor_factory, &initial_state);
     let (tx, rx) = channel::<_>();
-    rayon::spawn(move || {
+    let snapshots = config.snapshots.clone();
+    let state_out_dir = config.state_out_dir.clone();
+    pool.spawn(move || {
         named_tests.into_par_iter().for_each(|(name, test)| {
-            let result =
-                run_single_test(test, &name, &runner, custom_hint_processor_factory.clone());
-            tx.send((name, result)).unwrap();
+            if !test.ignored {
+                tx.send(RunEvent::Started(name.clone())).unwrap();
+            }
+            let result = run_single_test(
+                test,
+                &name,
+                &runner,
+                custom_hint_processor_factory.clone(),
+                snapshots.as_ref(),
+                &fixtures,
+                &initial_state,
+                state_out_dir.as_deref(),
+            );
+            tx.send(RunEvent::Finished(name, Box::new(result))).unwrap();
         })
     });
 
@@ -415,47 +621,128 @@ pub fn run_tests(
         failed: vec![],
         ignored: vec![],
         failed_run_results: vec![],
+        reports: vec![],
+        coverage: None,
+        resource_checks: vec![],
     };
-    while let Ok((name, result)) = rx.recv() {
-        update_summary(&mut summary, name, result, &profiler_data, config.print_resource_usage);
+    while let Ok(event) = rx.recv() {
+        let (name, result) = match event {
+            RunEvent::Started(name) => {
+                if config.output_format == OutputFormat::Json {
+                    TestEvent::Started { name: &name }.print();
+                }
+                continue;
+            }
+            RunEvent::Finished(name, result) => (name, *result),
+        };
+        if let (Some(collector), Ok(Some(TestResult { profiling_info: Some(info), .. }))) =
+            (&mut coverage_collector, &result)
+        {
+            collector.add_run(&info.sierra_statement_hits);
+        }
+        update_summary(&mut summary, name, result, &profiler_data, config);
     }
+    summary.coverage = coverage_collector.map(|collector| collector.report());
 
     Ok(summary)
 }
 
+/// An event of a test run, sent from the test threads.
+enum RunEvent {
+    /// A test started running.
+    Started(String),
+    /// A test finished, with its result, or `None` if it was ignored.
+    Finished(String, Box<Result<Option<TestResult>>>),
+}
+
 /// Runs a single test and returns a tuple of its name and result.
+#[allow(clippy::too_many_arguments)]
 fn run_single_test(
     test: TestConfig,
     name: &str,
     runner: &SierraCasmRunner,
     custom_hint_processor_factory: Option<ArcCustomHintProcessorFactory>,
+    snapshots: Option<&SnapshotConfig>,
+    fixtures: &ModuleFixtures,
+    initial_state: &StarknetState,
+    state_out_dir: Option<&Path>,
 ) -> Result<Option<TestResult>> {
     if test.ignored {
         return Ok(None);
     }
     let func = runner.find_function(name)?;
+    let run = |args, starknet_state| {
+        run_test_case(
+            &test,
+            name,
+            func,
+            args,
+            starknet_state,
+            runner,
+            &custom_hint_processor_factory,
+            snapshots,
+        )
+    };
+    if let Some(fuzz_config) = &test.fuzz {
+        return run_fuzz_test(runner.builder(), func, fuzz_config, |args| {
+            run(args, initial_state.clone()).map(|(result, _)| result)
+        })
+        .map(Some);
+    }
+    let (args, starknet_state) = if test.uses_fixtures {
+        fixture_context(&test, fixtures, initial_state)?
+    } else {
+        (vec![], initial_state.clone())
+    };
+    let (result, final_state) = run(args, starknet_state)?;
+    if let Some(dir) = state_out_dir
+        && matches!(result.status, TestStatus::Success)
+    {
+        let path = dir.join(format!("{}.json", name.replace("::", "__")));
+        final_state
+            .dump(&path)
+            .with_context(|| format!("Failed dumping the state into `{}`.", path.display()))?;
+    }
+    Ok(Some(result))
+}
 
+/// Runs a test function with the given arguments, starting from the given Starknet state. Returns
+/// the result of the test, and the state it ended in.
+#[allow(clippy::too_many_arguments)]
+fn run_test_case(
+    test: &TestConfig,
+    name: &str,
+    func: &Function,
+    args: Vec<Arg>,
+    starknet_state: StarknetState,
+    runner: &SierraCasmRunner,
+    custom_hint_processor_factory: &Option<ArcCustomHintProcessorFactory>,
+    snapshots: Option<&SnapshotConfig>,
+) -> Result<(TestResult, StarknetState)> {
     let (hint_processor, ctx) =
-        runner.prepare_starknet_context(func, vec![], test.available_gas, Default::default())?;
+        runner.prepare_starknet_context(func, args, test.available_gas, starknet_state)?;
 
     let mut hint_processor = match custom_hint_processor_factory {
         Some(f) => f(hint_processor),
         None => Box::new(hint_processor),
     };
+    if let Some(snapshots) = snapshots {
+        hint_processor = Box::new(SnapshotHintProcessor::new(hint_processor, snapshots, name));
+    }
 
     let result =
         runner.run_function_with_prepared_starknet_context(func, &mut *hint_processor, ctx)?;
 
-    Ok(Some(TestResult {
+    let test_result = TestResult {
         status: match &result.value {
-            RunResultValue::Success(_) => match test.expectation {
+            RunResultValue::Success(_) => match &test.expectation {
                 TestExpectation::Success => TestStatus::Success,
                 TestExpectation::Panics(_) => TestStatus::Fail(result.value),
             },
-            RunResultValue::Panic(value) => match test.expectation {
+            RunResultValue::Panic(value) => match &test.expectation {
                 TestExpectation::Success => TestStatus::Fail(result.value),
                 TestExpectation::Panics(panic_expectation) => match panic_expectation {
-                    PanicExpectation::Exact(expected) if value != &expected => {
+                    PanicExpectation::Exact(expected) if value != expected => {
                         TestStatus::Fail(result.value)
                     }
                     _ => TestStatus::Success,
@@ -471,44 +758,166 @@ fn run_single_test(
             .or_else(|| runner.initial_required_gas(func).map(|gas| gas.into_or_panic::<i64>())),
         used_resources: result.used_resources,
         profiling_info: result.profiling_info,
-    }))
+        fuzzing: None,
+    };
+    Ok((test_result, result.starknet_state))
 }
 
-/// Updates the test summary with the given test result.
+/// Updates the test summary with the given test result, and reports it in the configured output
+/// format.
 fn update_summary(
     summary: &mut TestsSummary,
     name: String,
     test_result: Result<Option<TestResult>>,
     profiler_data: &Option<(ProfilingInfoProcessor<'_>, ProfilingInfoProcessorParams)>,
-    print_resource_usage: bool,
+    config: &TestRunConfig,
 ) {
-    let (res_type, status_str, gas_usage, used_resources, profiling_info) = match test_result {
-        Ok(None) => (&mut summary.ignored, "ignored".bright_yellow(), None, None, None),
+    let mut report = TestReport {
+        name: name.clone(),
+        status: TestReportStatus::Ignored,
+        gas_usage: None,
+        steps: None,
+        panic_data: None,
+        error: None,
+        fuzzing: None,
+        resource_regressions: vec![],
+    };
+    let (res_type, status_str, used_resources, profiling_info) = match test_result {
+        Ok(None) => (&mut summary.ignored, "ignored".bright_yellow(), None, None),
         Err(err) => {
-            summary.failed_run_results.push(Err(err));
-            (&mut summary.failed, "failed to run".bright_magenta(), None, None, None)
+            report.status = TestReportStatus::Failed;
+            report.error = Some(err.to_string());
+            summary.failed_run_results.push((Err(err), None));
+            (&mut summary.failed, "failed to run".bright_magenta(), None, None)
         }
         Ok(Some(result)) => {
+            report.gas_usage = result.gas_usage;
+            report.steps = Some(result.used_resources.basic_resources.n_steps);
+            report.fuzzing = result.fuzzing.clone();
+            let resource_failure = check_resource_snapshot(summary, &mut report, &result, config);
             let (res_type, status_str) = match result.status {
-                TestStatus::Success => (&mut summary.passed, "ok".bright_green()),
+                TestStatus::Success => match resource_failure {
+                    None => {
+                        report.status = TestReportStatus::Passed;
+                        (&mut summary.passed, "ok".bright_green())
+                    }
+                    Some(error) => {
+                        report.status = TestReportStatus::Failed;
+                        report.error = Some(error.clone());
+                        summary.failed_run_results.push((Err(anyhow!(error)), None));
+                        (&mut summary.failed, "fail".bright_red())
+                    }
+                },
                 TestStatus::Fail(run_result) => {
-                    summary.failed_run_results.push(Ok(run_result));
+                    report.status = TestReportStatus::Failed;
+                    match &run_result {
+                        RunResultValue::Success(_) => {
+                            report.error = Some("Expected panic but finished successfully.".into());
+                        }
+                        RunResultValue::Panic(values) => {
+                            report.panic_data = Some(format_for_panic(values.iter().cloned()));
+                        }
+                    }
+                    summary.failed_run_results.push((Ok(run_result), result.fuzzing));
                     (&mut summary.failed, "fail".bright_red())
                 }
             };
             (
                 res_type,
                 status_str,
-                result.gas_usage,
-                print_resource_usage.then_some(result.used_resources),
+                config.print_resource_usage.then_some(result.used_resources),
                 result.profiling_info,
             )
         }
     };
-    if let Some(gas_usage) = gas_usage {
-        println!("test {name} ... {status_str} (gas usage est.: {gas_usage})");
-    } else {
-        println!("test {name} ... {status_str}");
+    res_type.push(name);
+    match config.output_format {
+        OutputFormat::Pretty => {
+            print_test_result(&report, status_str, used_resources, profiling_info, profiler_data)
+        }
+        OutputFormat::Json => TestEvent::finished(&report).print(),
+        OutputFormat::Junit => {}
+    }
+    summary.reports.push(report);
+}
+
+/// Checks the resource usage of a passing test against its snapshot, if configured, and adds the
+/// regressions to its report.
+/// Returns the reason for failing the test, if the check should fail it.
+fn check_resource_snapshot(
+    summary: &mut TestsSummary,
+    report: &mut TestReport,
+    result: &TestResult,
+    config: &TestRunConfig,
+) -> Option<String> {
+    let snapshots = config.resource_snapshots.as_ref()?;
+    if !matches!(result.status, TestStatus::Success) || result.fuzzing.is_some() {
+        return None;
+    }
+    let snapshot = ResourceSnapshot::new(result.gas_usage, &result.used_resources);
+    let check = match snapshots.check(&report.name, &snapshot) {
+        Ok(check) => check,
+        Err(err) => return Some(format!("{err:#}")),
+    };
+    let failure = match &check {
+        ResourceCheck::Regressed(regressions) => {
+            report.resource_regressions = regressions.clone();
+            snapshots.fail_on_regression.then(|| {
+                format!("Resource usage went over the snapshot: {}.", regressions.join(", "))
+            })
+        }
+        ResourceCheck::Passed | ResourceCheck::Missing | ResourceCheck::Updated => None,
+    };
+    summary.resource_checks.push(check);
+    failure
+}
+
+/// Prints a summary of the resource snapshot checks of a run.
+fn print_resource_checks_summary(checks: &[ResourceCheck], config: &ResourceSnapshotConfig) {
+    let count = |f: fn(&ResourceCheck) -> bool| checks.iter().filter(|check| f(check)).count();
+    let updated = count(|check| matches!(check, ResourceCheck::Updated));
+    let missing = count(|check| matches!(check, ResourceCheck::Missing));
+    let regressed = count(|check| matches!(check, ResourceCheck::Regressed(_)));
+    println!(
+        "resource snapshots: {} checked; {updated} updated; {missing} missing; {regressed} \
+         regressed; snapshots directory: `{}`.",
+        checks.len(),
+        config.dir.display()
+    );
+    if missing > 0 {
+        println!(
+            "{}: {missing} tests have no resource snapshot. Run with `--update-snapshots` to \
+             create them.",
+            "warning".bright_yellow()
+        );
+    }
+    if regressed > 0 && !config.fail_on_regression {
+        println!(
+            "{}: {regressed} tests went over their resource snapshot.",
+            "warning".bright_yellow()
+        );
+    }
+}
+
+/// Prints the result of a test in the [OutputFormat::Pretty] format.
+fn print_test_result(
+    report: &TestReport,
+    status_str: ColoredString,
+    used_resources: Option<StarknetExecutionResources>,
+    profiling_info: Option<ProfilingInfo>,
+    profiler_data: &Option<(ProfilingInfoProcessor<'_>, ProfilingInfoProcessorParams)>,
+) {
+    let name = &report.name;
+    let fuzz_runs = report.fuzzing.as_ref().map(|fuzzing| fuzzing.runs);
+    match (fuzz_runs, report.gas_usage) {
+        (Some(runs), Some(gas_usage)) => {
+            println!("test {name} ... {status_str} (runs: {runs}, gas usage est.: {gas_usage})")
+        }
+        (Some(runs), None) => println!("test {name} ... {status_str} (runs: {runs})"),
+        (None, Some(gas_usage)) => {
+            println!("test {name} ... {status_str} (gas usage est.: {gas_usage})")
+        }
+        (None, None) => println!("test {name} ... {status_str}"),
     }
     if let Some(used_resources) = used_resources {
         let filtered = used_resources.basic_resources.filter_unused_builtins();
@@ -534,6 +943,9 @@ fn update_summary(
         );
         print_resource_map(used_resources.syscalls.into_iter(), "syscalls");
     }
+    for regression in &report.resource_regressions {
+        println!("    resource regression: {regression}");
+    }
     if let Some((profiling_processor, profiling_params)) = profiler_data {
         let processed_profiling_info = profiling_processor.process(
             &profiling_info.expect("profiling_info must be Some when profiler_config is Some"),
@@ -541,7 +953,6 @@ fn update_summary(
         );
         println!("Profiling info:\n{processed_profiling_info}");
     }
-    res_type.push(name);
 }
 
 /// Given an iterator of (String, usize) pairs, prints a usage map. E.g.:
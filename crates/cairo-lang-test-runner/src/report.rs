@@ -0,0 +1,181 @@
+//! Machine-readable reports of test runs, for CI dashboards and IDE test explorers.
+
+use std::fmt::Write;
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::FuzzingResult;
+
+#[cfg(test)]
+#[path = "report_test.rs"]
+mod test;
+
+/// The format of the output of a test run. With the `clap` feature, it may be parsed as a command
+/// line argument value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Pretty,
+    /// A line with a JSON object for each event of the run, printed as the tests run.
+    Json,
+    /// A JUnit XML report, printed once all the tests ran.
+    Junit,
+}
+
+/// The status of a ran test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestReportStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// The report of a single test.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TestReport {
+    /// The full path of the test.
+    pub name: String,
+    /// The status of the test. Not serialized, as it is the kind of the event of the report.
+    #[serde(skip)]
+    pub status: TestReportStatus,
+    /// The gas usage of the run, if relevant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_usage: Option<i64>,
+    /// The number of steps of the run, if the test ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<usize>,
+    /// The panic data of the run decoded as a string, if the test panicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panic_data: Option<String>,
+    /// The reason of the failure, if the test failed without panicking or failed to run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The fuzzing summary, if the test is a fuzz test.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzing: Option<FuzzingResult>,
+    /// The resources that went over their snapshot, if checking resource snapshots.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub resource_regressions: Vec<String>,
+}
+
+/// An event of a test run, printed as a JSON line in the [OutputFormat::Json] format.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TestEvent<'a> {
+    /// The run started, with the number of tests to run.
+    SuiteStarted { test_count: usize },
+    /// A test started running.
+    Started { name: &'a str },
+    /// A test passed.
+    Passed(&'a TestReport),
+    /// A test failed.
+    Failed(&'a TestReport),
+    /// A test was ignored.
+    Ignored(&'a TestReport),
+    /// The run finished.
+    SuiteFinished { passed: usize, failed: usize, ignored: usize, filtered_out: usize },
+}
+
+impl<'a> TestEvent<'a> {
+    /// Returns the event of a finished test.
+    pub fn finished(report: &'a TestReport) -> Self {
+        match report.status {
+            TestReportStatus::Passed => TestEvent::Passed(report),
+            TestReportStatus::Failed => TestEvent::Failed(report),
+            TestReportStatus::Ignored => TestEvent::Ignored(report),
+        }
+    }
+
+    /// Prints the event as a JSON line.
+    pub fn print(&self) {
+        println!("{}", serde_json::to_string(self).expect("Failed serializing test event."));
+    }
+}
+
+/// Returns the JUnit XML report of the given tests.
+///
+/// Each test is a test case whose class name is the path of its module. The gas usage and steps
+/// of the tests are reported as test case properties.
+pub fn junit_report(reports: &[TestReport]) -> String {
+    let count = |status| reports.iter().filter(|report| report.status == status).count();
+    let (tests, failures, skipped) =
+        (reports.len(), count(TestReportStatus::Failed), count(TestReportStatus::Ignored));
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(xml, r#"<testsuites tests="{tests}" failures="{failures}" skipped="{skipped}">"#)
+        .unwrap();
+    writeln!(
+        xml,
+        r#"  <testsuite name="cairo-test" tests="{tests}" failures="{failures}" errors="0" skipped="{skipped}">"#
+    )
+    .unwrap();
+    for report in reports.iter().sorted_by(|a, b| a.name.cmp(&b.name)) {
+        let (class_name, name) = report.name.rsplit_once("::").unwrap_or(("", &report.name));
+        writeln!(
+            xml,
+            r#"    <testcase name="{}" classname="{}">"#,
+            escape_xml(name),
+            escape_xml(class_name)
+        )
+        .unwrap();
+        let properties = [
+            ("gas_usage", report.gas_usage.map(|gas_usage| gas_usage.to_string())),
+            ("steps", report.steps.map(|steps| steps.to_string())),
+        ];
+        if properties.iter().any(|(_, value)| value.is_some()) {
+            writeln!(xml, "      <properties>").unwrap();
+            for (property, value) in properties {
+                if let Some(value) = value {
+                    writeln!(xml, r#"        <property name="{property}" value="{value}"/>"#)
+                        .unwrap();
+                }
+            }
+            writeln!(xml, "      </properties>").unwrap();
+        }
+        match report.status {
+            TestReportStatus::Passed => {}
+            TestReportStatus::Failed => {
+                let message = report.failure_message();
+                writeln!(xml, r#"      <failure message="{}"/>"#, escape_xml(&message)).unwrap();
+            }
+            TestReportStatus::Ignored => writeln!(xml, "      <skipped/>").unwrap(),
+        }
+        writeln!(xml, "    </testcase>").unwrap();
+    }
+    writeln!(xml, "  </testsuite>").unwrap();
+    writeln!(xml, "</testsuites>").unwrap();
+    xml
+}
+
+impl TestReport {
+    /// Returns a description of the failure of the test.
+    fn failure_message(&self) -> String {
+        let mut message = self.error.iter().chain(&self.panic_data).join(" ");
+        if let Some(FuzzingResult { seed, runs, failing_args: Some(args) }) = &self.fuzzing {
+            write!(message, " Failing arguments: {args} (seed: {seed}, found in run {runs}).")
+                .unwrap();
+        }
+        message
+    }
+}
+
+/// Escapes a string for use in an XML attribute.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
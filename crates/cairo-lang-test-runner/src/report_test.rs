@@ -0,0 +1,100 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use super::{TestEvent, TestReport, TestReportStatus, junit_report};
+use crate::FuzzingResult;
+
+fn report(name: &str, status: TestReportStatus) -> TestReport {
+    TestReport {
+        name: name.into(),
+        status,
+        gas_usage: None,
+        steps: None,
+        panic_data: None,
+        error: None,
+        fuzzing: None,
+        resource_regressions: vec![],
+    }
+}
+
+#[test]
+fn test_json_events() {
+    let passed = TestReport {
+        gas_usage: Some(100),
+        steps: Some(12),
+        ..report("tests::test_passed", TestReportStatus::Passed)
+    };
+    let failed = TestReport {
+        panic_data: Some("Panicked with 0x4f6f7073 ('Oops').".into()),
+        fuzzing: Some(FuzzingResult { seed: 7, runs: 3, failing_args: Some("(1, 2)".into()) }),
+        ..report("tests::test_failed", TestReportStatus::Failed)
+    };
+    let ignored = report("tests::test_ignored", TestReportStatus::Ignored);
+    let events = [
+        TestEvent::SuiteStarted { test_count: 3 },
+        TestEvent::Started { name: "tests::test_passed" },
+        TestEvent::finished(&passed),
+        TestEvent::finished(&failed),
+        TestEvent::finished(&ignored),
+        TestEvent::SuiteFinished { passed: 1, failed: 1, ignored: 1, filtered_out: 0 },
+    ];
+    let lines =
+        events.iter().map(|event| serde_json::to_string(event).unwrap()).collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            r#"{"event":"suite_started","test_count":3}"#,
+            r#"{"event":"started","name":"tests::test_passed"}"#,
+            r#"{"event":"passed","name":"tests::test_passed","gas_usage":100,"steps":12}"#,
+            concat!(
+                r#"{"event":"failed","name":"tests::test_failed","#,
+                r#""panic_data":"Panicked with 0x4f6f7073 ('Oops').","#,
+                r#""fuzzing":{"seed":7,"runs":3,"failing_args":"(1, 2)"}}"#
+            ),
+            r#"{"event":"ignored","name":"tests::test_ignored"}"#,
+            r#"{"event":"suite_finished","passed":1,"failed":1,"ignored":1,"filtered_out":0}"#,
+        ]
+    );
+}
+
+#[test]
+fn test_junit_report() {
+    let reports = [
+        TestReport {
+            error: Some("Expected panic but finished successfully.".into()),
+            steps: Some(5),
+            ..report("tests::inner::test_failed", TestReportStatus::Failed)
+        },
+        report("test_ignored", TestReportStatus::Ignored),
+        TestReport {
+            gas_usage: Some(100),
+            steps: Some(12),
+            ..report("tests::test_<passed>", TestReportStatus::Passed)
+        },
+    ];
+    assert_eq!(
+        junit_report(&reports),
+        indoc! {r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites tests="3" failures="1" skipped="1">
+              <testsuite name="cairo-test" tests="3" failures="1" errors="0" skipped="1">
+                <testcase name="test_ignored" classname="">
+                  <skipped/>
+                </testcase>
+                <testcase name="test_failed" classname="tests::inner">
+                  <properties>
+                    <property name="steps" value="5"/>
+                  </properties>
+                  <failure message="Expected panic but finished successfully."/>
+                </testcase>
+                <testcase name="test_&lt;passed&gt;" classname="tests">
+                  <properties>
+                    <property name="gas_usage" value="100"/>
+                    <property name="steps" value="12"/>
+                  </properties>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#}
+    );
+}
@@ -0,0 +1,162 @@
+//! Resource usage snapshots of tests, used as baselines for catching gas and step regressions.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use cairo_lang_runner::StarknetExecutionResources;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[path = "resource_snapshot_test.rs"]
+mod test;
+
+/// The extension of resource snapshot files.
+pub const RESOURCE_SNAPSHOT_EXTENSION: &str = "json";
+
+/// The configuration of the resource snapshots of a test run.
+#[derive(Clone, Debug)]
+pub struct ResourceSnapshotConfig {
+    /// The directory of the resource snapshot files.
+    pub dir: PathBuf,
+    /// Whether to write the resource usage of the tests into the snapshot files, instead of
+    /// comparing them.
+    pub update: bool,
+    /// The percentage a resource may go over its snapshot without counting as a regression.
+    pub threshold_percent: f64,
+    /// Whether a regression fails the test, or is only reported as a warning.
+    pub fail_on_regression: bool,
+}
+
+/// The resource usage of a single run of a test, as stored in its snapshot file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceSnapshot {
+    /// The gas usage, if relevant.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub gas: Option<i64>,
+    /// The number of steps.
+    pub steps: usize,
+    /// The number of memory holes.
+    pub memory_holes: usize,
+    /// The number of instances of each used builtin.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub builtins: BTreeMap<String, usize>,
+    /// The number of calls of each used syscall.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub syscalls: BTreeMap<String, usize>,
+}
+
+impl ResourceSnapshot {
+    /// Creates the snapshot of a run of a test.
+    pub fn new(gas: Option<i64>, used_resources: &StarknetExecutionResources) -> Self {
+        let filtered = used_resources.basic_resources.filter_unused_builtins();
+        Self {
+            gas,
+            steps: filtered.n_steps,
+            memory_holes: filtered.n_memory_holes,
+            builtins: filtered
+                .builtin_instance_counter
+                .iter()
+                .map(|(name, count)| (name.to_string(), *count))
+                .collect(),
+            syscalls: used_resources
+                .syscalls
+                .iter()
+                .map(|(name, count)| (name.clone(), *count))
+                .collect(),
+        }
+    }
+
+    /// Returns the resources of the snapshot, by their names in regression descriptions.
+    fn resources(&self) -> BTreeMap<String, i64> {
+        let counters =
+            [("steps".to_string(), self.steps), ("memory holes".into(), self.memory_holes)]
+                .into_iter()
+                .chain(
+                    self.builtins.iter().map(|(name, count)| (format!("builtin {name}"), *count)),
+                )
+                .chain(
+                    self.syscalls.iter().map(|(name, count)| (format!("syscall {name}"), *count)),
+                )
+                .map(|(name, count)| (name, count as i64));
+        self.gas.map(|gas| ("gas".to_string(), gas)).into_iter().chain(counters).collect()
+    }
+
+    /// Returns descriptions of the resources of this snapshot that are over the resources of
+    /// `baseline` by more than `threshold_percent`.
+    pub fn regressions(&self, baseline: &ResourceSnapshot, threshold_percent: f64) -> Vec<String> {
+        let baseline = baseline.resources();
+        self.resources()
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let base = baseline.get(&name).copied().unwrap_or_default();
+                if (value as f64) <= (base as f64) * (1.0 + threshold_percent / 100.0) {
+                    return None;
+                }
+                Some(if base > 0 {
+                    let percent = (value - base) as f64 * 100.0 / base as f64;
+                    format!("{name}: {base} -> {value} (+{percent:.2}%)")
+                } else {
+                    format!("{name}: {base} -> {value}")
+                })
+            })
+            .collect()
+    }
+}
+
+/// The result of checking the resource usage of a test against its snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResourceCheck {
+    /// The resource usage is within the threshold of the snapshot.
+    Passed,
+    /// The test has no snapshot yet.
+    Missing,
+    /// The snapshot was written with the resource usage of the test.
+    Updated,
+    /// Resources went over the threshold, with a description of each regressed resource.
+    Regressed(Vec<String>),
+}
+
+impl ResourceSnapshotConfig {
+    /// Returns the path of the resource snapshot of a test.
+    pub fn snapshot_path(&self, test_name: &str) -> PathBuf {
+        let test_name = test_name.replace("::", "__");
+        self.dir.join(format!("{test_name}.{RESOURCE_SNAPSHOT_EXTENSION}"))
+    }
+
+    /// Checks the resource usage of a test against its snapshot, or writes it if updating
+    /// snapshots.
+    pub fn check(&self, test_name: &str, snapshot: &ResourceSnapshot) -> Result<ResourceCheck> {
+        let path = self.snapshot_path(test_name);
+        let baseline = if path.exists() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed reading `{}`.", path.display()))?;
+            Some(
+                serde_json::from_str::<ResourceSnapshot>(&content)
+                    .with_context(|| format!("Failed parsing `{}`.", path.display()))?,
+            )
+        } else {
+            None
+        };
+        if self.update {
+            if baseline.as_ref() == Some(snapshot) {
+                return Ok(ResourceCheck::Passed);
+            }
+            fs::create_dir_all(&self.dir)
+                .with_context(|| format!("Failed creating directory `{}`.", self.dir.display()))?;
+            fs::write(&path, serde_json::to_string_pretty(snapshot)? + "\n")
+                .with_context(|| format!("Failed writing `{}`.", path.display()))?;
+            return Ok(ResourceCheck::Updated);
+        }
+        let Some(baseline) = baseline else {
+            return Ok(ResourceCheck::Missing);
+        };
+        let regressions = snapshot.regressions(&baseline, self.threshold_percent);
+        Ok(if regressions.is_empty() {
+            ResourceCheck::Passed
+        } else {
+            ResourceCheck::Regressed(regressions)
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::{ResourceCheck, ResourceSnapshot, ResourceSnapshotConfig};
+use crate::test_utils::TempDir;
+
+fn snapshot(gas: i64, steps: usize) -> ResourceSnapshot {
+    ResourceSnapshot {
+        gas: Some(gas),
+        steps,
+        memory_holes: 2,
+        builtins: [("range_check_builtin".to_string(), 3)].into(),
+        syscalls: Default::default(),
+    }
+}
+
+#[test]
+fn test_snapshot_path() {
+    let config = ResourceSnapshotConfig {
+        dir: PathBuf::from("snapshots/resources"),
+        update: false,
+        threshold_percent: 0.0,
+        fail_on_regression: true,
+    };
+    assert_eq!(
+        config.snapshot_path("crate::tests::test_a"),
+        PathBuf::from("snapshots/resources/crate__tests__test_a.json")
+    );
+}
+
+#[test]
+fn test_regressions() {
+    let baseline = snapshot(1000, 100);
+    assert_eq!(snapshot(1000, 100).regressions(&baseline, 0.0), Vec::<String>::new());
+    assert_eq!(snapshot(900, 90).regressions(&baseline, 0.0), Vec::<String>::new());
+    assert_eq!(
+        snapshot(1010, 101).regressions(&baseline, 0.0),
+        ["gas: 1000 -> 1010 (+1.00%)", "steps: 100 -> 101 (+1.00%)"]
+    );
+    assert_eq!(snapshot(1010, 120).regressions(&baseline, 5.0), ["steps: 100 -> 120 (+20.00%)"]);
+    let with_syscall =
+        ResourceSnapshot { syscalls: [("EmitEvent".to_string(), 1)].into(), ..snapshot(1000, 100) };
+    assert_eq!(with_syscall.regressions(&baseline, 50.0), ["syscall EmitEvent: 0 -> 1"]);
+}
+
+#[test]
+fn test_check_and_update() {
+    let dir = TempDir::new("cairo_resource_snapshot_test");
+    let check = ResourceSnapshotConfig {
+        dir: dir.path().to_path_buf(),
+        update: false,
+        threshold_percent: 10.0,
+        fail_on_regression: true,
+    };
+    let update = ResourceSnapshotConfig { update: true, ..check.clone() };
+
+    assert_eq!(check.check("test", &snapshot(1000, 100)).unwrap(), ResourceCheck::Missing);
+    assert_eq!(update.check("test", &snapshot(1000, 100)).unwrap(), ResourceCheck::Updated);
+    assert_eq!(update.check("test", &snapshot(1000, 100)).unwrap(), ResourceCheck::Passed);
+    assert_eq!(check.check("test", &snapshot(1100, 100)).unwrap(), ResourceCheck::Passed);
+    assert_eq!(
+        check.check("test", &snapshot(1200, 100)).unwrap(),
+        ResourceCheck::Regressed(vec!["gas: 1000 -> 1200 (+20.00%)".into()])
+    );
+    assert_eq!(
+        fs::read_to_string(check.snapshot_path("test")).unwrap(),
+        indoc::indoc! {r#"
+            {
+              "gas": 1000,
+              "steps": 100,
+              "memory_holes": 2,
+              "builtins": {
+                "range_check_builtin": 3
+              }
+            }
+        "#}
+    );
+}
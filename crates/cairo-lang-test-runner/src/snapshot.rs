@@ -0,0 +1,263 @@
+//! Snapshot testing, comparing the `Debug` formatting of values asserted with `assert_snapshot!`
+//! with golden `.snap` files stored in the project.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use cairo_lang_casm::hints::{Hint, StarknetHint};
+use cairo_lang_runner::casm_run::{
+    MemBuffer, StarknetHintProcessor, cell_ref_to_relocatable, extract_relocatable, vm_get_range,
+};
+use cairo_lang_runner::{StarknetExecutionResources, StarknetState};
+use cairo_lang_utils::byte_array::BYTES_IN_WORD;
+use cairo_vm::hint_processor::hint_processor_definition::{HintProcessorLogic, HintReference};
+use cairo_vm::serde::deserialize_program::ApTracking;
+use cairo_vm::types::exec_scope::ExecutionScopes;
+use cairo_vm::vm::errors::hint_errors::HintError;
+use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
+use cairo_vm::vm::runners::cairo_runner::{ResourceTracker, RunResources};
+use cairo_vm::vm::vm_core::VirtualMachine;
+use diffy::DiffOptions;
+use itertools::chain;
+use num_traits::ToPrimitive;
+use starknet_types_core::felt::Felt as Felt252;
+
+#[cfg(test)]
+#[path = "snapshot_test.rs"]
+mod test;
+
+/// The selector of the cheatcode used by `assert_snapshot!`.
+const SNAPSHOT_CHEATCODE: &str = "assert_snapshot";
+/// The extension of snapshot files.
+pub const SNAPSHOT_EXTENSION: &str = "snap";
+
+/// The configuration of the snapshot assertions of a test run.
+#[derive(Clone, Debug)]
+pub struct SnapshotConfig {
+    /// The directory of the snapshot files.
+    pub dir: PathBuf,
+    /// Whether to write the asserted values into the snapshot files, instead of comparing them.
+    pub update: bool,
+}
+
+impl SnapshotConfig {
+    /// Returns the path of a snapshot of a test.
+    ///
+    /// The file of a named snapshot is `<test path>@<name>.snap`. The file of the `n`th unnamed
+    /// snapshot is `<test path>.snap` for the first, and `<test path>-<n>.snap` for the rest.
+    pub fn snapshot_path(&self, test_name: &str, snapshot: &SnapshotName<'_>) -> PathBuf {
+        let test_name = test_name.replace("::", "__");
+        let file_name = match snapshot {
+            SnapshotName::Named(name) => format!("{test_name}@{name}"),
+            SnapshotName::Unnamed(1) => test_name,
+            SnapshotName::Unnamed(index) => format!("{test_name}-{index}"),
+        };
+        self.dir.join(format!("{file_name}.{SNAPSHOT_EXTENSION}"))
+    }
+
+    /// Checks a value against the snapshot at the given path, or writes it if updating snapshots.
+    /// Returns a description of the mismatch if the value does not match the snapshot.
+    pub fn check(&self, path: &Path, value: &str) -> Result<Option<String>> {
+        let content = format!("{value}\n");
+        let existing = fs::read_to_string(path).ok();
+        if existing.as_deref() == Some(content.as_str()) {
+            return Ok(None);
+        }
+        if self.update {
+            fs::create_dir_all(&self.dir)
+                .with_context(|| format!("Failed creating directory `{}`.", self.dir.display()))?;
+            fs::write(path, content)
+                .with_context(|| format!("Failed writing `{}`.", path.display()))?;
+            return Ok(None);
+        }
+        Ok(Some(match existing {
+            None => format!(
+                "Snapshot `{}` does not exist. Run with `--update-snapshots` to create it. \
+                 Value:\n{value}",
+                path.display()
+            ),
+            Some(existing) => format!(
+                "Snapshot `{}` does not match. Run with `--update-snapshots` to update it.\n{}",
+                path.display(),
+                DiffOptions::new()
+                    .set_original_filename("snapshot")
+                    .set_modified_filename("actual")
+                    .create_patch(&existing, &content)
+            ),
+        }))
+    }
+}
+
+/// The name of a snapshot of a test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotName<'a> {
+    /// A snapshot named in the assertion.
+    Named(&'a str),
+    /// The `n`th unnamed snapshot of the test, starting from 1.
+    Unnamed(usize),
+}
+
+/// A hint processor running the snapshot assertions of a test, wrapping the hint processor
+/// running the rest of the hints.
+pub struct SnapshotHintProcessor<'a> {
+    /// The wrapped hint processor.
+    inner: Box<dyn StarknetHintProcessor + 'a>,
+    /// The configuration of the snapshots.
+    config: &'a SnapshotConfig,
+    /// The name of the test.
+    test_name: &'a str,
+    /// The number of unnamed snapshots asserted so far.
+    unnamed_count: usize,
+}
+
+impl<'a> SnapshotHintProcessor<'a> {
+    pub fn new(
+        inner: Box<dyn StarknetHintProcessor + 'a>,
+        config: &'a SnapshotConfig,
+        test_name: &'a str,
+    ) -> Self {
+        Self { inner, config, test_name, unnamed_count: 0 }
+    }
+
+    /// Runs a snapshot assertion, given the serialized name and value `ByteArray`s.
+    /// Returns the serialized `ByteArray` of the failure message if the assertion failed, or an
+    /// empty vector if it passed.
+    fn assert_snapshot(&mut self, inputs: &[Felt252]) -> Result<Vec<Felt252>, HintError> {
+        let invalid_args = || {
+            HintError::CustomHint(Box::from(format!(
+                "`{SNAPSHOT_CHEATCODE}` cheatcode invalid args: pass the serialization of the \
+                 snapshot name and value `ByteArray`s."
+            )))
+        };
+        let mut inputs = inputs.iter();
+        let name = deserialize_byte_array(&mut inputs).ok_or_else(invalid_args)?;
+        let value = deserialize_byte_array(&mut inputs).ok_or_else(invalid_args)?;
+        if inputs.next().is_some() {
+            return Err(invalid_args());
+        }
+        let name = if name.is_empty() {
+            self.unnamed_count += 1;
+            SnapshotName::Unnamed(self.unnamed_count)
+        } else {
+            SnapshotName::Named(&name)
+        };
+        let path = self.config.snapshot_path(self.test_name, &name);
+        let mismatch = self
+            .config
+            .check(&path, &value)
+            .map_err(|err| HintError::CustomHint(Box::from(format!("{err:#}"))))?;
+        Ok(mismatch.map(|message| serialize_byte_array(&message)).unwrap_or_default())
+    }
+}
+
+impl HintProcessorLogic for SnapshotHintProcessor<'_> {
+    fn execute_hint(
+        &mut self,
+        vm: &mut VirtualMachine,
+        exec_scopes: &mut ExecutionScopes,
+        hint_data: &Box<dyn Any>,
+        constants: &HashMap<String, Felt252>,
+    ) -> Result<(), HintError> {
+        let Some(Hint::Starknet(StarknetHint::Cheatcode {
+            selector,
+            input_start,
+            input_end,
+            output_start,
+            output_end,
+        })) = hint_data.downcast_ref::<Hint>()
+        else {
+            return self.inner.execute_hint(vm, exec_scopes, hint_data, constants);
+        };
+        if selector.value.to_bytes_be().1 != SNAPSHOT_CHEATCODE.as_bytes() {
+            return self.inner.execute_hint(vm, exec_scopes, hint_data, constants);
+        }
+        let input_start = extract_relocatable(vm, input_start)?;
+        let input_end = extract_relocatable(vm, input_end)?;
+        let inputs = vm_get_range(vm, input_start, input_end)?;
+        let outputs = self.assert_snapshot(&inputs)?;
+        let mut res_segment = MemBuffer::new_segment(vm);
+        let res_segment_start = res_segment.ptr;
+        res_segment.write_data(outputs.into_iter())?;
+        let res_segment_end = res_segment.ptr;
+        vm.insert_value(cell_ref_to_relocatable(output_start, vm), res_segment_start)?;
+        vm.insert_value(cell_ref_to_relocatable(output_end, vm), res_segment_end)?;
+        Ok(())
+    }
+
+    fn compile_hint(
+        &self,
+        hint_code: &str,
+        ap_tracking_data: &ApTracking,
+        reference_ids: &HashMap<String, usize>,
+        references: &[HintReference],
+    ) -> Result<Box<dyn Any>, VirtualMachineError> {
+        self.inner.compile_hint(hint_code, ap_tracking_data, reference_ids, references)
+    }
+}
+
+impl ResourceTracker for SnapshotHintProcessor<'_> {
+    fn consumed(&self) -> bool {
+        self.inner.consumed()
+    }
+
+    fn consume_step(&mut self) {
+        self.inner.consume_step()
+    }
+
+    fn get_n_steps(&self) -> Option<usize> {
+        self.inner.get_n_steps()
+    }
+
+    fn run_resources(&self) -> &RunResources {
+        self.inner.run_resources()
+    }
+}
+
+impl StarknetHintProcessor for SnapshotHintProcessor<'_> {
+    fn take_starknet_state(&mut self) -> StarknetState {
+        self.inner.take_starknet_state()
+    }
+
+    fn take_syscalls_used_resources(&mut self) -> StarknetExecutionResources {
+        self.inner.take_syscalls_used_resources()
+    }
+}
+
+/// Deserializes a `ByteArray` from the given felts, decoding invalid UTF-8 lossily.
+fn deserialize_byte_array<'a>(felts: &mut impl Iterator<Item = &'a Felt252>) -> Option<String> {
+    let num_full_words = felts.next()?.to_usize()?;
+    let mut bytes = vec![];
+    for _ in 0..num_full_words {
+        bytes.extend(word_bytes(felts.next()?, BYTES_IN_WORD)?);
+    }
+    let pending_word = felts.next()?;
+    let pending_word_len = felts.next()?.to_usize()?;
+    if pending_word_len >= BYTES_IN_WORD {
+        return None;
+    }
+    bytes.extend(word_bytes(pending_word, pending_word_len)?);
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Returns the `len` low bytes of a word, big-endian, if it has no higher bytes.
+fn word_bytes(word: &Felt252, len: usize) -> Option<Vec<u8>> {
+    let bytes = word.to_bytes_be();
+    let (high, low) = bytes.split_at(bytes.len() - len);
+    high.iter().all(|byte| *byte == 0).then(|| low.to_vec())
+}
+
+/// Serializes a string as a `ByteArray`.
+fn serialize_byte_array(value: &str) -> Vec<Felt252> {
+    let chunks = value.as_bytes().chunks_exact(BYTES_IN_WORD);
+    let pending_word = chunks.remainder();
+    let words = chunks.map(Felt252::from_bytes_be_slice).collect::<Vec<_>>();
+    chain!(
+        [Felt252::from(words.len())],
+        words,
+        [Felt252::from_bytes_be_slice(pending_word), Felt252::from(pending_word.len())]
+    )
+    .collect()
+}
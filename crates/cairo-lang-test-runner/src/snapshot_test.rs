@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::PathBuf;
+
+use cairo_lang_runner::RunResultValue;
+use cairo_lang_runner::casm_run::format_for_panic;
+use cairo_lang_test_plugin::TestsCompilationConfig;
+use indoc::indoc;
+
+use super::{SnapshotConfig, SnapshotName, deserialize_byte_array, serialize_byte_array};
+use crate::test_utils::TempDir;
+use crate::{TestCompiler, TestRunConfig, run_tests};
+
+#[test]
+fn test_snapshot_path() {
+    let config = SnapshotConfig { dir: PathBuf::from("snapshots"), update: false };
+    let path = |snapshot| config.snapshot_path("crate::tests::test_a", &snapshot);
+    assert_eq!(
+        path(SnapshotName::Unnamed(1)),
+        PathBuf::from("snapshots/crate__tests__test_a.snap")
+    );
+    assert_eq!(
+        path(SnapshotName::Unnamed(2)),
+        PathBuf::from("snapshots/crate__tests__test_a-2.snap")
+    );
+    assert_eq!(
+        path(SnapshotName::Named("value")),
+        PathBuf::from("snapshots/crate__tests__test_a@value.snap")
+    );
+}
+
+#[test]
+fn test_byte_array_serialization() {
+    for value in ["", "short", "exactly 31 bytes long string!!!", "a longer string, with\nlines."] {
+        let felts = serialize_byte_array(value);
+        let mut iter = felts.iter();
+        assert_eq!(deserialize_byte_array(&mut iter).as_deref(), Some(value));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[test]
+fn test_check_and_update() {
+    let dir = TempDir::new("cairo_snapshot_test");
+    let path = dir.path().join("test.snap");
+    let check = SnapshotConfig { dir: dir.path().to_path_buf(), update: false };
+    let update = SnapshotConfig { dir: dir.path().to_path_buf(), update: true };
+
+    let missing = check.check(&path, "value").unwrap().unwrap();
+    assert!(missing.contains("does not exist"), "{missing}");
+    assert_eq!(update.check(&path, "value").unwrap(), None);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "value\n");
+    assert_eq!(check.check(&path, "value").unwrap(), None);
+    assert_eq!(
+        check.check(&path, "other").unwrap().unwrap(),
+        format!(
+            indoc! {"
+                Snapshot `{}` does not match. Run with `--update-snapshots` to update it.
+                --- snapshot
+                +++ actual
+                @@ -1 +1 @@
+                -value
+                +other
+            "},
+            path.display()
+        )
+    );
+}
+
+#[test]
+fn test_run_snapshot_tests() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/snapshot");
+    let compiler =
+        TestCompiler::try_new(&path, false, true, TestsCompilationConfig::default()).unwrap();
+    let config = TestRunConfig {
+        snapshots: Some(SnapshotConfig { dir: path.join("snapshots"), update: false }),
+        ..Default::default()
+    };
+    let summary = run_tests(None, compiler.build().unwrap(), &config, None).unwrap();
+    assert_eq!(summary.passed, ["snapshot::test_matching"]);
+    assert_eq!(summary.failed, ["snapshot::test_mismatching"]);
+    let [(Ok(RunResultValue::Panic(panic_data)), None)] = summary.failed_run_results.as_slice()
+    else {
+        panic!("Expected the mismatching test to panic.");
+    };
+    let message = format_for_panic(panic_data.iter().cloned());
+    assert!(message.contains("-Point { x: 1, y: 2 }\n+Point { x: 1, y: 3 }"), "{message}");
+}
@@ -6,7 +6,9 @@ use cairo_lang_utils::byte_array::BYTE_ARRAY_MAGIC;
 use itertools::Itertools;
 use starknet_types_core::felt::Felt as Felt252;
 
-use crate::{TestCompilation, TestCompiler, filter_test_cases};
+use crate::contract_classes::ContractClassSource;
+use crate::test_utils::TempDir;
+use crate::{TestCompilation, TestCompiler, TestRunConfig, filter_test_cases, run_tests};
 
 #[test]
 fn test_compiled_serialization() {
@@ -17,14 +19,7 @@ fn test_compiled_serialization() {
         &path,
         true,
         false,
-        TestsCompilationConfig {
-            starknet: true,
-            add_statements_functions: false,
-            add_statements_code_locations: false,
-            contract_declarations: None,
-            contract_crate_ids: None,
-            executable_crate_ids: None,
-        },
+        TestsCompilationConfig { starknet: true, ..Default::default() },
     )
     .unwrap();
     let compiled = compiler.build().unwrap();
@@ -234,7 +229,14 @@ fn test_format_for_panic() {
 fn to_named_test(test: &(&str, bool)) -> (String, TestConfig) {
     (
         String::from(test.0),
-        TestConfig { available_gas: None, expectation: TestExpectation::Success, ignored: test.1 },
+        TestConfig {
+            available_gas: None,
+            expectation: TestExpectation::Success,
+            ignored: test.1,
+            fuzz: None,
+            uses_fixtures: false,
+            module_fixture: None,
+        },
     )
 }
 
@@ -307,3 +309,205 @@ fn test_filter_test_cases_include_ignored_and_ignored() {
         (to_test_compilation(&[("test1", false), ("test2", false), ("test3", false)]), 0)
     );
 }
+
+#[test]
+fn test_run_test_cases() {
+    use std::path::PathBuf;
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/test_case");
+
+    let compiler =
+        TestCompiler::try_new(&path, false, true, TestsCompilationConfig::default()).unwrap();
+    let config = TestRunConfig::default();
+    let summary = run_tests(None, compiler.build().unwrap(), &config, None).unwrap();
+    assert_eq!(
+        summary.passed.iter().sorted().collect_vec(),
+        [
+            "test_case::test_add_case_1",
+            "test_case::test_add_overflow_case_1",
+            "test_case::test_add_twos",
+            "test_case::test_sum_empty",
+            "test_case::test_sum_three",
+        ]
+    );
+    assert_eq!(summary.failed, ["test_case::test_add_case_3"]);
+}
+
+#[test]
+fn test_run_fixtures() {
+    use std::path::PathBuf;
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/fixture");
+
+    let compiler =
+        TestCompiler::try_new(&path, false, true, TestsCompilationConfig::default()).unwrap();
+    let config = TestRunConfig::default();
+    let summary = run_tests(None, compiler.build().unwrap(), &config, None).unwrap();
+    assert_eq!(
+        summary.passed.iter().sorted().collect_vec(),
+        [
+            "fixture::test_module_and_test_fixtures",
+            "fixture::test_module_fixture",
+            "fixture::test_test_fixture",
+        ]
+    );
+    assert_eq!(summary.failed, ["fixture::test_failing_fixture"]);
+    let [(Err(err), None)] = summary.failed_run_results.as_slice() else {
+        panic!("Expected the test of the failing fixture to fail to run.");
+    };
+    assert_eq!(
+        err.to_string(),
+        "Fixture `fixture::__fixture_value__failing` failed: Panicked with \"Setup failed.\"."
+    );
+}
+
+#[test]
+fn test_run_with_contract_classes() {
+    use std::path::PathBuf;
+    let contract_classes_path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/contract_classes");
+    let source = ContractClassSource::Crate(contract_classes_path);
+    let [(_, class_hash)] = source.load().unwrap().try_into().unwrap();
+
+    // The tests refer to the class by its hash, which depends on the compiler version.
+    let dir = TempDir::new("cairo_test_runner_contract_classes_test");
+    let path = dir.path();
+    std::fs::write(path.join("cairo_project.toml"), "[crate_roots]\ntests = \".\"\n").unwrap();
+    std::fs::write(
+        path.join("lib.cairo"),
+        indoc::formatdoc! {r#"
+            use starknet::ContractAddress;
+            use starknet::syscalls::deploy_syscall;
+
+            #[starknet::interface]
+            trait IForwarder<T> {{
+                fn get_value(self: @T) -> felt252;
+                fn forward_get_value(self: @T, target: ContractAddress) -> felt252;
+                fn fail(self: @T);
+            }}
+
+            #[starknet::contract]
+            mod value {{
+                #[storage]
+                struct Storage {{}}
+
+                #[external(v0)]
+                fn get_value(self: @ContractState) -> felt252 {{
+                    42
+                }}
+            }}
+
+            fn deploy_forwarder() -> IForwarderDispatcher {{
+                let class_hash = {class_hash:#x}.try_into().unwrap();
+                let (contract_address, _) = deploy_syscall(class_hash, 0, [7].span(), false)
+                    .unwrap();
+                IForwarderDispatcher {{ contract_address }}
+            }}
+
+            #[test]
+            fn test_deploy() {{
+                assert_eq!(deploy_forwarder().get_value(), 7);
+            }}
+
+            #[test]
+            fn test_call_into_tested_program() {{
+                let (target, _) = deploy_syscall(value::TEST_CLASS_HASH, 0, [].span(), false)
+                    .unwrap();
+                assert_eq!(deploy_forwarder().forward_get_value(target), 42);
+            }}
+
+            #[test]
+            #[should_panic(expected: ('Forwarder failed', 'ENTRYPOINT_FAILED'))]
+            fn test_failure() {{
+                deploy_forwarder().fail();
+            }}
+
+            #[test]
+            fn test_unknown_class() {{
+                assert!(deploy_syscall(0x1234.try_into().unwrap(), 0, [].span(), false).is_err());
+            }}
+        "#},
+    )
+    .unwrap();
+
+    let compiler = TestCompiler::try_new(
+        path,
+        false,
+        true,
+        TestsCompilationConfig { starknet: true, ..Default::default() },
+    )
+    .unwrap();
+    let config = TestRunConfig { contract_classes: vec![source], ..Default::default() };
+    let summary = run_tests(None, compiler.build().unwrap(), &config, None).unwrap();
+    assert_eq!(
+        summary.passed.iter().sorted().collect_vec(),
+        [
+            "tests::test_call_into_tested_program",
+            "tests::test_deploy",
+            "tests::test_failure",
+            "tests::test_unknown_class",
+        ]
+    );
+    assert!(summary.failed.is_empty());
+}
+
+#[test]
+fn test_contract_class_file_with_declared_abi() {
+    use std::path::PathBuf;
+    let contract_classes_path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/contract_classes");
+    let [(contract_class, _)] =
+        ContractClassSource::Crate(contract_classes_path).load().unwrap().try_into().unwrap();
+
+    // Classes returned by Starknet nodes hold the ABI as the string they were declared with.
+    let declared_abi = serde_json::to_string_pretty(&contract_class.abi).unwrap();
+    let mut class_json = serde_json::to_value(&contract_class).unwrap();
+    class_json["abi"] = serde_json::Value::String(declared_abi.clone());
+    let dir = TempDir::new("cairo_test_runner_declared_abi_test");
+    let class_path = dir.path().join("class.contract_class.json");
+    std::fs::write(&class_path, class_json.to_string()).unwrap();
+
+    let [(loaded_class, class_hash)] =
+        ContractClassSource::File(class_path).load().unwrap().try_into().unwrap();
+    assert_eq!(loaded_class.abi, contract_class.abi);
+    assert_eq!(class_hash, contract_class.class_hash_with_abi(&declared_abi));
+    assert_ne!(class_hash, contract_class.class_hash());
+}
+
+#[test]
+fn test_run_with_state_in_and_out() {
+    use std::path::PathBuf;
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data/state");
+    let dir = TempDir::new("cairo_test_runner_state_test");
+    let state_dir = dir.path();
+
+    let run = |filter: &str, state_in: Option<PathBuf>| {
+        let compiler = TestCompiler::try_new(
+            &path,
+            false,
+            true,
+            TestsCompilationConfig { starknet: true, ..Default::default() },
+        )
+        .unwrap();
+        let config = TestRunConfig {
+            state_in,
+            state_out_dir: Some(state_dir.to_path_buf()),
+            ..Default::default()
+        };
+        let (compiled, _) = filter_test_cases(compiler.build().unwrap(), false, false, filter);
+        run_tests(None, compiled, &config, None).unwrap()
+    };
+
+    // Without the stored values, loading fails.
+    let summary = run("test_load", None);
+    assert_eq!(summary.failed, ["state::test_load"]);
+    assert!(!state_dir.join("state__test_load.json").exists());
+
+    let summary = run("test_store", None);
+    assert_eq!(summary.passed, ["state::test_store"]);
+    let stored_state = state_dir.join("state__test_store.json");
+    assert!(stored_state.exists());
+
+    // Starting from the state the storing test ended in, loading passes.
+    let summary = run("test_load", Some(stored_state));
+    assert_eq!(summary.passed, ["state::test_load"]);
+    assert!(state_dir.join("state__test_load.json").exists());
+}
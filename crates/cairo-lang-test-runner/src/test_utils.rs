@@ -0,0 +1,24 @@
+use std::path::{Path, PathBuf};
+
+/// A directory under the system temporary directory, removed when dropped - so it is cleaned up
+/// even if the test using it fails.
+pub struct TempDir(PathBuf);
+impl TempDir {
+    /// Creates an empty directory, named by `name` and the id of the current process.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    /// Returns the path of the directory.
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
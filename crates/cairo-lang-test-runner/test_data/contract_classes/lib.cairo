@@ -0,0 +1,43 @@
+#[starknet::interface]
+pub trait IValue<T> {
+    fn get_value(self: @T) -> felt252;
+}
+
+#[starknet::interface]
+pub trait IForwarder<T> {
+    fn get_value(self: @T) -> felt252;
+    fn forward_get_value(self: @T, target: starknet::ContractAddress) -> felt252;
+    fn fail(self: @T);
+}
+
+#[starknet::contract]
+mod forwarder {
+    use starknet::ContractAddress;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+    use super::{IValueDispatcher, IValueDispatcherTrait};
+
+    #[storage]
+    struct Storage {
+        value: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, value: felt252) {
+        self.value.write(value);
+    }
+
+    #[abi(embed_v0)]
+    impl ForwarderImpl of super::IForwarder<ContractState> {
+        fn get_value(self: @ContractState) -> felt252 {
+            self.value.read()
+        }
+
+        fn forward_get_value(self: @ContractState, target: ContractAddress) -> felt252 {
+            IValueDispatcher { contract_address: target }.get_value()
+        }
+
+        fn fail(self: @ContractState) {
+            core::panic_with_felt252('Forwarder failed');
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#[inline(always)]
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn classify(x: u32) -> felt252 {
+    if x > 10 {
+        'big'
+    } else {
+        'small'
+    }
+}
+
+#[test]
+fn test_double() {
+    let values = array![3, 20];
+    assert_eq!(double(*values[0]), 6);
+    assert_eq!(classify(*values[1]), 'big');
+}
@@ -0,0 +1,62 @@
+use starknet::storage_access::{
+    StorageAddress, storage_address_from_base, storage_base_address_const,
+};
+use starknet::syscalls::{storage_read_syscall, storage_write_syscall};
+use starknet::{SyscallResultTrait, get_block_number};
+
+fn address() -> StorageAddress {
+    storage_address_from_base(storage_base_address_const::<1>())
+}
+
+#[derive(Drop, Serde)]
+struct Setup {
+    owner: felt252,
+    values: Array<u32>,
+}
+
+#[fixture(scope: module)]
+fn setup() -> Setup {
+    starknet::testing::set_block_number(10);
+    storage_write_syscall(0, address(), 42).unwrap_syscall();
+    Setup { owner: 'owner', values: array![1, 2, 3] }
+}
+
+#[fixture]
+fn counter() -> u32 {
+    storage_write_syscall(0, address(), 5).unwrap_syscall();
+    5
+}
+
+#[fixture(scope: module)]
+fn failing() -> u32 {
+    panic!("Setup failed.")
+}
+
+#[test]
+fn test_module_fixture(setup: Setup) {
+    assert_eq!(setup.owner, 'owner');
+    assert_eq!(setup.values, array![1, 2, 3]);
+    assert_eq!(get_block_number(), 10);
+    assert_eq!(storage_read_syscall(0, address()).unwrap_syscall(), 42);
+}
+
+#[test]
+fn test_module_and_test_fixtures(setup: Setup, mut counter: u32) {
+    counter += 1;
+    assert_eq!(counter, 6);
+    assert_eq!(setup.values.len(), 3);
+    assert_eq!(get_block_number(), 10);
+    // The test-scoped fixture runs after the state of the module-scoped fixture is restored.
+    assert_eq!(storage_read_syscall(0, address()).unwrap_syscall(), 5);
+}
+
+#[test]
+fn test_test_fixture(counter: u32) {
+    assert_eq!(counter, 5);
+    assert_eq!(get_block_number(), 0);
+}
+
+#[test]
+fn test_failing_fixture(failing: u32) {
+    assert_eq!(failing, 0);
+}
@@ -0,0 +1,21 @@
+#[derive(Drop)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[test]
+#[fuzz(runs: 50, seed: 1)]
+fn test_passing(a: u64, b: u64) {
+    let a: u128 = a.into();
+    let b: u128 = b.into();
+    assert_eq!(a + b, b + a);
+}
+
+#[test]
+#[fuzz(seed: 1)]
+fn test_failing(values: Array<u32>, p: Point, flag: bool, s: ByteArray) {
+    if values.len() > 2 && p.x > 100 && s.len() > 3 {
+        panic!("found bug");
+    }
+}
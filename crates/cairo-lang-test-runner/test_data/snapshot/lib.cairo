@@ -0,0 +1,17 @@
+#[derive(Drop, Debug)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[test]
+fn test_matching() {
+    assert_snapshot!(Point { x: 1, y: 2 });
+    assert_snapshot!(array![1, 2, 3]);
+    assert_snapshot!(Point { x: 3, y: 4 }, "named");
+}
+
+#[test]
+fn test_mismatching() {
+    assert_snapshot!(Point { x: 1, y: 3 });
+}
@@ -0,0 +1,11 @@
+use starknet::testing::{load, store};
+
+#[test]
+fn test_store() {
+    store(0x10.try_into().unwrap(), 0x20, [5, 6].span());
+}
+
+#[test]
+fn test_load() {
+    assert_eq!(load(0x10.try_into().unwrap(), 0x20, 2), [5, 6].span());
+}
@@ -0,0 +1,22 @@
+#[test_case(1, 2, 3)]
+#[test_case(2, 2, 4, name: "twos")]
+#[test_case(1, 1, 3)]
+fn test_add(a: u32, b: u32, expected: u32) {
+    assert_eq!(a + b, expected);
+}
+
+#[test_case(0xffffffff, 1)]
+#[should_panic]
+fn test_add_overflow(a: u32, b: u32) {
+    let _ = a + b;
+}
+
+#[test_case(array![1, 2, 3], 6, name: "three")]
+#[test_case(array![], 0, name: "empty")]
+fn test_sum(values: Array<felt252>, expected: felt252) {
+    let mut sum = 0;
+    for value in values {
+        sum += value;
+    }
+    assert_eq!(sum, expected);
+}
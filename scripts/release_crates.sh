@@ -47,6 +47,7 @@ CRATES_TO_PUBLISH=(
     cairo-lang-execute-utils
     cairo-lang-doc
     cairo-compile
+    cairo-fix
     cairo-format
     cairo-run
     cairo-execute